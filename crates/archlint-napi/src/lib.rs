@@ -7,7 +7,7 @@ use std::sync::{Arc, Mutex};
 mod types;
 use types::{
     JsConfig, JsDetectorInfo, JsDiffOptions, JsDiffResult, JsIncrementalResult, JsScanOptions,
-    JsScanResult, JsStateStats,
+    JsScanResult, JsStateStats, JsSymbolUsages,
 };
 
 #[napi]
@@ -29,6 +29,7 @@ pub fn run_diff(options: JsDiffOptions) -> Result<JsDiffResult> {
             .map_err(|e| Error::from_reason(e.to_string()))?;
 
         archlint::snapshot::SnapshotGenerator::new(PathBuf::from(&options.project))
+            .with_debt_model(archlint::debt::DebtModel::new(&analyzer.config().debt))
             .generate(&scan_result)
     };
 
@@ -239,6 +240,14 @@ impl ArchlintAnalyzer {
             .collect()
     }
 
+    #[napi]
+    pub fn get_symbol_usages(&self, file: String, name: String) -> Option<JsSymbolUsages> {
+        let analyzer = self.inner.lock().unwrap();
+        analyzer
+            .get_symbol_usages(Path::new(&file), &name)
+            .map(Into::into)
+    }
+
     #[napi]
     #[must_use]
     pub fn get_state_stats(&self) -> JsStateStats {
@@ -526,6 +526,50 @@ impl From<archlint::StateStats> for JsStateStats {
     }
 }
 
+// ============ Symbol Usages ============
+
+#[napi(object)]
+pub struct JsSymbolRef {
+    pub file: String,
+    pub name: String,
+    pub kind: String,
+    pub line: u32,
+}
+
+impl From<archlint::SymbolRef> for JsSymbolRef {
+    fn from(s: archlint::SymbolRef) -> Self {
+        Self {
+            file: s.file.to_string_lossy().to_string(),
+            name: s.name,
+            kind: format!("{:?}", s.kind).to_lowercase(),
+            line: s.line.to_js_u32(),
+        }
+    }
+}
+
+#[napi(object)]
+pub struct JsSymbolUsages {
+    pub symbol: JsSymbolRef,
+    /// Exported symbols referring to the symbol
+    pub used_by: Vec<JsSymbolRef>,
+    /// Files using the symbol outside of their exported declarations
+    pub used_in_files: Vec<String>,
+}
+
+impl From<archlint::SymbolUsages> for JsSymbolUsages {
+    fn from(u: archlint::SymbolUsages) -> Self {
+        Self {
+            symbol: u.symbol.into(),
+            used_by: u.used_by.into_iter().map(Into::into).collect(),
+            used_in_files: u
+                .used_in_files
+                .into_iter()
+                .map(|p| p.to_string_lossy().to_string())
+                .collect(),
+        }
+    }
+}
+
 // ============ Diff ============
 
 #[napi(object)]
@@ -543,6 +587,16 @@ pub struct JsDiffResult {
     pub summary: JsDiffSummary,
     pub baseline_commit: Option<String>,
     pub current_commit: Option<String>,
+    pub debt: Option<JsDebtDiff>,
+}
+
+#[napi(object)]
+pub struct JsDebtDiff {
+    pub baseline: f64,
+    pub current: f64,
+    pub added: f64,
+    pub removed: f64,
+    pub net: f64,
 }
 
 #[napi(object)]
@@ -621,6 +675,19 @@ impl From<archlint::diff::DiffResult> for JsDiffResult {
             summary: res.summary.into(),
             baseline_commit: res.baseline_commit,
             current_commit: res.current_commit,
+            debt: res.debt.map(Into::into),
+        }
+    }
+}
+
+impl From<archlint::diff::DebtDiff> for JsDebtDiff {
+    fn from(d: archlint::diff::DebtDiff) -> Self {
+        Self {
+            baseline: d.baseline,
+            current: d.current,
+            added: d.added,
+            removed: d.removed,
+            net: d.net,
         }
     }
 }
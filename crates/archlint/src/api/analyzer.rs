@@ -1,14 +1,18 @@
 use crate::api::options::ScanOptions;
 use crate::api::result::{IncrementalResult, ScanResult, SmellWithExplanation};
+use crate::api::symbols::{SymbolRef, SymbolUsages};
 use crate::args::ScanArgs;
 use crate::config::Config;
-use crate::detectors::DetectorRegistry;
+use crate::detectors::{ArchSmell, DetectorRegistry};
 use crate::engine::context::AnalysisContext;
+use crate::engine::suppressions::apply_suppressions;
 use crate::engine::AnalysisEngine;
 use crate::error::Result;
 use crate::incremental::IncrementalState;
 use crate::parser::{FileIgnoredLines, ImportParser, ParserConfig};
-use crate::resolver::PathResolver;
+use crate::report::{AnalysisReportBuilder, Suppression};
+use crate::resolver::{ImportDiagnostics, PathResolver, ResolutionStats};
+use crate::symbol_graph::SymbolGraph;
 use sha2::{Digest, Sha256};
 use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
@@ -62,7 +66,9 @@ impl Analyzer {
     ///
     /// This should be called at least once to initialize the analyzer state.
     pub fn scan(&mut self) -> Result<ScanResult> {
-        let engine = AnalysisEngine::new(self.args.clone(), self.config.clone())?;
+        let mut engine = AnalysisEngine::new(self.args.clone(), self.config.clone())?;
+        // `symbol_graph` queries need the usages of every export
+        engine.collect_symbol_usages = true;
         let report = engine.run()?;
 
         // Initialize state from report
@@ -71,8 +77,18 @@ impl Analyzer {
         self.state.file_metrics = Arc::new(report.file_metrics.clone());
         self.state.function_complexity = Arc::new(report.function_complexity.clone());
         self.state.ignored_lines = Arc::new(report.ignored_lines.clone());
+        let resolver = PathResolver::new(&self.project_root, &self.config);
+        self.state.import_diagnostics = Arc::new(ImportDiagnostics::collect(
+            &self.state.file_symbols,
+            &resolver,
+        ));
         self.state.churn_map = report.churn_map.clone();
         self.state.presets = report.presets.clone();
+        self.state.suppressed = report
+            .suppressed
+            .iter()
+            .map(|s| (s.smell.clone(), s.suppression.clone()))
+            .collect();
         self.state.last_full_scan = Some(Instant::now());
 
         // Update framework and project info from engine/context if possible
@@ -149,6 +165,7 @@ impl Analyzer {
             function_complexity: Arc::clone(&self.state.function_complexity),
             file_metrics: Arc::clone(&self.state.file_metrics),
             ignored_lines: Arc::clone(&self.state.ignored_lines),
+            import_diagnostics: Arc::clone(&self.state.import_diagnostics),
             churn_map: self.state.churn_map.clone(),
             config: self.config.clone(),
             script_entry_points: self.state.script_entry_points.clone(),
@@ -175,7 +192,7 @@ impl Analyzer {
         }
     }
 
-    /// Run detectors and filter results to affected files
+    /// Run detectors, apply suppressions and filter results to affected files
     fn run_detectors_incremental(
         &mut self,
         enabled_detectors: &[(String, Box<dyn crate::detectors::Detector>)],
@@ -198,16 +215,31 @@ impl Analyzer {
                 self.cache_file_local_smells(detector_id, &smells);
             }
 
-            // Filter to affected files
-            for smell in smells {
-                if smell.files.iter().any(|f| affected.contains(f)) {
-                    let explanation = crate::explain::ExplainEngine::explain(&smell, &ctx.config);
-                    all_smells.push(SmellWithExplanation { smell, explanation });
-                }
-            }
+            all_smells.extend(smells);
         }
 
-        all_smells
+        // Every smell is needed to tell which comments are unused
+        let active_ids: HashSet<String> =
+            enabled_detectors.iter().map(|(id, _)| id.clone()).collect();
+        let pass = apply_suppressions(ctx, all_smells, &active_ids);
+        let is_affected = |smell: &ArchSmell| smell.files.iter().any(|f| affected.contains(f));
+        self.state
+            .suppressed
+            .retain(|(smell, _)| !is_affected(smell));
+        self.state.suppressed.extend(
+            pass.suppressed
+                .into_iter()
+                .filter(|(smell, _)| is_affected(smell)),
+        );
+
+        pass.smells
+            .into_iter()
+            .filter(is_affected)
+            .map(|smell| {
+                let explanation = crate::explain::ExplainEngine::explain(&smell, &ctx.config);
+                SmellWithExplanation { smell, explanation }
+            })
+            .collect()
     }
 
     /// Perform an incremental scan using file content overlays.
@@ -229,7 +261,11 @@ impl Analyzer {
     ) -> ParserConfig {
         let active_ids: HashSet<String> =
             enabled_detectors.iter().map(|(id, _)| id.clone()).collect();
-        ParserConfig::from_active_detectors(&active_ids)
+        // `symbol_graph` queries need the usages of every export
+        ParserConfig {
+            collect_used_symbols: true,
+            ..ParserConfig::from_active_detectors(&active_ids, &self.config)
+        }
     }
 
     /// Core incremental scan logic shared by both `scan_incremental` and `scan_incremental_with_overlays`
@@ -269,6 +305,10 @@ impl Analyzer {
             self.state
                 .update_files(&changed, &parser, &parser_config, &resolver)?;
         }
+        self.state.import_diagnostics = Arc::new(ImportDiagnostics::collect(
+            &self.state.file_symbols,
+            &resolver,
+        ));
 
         // 3. Get affected files
         let affected = self.state.get_affected_files(&changed);
@@ -311,8 +351,15 @@ impl Analyzer {
             self.state.file_metrics_mut().remove(file);
             self.state.function_complexity_mut().remove(file);
             Arc::<FileIgnoredLines>::make_mut(&mut self.state.ignored_lines).remove(file);
+            // Stats are recounted by the next incremental scan
+            Arc::make_mut(&mut self.state.import_diagnostics)
+                .unresolved
+                .retain(|import| &import.file != file);
             self.state.file_hashes.remove(file);
             self.state.reverse_deps.remove(file);
+            self.state
+                .suppressed
+                .retain(|(smell, _)| !smell.files.contains(file));
 
             // Remove from reverse_deps of other files
             for importers in self.state.reverse_deps.values_mut() {
@@ -339,6 +386,124 @@ impl Analyzer {
         self.state.get_affected_files(changed).into_iter().collect()
     }
 
+    /// Files imported directly by `file`.
+    #[must_use]
+    pub fn get_dependencies(&self, file: &Path) -> Vec<PathBuf> {
+        let graph = &self.state.graph;
+        let Some(node) = graph.get_node(file) else {
+            return Vec::new();
+        };
+        let mut dependencies: Vec<PathBuf> = graph
+            .dependencies(node)
+            .filter_map(|dep| graph.get_file_path(dep).cloned())
+            .collect();
+        dependencies.sort();
+        dependencies
+    }
+
+    /// Files importing `file` directly.
+    #[must_use]
+    pub fn get_dependents(&self, file: &Path) -> Vec<PathBuf> {
+        let mut dependents: Vec<PathBuf> = self
+            .state
+            .reverse_deps
+            .get(file)
+            .map(|importers| importers.iter().cloned().collect())
+            .unwrap_or_default();
+        dependents.sort();
+        dependents
+    }
+
+    /// Build a scan result for `smells` from the current state.
+    ///
+    /// Lets callers merge the smells of incremental scans into the last full
+    /// result, and get the summary and grade a full scan would report.
+    /// `smells` are expected to have inline suppressions applied, as the
+    /// results of scans are; the suppressed smells come from the state.
+    pub fn build_result(&self, smells: Vec<ArchSmell>) -> Result<ScanResult> {
+        let mut report = AnalysisReportBuilder::new()
+            .with_smells(smells)
+            .with_suppressed(self.state.suppressed.clone())
+            .with_graph(Some((*self.state.graph).clone()))
+            .with_symbols((*self.state.file_symbols).clone())
+            .with_metrics((*self.state.file_metrics).clone())
+            .with_complexity((*self.state.function_complexity).clone())
+            .with_ignored_lines((*self.state.ignored_lines).clone())
+            .with_churn(self.state.churn_map.clone())
+            .with_presets(self.state.presets.clone())
+            .with_config(self.config.clone())
+            .with_files_analyzed(self.state.file_symbols.len())
+            .with_import_resolution(self.state.import_diagnostics.stats)
+            .build();
+        report.apply_severity_config(&self.config.scoring);
+        report.compute_debt(&self.project_root);
+        report.evaluate_gates(&self.project_root);
+
+        let files = crate::api::build_file_info(&report, &self.project_root)?;
+        Ok(ScanResult::from_report(
+            report,
+            files,
+            &self.project_root,
+            &self.config.scoring,
+        ))
+    }
+
+    /// Build the graph of exported symbols from the last scan.
+    ///
+    /// References between symbols are collected while `cyclic_dependency`,
+    /// `dead_symbols`, `lcom` or `scattered_module` is enabled.
+    #[must_use]
+    pub fn symbol_graph(&self) -> SymbolGraph {
+        SymbolGraph::build(&self.state.file_symbols)
+    }
+
+    /// Symbols and files using the symbol `name` declared in `file`.
+    #[must_use]
+    pub fn get_symbol_usages(&self, file: &Path, name: &str) -> Option<SymbolUsages> {
+        let graph = self.symbol_graph();
+        let id = graph.find(file, name)?;
+        let mut used_by: Vec<SymbolRef> = graph
+            .dependents(id)
+            .map(|user| SymbolRef::new(&graph, user))
+            .collect();
+        used_by.sort_by(|a, b| (&a.file, a.line).cmp(&(&b.file, b.line)));
+
+        Some(SymbolUsages {
+            symbol: SymbolRef::new(&graph, id),
+            used_by,
+            used_in_files: graph.file_users(id).cloned().collect(),
+        })
+    }
+
+    /// Smells hidden by inline comments, kept when `suppressions.include_suppressed` is set.
+    #[must_use]
+    pub fn suppressed(&self) -> &[(ArchSmell, Suppression)] {
+        &self.state.suppressed
+    }
+
+    /// Share of internal imports resolved to a file.
+    #[must_use]
+    pub fn import_resolution(&self) -> ResolutionStats {
+        self.state.import_diagnostics.stats
+    }
+
+    /// Files tracked since the last scan.
+    pub fn files(&self) -> impl Iterator<Item = &PathBuf> + '_ {
+        self.state.file_symbols.keys()
+    }
+
+    /// Root of the analyzed project.
+    #[must_use]
+    pub fn project_root(&self) -> &Path {
+        &self.project_root
+    }
+
+    /// The configuration this analyzer was created with.
+    #[must_use]
+    pub const fn config(&self) -> &Config {
+        &self.config
+    }
+
     /// Get statistics about the current internal state of the analyzer.
     #[must_use]
     pub fn get_state_stats(&self) -> StateStats {
@@ -427,4 +592,24 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_get_symbol_usages() -> Result<()> {
+        let (_dir, project_path, a_ts, b_ts) = setup_test_project()?;
+        let mut analyzer = Analyzer::new(&project_path, ScanOptions::default())?;
+        analyzer.scan()?;
+
+        let usages = analyzer
+            .get_symbol_usages(&a_ts, "a")
+            .expect("a is exported");
+        assert_eq!(usages.symbol.name, "a");
+        assert_eq!(usages.used_by.len(), 1);
+        assert_eq!(usages.used_by[0].name, "b");
+        assert_eq!(usages.used_by[0].file, b_ts);
+        assert!(usages.used_in_files.is_empty());
+
+        assert!(analyzer.get_symbol_usages(&a_ts, "missing").is_none());
+
+        Ok(())
+    }
 }
@@ -68,6 +68,21 @@ pub enum ExportKind {
     Reexport,
 }
 
+impl From<&crate::parser::SymbolKind> for ExportKind {
+    fn from(kind: &crate::parser::SymbolKind) -> Self {
+        match kind {
+            crate::parser::SymbolKind::Function => Self::Function,
+            crate::parser::SymbolKind::Class => Self::Class,
+            crate::parser::SymbolKind::Type => Self::Type,
+            crate::parser::SymbolKind::Interface => Self::Interface,
+            crate::parser::SymbolKind::Enum => Self::Enum,
+            crate::parser::SymbolKind::Variable | crate::parser::SymbolKind::Unknown => {
+                Self::Variable
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FileMetrics {
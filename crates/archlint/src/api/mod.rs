@@ -12,11 +12,13 @@ pub mod analyzer;
 pub mod file_info;
 pub mod options;
 pub mod result;
+pub mod symbols;
 
 pub use analyzer::{Analyzer, StateStats};
 pub use file_info::{ExportInfo, ExportKind, FileInfo, FileMetrics, ImportInfo};
 pub use options::ScanOptions;
 pub use result::{IncrementalResult, ScanResult, SmellWithExplanation, Summary};
+pub use symbols::{SymbolRef, SymbolUsages};
 
 /// Scan a project for architectural smells
 ///
@@ -136,15 +138,7 @@ pub(crate) fn build_file_info(
             .iter()
             .map(|e| ExportInfo {
                 name: e.name.to_string(),
-                kind: match e.kind {
-                    crate::parser::SymbolKind::Function => ExportKind::Function,
-                    crate::parser::SymbolKind::Class => ExportKind::Class,
-                    crate::parser::SymbolKind::Variable => ExportKind::Variable,
-                    crate::parser::SymbolKind::Type => ExportKind::Type,
-                    crate::parser::SymbolKind::Interface => ExportKind::Interface,
-                    crate::parser::SymbolKind::Enum => ExportKind::Enum,
-                    crate::parser::SymbolKind::Unknown => ExportKind::Variable,
-                },
+                kind: ExportKind::from(&e.kind),
                 is_default: e.is_default,
                 source: e.source.as_ref().map(std::string::ToString::to_string),
             })
@@ -0,0 +1,47 @@
+use super::file_info::ExportKind;
+use crate::symbol_graph::{SymbolGraph, SymbolId};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Reference to an exported declaration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SymbolRef {
+    /// File declaring the symbol
+    pub file: PathBuf,
+
+    /// Exported name
+    pub name: String,
+
+    /// Symbol kind
+    pub kind: ExportKind,
+
+    /// Line of the declaration
+    pub line: usize,
+}
+
+impl SymbolRef {
+    pub(crate) fn new(graph: &SymbolGraph, id: SymbolId) -> Self {
+        let symbol = graph.symbol(id);
+        Self {
+            file: symbol.file.clone(),
+            name: symbol.name.clone(),
+            kind: ExportKind::from(&symbol.kind),
+            line: symbol.line,
+        }
+    }
+}
+
+/// Everything that uses one exported symbol.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SymbolUsages {
+    /// The symbol itself
+    pub symbol: SymbolRef,
+
+    /// Exported symbols referring to it
+    pub used_by: Vec<SymbolRef>,
+
+    /// Files using it outside of their exported declarations
+    pub used_in_files: Vec<PathBuf>,
+}
@@ -1,4 +1,5 @@
 use crate::config::Config;
+use crate::parser::ParserConfig;
 use crate::Result;
 use sha2::{Digest, Sha256};
 use std::fs;
@@ -20,12 +21,21 @@ fn content_hash_bytes(bytes: &[u8]) -> String {
     format!("{:x}", hasher.finalize())
 }
 
+/// Hash of the parser options: parse results depend on them, not on the rest of the config.
 #[must_use]
-pub fn config_hash(config: &Config) -> String {
+pub fn parser_config_hash(config: &ParserConfig) -> String {
     let serialized = serde_json::to_string(config).unwrap_or_default();
-    let mut hasher = Sha256::new();
-    hasher.update(serialized.as_bytes());
-    format!("{:x}", hasher.finalize())
+    content_hash(&serialized)
+}
+
+/// Hash of the full config, equal across processes for the same config.
+#[must_use]
+pub fn config_hash(config: &Config) -> String {
+    // Going through `Value` sorts map keys, unlike serializing the `HashMap`s directly
+    let serialized = serde_json::to_value(config)
+        .map(|value| value.to_string())
+        .unwrap_or_default();
+    content_hash(&serialized)
 }
 
 #[must_use]
@@ -1,53 +1,114 @@
 use crate::config::Config;
-use crate::parser::ParsedFile;
-use crate::Result;
-use bincode::{
-    config,
-    serde::{decode_from_slice, encode_to_vec},
-};
+use crate::parser::{ParsedFile, ParserConfig};
+use crate::{AnalysisError, Result};
+use log::debug;
+use rustc_hash::FxHashSet;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
 pub mod hash;
-use self::hash::config_hash;
+mod storage;
+use self::hash::{get_git_head, parser_config_hash};
+use self::storage::{decode, encode, ParseStore, WriteBatch};
 
+/// Churn map with the git state it was computed for, by project-relative path.
 #[derive(Serialize, Deserialize)]
-pub struct CacheMeta {
-    pub version: String,
-    pub app_version: String,
-    pub config_hash: String,
-    pub git_head: Option<String>,
-    pub created_at: chrono::DateTime<chrono::Utc>,
+struct ChurnEntry {
+    key: String,
+    churn_map: HashMap<PathBuf, usize>,
 }
 
-#[derive(Serialize, Deserialize, Clone)]
-pub struct FileEntry {
-    pub content_hash: String,
-    pub parsed: ParsedFile,
+/// Hits and misses of the last run that saved the cache.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct RunStats {
+    pub hits: u64,
+    pub misses: u64,
 }
 
+impl RunStats {
+    /// Share of files read from the cache, or `None` when nothing was looked up.
+    #[must_use]
+    pub fn hit_ratio(&self) -> Option<f64> {
+        let total = self.hits + self.misses;
+        #[allow(clippy::cast_precision_loss)]
+        (total > 0).then(|| self.hits as f64 / total as f64)
+    }
+}
+
+/// Summary shown by `archlint cache stats`.
+#[derive(Debug, Clone)]
+pub struct CacheStats {
+    pub path: PathBuf,
+    /// Size of the database file in bytes.
+    pub size: u64,
+    /// Number of stored parse results.
+    pub entries: u64,
+    /// Number of project files pointing to a parse result.
+    pub files: u64,
+    pub last_run: Option<RunStats>,
+    /// Cache version that wrote the store.
+    pub version: Option<String>,
+    /// Whether another archlint version wrote the store; the next scan clears it.
+    pub is_outdated: bool,
+}
+
+/// Portable copy of a project cache, written by [`AnalysisCache::export`].
+///
+/// Holds only project-relative paths and content-addressed entries, so it can
+/// be imported into a checkout at another location.
 #[derive(Serialize, Deserialize)]
-pub struct CacheData {
-    pub meta: CacheMeta,
-    pub entries: HashMap<PathBuf, FileEntry>,
-    #[serde(default)]
-    pub churn_map: HashMap<PathBuf, usize>,
+struct CacheArchive {
+    version: String,
+    files: Vec<(String, String)>,
+    entries: Vec<(String, Vec<u8>)>,
+    churn: Option<ChurnEntry>,
 }
 
+/// Parse results stored per file content, shared by every run on the project.
+///
+/// Entries are keyed by content hash, file extension and parser config hash,
+/// so changing unrelated config keeps them valid, and each project-relative
+/// path points to the entry of its last parse. Nothing depends on where the
+/// project is checked out, which lets CI move the cache between runners with
+/// [`AnalysisCache::export`] and [`AnalysisCache::import`]. New entries are
+/// written on [`AnalysisCache::save`], and entries unused for
+/// [`Self::MAX_IDLE_SECS`] are removed then.
+///
+/// The store is only opened for the lookups of [`AnalysisCache::get_many`]
+/// and the writes of [`AnalysisCache::save`], so other archlint processes can
+/// use it while this one parses and analyzes the project.
 pub struct AnalysisCache {
-    cache_file: PathBuf,
-    data: CacheData,
-    is_dirty: bool,
+    store_path: PathBuf,
+    project_root: PathBuf,
+    parser_hash: String,
+    churn_key: Option<String>,
+    churn_map: HashMap<PathBuf, usize>,
+    is_churn_dirty: bool,
+    pending: HashMap<String, ParsedFile>,
+    /// Entry key of each file looked up or inserted in this run, by relative path.
+    files: Mutex<HashMap<String, String>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
 }
 
 impl AnalysisCache {
     const CACHE_DIR: &'static str = ".archlint-cache";
-    const CACHE_FILE: &'static str = "cache.bin";
-    const VERSION: &'static str = "7"; // v7: Added support for private field/method tracking (#PrivateFieldExpression)
+    const CACHE_FILE: &'static str = "cache.redb";
+    /// Single-file cache written by earlier versions.
+    const LEGACY_CACHE_FILE: &'static str = "cache.bin";
+    /// Bump whenever a cached type such as `FileSymbols` gains or changes a field.
+    const VERSION: &'static str = "18"; // v18: Every suppression comment per rule
     const APP_VERSION: &'static str = env!("CARGO_PKG_VERSION");
+    const VERSION_KEY: &'static str = "version";
+    const CHURN_KEY: &'static str = "churn";
+    const RUN_STATS_KEY: &'static str = "last_run";
+    /// One week.
+    const MAX_IDLE_SECS: u64 = 7 * 24 * 60 * 60;
 
-    fn resolve_cache_dir(project_root: &Path) -> PathBuf {
+    pub(crate) fn resolve_cache_dir(project_root: &Path) -> PathBuf {
         let node_modules = project_root.join("node_modules");
         if node_modules.exists() && node_modules.is_dir() {
             node_modules.join(".cache").join("archlint")
@@ -56,113 +117,315 @@ impl AnalysisCache {
         }
     }
 
-    pub fn load(project_root: &Path, config: &Config) -> Result<Self> {
+    fn version() -> String {
+        format!("{}/{}", Self::VERSION, Self::APP_VERSION)
+    }
+
+    fn store_path(project_root: &Path) -> PathBuf {
+        Self::resolve_cache_dir(project_root).join(Self::CACHE_FILE)
+    }
+
+    /// Opens the project store, clearing it when it was written by another version.
+    fn open_store(project_root: &Path) -> Result<ParseStore> {
         let cache_dir = Self::resolve_cache_dir(project_root);
-        let cache_file = cache_dir.join(Self::CACHE_FILE);
-
-        let data = if cache_file.exists() {
-            let content = fs::read(&cache_file)?;
-            match decode_from_slice::<CacheData, _>(&content, config::standard()) {
-                Ok((mut data, _))
-                    if data.meta.version == Self::VERSION
-                        && data.meta.app_version == Self::APP_VERSION
-                        && data.meta.config_hash == config_hash(config) =>
-                {
-                    // Only check git head if git is enabled
-                    let current_head = if config.git.enabled {
-                        crate::cache::hash::get_git_head(project_root)
-                    } else {
-                        None
-                    };
-                    if data.meta.git_head != current_head {
-                        data.churn_map.clear();
-                        data.meta.git_head = current_head;
-                    }
-                    data
-                }
-                _ => Self::empty_data(project_root, config),
-            }
-        } else {
-            Self::empty_data(project_root, config)
+        fs::create_dir_all(&cache_dir)?;
+        let _ = fs::remove_file(cache_dir.join(Self::LEGACY_CACHE_FILE));
+
+        let store = ParseStore::open(&Self::store_path(project_root))?;
+        let version = Self::version();
+        if store.get_meta::<String>(Self::VERSION_KEY)?.as_ref() != Some(&version) {
+            debug!("Cache version changed, clearing cache");
+            store.clear()?;
+            let batch = WriteBatch {
+                meta: vec![(Self::VERSION_KEY, encode(&version)?)],
+                ..WriteBatch::default()
+            };
+            store.write(&batch, now())?;
+        }
+        Ok(store)
+    }
+
+    /// Opens the project store as it is, with the version that wrote it, or
+    /// `None` when there is no store.
+    fn open_existing(project_root: &Path) -> Result<Option<(ParseStore, Option<String>)>> {
+        let path = Self::store_path(project_root);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let store = ParseStore::open(&path)?;
+        let version = store.get_meta::<String>(Self::VERSION_KEY)?;
+        Ok(Some((store, version)))
+    }
+
+    /// Reads the project cache state, waiting while another archlint process
+    /// writes to it.
+    pub fn load(
+        project_root: &Path,
+        config: &Config,
+        parser_config: &ParserConfig,
+    ) -> Result<Self> {
+        let store = Self::open_store(project_root)?;
+
+        // Churn depends on the git history, not on file contents
+        let churn_key = config.git.enabled.then(|| {
+            format!(
+                "{}:{}",
+                get_git_head(project_root).unwrap_or_default(),
+                config.git.history_period
+            )
+        });
+        let churn_map = match (&churn_key, store.get_meta::<ChurnEntry>(Self::CHURN_KEY)?) {
+            (Some(key), Some(entry)) if entry.key == *key => entry
+                .churn_map
+                .into_iter()
+                .map(|(path, churn)| (project_root.join(path), churn))
+                .collect(),
+            _ => HashMap::new(),
         };
+        drop(store);
 
         Ok(Self {
-            cache_file,
-            data,
-            is_dirty: false,
+            store_path: Self::store_path(project_root),
+            project_root: project_root.to_path_buf(),
+            parser_hash: parser_config_hash(parser_config),
+            churn_key,
+            churn_map,
+            is_churn_dirty: false,
+            pending: HashMap::new(),
+            files: Mutex::new(HashMap::new()),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
         })
     }
 
-    fn empty_data(project_root: &Path, config: &Config) -> CacheData {
-        CacheData {
-            meta: CacheMeta {
-                version: Self::VERSION.to_string(),
-                app_version: Self::APP_VERSION.to_string(),
-                config_hash: config_hash(config),
-                git_head: if config.git.enabled {
-                    crate::cache::hash::get_git_head(project_root)
-                } else {
-                    None
-                },
-                created_at: chrono::Utc::now(),
-            },
-            entries: HashMap::new(),
-            churn_map: HashMap::new(),
-        }
+    fn relative_path(&self, path: &Path) -> String {
+        let rel = path.strip_prefix(&self.project_root).unwrap_or(path);
+        rel.to_string_lossy().replace('\\', "/")
+    }
+
+    fn entry_key(&self, path: &Path, content_hash: &str) -> String {
+        // The extension decides how the content is parsed
+        let extension = path.extension().unwrap_or_default().to_string_lossy();
+        format!("{content_hash}:{extension}:{}", self.parser_hash)
     }
 
     #[must_use]
-    pub fn get(&self, path: &Path, content_hash: &str) -> Option<&ParsedFile> {
-        self.data.entries.get(path).and_then(|entry| {
-            if entry.content_hash == content_hash {
-                Some(&entry.parsed)
-            } else {
+    pub fn get(&self, path: &Path, content_hash: &str) -> Option<ParsedFile> {
+        self.get_many([(path, content_hash)]).remove(path)
+    }
+
+    /// Cached parse results of `files`, given with their content hash.
+    ///
+    /// Opens the store once for all lookups and releases it before returning.
+    #[must_use]
+    pub fn get_many<'a>(
+        &self,
+        files: impl IntoIterator<Item = (&'a Path, &'a str)>,
+    ) -> HashMap<PathBuf, ParsedFile> {
+        let store = match ParseStore::open(&self.store_path) {
+            Ok(store) => Some(store),
+            Err(e) => {
+                debug!("Failed to open cache for lookups: {e}");
                 None
             }
-        })
+        };
+
+        let mut found = HashMap::new();
+        for (path, content_hash) in files {
+            let key = self.entry_key(path, content_hash);
+            let parsed = store
+                .as_ref()
+                .and_then(|store| match store.get_parsed(&key) {
+                    Ok(parsed) => parsed,
+                    Err(e) => {
+                        debug!("Failed to read cache entry for {}: {e}", path.display());
+                        None
+                    }
+                });
+            let Some(parsed) = parsed else {
+                self.misses.fetch_add(1, Ordering::Relaxed);
+                continue;
+            };
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            if let Ok(mut files) = self.files.lock() {
+                files.insert(self.relative_path(path), key);
+            }
+            found.insert(path.to_path_buf(), parsed);
+        }
+        found
     }
 
-    pub fn insert(&mut self, path: PathBuf, content_hash: String, parsed: ParsedFile) {
-        self.data.entries.insert(
-            path,
-            FileEntry {
-                content_hash,
-                parsed,
-            },
-        );
-        self.is_dirty = true;
+    /// Queues a parse result for the next [`AnalysisCache::save`], unless it came from the cache.
+    pub fn insert(&mut self, path: &Path, content_hash: &str, parsed: ParsedFile) {
+        let key = self.entry_key(path, content_hash);
+        let rel_path = self.relative_path(path);
+        let Ok(files) = self.files.get_mut() else {
+            return;
+        };
+        if files.get(&rel_path) != Some(&key) {
+            files.insert(rel_path, key.clone());
+            self.pending.insert(key, parsed);
+        }
     }
 
     #[must_use]
     pub fn get_churn_map(&self) -> Option<&HashMap<PathBuf, usize>> {
-        if self.data.churn_map.is_empty() {
+        if self.churn_map.is_empty() {
             None
         } else {
-            Some(&self.data.churn_map)
+            Some(&self.churn_map)
         }
     }
 
     pub fn insert_churn_map(&mut self, churn_map: HashMap<PathBuf, usize>) {
-        self.data.churn_map = churn_map;
-        self.is_dirty = true;
+        self.churn_map = churn_map;
+        self.is_churn_dirty = true;
     }
 
-    pub fn save(&self) -> Result<()> {
-        if !self.is_dirty {
-            return Ok(());
-        }
+    /// Writes new entries, refreshes the access time of the reused ones and
+    /// removes stale entries, then releases the cache for other processes.
+    ///
+    /// The version is checked again, since another archlint version may have
+    /// cleared the store since [`AnalysisCache::load`].
+    pub fn save(self) -> Result<()> {
+        let now = now();
+        let parsed = self
+            .pending
+            .iter()
+            .map(|(key, parsed)| Ok((key.as_str(), encode(parsed)?)))
+            .collect::<Result<Vec<_>>>()?;
+        let files = self.files.into_inner().unwrap_or_default();
+        let run_stats = RunStats {
+            hits: self.hits.into_inner(),
+            misses: self.misses.into_inner(),
+        };
 
-        if let Some(parent) = self.cache_file.parent() {
-            fs::create_dir_all(parent)?;
+        let mut meta = vec![(Self::RUN_STATS_KEY, encode(&run_stats)?)];
+        if let (true, Some(key)) = (self.is_churn_dirty, self.churn_key) {
+            let churn_map = self
+                .churn_map
+                .into_iter()
+                .map(|(path, churn)| {
+                    let rel = path.strip_prefix(&self.project_root).unwrap_or(&path);
+                    (rel.to_path_buf(), churn)
+                })
+                .collect();
+            meta.push((Self::CHURN_KEY, encode(&ChurnEntry { key, churn_map })?));
         }
 
-        let bytes = encode_to_vec(&self.data, config::standard()).map_err(|e| {
-            crate::AnalysisError::Storage(format!("Failed to serialize cache: {e}"))
-        })?;
-        fs::write(&self.cache_file, bytes)?;
+        let batch = WriteBatch {
+            parsed,
+            files: files
+                .iter()
+                .map(|(path, key)| (path.as_str(), key.as_str()))
+                .collect(),
+            meta,
+        };
+        let store = Self::open_store(&self.project_root)?;
+        store.write(&batch, now)?;
+        let removed = store.collect_garbage(now.saturating_sub(Self::MAX_IDLE_SECS))?;
+        if removed > 0 {
+            debug!("Removed {removed} stale cache entries");
+        }
         Ok(())
     }
 
+    /// Writes the entries of the project files to `output`, returning how many files it covers.
+    ///
+    /// Fails when there is no cache or another archlint version wrote it.
+    pub fn export(project_root: &Path, output: &Path) -> Result<usize> {
+        let Some((store, version)) = Self::open_existing(project_root)? else {
+            return Err(AnalysisError::Storage(format!(
+                "No cache found for {}",
+                project_root.display()
+            )));
+        };
+        if version.as_deref() != Some(Self::version().as_str()) {
+            return Err(AnalysisError::Storage(format!(
+                "Cache was written by version {}, expected {}; run a scan to rebuild it",
+                version.as_deref().unwrap_or("unknown"),
+                Self::version()
+            )));
+        }
+        let files = store.files()?;
+        let mut entries = Vec::new();
+        let mut seen = FxHashSet::default();
+        for (_, key) in &files {
+            if seen.insert(key.as_str()) {
+                if let Some(bytes) = store.get_raw(key)? {
+                    entries.push((key.clone(), bytes));
+                }
+            }
+        }
+        let archive = CacheArchive {
+            version: Self::version(),
+            churn: store.get_meta(Self::CHURN_KEY)?,
+            files,
+            entries,
+        };
+        fs::write(output, encode(&archive)?)?;
+        Ok(archive.files.len())
+    }
+
+    /// Merges a cache written by [`AnalysisCache::export`] into the project
+    /// cache, returning how many files it covers.
+    ///
+    /// Fails when the archive comes from another archlint version, since its
+    /// parse results would be cleared on the next run anyway.
+    pub fn import(project_root: &Path, input: &Path) -> Result<usize> {
+        let archive: CacheArchive = decode(&fs::read(input)?)?;
+        if archive.version != Self::version() {
+            return Err(AnalysisError::Storage(format!(
+                "Cache archive was written by version {}, expected {}",
+                archive.version,
+                Self::version()
+            )));
+        }
+
+        let store = Self::open_store(project_root)?;
+        let mut meta = Vec::new();
+        if let Some(churn) = &archive.churn {
+            meta.push((Self::CHURN_KEY, encode(churn)?));
+        }
+        let batch = WriteBatch {
+            parsed: archive
+                .entries
+                .iter()
+                .map(|(key, bytes)| (key.as_str(), bytes.clone()))
+                .collect(),
+            files: archive
+                .files
+                .iter()
+                .map(|(path, key)| (path.as_str(), key.as_str()))
+                .collect(),
+            meta,
+        };
+        store.write(&batch, now())?;
+        Ok(archive.files.len())
+    }
+
+    /// Size, entry counts and hit ratio of the project cache, or `None` when there is none.
+    ///
+    /// A store written by another archlint version is reported as outdated, not cleared.
+    pub fn stats(project_root: &Path) -> Result<Option<CacheStats>> {
+        let Some((store, version)) = Self::open_existing(project_root)? else {
+            return Ok(None);
+        };
+        let (entries, files) = store.counts()?;
+        let last_run = store.get_meta(Self::RUN_STATS_KEY)?;
+        drop(store);
+        let path = Self::store_path(project_root);
+        Ok(Some(CacheStats {
+            size: fs::metadata(&path)?.len(),
+            path,
+            entries,
+            files,
+            last_run,
+            is_outdated: version.as_deref() != Some(Self::version().as_str()),
+            version,
+        }))
+    }
+
     pub fn clear(project_root: &Path) -> Result<()> {
         let _ = crate::git_cache::GitHistoryCache::clear(project_root);
         let locations = [
@@ -178,3 +441,39 @@ impl AnalysisCache {
         Ok(())
     }
 }
+
+fn now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_stats_and_export_keep_an_outdated_store() {
+        let dir = TempDir::new().unwrap();
+        let store = AnalysisCache::open_store(dir.path()).unwrap();
+        let batch = WriteBatch {
+            parsed: vec![("entry", vec![1, 2, 3])],
+            files: vec![("src/a.ts", "entry")],
+            meta: vec![(AnalysisCache::VERSION_KEY, encode(&"1/0.1.0").unwrap())],
+        };
+        store.write(&batch, now()).unwrap();
+        drop(store);
+
+        let stats = AnalysisCache::stats(dir.path()).unwrap().unwrap();
+        assert!(stats.is_outdated);
+        assert_eq!(stats.version.as_deref(), Some("1/0.1.0"));
+        assert_eq!((stats.entries, stats.files), (1, 1));
+
+        let archive = dir.path().join("cache.bin");
+        assert!(AnalysisCache::export(dir.path(), &archive).is_err());
+        assert!(!archive.exists());
+        let stats = AnalysisCache::stats(dir.path()).unwrap().unwrap();
+        assert_eq!((stats.entries, stats.files), (1, 1));
+    }
+}
@@ -0,0 +1,288 @@
+use crate::parser::ParsedFile;
+use crate::{AnalysisError, Result};
+use bincode::{
+    config,
+    serde::{decode_from_slice, encode_to_vec},
+};
+use redb::{
+    Database, DatabaseError, ReadableDatabase, ReadableTable, ReadableTableMetadata,
+    TableDefinition,
+};
+use rustc_hash::FxHashSet;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fmt::Display;
+use std::path::Path;
+use std::thread;
+use std::time::Duration;
+
+/// Parse results by entry key (content hash plus parser config hash).
+const PARSED_TABLE: TableDefinition<&str, &[u8]> = TableDefinition::new("parsed");
+/// Last time each parse result was used, in seconds since the Unix epoch.
+const ACCESSED_TABLE: TableDefinition<&str, u64> = TableDefinition::new("accessed");
+/// Entry key of the last parse of each file, by project-relative path.
+const FILES_TABLE: TableDefinition<&str, &str> = TableDefinition::new("files");
+/// Cache version and values shared by the whole project, like the churn map.
+const META_TABLE: TableDefinition<&str, &[u8]> = TableDefinition::new("meta");
+
+/// How long to wait for another archlint process to release the database.
+const OPEN_ATTEMPTS: u32 = 50;
+const OPEN_RETRY_DELAY: Duration = Duration::from_millis(100);
+
+fn storage_error(context: &str, e: impl Display) -> AnalysisError {
+    AnalysisError::Storage(format!("{context}: {e}"))
+}
+
+/// Per-entry store of parse results, backed by redb.
+///
+/// redb locks the database file, so concurrent archlint processes take turns:
+/// [`ParseStore::open`] waits for the lock instead of failing right away.
+/// Drop the store as soon as its transactions are done to release the lock.
+pub struct ParseStore {
+    db: Database,
+}
+
+impl ParseStore {
+    pub fn open(path: &Path) -> Result<Self> {
+        let mut attempts = 0;
+        let db = loop {
+            match Database::create(path) {
+                Ok(db) => break db,
+                Err(DatabaseError::DatabaseAlreadyOpen) if attempts < OPEN_ATTEMPTS => {
+                    attempts += 1;
+                    thread::sleep(OPEN_RETRY_DELAY);
+                }
+                Err(e) => return Err(storage_error("Failed to open cache db", e)),
+            }
+        };
+
+        let write_txn = db
+            .begin_write()
+            .map_err(|e| storage_error("Failed to begin write txn", e))?;
+        {
+            write_txn
+                .open_table(PARSED_TABLE)
+                .map_err(|e| storage_error("Failed to open table", e))?;
+            write_txn
+                .open_table(ACCESSED_TABLE)
+                .map_err(|e| storage_error("Failed to open table", e))?;
+            write_txn
+                .open_table(FILES_TABLE)
+                .map_err(|e| storage_error("Failed to open table", e))?;
+            write_txn
+                .open_table(META_TABLE)
+                .map_err(|e| storage_error("Failed to open table", e))?;
+        }
+        write_txn
+            .commit()
+            .map_err(|e| storage_error("Failed to commit txn", e))?;
+
+        Ok(Self { db })
+    }
+
+    pub fn get_parsed(&self, key: &str) -> Result<Option<ParsedFile>> {
+        self.get_raw(key)?.map(|bytes| decode(&bytes)).transpose()
+    }
+
+    /// Encoded parse result stored under `key`.
+    pub fn get_raw(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        let read_txn = self
+            .db
+            .begin_read()
+            .map_err(|e| storage_error("Failed to begin read txn", e))?;
+        let table = read_txn
+            .open_table(PARSED_TABLE)
+            .map_err(|e| storage_error("Failed to open table", e))?;
+        let value = table
+            .get(key)
+            .map_err(|e| storage_error("Failed to get from table", e))?;
+        Ok(value.map(|bytes| bytes.value().to_vec()))
+    }
+
+    pub fn get_meta<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>> {
+        let read_txn = self
+            .db
+            .begin_read()
+            .map_err(|e| storage_error("Failed to begin read txn", e))?;
+        let table = read_txn
+            .open_table(META_TABLE)
+            .map_err(|e| storage_error("Failed to open table", e))?;
+        let value = table
+            .get(key)
+            .map_err(|e| storage_error("Failed to get from table", e))?;
+        value.map(|bytes| decode(bytes.value())).transpose()
+    }
+
+    /// Project-relative paths with the entry key of their last parse.
+    pub fn files(&self) -> Result<Vec<(String, String)>> {
+        let read_txn = self
+            .db
+            .begin_read()
+            .map_err(|e| storage_error("Failed to begin read txn", e))?;
+        let table = read_txn
+            .open_table(FILES_TABLE)
+            .map_err(|e| storage_error("Failed to open table", e))?;
+        let iter = table
+            .iter()
+            .map_err(|e| storage_error("Failed to read table", e))?;
+        iter.map(|item| {
+            let (path, key) = item.map_err(|e| storage_error("Failed to read table", e))?;
+            Ok((path.value().to_string(), key.value().to_string()))
+        })
+        .collect()
+    }
+
+    /// Number of parse results and of files pointing to them.
+    pub fn counts(&self) -> Result<(u64, u64)> {
+        let read_txn = self
+            .db
+            .begin_read()
+            .map_err(|e| storage_error("Failed to begin read txn", e))?;
+        let parsed = read_txn
+            .open_table(PARSED_TABLE)
+            .and_then(|t| Ok(t.len()?))
+            .map_err(|e| storage_error("Failed to count entries", e))?;
+        let files = read_txn
+            .open_table(FILES_TABLE)
+            .and_then(|t| Ok(t.len()?))
+            .map_err(|e| storage_error("Failed to count entries", e))?;
+        Ok((parsed, files))
+    }
+
+    /// Applies `batch` in a single transaction, marking its parse results as
+    /// accessed at `now`.
+    pub fn write(&self, batch: &WriteBatch<'_>, now: u64) -> Result<()> {
+        let write_txn = self
+            .db
+            .begin_write()
+            .map_err(|e| storage_error("Failed to begin write txn", e))?;
+        {
+            let mut parsed_table = write_txn
+                .open_table(PARSED_TABLE)
+                .map_err(|e| storage_error("Failed to open table", e))?;
+            let mut accessed_table = write_txn
+                .open_table(ACCESSED_TABLE)
+                .map_err(|e| storage_error("Failed to open table", e))?;
+            let mut files_table = write_txn
+                .open_table(FILES_TABLE)
+                .map_err(|e| storage_error("Failed to open table", e))?;
+            let mut meta_table = write_txn
+                .open_table(META_TABLE)
+                .map_err(|e| storage_error("Failed to open table", e))?;
+
+            for (key, bytes) in &batch.parsed {
+                parsed_table
+                    .insert(*key, bytes.as_slice())
+                    .map_err(|e| storage_error("Failed to insert", e))?;
+                accessed_table
+                    .insert(*key, now)
+                    .map_err(|e| storage_error("Failed to insert", e))?;
+            }
+            for (path, key) in &batch.files {
+                files_table
+                    .insert(*path, *key)
+                    .map_err(|e| storage_error("Failed to insert", e))?;
+                accessed_table
+                    .insert(*key, now)
+                    .map_err(|e| storage_error("Failed to insert", e))?;
+            }
+            for (key, bytes) in &batch.meta {
+                meta_table
+                    .insert(*key, bytes.as_slice())
+                    .map_err(|e| storage_error("Failed to insert", e))?;
+            }
+        }
+        write_txn
+            .commit()
+            .map_err(|e| storage_error("Failed to commit txn", e))
+    }
+
+    /// Removes parse results last used before `cutoff`, returning how many were removed.
+    pub fn collect_garbage(&self, cutoff: u64) -> Result<usize> {
+        let write_txn = self
+            .db
+            .begin_write()
+            .map_err(|e| storage_error("Failed to begin write txn", e))?;
+        let removed = {
+            let mut parsed_table = write_txn
+                .open_table(PARSED_TABLE)
+                .map_err(|e| storage_error("Failed to open table", e))?;
+            let mut accessed_table = write_txn
+                .open_table(ACCESSED_TABLE)
+                .map_err(|e| storage_error("Failed to open table", e))?;
+
+            let mut stale = FxHashSet::default();
+            for item in accessed_table
+                .iter()
+                .map_err(|e| storage_error("Failed to remove stale entries", e))?
+            {
+                let (key, accessed) =
+                    item.map_err(|e| storage_error("Failed to remove stale entries", e))?;
+                if accessed.value() < cutoff {
+                    stale.insert(key.value().to_string());
+                }
+            }
+            let mut files_table = write_txn
+                .open_table(FILES_TABLE)
+                .map_err(|e| storage_error("Failed to open table", e))?;
+            files_table
+                .retain(|_, key| !stale.contains(key))
+                .map_err(|e| storage_error("Failed to remove stale entries", e))?;
+            for key in &stale {
+                parsed_table
+                    .remove(key.as_str())
+                    .map_err(|e| storage_error("Failed to remove stale entries", e))?;
+                accessed_table
+                    .remove(key.as_str())
+                    .map_err(|e| storage_error("Failed to remove stale entries", e))?;
+            }
+            stale.len()
+        };
+        write_txn
+            .commit()
+            .map_err(|e| storage_error("Failed to commit txn", e))?;
+        Ok(removed)
+    }
+
+    /// Drops every entry, keeping the tables.
+    pub fn clear(&self) -> Result<()> {
+        let write_txn = self
+            .db
+            .begin_write()
+            .map_err(|e| storage_error("Failed to begin write txn", e))?;
+        {
+            let clear = |e| storage_error("Failed to clear table", e);
+            write_txn.delete_table(PARSED_TABLE).map_err(clear)?;
+            write_txn.delete_table(ACCESSED_TABLE).map_err(clear)?;
+            write_txn.delete_table(FILES_TABLE).map_err(clear)?;
+            write_txn.delete_table(META_TABLE).map_err(clear)?;
+            write_txn.open_table(PARSED_TABLE).map_err(clear)?;
+            write_txn.open_table(ACCESSED_TABLE).map_err(clear)?;
+            write_txn.open_table(FILES_TABLE).map_err(clear)?;
+            write_txn.open_table(META_TABLE).map_err(clear)?;
+        }
+        write_txn
+            .commit()
+            .map_err(|e| storage_error("Failed to commit txn", e))
+    }
+}
+
+/// Changes written to the store in one transaction.
+#[derive(Default)]
+pub struct WriteBatch<'a> {
+    /// Encoded parse results by entry key.
+    pub parsed: Vec<(&'a str, Vec<u8>)>,
+    /// Project-relative paths with the entry key of their parse.
+    pub files: Vec<(&'a str, &'a str)>,
+    pub meta: Vec<(&'static str, Vec<u8>)>,
+}
+
+pub fn encode<T: Serialize>(value: &T) -> Result<Vec<u8>> {
+    encode_to_vec(value, config::standard()).map_err(|e| storage_error("Failed to serialize", e))
+}
+
+pub fn decode<T: DeserializeOwned>(bytes: &[u8]) -> Result<T> {
+    decode_from_slice(bytes, config::standard())
+        .map(|(value, _)| value)
+        .map_err(|e| storage_error("Failed to deserialize", e))
+}
@@ -0,0 +1,390 @@
+//! Threshold calibration from project-wide metric distributions.
+//!
+//! Collects the values that threshold-based detectors compare against and
+//! proposes, per metric, the threshold that flags at most the top N% of the
+//! project's files, functions or classes.
+
+use crate::config::{Config, RuleConfig};
+use crate::detectors::metrics::lcom::LcomDetector;
+use crate::report::AnalysisReport;
+use crate::rule_resolver::ResolvedRuleConfig;
+use serde::Serialize;
+
+/// How a detector compares a measured value against its threshold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Comparison {
+    /// Flags values strictly above the threshold.
+    Above,
+    /// Flags values at or above the threshold.
+    AtLeast,
+}
+
+/// A detector option that acts as a threshold for one metric.
+#[derive(Debug, Clone, Copy)]
+pub struct ThresholdOption {
+    /// Metric name shown in the output.
+    pub metric: &'static str,
+    pub detector: &'static str,
+    pub option: &'static str,
+    /// Older option names the detector still reads, in order of precedence.
+    aliases: &'static [&'static str],
+    pub default: usize,
+    comparison: Comparison,
+}
+
+impl ThresholdOption {
+    const fn new(
+        metric: &'static str,
+        detector: &'static str,
+        option: &'static str,
+        default: usize,
+        comparison: Comparison,
+    ) -> Self {
+        Self {
+            metric,
+            detector,
+            option,
+            aliases: &[],
+            default,
+            comparison,
+        }
+    }
+
+    const fn with_aliases(mut self, aliases: &'static [&'static str]) -> Self {
+        self.aliases = aliases;
+        self
+    }
+
+    fn keys(&self) -> impl Iterator<Item = &'static str> {
+        std::iter::once(self.option).chain(self.aliases.iter().copied())
+    }
+
+    /// The key the user config sets this threshold with, if any.
+    fn configured_key(&self, config: &Config) -> Option<&'static str> {
+        let Some(RuleConfig::Full(rule)) = config.rules.get(self.detector) else {
+            return None;
+        };
+        let options = rule.options.as_mapping()?;
+        self.keys().find(|key| options.contains_key(*key))
+    }
+
+    /// Threshold in effect for `rule`: configured or the detector default.
+    #[must_use]
+    pub fn current(&self, rule: &ResolvedRuleConfig) -> usize {
+        self.keys()
+            .find_map(|key| rule.get_option(key))
+            .unwrap_or(self.default)
+    }
+
+    /// Whether the detector flags `value` with `threshold` in effect.
+    #[must_use]
+    pub const fn flags(&self, value: usize, threshold: usize) -> bool {
+        match self.comparison {
+            Comparison::Above => value > threshold,
+            Comparison::AtLeast => value >= threshold,
+        }
+    }
+
+    /// Smallest threshold that flags at most `max_flagged` of the sorted values.
+    fn threshold_for(&self, sorted: &[usize], max_flagged: usize) -> usize {
+        let boundary = if max_flagged < sorted.len() {
+            sorted[sorted.len() - max_flagged - 1]
+        } else {
+            0
+        };
+        let threshold = match self.comparison {
+            Comparison::Above => boundary,
+            Comparison::AtLeast => boundary + 1,
+        };
+        threshold.max(1)
+    }
+}
+
+/// Thresholds that can be calibrated.
+pub const THRESHOLDS: &[ThresholdOption] = &[
+    ThresholdOption::new("fan-in", "god_module", "fan_in", 10, Comparison::AtLeast),
+    ThresholdOption::new("fan-out", "god_module", "fan_out", 10, Comparison::AtLeast),
+    ThresholdOption::new("churn", "god_module", "churn", 20, Comparison::AtLeast),
+    ThresholdOption::new(
+        "cyclomatic complexity",
+        "cyclomatic_complexity",
+        "max_complexity",
+        15,
+        Comparison::Above,
+    )
+    .with_aliases(&["function_threshold", "threshold"]),
+    ThresholdOption::new(
+        "cognitive complexity",
+        "cognitive_complexity",
+        "max_complexity",
+        15,
+        Comparison::Above,
+    )
+    .with_aliases(&["function_threshold", "threshold"]),
+    ThresholdOption::new(
+        "nesting depth",
+        "deep_nesting",
+        "max_depth",
+        4,
+        Comparison::Above,
+    ),
+    ThresholdOption::new(
+        "parameters",
+        "long_params",
+        "max_params",
+        5,
+        Comparison::Above,
+    ),
+    ThresholdOption::new("LCOM4", "lcom", "max_lcom", 4, Comparison::Above),
+    ThresholdOption::new(
+        "file lines",
+        "large_file",
+        "max_lines",
+        1000,
+        Comparison::Above,
+    )
+    .with_aliases(&["lines"]),
+    ThresholdOption::new(
+        "function lines",
+        "long_function",
+        "max_lines",
+        50,
+        Comparison::Above,
+    )
+    .with_aliases(&["lines"]),
+];
+
+/// Summary of a metric's distribution across the project.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Distribution {
+    pub count: usize,
+    pub min: usize,
+    pub p50: usize,
+    pub p75: usize,
+    pub p90: usize,
+    pub p95: usize,
+    pub p99: usize,
+    pub max: usize,
+}
+
+impl Distribution {
+    /// Build a distribution from values sorted in ascending order.
+    #[must_use]
+    pub fn from_sorted(sorted: &[usize]) -> Option<Self> {
+        let (&min, &max) = (sorted.first()?, sorted.last()?);
+        Some(Self {
+            count: sorted.len(),
+            min,
+            p50: percentile(sorted, 50),
+            p75: percentile(sorted, 75),
+            p90: percentile(sorted, 90),
+            p95: percentile(sorted, 95),
+            p99: percentile(sorted, 99),
+            max,
+        })
+    }
+}
+
+/// Nearest-rank percentile of values sorted in ascending order.
+#[must_use]
+pub fn percentile(sorted: &[usize], percent: usize) -> usize {
+    let rank = (percent * sorted.len()).div_ceil(100).max(1);
+    sorted.get(rank - 1).copied().unwrap_or_default()
+}
+
+/// Proposed value for one threshold.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThresholdProposal {
+    pub metric: &'static str,
+    pub detector: &'static str,
+    /// Option key to write; an alias already present in the config is kept.
+    pub option: &'static str,
+    pub distribution: Distribution,
+    /// Threshold in effect: configured or the detector default.
+    pub current: usize,
+    /// Whether `current` comes from the config file.
+    pub configured: bool,
+    pub proposed: usize,
+    /// Samples flagged with the current threshold.
+    pub flagged_current: usize,
+    /// Samples flagged with the proposed threshold.
+    pub flagged_proposed: usize,
+}
+
+impl ThresholdProposal {
+    #[must_use]
+    pub const fn changed(&self) -> bool {
+        self.current != self.proposed
+    }
+}
+
+/// Calibration result for all metrics with samples.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Calibration {
+    /// Share of samples each proposed threshold flags at most, in percent.
+    pub top_percent: usize,
+    pub thresholds: Vec<ThresholdProposal>,
+}
+
+impl Calibration {
+    /// Propose thresholds flagging the top `top_percent`% of each metric.
+    #[must_use]
+    pub fn compute(report: &AnalysisReport, config: &Config, top_percent: usize) -> Self {
+        let thresholds = THRESHOLDS
+            .iter()
+            .filter_map(|threshold| {
+                let rule = ResolvedRuleConfig::resolve(config, threshold.detector, None);
+                let mut samples = collect_samples(threshold, report, &rule);
+                samples.sort_unstable();
+                propose(threshold, &samples, config, &rule, top_percent)
+            })
+            .collect();
+
+        Self {
+            top_percent,
+            thresholds,
+        }
+    }
+}
+
+fn propose(
+    threshold: &ThresholdOption,
+    sorted: &[usize],
+    config: &Config,
+    rule: &ResolvedRuleConfig,
+    top_percent: usize,
+) -> Option<ThresholdProposal> {
+    let distribution = Distribution::from_sorted(sorted)?;
+    let current = threshold.current(rule);
+    let proposed = threshold.threshold_for(sorted, sorted.len() * top_percent / 100);
+    let flagged = |limit| {
+        sorted
+            .iter()
+            .filter(|v| threshold.flags(**v, limit))
+            .count()
+    };
+    let configured_key = threshold.configured_key(config);
+
+    Some(ThresholdProposal {
+        metric: threshold.metric,
+        detector: threshold.detector,
+        option: configured_key.unwrap_or(threshold.option),
+        distribution,
+        current,
+        configured: configured_key.is_some(),
+        proposed,
+        flagged_current: flagged(current),
+        flagged_proposed: flagged(proposed),
+    })
+}
+
+/// Values the detector behind `threshold` compares, one per file, function or class.
+fn collect_samples(
+    threshold: &ThresholdOption,
+    report: &AnalysisReport,
+    rule: &ResolvedRuleConfig,
+) -> Vec<usize> {
+    let functions = || report.function_complexity.values().flatten();
+
+    match (threshold.detector, threshold.option) {
+        ("god_module", "fan_in" | "fan_out") => report.graph.as_ref().map_or_else(Vec::new, |g| {
+            g.nodes()
+                .map(|node| {
+                    if threshold.option == "fan_in" {
+                        g.fan_in(node)
+                    } else {
+                        g.fan_out(node)
+                    }
+                })
+                .collect()
+        }),
+        ("god_module", "churn") => report.churn_map.values().copied().collect(),
+        ("cyclomatic_complexity", _) => functions().map(|f| f.cyclomatic_complexity).collect(),
+        ("cognitive_complexity", _) => functions().map(|f| f.cognitive_complexity).collect(),
+        ("deep_nesting", _) => functions().map(|f| f.max_depth).collect(),
+        ("long_params", _) => {
+            let ignore_constructors = rule.get_option("ignore_constructors").unwrap_or(true);
+            functions()
+                .filter(|f| !(ignore_constructors && f.is_constructor))
+                .map(|f| f.param_count)
+                .collect()
+        }
+        ("long_function", _) => {
+            let ignore_constructors = rule.get_option("ignore_constructors").unwrap_or(false);
+            functions()
+                .filter(|f| !(ignore_constructors && f.is_constructor))
+                .map(|f| f.loc)
+                .collect()
+        }
+        ("lcom", _) => {
+            let min_methods: usize = rule.get_option("min_methods").unwrap_or(3);
+            report
+                .file_symbols
+                .values()
+                .flat_map(|symbols| &symbols.classes)
+                .filter(|class| {
+                    class
+                        .methods
+                        .iter()
+                        .filter(|m| m.name != "constructor" && !m.is_accessor)
+                        .count()
+                        >= min_methods
+                })
+                .map(LcomDetector::calculate_lcom4)
+                .collect()
+        }
+        ("large_file", _) => report.file_metrics.values().map(|m| m.lines).collect(),
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn threshold(detector: &str) -> &'static ThresholdOption {
+        THRESHOLDS.iter().find(|t| t.detector == detector).unwrap()
+    }
+
+    #[test]
+    fn test_percentile_nearest_rank() {
+        let values: Vec<usize> = (1..=20).collect();
+        assert_eq!(percentile(&values, 50), 10);
+        assert_eq!(percentile(&values, 95), 19);
+        assert_eq!(percentile(&values, 99), 20);
+        assert_eq!(percentile(&[7], 50), 7);
+    }
+
+    #[test]
+    fn test_threshold_flags_at_most_top_share() {
+        let values: Vec<usize> = (1..=100).collect();
+
+        // Strict comparison: values above 95 are the top 5
+        let strict = threshold("deep_nesting");
+        assert_eq!(strict.threshold_for(&values, 5), 95);
+
+        // Inclusive comparison: values from 96 on are the top 5
+        let inclusive = threshold("god_module");
+        assert_eq!(inclusive.threshold_for(&values, 5), 96);
+
+        // Ties at the boundary are not flagged rather than exceeding the share
+        let tied = [1, 2, 3, 3, 3, 3];
+        assert_eq!(strict.threshold_for(&tied, 2), 3);
+        assert_eq!(strict.threshold_for(&tied, 0), 3);
+    }
+
+    #[test]
+    fn test_configured_alias_is_kept() {
+        let config: Config =
+            serde_yaml::from_str("rules:\n  large_file:\n    lines: 800\n").unwrap();
+        let large_file = threshold("large_file");
+        assert_eq!(large_file.configured_key(&config), Some("lines"));
+
+        let rule = ResolvedRuleConfig::resolve(&config, "large_file", None);
+        assert_eq!(large_file.current(&rule), 800);
+        assert_eq!(threshold("long_params").configured_key(&config), None);
+    }
+}
@@ -1,4 +1,10 @@
 use crate::args::{validate_detector_ids, OutputFormat, ScanArgs};
+use crate::commands::affected::AffectedFormat;
+use crate::commands::calibrate::CalibrateFormat;
+use crate::commands::explain::ExplainFormat;
+use crate::commands::injections::InjectionsFormat;
+use crate::commands::metrics::{MetricsFormat, MetricsGroupBy};
+use crate::commands::modularize::ModularizeFormat;
 use clap::Parser;
 use std::path::PathBuf;
 
@@ -29,7 +35,7 @@ pub struct Cli {
     #[arg(short, long, action = clap::ArgAction::SetTrue)]
     pub json: bool,
 
-    /// Disable dependency diagram in Markdown reports
+    /// Disable dependency and main sequence diagrams in Markdown reports
     #[arg(long, action = clap::ArgAction::SetTrue)]
     pub no_diagram: bool,
 
@@ -142,6 +148,194 @@ pub enum Command {
 
     /// Initialize a new configuration file
     Init(InitArgs),
+
+    /// Show package metrics (coupling, instability, abstractness, distance)
+    Metrics(MetricsArgs),
+
+    /// Propose detector thresholds from the project's metric distributions
+    Calibrate(CalibrateArgs),
+
+    /// Suggest module boundaries by clustering the dependency graph
+    Modularize(ModularizeArgs),
+
+    /// Show the dependency injection graph of Nest and Angular modules
+    Injections(InjectionsArgs),
+
+    /// List the files, entry points and tests affected by a change set
+    Affected(AffectedArgs),
+
+    /// Explain a smell found by scan, by its ID or one of its locations
+    Explain(ExplainArgs),
+
+    /// Keep the project analyzed in the background and answer queries over a local socket
+    Daemon(DaemonArgs),
+}
+
+#[derive(Parser, Debug, Clone)]
+pub struct CalibrateArgs {
+    /// Path to the project directory
+    #[arg(value_name = "PATH", default_value = ".")]
+    pub path: PathBuf,
+
+    /// Config file path
+    #[arg(short, long, value_name = "FILE")]
+    pub config: Option<PathBuf>,
+
+    /// Propose thresholds that flag at most this percentage of samples
+    #[arg(long, value_name = "PERCENT", default_value = "5", value_parser = clap::value_parser!(u8).range(1..=50))]
+    pub top: u8,
+
+    /// Output format
+    #[arg(short, long, value_enum, default_value = "table")]
+    pub format: CalibrateFormat,
+
+    /// Output file (defaults to stdout if not specified)
+    #[arg(short, long, value_name = "FILE")]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Parser, Debug, Clone)]
+pub struct ModularizeArgs {
+    /// Path to the project directory
+    #[arg(value_name = "PATH", default_value = ".")]
+    pub path: PathBuf,
+
+    /// Config file path
+    #[arg(short, long, value_name = "FILE")]
+    pub config: Option<PathBuf>,
+
+    /// Folder depth below the project root that modules are compared with
+    #[arg(long, default_value = "2")]
+    pub depth: usize,
+
+    /// Resolution of the clustering; higher values suggest smaller modules
+    #[arg(long, default_value = "1.0")]
+    pub resolution: f64,
+
+    /// Also weight files by how often they changed in the same commit
+    #[arg(long)]
+    pub co_change: bool,
+
+    /// Number of recent commits read for --co-change
+    #[arg(
+        long,
+        value_name = "COMMITS",
+        default_value = "500",
+        requires = "co_change"
+    )]
+    pub lookback: usize,
+
+    /// Output format
+    #[arg(short, long, value_enum, default_value = "table")]
+    pub format: ModularizeFormat,
+
+    /// Output file (defaults to stdout if not specified)
+    #[arg(short, long, value_name = "FILE")]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Parser, Debug, Clone)]
+pub struct InjectionsArgs {
+    /// Path to the project directory
+    #[arg(value_name = "PATH", default_value = ".")]
+    pub path: PathBuf,
+
+    /// Config file path
+    #[arg(short, long, value_name = "FILE")]
+    pub config: Option<PathBuf>,
+
+    /// Output format
+    #[arg(short, long, value_enum, default_value = "table")]
+    pub format: InjectionsFormat,
+
+    /// Output file (defaults to stdout if not specified)
+    #[arg(short, long, value_name = "FILE")]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Parser, Debug, Clone)]
+pub struct AffectedArgs {
+    /// Changed files, in addition to those changed since --since
+    #[arg(value_name = "FILES")]
+    pub files: Vec<PathBuf>,
+
+    /// Path to the project directory
+    #[arg(short, long, value_name = "PATH", default_value = ".")]
+    pub path: PathBuf,
+
+    /// Config file path
+    #[arg(short, long, value_name = "FILE")]
+    pub config: Option<PathBuf>,
+
+    /// Git ref to compare the working tree with (e.g. origin/main, HEAD~1)
+    #[arg(long, value_name = "REF")]
+    pub since: Option<String>,
+
+    /// Only print the affected test files
+    #[arg(long)]
+    pub tests_only: bool,
+
+    /// Output format
+    #[arg(short, long, value_enum, default_value = "table")]
+    pub format: AffectedFormat,
+
+    /// Output file (defaults to stdout if not specified)
+    #[arg(short, long, value_name = "FILE")]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Parser, Debug, Clone)]
+pub struct ExplainArgs {
+    /// Smell ID as shown in snapshots and diffs, or `file:line` of one of its locations
+    #[arg(value_name = "SMELL")]
+    pub target: String,
+
+    /// Path to the project directory
+    #[arg(short, long, value_name = "PATH", default_value = ".")]
+    pub path: PathBuf,
+
+    /// Config file path
+    #[arg(short, long, value_name = "FILE")]
+    pub config: Option<PathBuf>,
+
+    /// Output format
+    #[arg(short, long, value_enum, default_value = "text")]
+    pub format: ExplainFormat,
+
+    /// Output file (defaults to stdout if not specified)
+    #[arg(short, long, value_name = "FILE")]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Parser, Debug, Clone)]
+pub struct MetricsArgs {
+    /// Path to the project directory
+    #[arg(value_name = "PATH", default_value = ".")]
+    pub path: PathBuf,
+
+    /// Config file path
+    #[arg(short, long, value_name = "FILE")]
+    pub config: Option<PathBuf>,
+
+    /// Group files by folder or by workspace package
+    #[arg(long, value_enum, default_value = "folder")]
+    pub by: MetricsGroupBy,
+
+    /// Folder depth below the project root used for grouping [default: `metrics.folder_depth`]
+    #[arg(long)]
+    pub depth: Option<usize>,
+
+    /// Output format
+    #[arg(short, long, value_enum, default_value = "table")]
+    pub format: MetricsFormat,
+
+    /// Output file (defaults to stdout if not specified)
+    #[arg(short, long, value_name = "FILE")]
+    pub output: Option<PathBuf>,
+
+    /// Disable the main sequence chart in Markdown output
+    #[arg(long, action = clap::ArgAction::SetTrue)]
+    pub no_diagram: bool,
 }
 
 #[derive(Parser, Debug, Clone)]
@@ -157,6 +351,14 @@ pub struct InitArgs {
     /// Explicitly specify framework presets (comma-separated or multiple flags)
     #[arg(long, value_delimiter = ',')]
     pub presets: Vec<String>,
+
+    /// Propose `layer_violation` layers from the current dependency graph
+    #[arg(long)]
+    pub infer_layers: bool,
+
+    /// Folder depth below the project root used as layers
+    #[arg(long, default_value = "2", requires = "infer_layers")]
+    pub layer_depth: usize,
 }
 
 #[derive(Parser, Debug, Clone)]
@@ -191,9 +393,10 @@ pub struct DiffArgs {
     #[arg(short, long)]
     pub json: bool,
 
-    /// Minimum severity to fail on (low, medium, high, critical)
-    #[arg(long, default_value = "low")]
-    pub fail_on: String,
+    /// Minimum severity to fail on (low, medium, high, critical).
+    /// Defaults to `low`, or to gates only when `gates` are configured
+    #[arg(long)]
+    pub fail_on: Option<String>,
 
     /// Project path
     #[arg(short, long)]
@@ -239,7 +442,68 @@ pub struct CacheArgs {
 #[derive(Parser, Debug)]
 pub enum CacheCommand {
     /// Clear analysis cache
-    Clear,
+    Clear {
+        /// Path to the project directory
+        #[arg(short, long, value_name = "PATH", default_value = ".")]
+        path: PathBuf,
+    },
+
+    /// Write the project cache to a relocatable file
+    Export {
+        /// Output file
+        file: PathBuf,
+
+        /// Path to the project directory
+        #[arg(short, long, value_name = "PATH", default_value = ".")]
+        path: PathBuf,
+    },
+
+    /// Merge a file written by `cache export` into the project cache
+    Import {
+        /// Input file
+        file: PathBuf,
+
+        /// Path to the project directory
+        #[arg(short, long, value_name = "PATH", default_value = ".")]
+        path: PathBuf,
+    },
+
+    /// Show cache size, entry counts and hit ratio of the last run
+    Stats {
+        /// Path to the project directory
+        #[arg(short, long, value_name = "PATH", default_value = ".")]
+        path: PathBuf,
+    },
+}
+
+#[derive(Parser, Debug)]
+pub struct DaemonArgs {
+    #[command(subcommand)]
+    pub command: DaemonCommand,
+}
+
+#[derive(Parser, Debug)]
+pub enum DaemonCommand {
+    /// Run the daemon in the foreground until stopped
+    Start {
+        /// Path to the project directory
+        #[arg(value_name = "PATH", default_value = ".")]
+        path: PathBuf,
+    },
+
+    /// Stop the daemon of a project
+    Stop {
+        /// Path to the project directory
+        #[arg(value_name = "PATH", default_value = ".")]
+        path: PathBuf,
+    },
+
+    /// Show whether a daemon runs for a project and what it holds
+    Status {
+        /// Path to the project directory
+        #[arg(value_name = "PATH", default_value = ".")]
+        path: PathBuf,
+    },
 }
 
 #[derive(Parser, Debug)]
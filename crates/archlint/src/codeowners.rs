@@ -0,0 +1,139 @@
+//! Minimal `CODEOWNERS` support for attributing findings to owners.
+//!
+//! Follows GitHub semantics: the last matching rule wins, a pattern without a
+//! slash (other than a trailing one) matches at any depth, and a pattern
+//! matching a directory covers everything below it.
+
+use glob::{MatchOptions, Pattern};
+use std::fs;
+use std::path::Path;
+
+/// Locations searched for a `CODEOWNERS` file, in GitHub's order.
+const LOCATIONS: &[&str] = &[".github/CODEOWNERS", "CODEOWNERS", "docs/CODEOWNERS"];
+
+const MATCH_OPTIONS: MatchOptions = MatchOptions {
+    case_sensitive: true,
+    require_literal_separator: true,
+    require_literal_leading_dot: false,
+};
+
+struct OwnerRule {
+    patterns: Vec<Pattern>,
+    owners: Vec<String>,
+}
+
+/// Parsed `CODEOWNERS` rules.
+pub struct CodeOwners {
+    rules: Vec<OwnerRule>,
+}
+
+impl CodeOwners {
+    /// Load the first `CODEOWNERS` file found under `project_root`.
+    #[must_use]
+    pub fn discover(project_root: &Path) -> Option<Self> {
+        LOCATIONS
+            .iter()
+            .map(|location| project_root.join(location))
+            .find_map(|path| fs::read_to_string(path).ok())
+            .map(|content| Self::parse(&content))
+    }
+
+    #[must_use]
+    pub fn parse(content: &str) -> Self {
+        let rules = content
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| {
+                let mut parts = line.split_whitespace();
+                let pattern = parts.next()?;
+                let owners = parts
+                    .take_while(|part| !part.starts_with('#'))
+                    .map(ToString::to_string)
+                    .collect();
+                Some(OwnerRule {
+                    patterns: Self::translate(pattern),
+                    owners,
+                })
+            })
+            .collect();
+
+        Self { rules }
+    }
+
+    /// Glob patterns equivalent to a `CODEOWNERS` pattern.
+    fn translate(pattern: &str) -> Vec<Pattern> {
+        let dir_only = pattern.ends_with('/');
+        let trimmed = pattern.trim_end_matches('/');
+        let anchored = trimmed.starts_with('/') || trimmed.contains('/');
+        let base = trimmed.trim_start_matches('/');
+
+        let mut globs = Vec::new();
+        let prefixes: &[&str] = if anchored { &[""] } else { &["", "**/"] };
+        for prefix in prefixes {
+            if !dir_only {
+                globs.push(format!("{prefix}{base}"));
+            }
+            globs.push(format!("{prefix}{base}/**"));
+        }
+
+        globs
+            .iter()
+            .filter_map(|glob| Pattern::new(glob).ok())
+            .collect()
+    }
+
+    /// Owners of `relative_path`, or `None` if no rule assigns any.
+    #[must_use]
+    pub fn owners_of(&self, relative_path: &str) -> Option<&[String]> {
+        self.rules
+            .iter()
+            .rev()
+            .find(|rule| {
+                rule.patterns
+                    .iter()
+                    .any(|p| p.matches_with(relative_path, MATCH_OPTIONS))
+            })
+            .map(|rule| rule.owners.as_slice())
+            .filter(|owners| !owners.is_empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_last_matching_rule_wins() {
+        let owners = CodeOwners::parse(
+            "# Default owners\n\
+             *            @org/core\n\
+             *.md         @org/docs\n\
+             /src/billing/ @org/billing @alice # payments\n\
+             /src/billing/legacy\n",
+        );
+
+        assert_eq!(
+            owners.owners_of("src/app/main.ts"),
+            Some(&["@org/core".to_string()][..])
+        );
+        assert_eq!(
+            owners.owners_of("src/app/README.md"),
+            Some(&["@org/docs".to_string()][..])
+        );
+        assert_eq!(
+            owners.owners_of("src/billing/invoice.ts"),
+            Some(&["@org/billing".to_string(), "@alice".to_string()][..])
+        );
+        // A rule without owners removes ownership
+        assert_eq!(owners.owners_of("src/billing/legacy/old.ts"), None);
+    }
+
+    #[test]
+    fn test_unanchored_directory_matches_at_any_depth() {
+        let owners = CodeOwners::parse("utils/ @org/platform\n");
+        assert!(owners.owners_of("packages/a/utils/date.ts").is_some());
+        assert!(owners.owners_of("utils/date.ts").is_some());
+        assert!(owners.owners_of("src/utils.ts").is_none());
+    }
+}
@@ -0,0 +1,281 @@
+use crate::api::ScanOptions;
+#[cfg(all(feature = "cli", unix))]
+use crate::cache::hash::config_hash;
+use crate::config::Config;
+use crate::detectors::dead_code::DeadCodeDetector;
+use crate::detectors::test_leakage::TestLeakageDetector;
+use crate::engine::AnalysisEngine;
+use crate::package_json::PackageJsonParser;
+use crate::project_root::detect_project_root;
+use crate::{AnalysisError, Result};
+use serde::Serialize;
+use std::collections::{BTreeSet, VecDeque};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+#[cfg(feature = "cli")]
+use clap::ValueEnum;
+
+#[cfg_attr(feature = "cli", derive(ValueEnum))]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AffectedFormat {
+    #[default]
+    #[cfg_attr(feature = "cli", value(name = "table"))]
+    Table,
+    #[cfg_attr(feature = "cli", value(name = "json"))]
+    Json,
+}
+
+#[derive(Debug, Clone)]
+pub struct AffectedOptions {
+    pub path: PathBuf,
+    pub config: Option<PathBuf>,
+    /// Git revision whose changes up to the working tree are the change set.
+    pub since: Option<String>,
+    /// Changed files, in addition to those found with `since`.
+    pub files: Vec<PathBuf>,
+    pub tests_only: bool,
+    pub format: AffectedFormat,
+    pub output: Option<PathBuf>,
+}
+
+/// Files depending on a change set, split the way CI uses them.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AffectedReport {
+    /// Changed source files, including deleted ones.
+    pub changed: Vec<String>,
+    /// Affected files that are not tests, changed files included.
+    pub sources: Vec<String>,
+    /// Affected sources that are entry points of the project.
+    pub entry_points: Vec<String>,
+    /// Affected test files, according to the `test_leakage` test patterns.
+    pub tests: Vec<String>,
+}
+
+pub fn run_affected(options: &AffectedOptions) -> Result<()> {
+    if !options.path.exists() {
+        return Err(AnalysisError::PathResolution(format!(
+            "Path does not exist: {}",
+            options.path.display()
+        )));
+    }
+    if options.since.is_none() && options.files.is_empty() {
+        return Err(AnalysisError::InvalidConfig(
+            "Pass --since <REF> or the changed files".to_string(),
+        ));
+    }
+
+    let report = collect_affected(options)?;
+    let output = match (options.format, options.tests_only) {
+        (AffectedFormat::Table, false) => render_table(&report),
+        // One path per line, ready to pass to a test runner
+        (AffectedFormat::Table, true) => report.tests.join("\n"),
+        (AffectedFormat::Json, false) => serde_json::to_string_pretty(&report)?,
+        (AffectedFormat::Json, true) => serde_json::to_string_pretty(&report.tests)?,
+    };
+
+    if let Some(path) = &options.output {
+        std::fs::write(path, output)?;
+    } else if !output.is_empty() {
+        println!("{output}");
+    }
+
+    Ok(())
+}
+
+/// Find the files depending on the change set and classify them.
+pub fn collect_affected(options: &AffectedOptions) -> Result<AffectedReport> {
+    let path = options
+        .path
+        .canonicalize()
+        .unwrap_or_else(|_| options.path.clone());
+    let project_root = detect_project_root(&path);
+    let config = match &options.config {
+        Some(config_path) => Config::load(config_path)?,
+        None => Config::load_or_default(None, Some(&project_root))?,
+    };
+
+    let mut changed = BTreeSet::new();
+    if let Some(since) = &options.since {
+        changed.extend(changed_since(&project_root, since)?);
+    }
+    changed.extend(options.files.iter().map(|file| {
+        file.canonicalize()
+            .unwrap_or_else(|_| project_root.join(file))
+    }));
+    let changed: Vec<PathBuf> = changed.into_iter().collect();
+    if changed.is_empty() {
+        return Ok(AffectedReport::default());
+    }
+
+    let affected = affected_files(options, &path, &project_root, &config, &changed)?;
+
+    let pkg_config = PackageJsonParser::parse(&project_root)?;
+    let entry_detector = DeadCodeDetector::new(
+        &config,
+        pkg_config.entry_points,
+        pkg_config.dynamic_load_patterns,
+        &config.ignore,
+        project_root.clone(),
+    );
+    let test_patterns = TestLeakageDetector::configured_test_patterns(&config);
+    let classifier = TestLeakageDetector;
+
+    let relative = |file: &Path| {
+        file.strip_prefix(&project_root)
+            .unwrap_or(file)
+            .to_string_lossy()
+            .replace('\\', "/")
+    };
+    let mut report = AffectedReport {
+        changed: changed.iter().map(|f| relative(f)).collect(),
+        ..AffectedReport::default()
+    };
+    // Deleted files have nothing left to run or build
+    for file in affected.iter().filter(|f| f.exists()) {
+        if classifier.is_test_file(file, Some(&test_patterns)) {
+            report.tests.push(relative(file));
+        } else {
+            if entry_detector.is_entry_point(file) {
+                report.entry_points.push(relative(file));
+            }
+            report.sources.push(relative(file));
+        }
+    }
+    Ok(report)
+}
+
+/// Reverse-dependency closure of `changed`, from the project daemon when one runs.
+fn affected_files(
+    options: &AffectedOptions,
+    path: &Path,
+    project_root: &Path,
+    config: &Config,
+    changed: &[PathBuf],
+) -> Result<BTreeSet<PathBuf>> {
+    let deleted: Vec<PathBuf> = changed.iter().filter(|f| !f.exists()).cloned().collect();
+
+    // The daemon drops deleted files from its graph, along with the edges to them
+    #[cfg(all(feature = "cli", unix))]
+    if deleted.is_empty() {
+        if let Some(affected) = daemon_affected(path, project_root, config, changed) {
+            return Ok(affected);
+        }
+    }
+    #[cfg(not(all(feature = "cli", unix)))]
+    let _ = project_root;
+
+    let args = ScanOptions {
+        config_path: options.config.clone(),
+        enable_cache: true,
+        enable_git: false,
+        ..ScanOptions::default()
+    }
+    .to_scan_args(path);
+    let engine = AnalysisEngine::new(args, config.clone())?;
+    let graph = engine.dependency_graph(&deleted)?;
+
+    let mut affected = BTreeSet::new();
+    let mut queue: VecDeque<PathBuf> = changed.iter().cloned().collect();
+    while let Some(file) = queue.pop_front() {
+        if !affected.insert(file.clone()) {
+            continue;
+        }
+        if let Some(node) = graph.get_node(&file) {
+            queue.extend(
+                graph
+                    .dependents(node)
+                    .filter_map(|importer| graph.get_file_path(importer).cloned()),
+            );
+        }
+    }
+    Ok(affected)
+}
+
+/// Affected files according to the daemon, when it analyzes `path` with `config`.
+#[cfg(all(feature = "cli", unix))]
+fn daemon_affected(
+    path: &Path,
+    project_root: &Path,
+    config: &Config,
+    changed: &[PathBuf],
+) -> Option<BTreeSet<PathBuf>> {
+    use crate::daemon::protocol::{DaemonStatus, FilesParams};
+
+    let mut client = crate::daemon::DaemonClient::connect(project_root)?;
+    let status: DaemonStatus = match client.call("status", &()) {
+        Ok(status) => status,
+        Err(e) => {
+            log::debug!("Daemon unavailable, analyzing locally: {e}");
+            return None;
+        }
+    };
+    if status.target_path != path || status.config_hash != config_hash(config) {
+        log::debug!("Daemon analyzes another path or config, analyzing locally");
+        return None;
+    }
+
+    let params = FilesParams {
+        files: changed.to_vec(),
+    };
+    match client.call::<_, Vec<PathBuf>>("affected", &params) {
+        Ok(affected) => Some(affected.into_iter().collect()),
+        Err(e) => {
+            log::debug!("Daemon unavailable, analyzing locally: {e}");
+            None
+        }
+    }
+}
+
+/// Source files changed between `since` and the working tree, untracked files included.
+fn changed_since(project_root: &Path, since: &str) -> Result<Vec<PathBuf>> {
+    let repo = git2::Repository::discover(project_root)?;
+    let tree = repo.revparse_single(since)?.peel_to_tree()?;
+    let workdir = repo
+        .workdir()
+        .ok_or_else(|| AnalysisError::GitCommand("Repository has no working tree".to_string()))?;
+    let workdir = workdir
+        .canonicalize()
+        .unwrap_or_else(|_| workdir.to_path_buf());
+
+    let mut diff_options = git2::DiffOptions::new();
+    diff_options
+        .include_untracked(true)
+        .recurse_untracked_dirs(true);
+    let diff = repo.diff_tree_to_workdir_with_index(Some(&tree), Some(&mut diff_options))?;
+
+    let mut files = BTreeSet::new();
+    for delta in diff.deltas() {
+        // Renames change both the old and the new path
+        for file in [delta.old_file(), delta.new_file()] {
+            let Some(path) = file.path() else { continue };
+            let is_source = path
+                .extension()
+                .and_then(|e| e.to_str())
+                .is_some_and(|e| crate::args::SUPPORTED_EXTENSIONS.contains(&e));
+            if is_source {
+                files.insert(workdir.join(path));
+            }
+        }
+    }
+    Ok(files.into_iter().collect())
+}
+
+fn render_table(report: &AffectedReport) -> String {
+    let mut output = String::new();
+    let sections = [
+        ("Changed files", &report.changed),
+        ("Affected source files", &report.sources),
+        ("Affected entry points", &report.entry_points),
+        ("Affected test files", &report.tests),
+    ];
+    for (title, files) in sections {
+        let _ = writeln!(output, "{title} ({})", files.len());
+        for file in files {
+            let _ = writeln!(output, "  {file}");
+        }
+        output.push('\n');
+    }
+    output.trim_end().to_string()
+}
@@ -0,0 +1,298 @@
+#[cfg(not(feature = "cli"))]
+use crate::no_cli_mocks::comfy_table::modifiers::UTF8_ROUND_CORNERS;
+#[cfg(not(feature = "cli"))]
+use crate::no_cli_mocks::comfy_table::presets::UTF8_FULL;
+#[cfg(not(feature = "cli"))]
+use crate::no_cli_mocks::comfy_table::{Attribute, Cell, Color, ContentArrangement, Table};
+
+#[cfg(feature = "cli")]
+use comfy_table::modifiers::UTF8_ROUND_CORNERS;
+#[cfg(feature = "cli")]
+use comfy_table::presets::UTF8_FULL;
+#[cfg(feature = "cli")]
+use comfy_table::{Attribute, Cell, Color, ContentArrangement, Table};
+
+use crate::args::ScanArgs;
+use crate::calibrate::{Calibration, ThresholdProposal};
+use crate::config::{Config, RuleConfig};
+use crate::engine::AnalysisEngine;
+use crate::{AnalysisError, Result};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+#[cfg(feature = "cli")]
+use clap::ValueEnum;
+
+#[cfg_attr(feature = "cli", derive(ValueEnum))]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CalibrateFormat {
+    /// Distribution table followed by the config diff
+    #[default]
+    #[cfg_attr(feature = "cli", value(name = "table"))]
+    Table,
+    /// Only the config diff
+    #[cfg_attr(feature = "cli", value(name = "diff"))]
+    Diff,
+    #[cfg_attr(feature = "cli", value(name = "json"))]
+    Json,
+}
+
+#[derive(Debug, Clone)]
+pub struct CalibrateOptions {
+    pub path: PathBuf,
+    pub config: Option<PathBuf>,
+    /// Share of samples proposed thresholds flag, in percent.
+    pub top: usize,
+    pub format: CalibrateFormat,
+    pub output: Option<PathBuf>,
+}
+
+pub fn run_calibrate(options: &CalibrateOptions) -> Result<()> {
+    if !options.path.exists() {
+        return Err(AnalysisError::PathResolution(format!(
+            "Path does not exist: {}",
+            options.path.display()
+        )));
+    }
+
+    let (calibration, config, config_path) = collect_calibration(options)?;
+    let diff = render_diff(&calibration, &config, config_path.as_deref());
+    let output = match options.format {
+        CalibrateFormat::Table => format!("{}\n\n{diff}", render_table(&calibration)),
+        CalibrateFormat::Diff => diff,
+        CalibrateFormat::Json => serde_json::to_string_pretty(&calibration)?,
+    };
+
+    if let Some(path) = &options.output {
+        std::fs::write(path, output)?;
+    } else {
+        println!("{output}");
+    }
+
+    Ok(())
+}
+
+/// Run the analysis and calibrate thresholds against the user config.
+fn collect_calibration(
+    options: &CalibrateOptions,
+) -> Result<(Calibration, Config, Option<PathBuf>)> {
+    // These detectors make the parser collect complexity, classes and the
+    // fields methods use; their own findings are not needed.
+    let args = ScanArgs {
+        path: options.path.clone(),
+        config: options.config.clone(),
+        report: None,
+        format: crate::args::OutputFormat::Json,
+        json: true,
+        no_diagram: true,
+        all_detectors: false,
+        detectors: Some("god_module,cyclomatic_complexity,lcom".to_string()),
+        exclude_detectors: None,
+        quiet: true,
+        verbose: false,
+        min_severity: None,
+        min_score: None,
+        severity: None,
+        no_cache: false,
+        no_git: false,
+        git_history_period: None,
+        max_file_size: None,
+        files: None,
+    };
+    let engine = AnalysisEngine::new_with_args(args)?;
+    let report = engine.run()?;
+
+    // The engine's config has the detector overrides above applied
+    let config_path = options
+        .config
+        .clone()
+        .or_else(|| Config::find_file(Some(&engine.project_root)));
+    let config = Config::load_or_default(config_path.as_deref(), Some(&engine.project_root))?;
+    let calibration = Calibration::compute(&report, &config, options.top);
+
+    let label = config_path.map(|p| {
+        p.strip_prefix(&engine.project_root)
+            .map(Path::to_path_buf)
+            .unwrap_or(p)
+    });
+    Ok((calibration, config, label))
+}
+
+fn render_table(calibration: &Calibration) -> String {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_ROUND_CORNERS)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            Cell::new("Metric").add_attribute(Attribute::Bold),
+            Cell::new("Option").add_attribute(Attribute::Bold),
+            Cell::new("Samples").add_attribute(Attribute::Bold),
+            Cell::new("p50").add_attribute(Attribute::Bold),
+            Cell::new("p90").add_attribute(Attribute::Bold),
+            Cell::new("p95").add_attribute(Attribute::Bold),
+            Cell::new("p99").add_attribute(Attribute::Bold),
+            Cell::new("Max").add_attribute(Attribute::Bold),
+            Cell::new("Current (flagged)").add_attribute(Attribute::Bold),
+            Cell::new(format!("Top {}% (flagged)", calibration.top_percent))
+                .add_attribute(Attribute::Bold),
+        ]);
+
+    for t in &calibration.thresholds {
+        let d = &t.distribution;
+        let proposed = Cell::new(format!("{} ({})", t.proposed, t.flagged_proposed));
+        table.add_row(vec![
+            Cell::new(t.metric).fg(Color::Cyan),
+            Cell::new(format!("{}.{}", t.detector, t.option)),
+            Cell::new(d.count),
+            Cell::new(d.p50),
+            Cell::new(d.p90),
+            Cell::new(d.p95),
+            Cell::new(d.p99),
+            Cell::new(d.max),
+            Cell::new(format!("{} ({})", t.current, t.flagged_current)),
+            if t.changed() {
+                proposed.fg(Color::Yellow)
+            } else {
+                proposed
+            },
+        ]);
+    }
+
+    table.to_string()
+}
+
+/// Proposed thresholds as a unified-diff style patch of the config's `rules`.
+fn render_diff(calibration: &Calibration, config: &Config, config_path: Option<&Path>) -> String {
+    let changed: Vec<&ThresholdProposal> = calibration
+        .thresholds
+        .iter()
+        .filter(|t| t.changed())
+        .collect();
+    if changed.is_empty() {
+        return "# Current thresholds already match the distribution; nothing to change.\n"
+            .to_string();
+    }
+
+    let name = config_path.map_or_else(
+        || ".archlint.yaml".to_string(),
+        |p| p.to_string_lossy().replace('\\', "/"),
+    );
+    let mut output = String::new();
+    let _ = writeln!(
+        output,
+        "--- {}",
+        if config_path.is_some() {
+            name.as_str()
+        } else {
+            "/dev/null"
+        }
+    );
+    let _ = writeln!(output, "+++ {name}");
+    output.push_str(if config.rules.is_empty() {
+        "+rules:\n"
+    } else {
+        " rules:\n"
+    });
+
+    let mut detectors: Vec<&str> = changed.iter().map(|t| t.detector).collect();
+    detectors.dedup();
+    for detector in detectors {
+        match config.rules.get(detector) {
+            Some(RuleConfig::Full(_)) => {
+                let _ = writeln!(output, "   {detector}:");
+            }
+            Some(RuleConfig::Short(severity)) => {
+                // The short form has to be expanded to hold options
+                let _ = writeln!(output, "-  {detector}: {severity}");
+                let _ = writeln!(output, "+  {detector}:");
+                let _ = writeln!(output, "+    severity: {severity}");
+            }
+            None => {
+                let _ = writeln!(output, "+  {detector}:");
+            }
+        }
+
+        for t in changed.iter().filter(|t| t.detector == detector) {
+            if t.configured {
+                let _ = writeln!(output, "-    {}: {}", t.option, t.current);
+                let _ = writeln!(output, "+    {}: {}", t.option, t.proposed);
+            } else {
+                let _ = writeln!(
+                    output,
+                    "+    {}: {} # default: {}",
+                    t.option, t.proposed, t.current
+                );
+            }
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calibrate::Distribution;
+
+    fn proposal(
+        detector: &'static str,
+        option: &'static str,
+        configured: bool,
+    ) -> ThresholdProposal {
+        ThresholdProposal {
+            metric: option,
+            detector,
+            option,
+            distribution: Distribution::from_sorted(&[1, 2, 3]).unwrap(),
+            current: 10,
+            configured,
+            proposed: 3,
+            flagged_current: 0,
+            flagged_proposed: 1,
+        }
+    }
+
+    #[test]
+    fn test_render_diff_expands_short_rules() {
+        let config: Config = serde_yaml::from_str(
+            "rules:\n  god_module: high\n  deep_nesting:\n    max_depth: 10\n",
+        )
+        .unwrap();
+        let calibration = Calibration {
+            top_percent: 5,
+            thresholds: vec![
+                proposal("god_module", "fan_in", false),
+                proposal("deep_nesting", "max_depth", true),
+                proposal("long_params", "max_params", false),
+            ],
+        };
+
+        let diff = render_diff(&calibration, &config, Some(Path::new(".archlint.yaml")));
+        assert_eq!(
+            diff,
+            "--- .archlint.yaml\n\
+             +++ .archlint.yaml\n \
+             rules:\n\
+             -  god_module: high\n\
+             +  god_module:\n\
+             +    severity: high\n\
+             +    fan_in: 3 # default: 10\n   \
+             deep_nesting:\n\
+             -    max_depth: 10\n\
+             +    max_depth: 3\n\
+             +  long_params:\n\
+             +    max_params: 3 # default: 10\n"
+        );
+    }
+
+    #[test]
+    fn test_render_diff_without_config_file() {
+        let calibration = Calibration {
+            top_percent: 5,
+            thresholds: vec![proposal("lcom", "max_lcom", false)],
+        };
+        let diff = render_diff(&calibration, &Config::default(), None);
+        assert!(diff.starts_with("--- /dev/null\n+++ .archlint.yaml\n+rules:\n+  lcom:\n"));
+    }
+}
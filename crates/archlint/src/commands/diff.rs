@@ -2,7 +2,9 @@ use super::diff_output::print_diff_result;
 use super::git_snapshot::generate_snapshot_from_git_ref;
 use crate::api::options::ScanOptions;
 use crate::api::Analyzer;
+use crate::debt::DebtModel;
 use crate::diff::{DiffEngine, DiffResult};
+use crate::gates::evaluate_diff;
 use crate::snapshot::{read_snapshot, SnapshotGenerator};
 use crate::Result;
 use std::path::{Path, PathBuf};
@@ -12,7 +14,7 @@ pub fn run_diff(
     current: String,
     explain: bool,
     json: bool,
-    fail_on: String,
+    fail_on: Option<String>,
     project_path: Option<PathBuf>,
 ) -> Result<i32> {
     let project_path = project_path
@@ -27,11 +29,14 @@ pub fn run_diff(
         .with_threshold(config.diff.metric_threshold_percent)
         .with_line_tolerance(config.diff.line_tolerance);
 
-    let result = if explain {
+    let mut result = if explain {
         engine.diff_with_explain(&baseline_snapshot, &current_snapshot, &config)
     } else {
         engine.diff(&baseline_snapshot, &current_snapshot)
     };
+    if !config.gates.is_empty() {
+        result.gates = Some(evaluate_diff(&config.gates, &result, &current_snapshot));
+    }
 
     if json {
         println!("{}", serde_json::to_string_pretty(&result)?);
@@ -39,7 +44,21 @@ pub fn run_diff(
         print_diff_result(&result, explain);
     }
 
-    Ok(i32::from(should_fail(&result, &fail_on)))
+    Ok(determine_exit_code(&result, fail_on.as_deref()))
+}
+
+/// Failed gates take precedence; `--fail-on` applies by default only without gates.
+fn determine_exit_code(result: &DiffResult, fail_on: Option<&str>) -> i32 {
+    if let Some(gates) = result.gates.as_ref().filter(|g| !g.passed) {
+        return gates.exit_code();
+    }
+
+    let fail_on = match (fail_on, &result.gates) {
+        (Some(fail_on), _) => fail_on,
+        (None, Some(_)) => return 0,
+        (None, None) => "low",
+    };
+    i32::from(should_fail(result, fail_on))
 }
 
 fn load_baseline(
@@ -65,7 +84,9 @@ fn load_current(
         }
         let mut analyzer = Analyzer::new(project_path, ScanOptions::default())?;
         let scan_result = analyzer.scan()?;
-        Ok(SnapshotGenerator::new(project_path.to_path_buf()).generate(&scan_result))
+        Ok(SnapshotGenerator::new(project_path.to_path_buf())
+            .with_debt_model(DebtModel::new(&analyzer.config().debt))
+            .generate(&scan_result))
     } else if is_file_path(current) {
         read_snapshot(Path::new(current)).map_err(Into::into)
     } else {
@@ -16,12 +16,17 @@ use comfy_table::{Attribute, Cell, Color, ContentArrangement, Table};
 #[cfg(feature = "cli")]
 use console::style;
 
-use crate::diff::{DiffResult, Regression, RegressionType};
+use super::gates_output::print_gate_report;
+use crate::debt::format_minutes;
+use crate::diff::{DebtDiff, DiffResult, Regression, RegressionType};
 use std::path::PathBuf;
 
 pub fn print_diff_result(result: &DiffResult, verbose: bool) {
     if !result.has_regressions && result.improvements.is_empty() {
         println!("{}", style("✓ No architectural changes detected.").green());
+        if let Some(gates) = &result.gates {
+            print_gate_report(gates);
+        }
         return;
     }
 
@@ -34,6 +39,10 @@ pub fn print_diff_result(result: &DiffResult, verbose: bool) {
     }
 
     print_footer(result, verbose);
+
+    if let Some(gates) = &result.gates {
+        print_gate_report(gates);
+    }
 }
 
 fn print_regressions_section(result: &DiffResult, verbose: bool) {
@@ -73,6 +82,9 @@ fn print_footer(result: &DiffResult, verbose: bool) {
         println!("Baseline: {}", style(base).dim());
         println!("Current:  {}", style(curr).dim());
     }
+    if let Some(debt) = &result.debt {
+        print_debt_line(debt);
+    }
     println!("{}", style("━".repeat(50)).dim());
 
     if !verbose && result.has_regressions {
@@ -84,6 +96,22 @@ fn print_footer(result: &DiffResult, verbose: bool) {
     }
 }
 
+fn print_debt_line(debt: &DebtDiff) {
+    let net = format_minutes(debt.net);
+    let net = if debt.net > 0.0 {
+        style(format!("+{net}")).red()
+    } else {
+        style(net).green()
+    };
+    println!(
+        "Debt:     {} → {} ({net}; added {}, removed {})",
+        format_minutes(debt.baseline),
+        format_minutes(debt.current),
+        format_minutes(debt.added),
+        format_minutes(debt.removed),
+    );
+}
+
 fn print_regressions_table(regressions: &[Regression]) {
     let mut table = Table::new();
     table
@@ -0,0 +1,531 @@
+use crate::args::ScanArgs;
+use crate::calibrate::{ThresholdOption, THRESHOLDS};
+use crate::detectors::{ArchSmell, Explanation, SmellMetric, SmellType};
+use crate::engine::AnalysisEngine;
+use crate::graph::DependencyGraph;
+use crate::report::AnalysisReport;
+use crate::rule_resolver::ResolvedRuleConfig;
+use crate::snapshot::id::generate_smell_id;
+use crate::snapshot::Location;
+use crate::{AnalysisError, Result};
+use petgraph::graph::NodeIndex;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+#[cfg(feature = "cli")]
+use clap::ValueEnum;
+
+#[cfg_attr(feature = "cli", derive(ValueEnum))]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ExplainFormat {
+    #[default]
+    #[cfg_attr(feature = "cli", value(name = "text"))]
+    Text,
+    #[cfg_attr(feature = "cli", value(name = "json"))]
+    Json,
+}
+
+#[derive(Debug, Clone)]
+pub struct ExplainOptions {
+    pub path: PathBuf,
+    pub config: Option<PathBuf>,
+    /// Smell ID as shown in snapshots, or `file:line` of one of its locations.
+    pub target: String,
+    pub format: ExplainFormat,
+    pub output: Option<PathBuf>,
+}
+
+/// Everything known about one smell.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SmellDetails {
+    pub id: String,
+    /// Detector ID, also the rule name in the config and in ignore comments.
+    pub rule: String,
+    pub name: String,
+    pub severity: String,
+    pub files: Vec<String>,
+    pub explanation: Explanation,
+    pub locations: Vec<Location>,
+    pub metrics: Vec<SmellMetric>,
+    pub thresholds: Vec<ThresholdCheck>,
+    /// Files of a dependency smell in import order, each with the line importing the next.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub import_chain: Vec<ChainLink>,
+    /// Commits touching each file; empty without git history.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub churn: Vec<FileChurn>,
+    pub suppression: SuppressionHint,
+}
+
+/// A metric of the smell compared to the threshold of its rule.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThresholdCheck {
+    pub metric: String,
+    /// Rule option holding the threshold, as `rule.option`.
+    pub option: String,
+    pub value: usize,
+    pub threshold: usize,
+    pub exceeded: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChainLink {
+    pub file: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileChurn {
+    pub file: String,
+    pub commits: usize,
+}
+
+/// Where to put which comment to suppress the smell.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SuppressionHint {
+    pub file: String,
+    /// Line the comment goes above; 1 for comments at the top of the file.
+    pub line: usize,
+    pub comment: String,
+}
+
+pub fn run_explain(options: &ExplainOptions) -> Result<()> {
+    if !options.path.exists() {
+        return Err(AnalysisError::PathResolution(format!(
+            "Path does not exist: {}",
+            options.path.display()
+        )));
+    }
+
+    let details = collect_explanations(options)?;
+    if details.is_empty() {
+        return Err(AnalysisError::NotFound(format!(
+            "no smell matches '{}'",
+            options.target
+        )));
+    }
+
+    let output = match options.format {
+        ExplainFormat::Text => details
+            .iter()
+            .map(render_text)
+            .collect::<Vec<_>>()
+            .join("\n\n"),
+        ExplainFormat::Json => serde_json::to_string_pretty(&details)?,
+    };
+
+    if let Some(path) = &options.output {
+        std::fs::write(path, output)?;
+    } else {
+        println!("{output}");
+    }
+
+    Ok(())
+}
+
+/// Analyze the project and explain the smells matching the target.
+///
+/// An ID matches at most one smell; `file:line` matches every smell with a
+/// location on that line.
+pub fn collect_explanations(options: &ExplainOptions) -> Result<Vec<SmellDetails>> {
+    let args = ScanArgs {
+        path: options.path.clone(),
+        config: options.config.clone(),
+        report: None,
+        format: crate::args::OutputFormat::Json,
+        json: true,
+        no_diagram: true,
+        all_detectors: false,
+        detectors: None,
+        exclude_detectors: None,
+        quiet: true,
+        verbose: false,
+        min_severity: None,
+        min_score: None,
+        severity: None,
+        no_cache: false,
+        no_git: false,
+        git_history_period: None,
+        max_file_size: None,
+        files: None,
+    };
+    let engine = AnalysisEngine::new_with_args(args)?;
+    let report = engine.run()?;
+    let root = &engine.project_root;
+
+    let by_id: Vec<&(ArchSmell, Explanation)> = report
+        .smells
+        .iter()
+        .filter(|(smell, _)| generate_smell_id(smell, root) == options.target)
+        .collect();
+    let matches = if by_id.is_empty() {
+        match parse_file_line(&options.target, root) {
+            Some((file, line)) => report
+                .smells
+                .iter()
+                .filter(|(smell, _)| smell.locations.iter().any(|l| covers(l, &file, line)))
+                .collect(),
+            None => Vec::new(),
+        }
+    } else {
+        by_id
+    };
+
+    Ok(matches
+        .into_iter()
+        .map(|(smell, explanation)| SmellDetails::new(smell, explanation, &report, root))
+        .collect())
+}
+
+impl SmellDetails {
+    fn new(
+        smell: &ArchSmell,
+        explanation: &Explanation,
+        report: &AnalysisReport,
+        root: &Path,
+    ) -> Self {
+        let rule = smell.smell_type.category().to_id();
+        Self {
+            id: generate_smell_id(smell, root),
+            rule: rule.to_string(),
+            name: smell.smell_type.category().display_name().to_string(),
+            severity: format!("{:?}", smell.severity),
+            files: smell.files.iter().map(|f| relative(f, root)).collect(),
+            explanation: explanation.clone(),
+            locations: smell
+                .locations
+                .iter()
+                .map(|loc| Location {
+                    file: relative(&loc.file, root),
+                    line: loc.line,
+                    column: loc.column,
+                    range: loc.range,
+                    description: (!loc.description.is_empty()).then(|| loc.description.clone()),
+                })
+                .collect(),
+            metrics: smell.metrics.clone(),
+            thresholds: threshold_checks(smell, rule, &report.config),
+            import_chain: report
+                .graph
+                .as_ref()
+                .map(|graph| import_chain(smell, graph, root))
+                .unwrap_or_default(),
+            churn: smell
+                .files
+                .iter()
+                .filter_map(|f| {
+                    report.churn_map.get(f).map(|&commits| FileChurn {
+                        file: relative(f, root),
+                        commits,
+                    })
+                })
+                .collect(),
+            suppression: suppression_hint(smell, rule, root),
+        }
+    }
+}
+
+fn parse_file_line(target: &str, root: &Path) -> Option<(PathBuf, usize)> {
+    let (file, line) = target.rsplit_once(':')?;
+    let line = line.parse().ok()?;
+    let path = root.join(file);
+    Some((path.canonicalize().unwrap_or(path), line))
+}
+
+fn covers(location: &crate::detectors::LocationDetail, file: &Path, line: usize) -> bool {
+    location.file == file
+        && (location.line == line
+            || location
+                .range
+                .is_some_and(|r| (r.start_line..=r.end_line).contains(&line)))
+}
+
+/// The smell's metrics next to the thresholds its rule resolves to for the smell's file.
+fn threshold_checks(
+    smell: &ArchSmell,
+    rule: &str,
+    config: &crate::config::Config,
+) -> Vec<ThresholdCheck> {
+    let resolved =
+        ResolvedRuleConfig::resolve(config, rule, smell.files.first().map(PathBuf::as_path));
+    THRESHOLDS
+        .iter()
+        .filter(|t| t.detector == rule)
+        .filter_map(|t| {
+            let value = smell.metrics.iter().find_map(|m| measured(t, m))?;
+            let threshold = t.current(&resolved);
+            Some(ThresholdCheck {
+                metric: t.metric.to_string(),
+                option: format!("{}.{}", t.detector, t.option),
+                value,
+                threshold,
+                exceeded: t.flags(value, threshold),
+            })
+        })
+        .collect()
+}
+
+/// The value `threshold` is compared with, if `metric` holds it.
+fn measured(threshold: &ThresholdOption, metric: &SmellMetric) -> Option<usize> {
+    match (threshold.detector, threshold.option, metric) {
+        ("god_module", "fan_in", SmellMetric::FanIn(v))
+        | ("god_module", "fan_out", SmellMetric::FanOut(v))
+        | ("god_module", "churn", SmellMetric::Churn(v))
+        | ("cyclomatic_complexity", _, SmellMetric::CyclomaticComplexity(v))
+        | ("cognitive_complexity", _, SmellMetric::CognitiveComplexity(v))
+        | ("deep_nesting", _, SmellMetric::Depth(v))
+        | ("long_params", _, SmellMetric::ParameterCount(v))
+        | ("lcom", _, SmellMetric::Lcom(v))
+        | ("large_file" | "long_function", _, SmellMetric::Lines(v)) => Some(*v),
+        _ => None,
+    }
+}
+
+/// How the files of a dependency smell import each other, empty for other smells.
+fn import_chain(smell: &ArchSmell, graph: &DependencyGraph, root: &Path) -> Vec<ChainLink> {
+    let path: Vec<PathBuf> = match &smell.smell_type {
+        SmellType::CyclicDependency | SmellType::CyclicDependencyCluster => {
+            let within: HashSet<&PathBuf> = smell.files.iter().collect();
+            let Some(start) = smell.files.first() else {
+                return Vec::new();
+            };
+            // Through the files in order when they list the cycle, else the
+            // shortest way back to the first file
+            let mut path = vec![start.clone()];
+            let hops: Vec<(&PathBuf, &PathBuf)> =
+                if matches!(smell.smell_type, SmellType::CyclicDependency) {
+                    smell
+                        .files
+                        .iter()
+                        .zip(smell.files.iter().cycle().skip(1))
+                        .collect()
+                } else {
+                    vec![(start, start)]
+                };
+            for (from, to) in hops {
+                match shortest_path(graph, from, to, &within) {
+                    Some(hop) => path.extend(hop.into_iter().skip(1)),
+                    None => return Vec::new(),
+                }
+            }
+            path
+        }
+        SmellType::LayerViolation { .. } | SmellType::SdpViolation => {
+            let Some(location) = smell.locations.first() else {
+                return Vec::new();
+            };
+            let Some(from) = graph.get_node(&location.file) else {
+                return Vec::new();
+            };
+            graph
+                .dependencies(from)
+                .find(|&to| {
+                    graph
+                        .get_edge_data(from, to)
+                        .is_some_and(|e| e.import_line == location.line)
+                })
+                .and_then(|to| graph.get_file_path(to))
+                .map(|to| vec![location.file.clone(), to.clone()])
+                .unwrap_or_default()
+        }
+        SmellType::TestLeakage { test_file } => smell
+            .files
+            .first()
+            .map(|from| vec![from.clone(), test_file.clone()])
+            .unwrap_or_default(),
+        // The locations already hold the chain with the import lines
+        SmellType::ServerCodeInClient { .. } => {
+            return smell
+                .locations
+                .iter()
+                .map(|loc| ChainLink {
+                    file: relative(&loc.file, root),
+                    line: Some(loc.line),
+                })
+                .collect();
+        }
+        _ => return Vec::new(),
+    };
+
+    path.iter()
+        .enumerate()
+        .map(|(i, file)| ChainLink {
+            file: relative(file, root),
+            line: path.get(i + 1).and_then(|next| {
+                let edge = graph.get_edge_data(graph.get_node(file)?, graph.get_node(next)?)?;
+                Some(edge.import_line)
+            }),
+        })
+        .collect()
+}
+
+/// Shortest import path of at least one edge from `from` to `to`, staying within `within`.
+fn shortest_path(
+    graph: &DependencyGraph,
+    from: &Path,
+    to: &Path,
+    within: &HashSet<&PathBuf>,
+) -> Option<Vec<PathBuf>> {
+    let start = graph.get_node(from)?;
+    let target = graph.get_node(to)?;
+    let mut previous: HashMap<NodeIndex, NodeIndex> = HashMap::new();
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        for next in graph.dependencies(node) {
+            let allowed = graph
+                .get_file_path(next)
+                .is_some_and(|p| within.contains(p));
+            if !allowed || previous.contains_key(&next) {
+                continue;
+            }
+            previous.insert(next, node);
+            if next == target {
+                let mut path = vec![next];
+                let mut current = node;
+                while current != start {
+                    path.push(current);
+                    current = previous[&current];
+                }
+                path.push(start);
+                path.reverse();
+                return path
+                    .into_iter()
+                    .map(|n| graph.get_file_path(n).cloned())
+                    .collect();
+            }
+            queue.push_back(next);
+        }
+    }
+    None
+}
+
+/// The comment the engine honors for the smell: on the line above its first
+/// location, or `archlint-disable *` at the top of the file for file-wide smells.
+fn suppression_hint(smell: &ArchSmell, rule: &str, root: &Path) -> SuppressionHint {
+    let anchor = smell
+        .locations
+        .first()
+        .map(|loc| (loc.file.as_path(), loc.line))
+        .or_else(|| smell.files.first().map(|f| (f.as_path(), 0)));
+    match anchor {
+        Some((file, line)) if line > 0 => SuppressionHint {
+            file: relative(file, root),
+            line,
+            comment: format!("// archlint-disable-next-line {rule} -- <reason>"),
+        },
+        _ => SuppressionHint {
+            file: anchor.map(|(f, _)| relative(f, root)).unwrap_or_default(),
+            line: 1,
+            comment: "// archlint-disable * -- <reason>".to_string(),
+        },
+    }
+}
+
+fn relative(path: &Path, root: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+fn render_text(details: &SmellDetails) -> String {
+    let mut output = String::new();
+    let explanation = &details.explanation;
+    let _ = writeln!(
+        output,
+        "{} ({}), severity {}",
+        details.name, details.rule, details.severity
+    );
+    let _ = writeln!(output, "ID: {}", details.id);
+    let _ = writeln!(output, "\n{}", explanation.problem);
+    let _ = writeln!(output, "\n{}", explanation.reason);
+    write_list(&mut output, "Risks", &explanation.risks);
+    write_list(&mut output, "Recommendations", &explanation.recommendations);
+
+    let locations: Vec<String> = if details.locations.is_empty() {
+        details.files.clone()
+    } else {
+        details
+            .locations
+            .iter()
+            .map(|loc| match &loc.description {
+                Some(description) => format!("{}:{}  {description}", loc.file, loc.line),
+                None => format!("{}:{}", loc.file, loc.line),
+            })
+            .collect()
+    };
+    write_list(&mut output, "Locations", &locations);
+
+    let thresholds: Vec<String> = details
+        .thresholds
+        .iter()
+        .map(|t| {
+            format!(
+                "{}: {} against {} from {}{}",
+                t.metric,
+                t.value,
+                t.threshold,
+                t.option,
+                if t.exceeded { ", exceeded" } else { "" }
+            )
+        })
+        .collect();
+    let metrics: Vec<String> = details
+        .metrics
+        .iter()
+        .filter_map(|m| match serde_json::to_value(m) {
+            Ok(serde_json::Value::Object(map)) => {
+                map.into_iter().next().map(|(k, v)| format!("{k}: {v}"))
+            }
+            _ => None,
+        })
+        .collect();
+    write_list(&mut output, "Metrics", &metrics);
+    write_list(&mut output, "Thresholds", &thresholds);
+
+    if !details.import_chain.is_empty() {
+        let chain: Vec<String> = details
+            .import_chain
+            .iter()
+            .map(|link| match link.line {
+                Some(line) => format!("{}:{line}", link.file),
+                None => link.file.clone(),
+            })
+            .collect();
+        let _ = writeln!(output, "\nImport chain:\n  {}", chain.join(" -> "));
+    }
+
+    let churn: Vec<String> = details
+        .churn
+        .iter()
+        .map(|c| format!("{}: {} commits", c.file, c.commits))
+        .collect();
+    write_list(&mut output, "Churn", &churn);
+
+    let hint = &details.suppression;
+    let _ = write!(
+        output,
+        "\nTo suppress, add above {}:{}:\n  {}",
+        hint.file, hint.line, hint.comment
+    );
+    output
+}
+
+fn write_list(output: &mut String, title: &str, items: &[String]) {
+    if items.is_empty() {
+        return;
+    }
+    let _ = writeln!(output, "\n{title}:");
+    for item in items {
+        let _ = writeln!(output, "  - {item}");
+    }
+}
@@ -0,0 +1,26 @@
+#[cfg(not(feature = "cli"))]
+use crate::no_cli_mocks::console::style;
+
+#[cfg(feature = "cli")]
+use console::style;
+
+use crate::gates::{GateReport, GateStatus};
+
+pub fn print_gate_report(gates: &GateReport) {
+    println!();
+    let verdict = if gates.passed {
+        style("passed").green().bold()
+    } else {
+        style("failed").red().bold()
+    };
+    println!("{} {verdict}", style("Quality gates:").bold());
+
+    for gate in &gates.results {
+        let (icon, message) = match gate.status {
+            GateStatus::Passed => (style("✓").green(), style(gate.message.as_str()).dim()),
+            GateStatus::Failed => (style("✗").red(), style(gate.message.as_str()).red()),
+            GateStatus::Skipped => (style("-").dim(), style(gate.message.as_str()).dim()),
+        };
+        println!("  {icon} {} ({message})", gate.name);
+    }
+}
@@ -1,5 +1,6 @@
 use crate::api::options::ScanOptions;
 use crate::api::Analyzer;
+use crate::debt::DebtModel;
 #[cfg(not(feature = "cli"))]
 use crate::no_cli_mocks::console::style;
 use crate::snapshot::{Snapshot, SnapshotGenerator};
@@ -166,6 +167,7 @@ pub fn generate_snapshot_from_git_ref(
     // Generate snapshot (paths relative to analysis_path)
     let snapshot = SnapshotGenerator::new(analysis_path)
         .with_commit(true)
+        .with_debt_model(DebtModel::new(&analyzer.config().debt))
         .generate(&scan_result);
 
     Ok(snapshot)
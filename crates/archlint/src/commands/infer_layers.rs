@@ -0,0 +1,118 @@
+#[cfg(not(feature = "cli"))]
+use crate::no_cli_mocks::console::style;
+
+#[cfg(feature = "cli")]
+use console::style;
+
+use crate::args::ScanArgs;
+use crate::engine::AnalysisEngine;
+use crate::layer_inference::{infer_layers, LayerInference};
+use crate::Result;
+use std::path::{Path, PathBuf};
+
+/// Example imports printed per violating folder dependency.
+const MAX_EXAMPLES: usize = 3;
+
+/// Build the dependency graph of `path` and propose layers for its folders.
+pub fn collect_layers(
+    path: &Path,
+    config: Option<PathBuf>,
+    depth: usize,
+) -> Result<(LayerInference, PathBuf)> {
+    // Only the graph is needed; `layer_violation` finds nothing without layers
+    let args = ScanArgs {
+        path: path.to_path_buf(),
+        config,
+        report: None,
+        format: crate::args::OutputFormat::Json,
+        json: true,
+        no_diagram: true,
+        all_detectors: false,
+        detectors: Some("layer_violation".to_string()),
+        exclude_detectors: None,
+        quiet: true,
+        verbose: false,
+        min_severity: None,
+        min_score: None,
+        severity: None,
+        no_cache: false,
+        no_git: true,
+        git_history_period: None,
+        max_file_size: None,
+        files: None,
+    };
+    let engine = AnalysisEngine::new_with_args(args)?;
+    let report = engine.run()?;
+    let inference = report
+        .graph
+        .as_ref()
+        .map(|graph| infer_layers(graph, &engine.project_root, depth))
+        .unwrap_or_default();
+
+    Ok((inference, engine.project_root))
+}
+
+pub fn print_layer_inference(inference: &LayerInference, project_root: &Path) {
+    let levels = inference
+        .layers
+        .iter()
+        .map(|l| l.level)
+        .max()
+        .map_or(0, |l| l + 1);
+    println!(
+        "{} Proposed {} layers on {} levels",
+        style("🧱").bold(),
+        style(inference.layers.len()).cyan(),
+        style(levels).cyan()
+    );
+
+    if inference.violations.is_empty() {
+        println!(
+            "  {} No imports violate the proposed order",
+            style("✓").green()
+        );
+        return;
+    }
+
+    let count: usize = inference.violations.iter().map(|v| v.imports.len()).sum();
+    println!(
+        "  {} {} {} the proposed order and will be reported as layer_violation:",
+        style("⚠").yellow(),
+        style(count).yellow().bold(),
+        if count == 1 {
+            "import violates"
+        } else {
+            "imports violate"
+        }
+    );
+    let relative = |p: &Path| {
+        p.strip_prefix(project_root)
+            .unwrap_or(p)
+            .display()
+            .to_string()
+    };
+    for violation in &inference.violations {
+        println!(
+            "    {} → {} ({})",
+            style(&violation.from).bold(),
+            style(&violation.to).bold(),
+            violation.imports.len()
+        );
+        for (from, to) in violation.imports.iter().take(MAX_EXAMPLES) {
+            println!(
+                "      {}",
+                style(format!("{} → {}", relative(from), relative(to))).dim()
+            );
+        }
+        if violation.imports.len() > MAX_EXAMPLES {
+            println!(
+                "      {}",
+                style(format!(
+                    "… and {} more",
+                    violation.imports.len() - MAX_EXAMPLES
+                ))
+                .dim()
+            );
+        }
+    }
+}
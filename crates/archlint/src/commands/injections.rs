@@ -0,0 +1,307 @@
+#[cfg(not(feature = "cli"))]
+use crate::no_cli_mocks::comfy_table::modifiers::UTF8_ROUND_CORNERS;
+#[cfg(not(feature = "cli"))]
+use crate::no_cli_mocks::comfy_table::presets::UTF8_FULL;
+#[cfg(not(feature = "cli"))]
+use crate::no_cli_mocks::comfy_table::{Attribute, Cell, Color, ContentArrangement, Table};
+
+#[cfg(feature = "cli")]
+use comfy_table::modifiers::UTF8_ROUND_CORNERS;
+#[cfg(feature = "cli")]
+use comfy_table::presets::UTF8_FULL;
+#[cfg(feature = "cli")]
+use comfy_table::{Attribute, Cell, Color, ContentArrangement, Table};
+
+use crate::args::ScanArgs;
+use crate::engine::AnalysisEngine;
+use crate::injection_graph::{InjectableId, InjectionGraph};
+use crate::parser::{InjectableRole, InjectionKind};
+use crate::{AnalysisError, Result};
+use serde::Serialize;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+#[cfg(feature = "cli")]
+use clap::ValueEnum;
+
+#[cfg_attr(feature = "cli", derive(ValueEnum))]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum InjectionsFormat {
+    #[default]
+    #[cfg_attr(feature = "cli", value(name = "table"))]
+    Table,
+    #[cfg_attr(feature = "cli", value(name = "json"))]
+    Json,
+}
+
+#[derive(Debug, Clone)]
+pub struct InjectionsOptions {
+    pub path: PathBuf,
+    pub config: Option<PathBuf>,
+    pub format: InjectionsFormat,
+    pub output: Option<PathBuf>,
+}
+
+/// Modules and providers of a project together with the problems found between them.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InjectionReport {
+    pub modules: Vec<ModuleEntry>,
+    pub providers: Vec<ProviderEntry>,
+    /// Modules importing each other, one group per cycle.
+    pub cycles: Vec<Vec<String>>,
+    pub violations: Vec<ViolationEntry>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModuleEntry {
+    pub name: String,
+    pub file: Option<String>,
+    pub line: usize,
+    pub global: bool,
+    pub imports: Vec<String>,
+    pub providers: Vec<String>,
+    pub declarations: Vec<String>,
+    pub exports: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProviderEntry {
+    pub name: String,
+    pub file: Option<String>,
+    pub line: usize,
+    pub role: &'static str,
+    pub modules: Vec<String>,
+    pub injects: Vec<String>,
+    pub injected_by: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ViolationEntry {
+    pub consumer: String,
+    pub provider: String,
+    pub file: Option<String>,
+    pub line: usize,
+    pub modules: Vec<String>,
+}
+
+pub fn run_injections(options: &InjectionsOptions) -> Result<()> {
+    if !options.path.exists() {
+        return Err(AnalysisError::PathResolution(format!(
+            "Path does not exist: {}",
+            options.path.display()
+        )));
+    }
+
+    let report = collect_injections(options)?;
+    let output = match options.format {
+        InjectionsFormat::Table => render_table(&report),
+        InjectionsFormat::Json => serde_json::to_string_pretty(&report)?,
+    };
+
+    if let Some(path) = &options.output {
+        std::fs::write(path, output)?;
+    } else {
+        println!("{output}");
+    }
+
+    Ok(())
+}
+
+/// Parse the project's decorated classes and describe its injection graph.
+pub fn collect_injections(options: &InjectionsOptions) -> Result<InjectionReport> {
+    // Selecting the DI detectors makes the parser collect decorator metadata
+    let args = ScanArgs {
+        path: options.path.clone(),
+        config: options.config.clone(),
+        report: None,
+        format: crate::args::OutputFormat::Json,
+        json: true,
+        no_diagram: true,
+        all_detectors: false,
+        detectors: Some("circular_module_imports,provider_outside_module".to_string()),
+        exclude_detectors: None,
+        quiet: true,
+        verbose: false,
+        min_severity: None,
+        min_score: None,
+        severity: None,
+        no_cache: false,
+        no_git: true,
+        git_history_period: None,
+        max_file_size: None,
+        files: None,
+    };
+    let engine = AnalysisEngine::new_with_args(args)?;
+    let report = engine.run()?;
+    let graph = InjectionGraph::build(&report.file_symbols);
+
+    Ok(describe(&graph, &engine.project_root))
+}
+
+/// Flatten the injection graph into named entries relative to `root`.
+#[must_use]
+pub fn describe(graph: &InjectionGraph, root: &Path) -> InjectionReport {
+    let names = |ids: Vec<InjectableId>| -> Vec<String> {
+        ids.into_iter()
+            .map(|id| graph.get(id).name.clone())
+            .collect()
+    };
+    let targets = |id: InjectableId, kind: InjectionKind| -> Vec<String> {
+        names(
+            graph
+                .targets(id, kind)
+                .into_iter()
+                .map(|(t, _)| t)
+                .collect(),
+        )
+    };
+    let relative = |id: InjectableId| -> Option<String> {
+        graph.get(id).file.as_ref().map(|file| {
+            file.strip_prefix(root)
+                .unwrap_or(file)
+                .to_string_lossy()
+                .replace('\\', "/")
+        })
+    };
+
+    let modules = graph
+        .with_role(InjectableRole::Module)
+        .into_iter()
+        .map(|id| ModuleEntry {
+            name: graph.get(id).name.clone(),
+            file: relative(id),
+            line: graph.get(id).line,
+            global: graph.get(id).is_global,
+            imports: targets(id, InjectionKind::Import),
+            providers: targets(id, InjectionKind::Provide),
+            declarations: targets(id, InjectionKind::Declare),
+            exports: targets(id, InjectionKind::Export),
+        })
+        .collect();
+
+    let providers = graph
+        .nodes()
+        .into_iter()
+        .filter_map(|id| {
+            let role = match graph.get(id).role? {
+                InjectableRole::Module => return None,
+                InjectableRole::Provider => "provider",
+                InjectableRole::Controller => "controller",
+                InjectableRole::Declarable => "declarable",
+            };
+            Some(ProviderEntry {
+                name: graph.get(id).name.clone(),
+                file: relative(id),
+                line: graph.get(id).line,
+                role,
+                modules: names(graph.modules_of(id)),
+                injects: targets(id, InjectionKind::Inject),
+                injected_by: names(graph.sources(id, InjectionKind::Inject)),
+            })
+        })
+        .collect();
+
+    let violations = graph
+        .scope_violations()
+        .into_iter()
+        .map(|v| ViolationEntry {
+            consumer: graph.get(v.consumer).name.clone(),
+            provider: graph.get(v.provider).name.clone(),
+            file: relative(v.consumer),
+            line: v.line,
+            modules: names(v.consumer_modules),
+        })
+        .collect();
+
+    InjectionReport {
+        modules,
+        providers,
+        cycles: graph.module_cycles().into_iter().map(names).collect(),
+        violations,
+    }
+}
+
+fn list(items: &[String]) -> String {
+    if items.is_empty() {
+        "-".to_string()
+    } else {
+        items.join("\n")
+    }
+}
+
+fn table(header: &[&str]) -> Table {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_ROUND_CORNERS)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(
+            header
+                .iter()
+                .map(|title| Cell::new(title).add_attribute(Attribute::Bold))
+                .collect::<Vec<_>>(),
+        );
+    table
+}
+
+fn render_table(report: &InjectionReport) -> String {
+    if report.modules.is_empty() && report.providers.is_empty() {
+        return "No dependency injection modules or providers found.".to_string();
+    }
+
+    let mut modules = table(&["Module", "Imports", "Providers", "Exports"]);
+    for module in &report.modules {
+        let name = if module.global {
+            format!("{} (global)", module.name)
+        } else {
+            module.name.clone()
+        };
+        modules.add_row(vec![
+            Cell::new(name).fg(Color::Cyan),
+            Cell::new(list(&module.imports)),
+            Cell::new(list(&module.providers)),
+            Cell::new(list(&module.exports)),
+        ]);
+    }
+    let mut output = format!("{modules}\n");
+
+    let mut providers = table(&["Class", "Modules", "Injects", "Injected by"]);
+    for provider in &report.providers {
+        providers.add_row(vec![
+            Cell::new(&provider.name).fg(Color::Cyan),
+            Cell::new(list(&provider.modules)),
+            Cell::new(list(&provider.injects)),
+            Cell::new(list(&provider.injected_by)),
+        ]);
+    }
+    let _ = write!(output, "\n{providers}\n");
+
+    for cycle in &report.cycles {
+        let _ = write!(
+            output,
+            "\nModule cycle: {} -> {}",
+            cycle.join(" -> "),
+            cycle[0]
+        );
+    }
+    for violation in &report.violations {
+        let _ = write!(
+            output,
+            "\n{} injects {} which {} neither provides nor imports ({}:{})",
+            violation.consumer,
+            violation.provider,
+            violation.modules.join(", "),
+            violation.file.as_deref().unwrap_or("?"),
+            violation.line
+        );
+    }
+    if report.cycles.is_empty() && report.violations.is_empty() {
+        output.push_str("\nNo module cycles or provider scope violations.");
+    }
+
+    output
+}
@@ -0,0 +1,253 @@
+#[cfg(not(feature = "cli"))]
+use crate::no_cli_mocks::comfy_table::modifiers::UTF8_ROUND_CORNERS;
+#[cfg(not(feature = "cli"))]
+use crate::no_cli_mocks::comfy_table::presets::UTF8_FULL;
+#[cfg(not(feature = "cli"))]
+use crate::no_cli_mocks::comfy_table::{Attribute, Cell, Color, ContentArrangement, Table};
+
+#[cfg(feature = "cli")]
+use comfy_table::modifiers::UTF8_ROUND_CORNERS;
+#[cfg(feature = "cli")]
+use comfy_table::presets::UTF8_FULL;
+#[cfg(feature = "cli")]
+use comfy_table::{Attribute, Cell, Color, ContentArrangement, Table};
+
+use crate::args::ScanArgs;
+use crate::engine::AnalysisEngine;
+use crate::package_metrics::{
+    assign_components, compute_metrics, ComponentGrouping, ComponentMetrics,
+};
+use crate::report::mermaid::MermaidGenerator;
+use crate::{AnalysisError, Result};
+use std::fmt::Write;
+use std::path::PathBuf;
+
+#[cfg(feature = "cli")]
+use clap::ValueEnum;
+
+#[cfg_attr(feature = "cli", derive(ValueEnum))]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MetricsFormat {
+    #[default]
+    #[cfg_attr(feature = "cli", value(name = "table"))]
+    Table,
+    #[cfg_attr(feature = "cli", value(name = "json"))]
+    Json,
+    #[cfg_attr(feature = "cli", value(name = "csv"))]
+    Csv,
+    #[cfg_attr(feature = "cli", value(name = "markdown"))]
+    Markdown,
+}
+
+#[cfg_attr(feature = "cli", derive(ValueEnum))]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MetricsGroupBy {
+    #[default]
+    #[cfg_attr(feature = "cli", value(name = "folder"))]
+    Folder,
+    #[cfg_attr(feature = "cli", value(name = "package"))]
+    Package,
+}
+
+#[derive(Debug, Clone)]
+pub struct MetricsOptions {
+    pub path: PathBuf,
+    pub config: Option<PathBuf>,
+    pub group_by: MetricsGroupBy,
+    /// Folder depth; `None` uses `metrics.folder_depth` from the config.
+    pub depth: Option<usize>,
+    pub format: MetricsFormat,
+    pub output: Option<PathBuf>,
+    pub no_diagram: bool,
+}
+
+pub fn run_metrics(options: &MetricsOptions) -> Result<()> {
+    if !options.path.exists() {
+        return Err(AnalysisError::PathResolution(format!(
+            "Path does not exist: {}",
+            options.path.display()
+        )));
+    }
+
+    let metrics = collect_metrics(options)?;
+    let output = match options.format {
+        MetricsFormat::Table => render_table(&metrics),
+        MetricsFormat::Json => serde_json::to_string_pretty(&metrics)?,
+        MetricsFormat::Csv => render_csv(&metrics),
+        MetricsFormat::Markdown => render_markdown(&metrics, !options.no_diagram),
+    };
+
+    if let Some(path) = &options.output {
+        std::fs::write(path, output)?;
+    } else {
+        println!("{output}");
+    }
+
+    Ok(())
+}
+
+/// Run the analysis and compute metrics for the requested grouping.
+pub fn collect_metrics(options: &MetricsOptions) -> Result<Vec<ComponentMetrics>> {
+    // Only the graph and symbols are needed; `abstractness` makes the parser
+    // collect classes so abstract classes are recognized.
+    let args = ScanArgs {
+        path: options.path.clone(),
+        config: options.config.clone(),
+        report: None,
+        format: crate::args::OutputFormat::Json,
+        json: true,
+        no_diagram: true,
+        all_detectors: false,
+        detectors: Some("abstractness".to_string()),
+        exclude_detectors: None,
+        quiet: true,
+        verbose: false,
+        min_severity: None,
+        min_score: None,
+        severity: None,
+        no_cache: false,
+        no_git: true,
+        git_history_period: None,
+        max_file_size: None,
+        files: None,
+    };
+    let engine = AnalysisEngine::new_with_args(args)?;
+    let report = engine.run()?;
+    let Some(graph) = report.graph.as_ref() else {
+        return Ok(Vec::new());
+    };
+
+    let grouping = match options.group_by {
+        MetricsGroupBy::Folder => ComponentGrouping::Folder {
+            depth: options.depth.unwrap_or(engine.config.metrics.folder_depth),
+        },
+        MetricsGroupBy::Package => ComponentGrouping::Package,
+    };
+    let components = assign_components(report.file_symbols.keys(), &engine.project_root, grouping);
+
+    Ok(compute_metrics(graph, &report.file_symbols, &components))
+}
+
+fn distance_color(distance: f64) -> Color {
+    if distance > 0.7 {
+        Color::Red
+    } else if distance > 0.4 {
+        Color::Yellow
+    } else {
+        Color::Green
+    }
+}
+
+fn render_table(metrics: &[ComponentMetrics]) -> String {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_ROUND_CORNERS)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            Cell::new("Component").add_attribute(Attribute::Bold),
+            Cell::new("Files").add_attribute(Attribute::Bold),
+            Cell::new("Ca").add_attribute(Attribute::Bold),
+            Cell::new("Ce").add_attribute(Attribute::Bold),
+            Cell::new("I").add_attribute(Attribute::Bold),
+            Cell::new("A").add_attribute(Attribute::Bold),
+            Cell::new("D").add_attribute(Attribute::Bold),
+        ]);
+
+    for m in metrics {
+        table.add_row(vec![
+            Cell::new(&m.name).fg(Color::Cyan),
+            Cell::new(m.files),
+            Cell::new(m.ca),
+            Cell::new(m.ce),
+            Cell::new(format!("{:.2}", m.instability)),
+            Cell::new(format!("{:.2}", m.abstractness)),
+            Cell::new(format!("{:.2}", m.distance)).fg(distance_color(m.distance)),
+        ]);
+    }
+
+    table.to_string()
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn render_csv(metrics: &[ComponentMetrics]) -> String {
+    let mut output = String::from("component,files,ca,ce,instability,abstractness,distance\n");
+    for m in metrics {
+        let _ = writeln!(
+            output,
+            "{},{},{},{},{:.4},{:.4},{:.4}",
+            csv_field(&m.name),
+            m.files,
+            m.ca,
+            m.ce,
+            m.instability,
+            m.abstractness,
+            m.distance
+        );
+    }
+    output
+}
+
+fn render_markdown(metrics: &[ComponentMetrics], include_diagram: bool) -> String {
+    let mut output = String::from("# Package Metrics\n\n");
+    output.push_str("| Component | Files | Ca | Ce | I | A | D |\n");
+    output.push_str("|-----------|-------|----|----|---|---|---|\n");
+    for m in metrics {
+        let _ = writeln!(
+            output,
+            "| `{}` | {} | {} | {} | {:.2} | {:.2} | {:.2} |",
+            m.name, m.files, m.ca, m.ce, m.instability, m.abstractness, m.distance
+        );
+    }
+
+    if include_diagram && !metrics.is_empty() {
+        output.push_str("\n## Main Sequence\n\n");
+        output.push_str(&MermaidGenerator::main_sequence(metrics));
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metric(name: &str) -> ComponentMetrics {
+        ComponentMetrics {
+            name: name.to_string(),
+            files: 3,
+            ca: 2,
+            ce: 1,
+            instability: 1.0 / 3.0,
+            abstractness: 0.5,
+            distance: 1.0 / 6.0,
+        }
+    }
+
+    #[test]
+    fn test_render_csv_quotes_names() {
+        let csv = render_csv(&[metric("src/a,b")]);
+        assert_eq!(
+            csv,
+            "component,files,ca,ce,instability,abstractness,distance\n\"src/a,b\",3,2,1,0.3333,0.5000,0.1667\n"
+        );
+    }
+
+    #[test]
+    fn test_render_markdown_includes_chart() {
+        let md = render_markdown(&[metric("@app/core")], true);
+        assert!(md.contains("| `@app/core` | 3 | 2 | 1 | 0.33 | 0.50 | 0.17 |"));
+        assert!(md.contains("quadrantChart"));
+        assert!(md.contains("    app-core: [0.33, 0.50]"));
+
+        let md = render_markdown(&[metric("src")], false);
+        assert!(!md.contains("quadrantChart"));
+    }
+}
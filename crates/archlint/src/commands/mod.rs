@@ -1,7 +1,21 @@
+pub mod affected;
+pub mod calibrate;
 pub mod diff;
 pub mod diff_output;
+pub mod explain;
+pub mod gates_output;
 pub mod git_snapshot;
+pub mod infer_layers;
+pub mod injections;
+pub mod metrics;
+pub mod modularize;
 pub mod snapshot;
 
+pub use affected::run_affected;
+pub use calibrate::run_calibrate;
 pub use diff::run_diff;
+pub use explain::run_explain;
+pub use injections::run_injections;
+pub use metrics::run_metrics;
+pub use modularize::run_modularize;
 pub use snapshot::run_snapshot;
@@ -0,0 +1,189 @@
+#[cfg(not(feature = "cli"))]
+use crate::no_cli_mocks::comfy_table::modifiers::UTF8_ROUND_CORNERS;
+#[cfg(not(feature = "cli"))]
+use crate::no_cli_mocks::comfy_table::presets::UTF8_FULL;
+#[cfg(not(feature = "cli"))]
+use crate::no_cli_mocks::comfy_table::{Attribute, Cell, Color, ContentArrangement, Table};
+
+#[cfg(feature = "cli")]
+use comfy_table::modifiers::UTF8_ROUND_CORNERS;
+#[cfg(feature = "cli")]
+use comfy_table::presets::UTF8_FULL;
+#[cfg(feature = "cli")]
+use comfy_table::{Attribute, Cell, Color, ContentArrangement, Table};
+
+use crate::args::ScanArgs;
+use crate::engine::AnalysisEngine;
+use crate::metrics::GitChurn;
+use crate::modularize::{modularize, Modularization, ModularizeSettings};
+use crate::{AnalysisError, Result};
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::path::PathBuf;
+
+#[cfg(feature = "cli")]
+use clap::ValueEnum;
+
+/// Files listed per module in the table output.
+const MAX_LISTED_FILES: usize = 5;
+
+#[cfg_attr(feature = "cli", derive(ValueEnum))]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ModularizeFormat {
+    #[default]
+    #[cfg_attr(feature = "cli", value(name = "table"))]
+    Table,
+    #[cfg_attr(feature = "cli", value(name = "json"))]
+    Json,
+}
+
+#[derive(Debug, Clone)]
+pub struct ModularizeOptions {
+    pub path: PathBuf,
+    pub config: Option<PathBuf>,
+    pub settings: ModularizeSettings,
+    /// Commits to read co-changes from; `None` leaves git history out.
+    pub co_change_commits: Option<usize>,
+    pub format: ModularizeFormat,
+    pub output: Option<PathBuf>,
+}
+
+pub fn run_modularize(options: &ModularizeOptions) -> Result<()> {
+    if !options.path.exists() {
+        return Err(AnalysisError::PathResolution(format!(
+            "Path does not exist: {}",
+            options.path.display()
+        )));
+    }
+
+    let result = collect_modularization(options)?;
+    let output = match options.format {
+        ModularizeFormat::Table => render_table(&result),
+        ModularizeFormat::Json => serde_json::to_string_pretty(&result)?,
+    };
+
+    if let Some(path) = &options.output {
+        std::fs::write(path, output)?;
+    } else {
+        println!("{output}");
+    }
+
+    Ok(())
+}
+
+/// Build the dependency graph of the project and group its files into modules.
+pub fn collect_modularization(options: &ModularizeOptions) -> Result<Modularization> {
+    // Only the graph is needed; `layer_violation` finds nothing without layers
+    let args = ScanArgs {
+        path: options.path.clone(),
+        config: options.config.clone(),
+        report: None,
+        format: crate::args::OutputFormat::Json,
+        json: true,
+        no_diagram: true,
+        all_detectors: false,
+        detectors: Some("layer_violation".to_string()),
+        exclude_detectors: None,
+        quiet: true,
+        verbose: false,
+        min_severity: None,
+        min_score: None,
+        severity: None,
+        no_cache: false,
+        no_git: true,
+        git_history_period: None,
+        max_file_size: None,
+        files: None,
+    };
+    let engine = AnalysisEngine::new_with_args(args)?;
+    let report = engine.run()?;
+    let Some(graph) = report.graph.as_ref() else {
+        return Ok(Modularization::default());
+    };
+
+    let co_changes = match options.co_change_commits {
+        Some(lookback) => {
+            let files: Vec<PathBuf> = graph
+                .nodes()
+                .filter_map(|node| graph.get_file_path(node).cloned())
+                .collect();
+            GitChurn::new(&engine.project_root).co_changes(&files, lookback)?
+        }
+        None => HashMap::new(),
+    };
+
+    Ok(modularize(
+        graph,
+        &co_changes,
+        &engine.project_root,
+        &options.settings,
+    ))
+}
+
+fn render_table(result: &Modularization) -> String {
+    let mut output = format!(
+        "Modularity: {:.3} suggested, {:.3} current folders ({} files)\n\n",
+        result.modularity, result.folder_modularity, result.files
+    );
+
+    let mut modules = Table::new();
+    modules
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_ROUND_CORNERS)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            Cell::new("Module").add_attribute(Attribute::Bold),
+            Cell::new("Files").add_attribute(Attribute::Bold),
+            Cell::new("In folder").add_attribute(Attribute::Bold),
+            Cell::new("Members").add_attribute(Attribute::Bold),
+        ]);
+    for module in &result.modules {
+        let mut members = module
+            .files
+            .iter()
+            .take(MAX_LISTED_FILES)
+            .cloned()
+            .collect::<Vec<_>>()
+            .join("\n");
+        if module.files.len() > MAX_LISTED_FILES {
+            let _ = write!(
+                members,
+                "\n… {} more",
+                module.files.len() - MAX_LISTED_FILES
+            );
+        }
+        modules.add_row(vec![
+            Cell::new(&module.name).fg(Color::Cyan),
+            Cell::new(module.files.len()),
+            Cell::new(format!("{:.0}%", module.folder_share * 100.0)),
+            Cell::new(members),
+        ]);
+    }
+    let _ = writeln!(output, "{modules}");
+
+    if result.misplaced.is_empty() {
+        output.push_str("\nEvery file lives in the folder of its module.");
+        return output;
+    }
+
+    let mut misplaced = Table::new();
+    misplaced
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_ROUND_CORNERS)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            Cell::new("Misplaced file").add_attribute(Attribute::Bold),
+            Cell::new("Folder").add_attribute(Attribute::Bold),
+            Cell::new("Module").add_attribute(Attribute::Bold),
+        ]);
+    for file in &result.misplaced {
+        misplaced.add_row(vec![
+            Cell::new(&file.file),
+            Cell::new(&file.folder),
+            Cell::new(&file.module).fg(Color::Yellow),
+        ]);
+    }
+    let _ = write!(output, "\n{misplaced}");
+
+    output
+}
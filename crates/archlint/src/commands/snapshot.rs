@@ -1,5 +1,6 @@
 use crate::api::options::ScanOptions;
 use crate::api::Analyzer;
+use crate::debt::DebtModel;
 #[cfg(not(feature = "cli"))]
 use crate::no_cli_mocks::console::style;
 use crate::snapshot::{write_snapshot, SnapshotGenerator};
@@ -24,7 +25,9 @@ pub fn run_snapshot(
     let scan_result = analyzer.scan()?;
 
     // Generate snapshot
-    let generator = SnapshotGenerator::new(project_path).with_commit(include_commit);
+    let generator = SnapshotGenerator::new(project_path)
+        .with_commit(include_commit)
+        .with_debt_model(DebtModel::new(&analyzer.config().debt));
     let snapshot = generator.generate(&scan_result);
 
     // Write to file
@@ -8,6 +8,14 @@ use std::path::{Path, PathBuf};
 pub mod types;
 pub use types::*;
 
+/// Config file names looked up in the project root, in order of precedence.
+pub const CONFIG_FILENAMES: &[&str] = &[
+    ".archlint.yaml",
+    ".archlint.yml",
+    "archlint.yaml",
+    "archlint.yml",
+];
+
 impl Config {
     /// Merges a framework preset into the current configuration.
     pub fn merge_preset(&mut self, preset: &FrameworkPreset) {
@@ -94,29 +102,25 @@ impl Config {
         Ok(config)
     }
 
+    /// Locate the config file in `project_root` (or the working directory).
+    #[must_use]
+    pub fn find_file(project_root: Option<&Path>) -> Option<PathBuf> {
+        CONFIG_FILENAMES
+            .iter()
+            .map(|filename| {
+                project_root.map_or_else(|| PathBuf::from(filename), |root| root.join(filename))
+            })
+            .find(|p| p.exists())
+    }
+
     pub fn load_or_default(path: Option<&Path>, project_root: Option<&Path>) -> Result<Self> {
         let mut config = if let Some(p) = path {
             Self::load(p)?
         } else {
-            let filenames = [
-                ".archlint.yaml",
-                ".archlint.yml",
-                "archlint.yaml",
-                "archlint.yml",
-            ];
-
-            let mut found_config = None;
-            for filename in filenames {
-                let p = project_root
-                    .map_or_else(|| PathBuf::from(filename), |root| root.join(filename));
-
-                if p.exists() {
-                    found_config = Some(Self::load(p)?);
-                    break;
-                }
+            match Self::find_file(project_root) {
+                Some(p) => Self::load(p)?,
+                None => Self::default(),
             }
-
-            found_config.unwrap_or_else(Self::default)
         };
 
         if let Some(tsconfig_opt) = &config.tsconfig {
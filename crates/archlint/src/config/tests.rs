@@ -39,6 +39,55 @@ fn test_deserialize_extends_empty_array() {
     assert_eq!(config.extends, Some(Vec::new()));
 }
 
+#[test]
+fn test_deserialize_gates() {
+    let yaml = r#"
+gates:
+  - name: few cycles
+    max_smells: { rule: cycles, max: 3 }
+  - no_new_smells: { rule: layer_violation, paths: ["src/core/**"] }
+  - min_grade: good
+  - max_debt: 90
+  - max_debt_increase: 1h 30m
+"#;
+    let config: Config = serde_yaml::from_str(yaml).unwrap();
+    let conditions: Vec<_> = config.gates.iter().map(|g| g.condition.clone()).collect();
+    assert_eq!(config.gates[0].name.as_deref(), Some("few cycles"));
+    assert_eq!(
+        conditions,
+        vec![
+            GateCondition::MaxSmells(MaxSmellsGate {
+                filter: SmellFilter {
+                    rule: Some("cycles".to_string()),
+                    ..Default::default()
+                },
+                max: 3,
+            }),
+            GateCondition::NoNewSmells(SmellFilter {
+                rule: Some("layer_violation".to_string()),
+                severity: None,
+                paths: vec!["src/core/**".to_string()],
+            }),
+            GateCondition::MinGrade(crate::report::GradeLevel::Good),
+            GateCondition::MaxDebt(Effort(90.0)),
+            GateCondition::MaxDebtIncrease(Effort(90.0)),
+        ]
+    );
+}
+
+#[test]
+fn test_effort_parse() {
+    assert_eq!(Effort::parse("45"), Some(45.0));
+    assert_eq!(Effort::parse("2h"), Some(120.0));
+    assert_eq!(Effort::parse("1h30m"), Some(90.0));
+    assert_eq!(Effort::parse("1d 2h"), Some(600.0));
+    assert_eq!(Effort::parse("2 weeks"), None);
+    assert_eq!(Effort::parse("h"), None);
+
+    let err = serde_yaml::from_str::<Config>("gates:\n  - max_debt: soon\n").unwrap_err();
+    assert!(err.to_string().contains("invalid effort"));
+}
+
 #[test]
 fn test_tsconfig_disabled_boolean() {
     let yaml = "tsconfig: false";
@@ -1,4 +1,5 @@
 use crate::detectors::Severity;
+use crate::report::GradeLevel;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -47,6 +48,9 @@ pub struct Config {
     )]
     pub tsconfig: Option<TsConfigConfig>,
 
+    #[serde(default, skip_serializing_if = "is_default_resolve")]
+    pub resolve: ResolveConfig,
+
     #[serde(
         default = "default_max_file_size",
         skip_serializing_if = "is_default_max_file_size"
@@ -58,6 +62,21 @@ pub struct Config {
 
     #[serde(default, skip_serializing_if = "is_default_diff")]
     pub diff: DiffConfig,
+
+    #[serde(default, skip_serializing_if = "is_default_suppressions")]
+    pub suppressions: SuppressionsConfig,
+
+    #[serde(default, skip_serializing_if = "is_default_debt")]
+    pub debt: DebtConfig,
+
+    #[serde(default, skip_serializing_if = "is_default_metrics")]
+    pub metrics: MetricsConfig,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub gates: Vec<GateConfig>,
+
+    #[serde(default, skip_serializing_if = "is_default_events")]
+    pub events: EventsConfig,
 }
 
 const fn is_true(v: &bool) -> bool {
@@ -89,6 +108,448 @@ fn is_default_diff(v: &DiffConfig) -> bool {
     *v == DiffConfig::default()
 }
 
+fn is_default_suppressions(v: &SuppressionsConfig) -> bool {
+    *v == SuppressionsConfig::default()
+}
+
+fn is_default_debt(v: &DebtConfig) -> bool {
+    *v == DebtConfig::default()
+}
+
+fn is_default_metrics(v: &MetricsConfig) -> bool {
+    *v == MetricsConfig::default()
+}
+
+fn is_default_events(v: &EventsConfig) -> bool {
+    *v == EventsConfig::default()
+}
+
+fn is_default_resolve(v: &ResolveConfig) -> bool {
+    *v == ResolveConfig::default()
+}
+
+/// Module resolution settings beyond tsconfig `paths`.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, JsonSchema)]
+pub struct ResolveConfig {
+    /// Conditions active when matching `package.json` `exports` and `imports`,
+    /// e.g. `source` or `development`. `default` always matches.
+    #[serde(default = "default_resolve_conditions")]
+    pub conditions: Vec<String>,
+}
+
+impl Default for ResolveConfig {
+    fn default() -> Self {
+        Self {
+            conditions: default_resolve_conditions(),
+        }
+    }
+}
+
+fn default_resolve_conditions() -> Vec<String> {
+    ["types", "import", "require", "node"]
+        .into_iter()
+        .map(String::from)
+        .collect()
+}
+
+/// Call sites that couple modules through string keys such as event names,
+/// message topics or action types.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, JsonSchema)]
+pub struct EventsConfig {
+    /// Calls that send or receive a key. Setting this replaces the built-in patterns.
+    #[serde(default = "default_event_patterns")]
+    pub patterns: Vec<EventPatternConfig>,
+    /// Keys (glob patterns) never reported as orphaned, such as events emitted by Node.js or the DOM.
+    #[serde(default = "default_ignored_event_keys")]
+    pub ignore_keys: Vec<String>,
+}
+
+impl Default for EventsConfig {
+    fn default() -> Self {
+        Self {
+            patterns: default_event_patterns(),
+            ignore_keys: default_ignored_event_keys(),
+        }
+    }
+}
+
+/// A call site that sends or receives a key.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, JsonSchema)]
+pub struct EventPatternConfig {
+    /// Called function or method, optionally with its object: `emit`, `eventBus.emit`.
+    pub callee: String,
+    /// Index of the argument holding the key.
+    #[serde(default)]
+    pub argument: usize,
+    /// Property of an object argument holding the key, e.g. `type` for `dispatch({ type: 'x' })`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub property: Option<String>,
+    /// Whether the call sends (`producer`) or receives (`consumer`) the key.
+    pub role: EventRole,
+}
+
+impl EventPatternConfig {
+    fn new(callee: &str, role: EventRole) -> Self {
+        Self {
+            callee: callee.to_string(),
+            argument: 0,
+            property: None,
+            role,
+        }
+    }
+}
+
+/// Side of an event a call site is on.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, Hash, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum EventRole {
+    Producer,
+    Consumer,
+}
+
+fn default_event_patterns() -> Vec<EventPatternConfig> {
+    let producers = ["emit", "emitAsync", "publish"];
+    let consumers = [
+        "on",
+        "once",
+        "addListener",
+        "prependListener",
+        "subscribe",
+        "OnEvent",
+        "EventPattern",
+        "ofType",
+    ];
+    producers
+        .iter()
+        .map(|callee| EventPatternConfig::new(callee, EventRole::Producer))
+        .chain(
+            consumers
+                .iter()
+                .map(|callee| EventPatternConfig::new(callee, EventRole::Consumer)),
+        )
+        .collect()
+}
+
+fn default_ignored_event_keys() -> Vec<String> {
+    [
+        "error",
+        "close",
+        "end",
+        "data",
+        "finish",
+        "drain",
+        "readable",
+        "open",
+        "connect",
+        "connection",
+        "disconnect",
+        "message",
+        "listening",
+        "request",
+        "ready",
+        "exit",
+        "SIG*",
+        "uncaughtException",
+        "unhandledRejection",
+        "beforeExit",
+        "warning",
+        "newListener",
+        "removeListener",
+    ]
+    .into_iter()
+    .map(String::from)
+    .collect()
+}
+
+/// Configuration for technical debt estimation.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema)]
+pub struct DebtConfig {
+    /// Remediation cost overrides keyed by detector ID. Unset fields keep the built-in values.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub costs: HashMap<String, RemediationCostConfig>,
+    /// Folder depth below the project root used to aggregate debt per folder.
+    #[serde(default = "default_debt_folder_depth")]
+    pub folder_depth: usize,
+}
+
+impl Default for DebtConfig {
+    fn default() -> Self {
+        Self {
+            costs: HashMap::new(),
+            folder_depth: default_debt_folder_depth(),
+        }
+    }
+}
+
+const fn default_debt_folder_depth() -> usize {
+    2
+}
+
+/// Configuration for Martin package metrics.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, JsonSchema)]
+pub struct MetricsConfig {
+    /// Folder depth below the project root used to group files into components,
+    /// in `archlint metrics` and the main sequence chart of Markdown reports.
+    #[serde(default = "default_metrics_folder_depth")]
+    pub folder_depth: usize,
+}
+
+impl Default for MetricsConfig {
+    fn default() -> Self {
+        Self {
+            folder_depth: default_metrics_folder_depth(),
+        }
+    }
+}
+
+const fn default_metrics_folder_depth() -> usize {
+    2
+}
+
+/// Estimated effort in minutes to fix one smell of a kind.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, JsonSchema)]
+pub struct RemediationCostConfig {
+    /// Fixed effort per smell.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base: Option<f64>,
+    /// Effort per affected file (e.g. each file in a cycle).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub per_file: Option<f64>,
+    /// Effort per unit the measured metric exceeds its threshold (complexity points, lines).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub per_excess: Option<f64>,
+}
+
+/// A quality gate checked after `scan` and `diff`.
+///
+/// Each gate sets exactly one condition, e.g. `max_smells: { rule: cycles, max: 3 }`.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct GateConfig {
+    /// Name shown in gate results. Defaults to a description of the condition.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(flatten)]
+    pub condition: GateCondition,
+}
+
+/// Condition a quality gate enforces.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum GateCondition {
+    /// At most `max` smells match the filter.
+    MaxSmells(MaxSmellsGate),
+    /// No smells matching the filter were introduced since the baseline (`diff` only).
+    NoNewSmells(SmellFilter),
+    /// The architecture grade is at least this level.
+    MinGrade(GradeLevel),
+    /// Total technical debt does not exceed this effort.
+    MaxDebt(Effort),
+    /// Technical debt does not grow by more than this effort since the baseline (`diff` only).
+    MaxDebtIncrease(Effort),
+}
+
+// The derived schema of a flattened enum forbids sibling keys such as `name`,
+// so the object schema is spelled out here.
+impl JsonSchema for GateConfig {
+    fn schema_name() -> String {
+        "GateConfig".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        use schemars::schema::{InstanceType, ObjectValidation, Schema, SchemaObject};
+
+        let conditions = [
+            ("max_smells", gen.subschema_for::<MaxSmellsGate>()),
+            ("no_new_smells", gen.subschema_for::<SmellFilter>()),
+            ("min_grade", grade_level_schema()),
+            ("max_debt", gen.subschema_for::<Effort>()),
+            ("max_debt_increase", gen.subschema_for::<Effort>()),
+        ];
+
+        let mut object = ObjectValidation {
+            additional_properties: Some(Box::new(Schema::Bool(false))),
+            ..Default::default()
+        };
+        object
+            .properties
+            .insert("name".to_string(), gen.subschema_for::<String>());
+        let one_of = conditions
+            .into_iter()
+            .map(|(key, schema)| {
+                object.properties.insert(key.to_string(), schema);
+                let mut required = ObjectValidation::default();
+                required.required.insert(key.to_string());
+                Schema::Object(SchemaObject {
+                    object: Some(Box::new(required)),
+                    ..Default::default()
+                })
+            })
+            .collect();
+
+        Schema::Object(SchemaObject {
+            metadata: Some(Box::new(schemars::schema::Metadata {
+                description: Some(
+                    "A quality gate checked after `scan` and `diff`. Set exactly one condition."
+                        .to_string(),
+                ),
+                ..Default::default()
+            })),
+            instance_type: Some(InstanceType::Object.into()),
+            object: Some(Box::new(object)),
+            subschemas: Some(Box::new(schemars::schema::SubschemaValidation {
+                one_of: Some(one_of),
+                ..Default::default()
+            })),
+            ..Default::default()
+        })
+    }
+}
+
+/// Grade levels accepted in config, in lower case as well as the report's spelling.
+fn grade_level_schema() -> schemars::schema::Schema {
+    let levels = ["excellent", "good", "fair", "moderate", "poor", "critical"];
+    let values = levels
+        .iter()
+        .map(|level| (*level).to_string())
+        .chain(levels.iter().map(|level| {
+            let mut chars = level.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        }))
+        .map(serde_json::Value::String)
+        .collect();
+
+    schemars::schema::Schema::Object(schemars::schema::SchemaObject {
+        instance_type: Some(schemars::schema::InstanceType::String.into()),
+        enum_values: Some(values),
+        ..Default::default()
+    })
+}
+
+/// Selects the smells a gate applies to. Empty fields match everything.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq, JsonSchema)]
+pub struct SmellFilter {
+    /// Detector ID or alias.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rule: Option<String>,
+    /// Minimum severity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub severity: Option<Severity>,
+    /// Glob patterns relative to the project root; a smell matches if any of its files does.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub paths: Vec<String>,
+}
+
+/// Settings for the `max_smells` gate.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq, JsonSchema)]
+pub struct MaxSmellsGate {
+    #[serde(flatten)]
+    pub filter: SmellFilter,
+    /// Maximum number of matching smells.
+    #[serde(default)]
+    pub max: usize,
+}
+
+/// Remediation effort in minutes.
+///
+/// Accepts a number of minutes or a duration such as `90m`, `2h`, `1h 30m` or
+/// `1d` (a workday of 8 hours).
+#[derive(Debug, Clone, Copy, Serialize, PartialEq)]
+pub struct Effort(pub f64);
+
+impl Effort {
+    #[must_use]
+    pub const fn minutes(self) -> f64 {
+        self.0
+    }
+
+    /// Parse a duration such as `1h 30m` into minutes.
+    #[must_use]
+    pub fn parse(input: &str) -> Option<f64> {
+        let mut total = 0.0;
+        let mut number = String::new();
+        let mut seen_unit = false;
+
+        for c in input.trim().chars() {
+            if c.is_ascii_digit() || c == '.' {
+                number.push(c);
+                continue;
+            }
+            if c.is_whitespace() {
+                continue;
+            }
+            let factor = match c.to_ascii_lowercase() {
+                'm' => 1.0,
+                'h' => 60.0,
+                'd' => 8.0 * 60.0,
+                _ => return None,
+            };
+            let value: f64 = number.parse().ok()?;
+            total += value * factor;
+            number.clear();
+            seen_unit = true;
+        }
+
+        match (number.is_empty(), seen_unit) {
+            (true, true) => Some(total),
+            (false, false) => number.parse().ok(),
+            _ => None,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Effort {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum NumberOrString {
+            Number(f64),
+            String(String),
+        }
+
+        match NumberOrString::deserialize(deserializer)? {
+            NumberOrString::Number(minutes) => Ok(Self(minutes)),
+            NumberOrString::String(s) => Self::parse(&s).map(Self).ok_or_else(|| {
+                serde::de::Error::custom(format!(
+                    "invalid effort '{s}', expected minutes or a duration like '2h' or '1h 30m'"
+                ))
+            }),
+        }
+    }
+}
+
+impl JsonSchema for Effort {
+    fn schema_name() -> String {
+        "Effort".to_string()
+    }
+
+    fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        schemars::schema::Schema::Object(schemars::schema::SchemaObject {
+            instance_type: Some(schemars::schema::SingleOrVec::Vec(vec![
+                schemars::schema::InstanceType::Number,
+                schemars::schema::InstanceType::String,
+            ])),
+            ..Default::default()
+        })
+    }
+}
+
+/// Configuration for inline `archlint-disable` comments.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq, JsonSchema)]
+pub struct SuppressionsConfig {
+    /// Require a `-- reason` on every suppression comment. Comments without one suppress nothing.
+    #[serde(default)]
+    pub require_reason: bool,
+    /// Keep suppressed smells in JSON and SARIF output instead of dropping them.
+    #[serde(default)]
+    pub include_suppressed: bool,
+}
+
 /// Configuration for diff command.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema)]
 pub struct DiffConfig {
@@ -467,6 +928,12 @@ impl Default for Config {
             max_file_size: default_max_file_size(),
             git: GitConfig::default(),
             diff: DiffConfig::default(),
+            suppressions: SuppressionsConfig::default(),
+            debt: DebtConfig::default(),
+            metrics: MetricsConfig::default(),
+            gates: Vec::new(),
+            events: EventsConfig::default(),
+            resolve: ResolveConfig::default(),
         }
     }
 }
@@ -0,0 +1,134 @@
+use super::protocol::{Request, Response, ScanParams, ScanReply};
+use super::{is_own_socket, socket_path};
+use crate::args::{OutputFormat, ScanArgs};
+use crate::cache::hash::config_hash;
+use crate::config::Config;
+use crate::detectors::Severity;
+use crate::report::{AnalysisReport, AnalysisReportBuilder};
+use crate::{AnalysisError, Result};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::io::{BufRead, BufReader, Write};
+use std::os::unix::net::UnixStream;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Connection to the daemon of a project.
+pub struct DaemonClient {
+    reader: BufReader<UnixStream>,
+    writer: UnixStream,
+    next_id: u64,
+}
+
+impl DaemonClient {
+    /// Connects to the daemon running for `project_root`, if any.
+    ///
+    /// Ignores sockets that do not belong to the current user.
+    #[must_use]
+    pub fn connect(project_root: &Path) -> Option<Self> {
+        let socket = socket_path(project_root);
+        if !is_own_socket(&socket) {
+            return None;
+        }
+        let stream = UnixStream::connect(socket).ok()?;
+        let writer = stream.try_clone().ok()?;
+        Some(Self {
+            reader: BufReader::new(stream),
+            writer,
+            next_id: 1,
+        })
+    }
+
+    /// Sends one request and waits for its result.
+    pub fn call<P: Serialize, T: DeserializeOwned>(
+        &mut self,
+        method: &str,
+        params: &P,
+    ) -> Result<T> {
+        let request = Request::new(self.next_id, method, serde_json::to_value(params)?);
+        self.next_id += 1;
+
+        let mut bytes = serde_json::to_vec(&request)?;
+        bytes.push(b'\n');
+        self.writer.write_all(&bytes)?;
+
+        let mut line = String::new();
+        if self.reader.read_line(&mut line)? == 0 {
+            return Err(daemon_error("Daemon closed the connection"));
+        }
+        let response: Response = serde_json::from_str(&line)?;
+        if let Some(error) = response.error {
+            return Err(daemon_error(&format!("{} ({})", error.message, error.code)));
+        }
+        Ok(serde_json::from_value(response.result.unwrap_or_default())?)
+    }
+
+    /// Builds the report `archlint scan` would print from the daemon state.
+    ///
+    /// Returns `None` when `args` change what is analyzed, since the daemon
+    /// only keeps the results of a plain scan of its own path and config.
+    pub fn scan_report(
+        &mut self,
+        args: &ScanArgs,
+        config: &Config,
+        project_root: &Path,
+    ) -> Result<Option<AnalysisReport>> {
+        if !Self::can_serve(args) {
+            return Ok(None);
+        }
+        let params = ScanParams {
+            path: Some(args.path.canonicalize()?),
+            config_hash: Some(config_hash(config)),
+        };
+        let reply: ScanReply = self.call("scan", &params)?;
+
+        let mut report = AnalysisReportBuilder::new()
+            .with_smells(reply.result.smells.into_iter().map(|s| s.smell).collect())
+            .with_suppressed(
+                reply
+                    .suppressed
+                    .into_iter()
+                    .map(|s| (s.smell, s.suppression))
+                    .collect(),
+            )
+            .with_config(config.clone())
+            .with_files_analyzed(reply.result.summary.files_analyzed)
+            .with_import_resolution(reply.import_resolution)
+            .build();
+        if let Some(severity) = args
+            .min_severity
+            .as_deref()
+            .and_then(|s| Severity::from_str(s).ok())
+        {
+            report.set_min_severity(severity);
+        }
+        if let Some(min_score) = args.min_score {
+            report.set_min_score(min_score);
+        }
+        report.apply_severity_config(&config.scoring);
+        report.compute_debt(project_root);
+        report.evaluate_gates(project_root);
+        Ok(Some(report))
+    }
+
+    const fn can_serve(args: &ScanArgs) -> bool {
+        // Markdown diagrams need the dependency graph, which the daemon keeps to itself
+        let needs_graph =
+            matches!(args.output_format(), OutputFormat::Markdown) && !args.no_diagram;
+        args.files.is_none()
+            && args.config.is_none()
+            && args.detectors.is_none()
+            && args.exclude_detectors.is_none()
+            && !args.all_detectors
+            && args.severity.is_none()
+            && !args.no_cache
+            && !args.no_git
+            && args.git_history_period.is_none()
+            && args.max_file_size.is_none()
+            && !needs_graph
+    }
+}
+
+fn daemon_error(message: &str) -> AnalysisError {
+    AnalysisError::Anyhow(anyhow::anyhow!("Daemon error: {message}"))
+}
@@ -0,0 +1,88 @@
+//! Long-running analyzer answering JSON-RPC 2.0 requests over a Unix socket.
+//!
+//! `archlint daemon start` keeps an [`Analyzer`](crate::api::Analyzer) of the
+//! project up to date as files change, so editors, hooks and scripts query it
+//! instead of analyzing the project from scratch. Requests and responses are
+//! single lines of JSON. Methods:
+//!
+//! - `scan`: the current [`ScanResult`](crate::api::ScanResult)
+//! - `smells { file }`: smells involving a file, with their IDs
+//! - `affected { files }`: files depending on the given files, transitively
+//! - `dependencies { file }`, `dependents { file }`: direct imports and importers
+//! - `symbolUsages { file, name }`: users of an exported symbol
+//! - `explain { id }`: a smell with its explanation, by snapshot ID
+//! - `status`, `shutdown`
+
+use crate::cache::hash::content_hash;
+use crate::cache::AnalysisCache;
+use crate::{AnalysisError, Result};
+use std::fs;
+use std::io::ErrorKind;
+use std::os::unix::fs::{DirBuilderExt, FileTypeExt, MetadataExt};
+use std::path::{Path, PathBuf};
+
+pub mod client;
+pub mod protocol;
+pub mod server;
+
+pub use client::DaemonClient;
+pub use server::DaemonServer;
+
+/// Socket of the daemon for `project_root`.
+///
+/// Lives in `$XDG_RUNTIME_DIR` when it is set, since socket paths are limited
+/// to about 100 bytes, and in the project cache dir otherwise. Either way the
+/// socket sits in a directory only the current user can access.
+#[must_use]
+pub fn socket_path(project_root: &Path) -> PathBuf {
+    match std::env::var_os("XDG_RUNTIME_DIR").map(PathBuf::from) {
+        Some(runtime_dir) if runtime_dir.is_absolute() => {
+            let hash = content_hash(&project_root.to_string_lossy());
+            runtime_dir
+                .join("archlint")
+                .join(format!("{}.sock", &hash[..16]))
+        }
+        _ => AnalysisCache::resolve_cache_dir(project_root)
+            .join("daemon")
+            .join("daemon.sock"),
+    }
+}
+
+/// Creates the directory of `socket` with 0700 permissions, refusing one
+/// another user could write to.
+fn create_socket_dir(socket: &Path) -> Result<()> {
+    let Some(dir) = socket.parent() else {
+        return Ok(());
+    };
+    if let Some(parent) = dir.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    match fs::DirBuilder::new().mode(0o700).create(dir) {
+        Err(e) if e.kind() != ErrorKind::AlreadyExists => return Err(e.into()),
+        _ => {}
+    }
+    if !is_private_dir(dir) {
+        return Err(AnalysisError::Anyhow(anyhow::anyhow!(
+            "{} must belong to the current user and be inaccessible to others",
+            dir.display()
+        )));
+    }
+    Ok(())
+}
+
+/// Whether `socket` was created by the current user in a directory only they
+/// can access, so it cannot be a listener planted by someone else.
+fn is_own_socket(socket: &Path) -> bool {
+    socket.parent().is_some_and(is_private_dir)
+        && fs::symlink_metadata(socket)
+            .is_ok_and(|m| m.file_type().is_socket() && m.uid() == current_uid())
+}
+
+fn is_private_dir(dir: &Path) -> bool {
+    fs::symlink_metadata(dir)
+        .is_ok_and(|m| m.is_dir() && m.uid() == current_uid() && m.mode() & 0o777 == 0o700)
+}
+
+fn current_uid() -> u32 {
+    rustix::process::geteuid().as_raw()
+}
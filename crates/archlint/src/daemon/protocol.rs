@@ -0,0 +1,167 @@
+use crate::api::{ScanResult, SmellWithExplanation};
+use crate::detectors::ArchSmell;
+use crate::report::Suppression;
+use crate::resolver::ResolutionStats;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::PathBuf;
+
+pub const JSONRPC_VERSION: &str = "2.0";
+
+/// Error codes defined by JSON-RPC 2.0.
+pub const PARSE_ERROR: i64 = -32700;
+pub const METHOD_NOT_FOUND: i64 = -32601;
+pub const INVALID_PARAMS: i64 = -32602;
+pub const INTERNAL_ERROR: i64 = -32603;
+/// The daemon analyzes another path or runs with another config than the client.
+pub const PROJECT_MISMATCH: i64 = -32001;
+/// No smell or symbol matches the request.
+pub const NOT_FOUND: i64 = -32002;
+
+/// A JSON-RPC request, sent as one line of JSON.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Request {
+    pub jsonrpc: String,
+    #[serde(default)]
+    pub id: Value,
+    pub method: String,
+    #[serde(default)]
+    pub params: Value,
+}
+
+impl Request {
+    #[must_use]
+    pub fn new(id: u64, method: &str, params: Value) -> Self {
+        Self {
+            jsonrpc: JSONRPC_VERSION.to_string(),
+            id: Value::from(id),
+            method: method.to_string(),
+            params,
+        }
+    }
+}
+
+/// A JSON-RPC response, sent as one line of JSON.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Response {
+    pub jsonrpc: String,
+    pub id: Value,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<RpcError>,
+}
+
+impl Response {
+    #[must_use]
+    pub fn success(id: Value, result: Value) -> Self {
+        Self {
+            jsonrpc: JSONRPC_VERSION.to_string(),
+            id,
+            result: Some(result),
+            error: None,
+        }
+    }
+
+    #[must_use]
+    pub fn failure(id: Value, error: RpcError) -> Self {
+        Self {
+            jsonrpc: JSONRPC_VERSION.to_string(),
+            id,
+            result: None,
+            error: Some(error),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcError {
+    pub code: i64,
+    pub message: String,
+}
+
+impl RpcError {
+    #[must_use]
+    pub fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+/// Params of `scan`: the daemon only answers for the path and config it analyzes.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScanParams {
+    /// Absolute path of the scanned directory, which must be the project root.
+    pub path: Option<PathBuf>,
+    pub config_hash: Option<String>,
+}
+
+/// Result of `scan`: the scan result with the rest of what `archlint scan` reports.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScanReply {
+    #[serde(flatten)]
+    pub result: ScanResult,
+    /// Smells hidden by inline comments, kept when `suppressions.include_suppressed` is set.
+    pub suppressed: Vec<SuppressedEntry>,
+    pub import_resolution: ResolutionStats,
+}
+
+/// A smell hidden by an inline comment, as returned by `scan`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SuppressedEntry {
+    pub smell: ArchSmell,
+    pub suppression: Suppression,
+}
+
+/// Params of `smells`, `dependencies` and `dependents`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileParams {
+    pub file: PathBuf,
+}
+
+/// Params of `affected`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FilesParams {
+    pub files: Vec<PathBuf>,
+}
+
+/// Params of `symbolUsages`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SymbolParams {
+    pub file: PathBuf,
+    pub name: String,
+}
+
+/// Params of `explain`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExplainParams {
+    pub id: String,
+}
+
+/// Result of `status`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DaemonStatus {
+    pub project_root: PathBuf,
+    pub target_path: PathBuf,
+    /// Hash of the config the daemon loaded, as computed by `config_hash`.
+    pub config_hash: String,
+    pub pid: u32,
+    pub files: usize,
+    pub graph_nodes: usize,
+    pub graph_edges: usize,
+    pub smells: usize,
+    pub uptime_secs: u64,
+}
+
+/// A smell with its stable ID, as returned by `smells` and `explain`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SmellEntry {
+    pub id: String,
+    #[serde(flatten)]
+    pub smell: SmellWithExplanation,
+}
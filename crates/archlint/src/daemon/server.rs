@@ -0,0 +1,424 @@
+use super::protocol::{
+    DaemonStatus, ExplainParams, FileParams, FilesParams, Request, Response, RpcError, ScanParams,
+    ScanReply, SmellEntry, SuppressedEntry, SymbolParams, INTERNAL_ERROR, INVALID_PARAMS,
+    METHOD_NOT_FOUND, NOT_FOUND, PARSE_ERROR, PROJECT_MISMATCH,
+};
+use super::{create_socket_dir, is_own_socket, socket_path};
+use crate::api::{Analyzer, ScanOptions, SmellWithExplanation};
+use crate::cache::hash::config_hash;
+use crate::detectors::ArchSmell;
+use crate::snapshot::id::generate_smell_id;
+use crate::watch::{FileWatcher, WatchConfig};
+use crate::{AnalysisError, Result};
+use log::{debug, info, warn};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::thread;
+use std::time::{Instant, SystemTime};
+
+/// Work handed to the thread owning the analyzer.
+enum Message {
+    Request(Request, Sender<Response>),
+    Changed(Vec<PathBuf>),
+    /// Sent once the response to `shutdown` reached the client.
+    Stop,
+}
+
+/// Outcome of a request, before it is wrapped in a response.
+type MethodResult = std::result::Result<Value, RpcError>;
+
+/// Modification time and size of a file when it was last analyzed.
+type FileStamp = (Option<SystemTime>, u64);
+
+/// Long-running analyzer answering JSON-RPC requests over a Unix socket.
+///
+/// A single thread owns the [`Analyzer`]: connections and the file watcher
+/// send it messages, so requests always see the state after the changes
+/// received before them.
+pub struct DaemonServer {
+    analyzer: Analyzer,
+    target_path: PathBuf,
+    config_hash: String,
+    /// Smells of the last full scan, updated by incremental scans.
+    smells: Vec<ArchSmell>,
+    /// Stamps of the analyzed files, taken before they were read.
+    stamps: HashMap<PathBuf, FileStamp>,
+    started_at: Instant,
+}
+
+impl DaemonServer {
+    /// Runs the initial scan of `path`.
+    pub fn new(path: &Path) -> Result<Self> {
+        let target_path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        let mut analyzer = Analyzer::new(&target_path, ScanOptions::new())?;
+        let config_hash = config_hash(analyzer.config());
+        let scan_started = SystemTime::now();
+        let result = analyzer.scan()?;
+        info!(
+            "Analyzed {} files, found {} smells",
+            result.summary.files_analyzed,
+            result.smells.len()
+        );
+
+        // Files written during the scan get no stamp, so the next request rescans them
+        let stamps = analyzer
+            .files()
+            .filter_map(|file| Some((file.clone(), file_stamp(file)?)))
+            .filter(|(_, (modified, _))| modified.is_some_and(|m| m < scan_started))
+            .collect();
+
+        Ok(Self {
+            analyzer,
+            target_path,
+            config_hash,
+            smells: result.smells.into_iter().map(|s| s.smell).collect(),
+            stamps,
+            started_at: Instant::now(),
+        })
+    }
+
+    #[must_use]
+    pub fn project_root(&self) -> &Path {
+        self.analyzer.project_root()
+    }
+
+    /// Listens on the project socket until a `shutdown` request, then removes the socket.
+    pub fn run(mut self) -> Result<()> {
+        let socket = socket_path(self.project_root());
+        create_socket_dir(&socket)?;
+        if UnixStream::connect(&socket).is_ok() {
+            return Err(AnalysisError::Anyhow(anyhow::anyhow!(
+                "A daemon is already running for {}",
+                self.project_root().display()
+            )));
+        }
+        // Left behind by a daemon that did not shut down cleanly
+        if is_own_socket(&socket) {
+            fs::remove_file(&socket)?;
+        }
+        let listener = UnixListener::bind(&socket)?;
+        info!("Listening on {}", socket.display());
+
+        let (tx, rx) = channel();
+        self.spawn_watcher(tx.clone());
+        thread::spawn(move || accept_connections(&listener, &tx));
+
+        let mut backlog = VecDeque::new();
+        while let Some(message) = backlog.pop_front().or_else(|| rx.recv().ok()) {
+            match message {
+                Message::Request(request, reply) => {
+                    if !matches!(request.method.as_str(), "status" | "shutdown") {
+                        self.catch_up(&rx, &mut backlog);
+                    }
+                    let response = match self.handle(&request) {
+                        Ok(result) => Response::success(request.id, result),
+                        Err(error) => Response::failure(request.id, error),
+                    };
+                    let _ = reply.send(response);
+                }
+                Message::Changed(files) => self.on_files_changed(files),
+                Message::Stop => break,
+            }
+        }
+
+        let _ = fs::remove_file(&socket);
+        Ok(())
+    }
+
+    fn spawn_watcher(&self, tx: Sender<Message>) {
+        let config = self.analyzer.config();
+        let mut ignore_patterns = config.watch.ignore.clone();
+        ignore_patterns.extend(["node_modules".to_string(), ".git/".to_string()]);
+        let watch_config = WatchConfig {
+            debounce_ms: config.watch.debounce_ms,
+            ignore_patterns,
+            clear_screen: false,
+            extensions: crate::args::SUPPORTED_EXTENSIONS
+                .iter()
+                .map(|&e| e.to_string())
+                .collect(),
+        };
+        let watcher = FileWatcher::new(self.target_path.clone(), watch_config);
+        thread::spawn(move || {
+            let result = watcher.watch(|files| {
+                tx.send(Message::Changed(files))
+                    .map_err(|e| AnalysisError::Anyhow(anyhow::anyhow!(e)))
+            });
+            if let Err(e) = result {
+                warn!("File watcher stopped: {e}");
+            }
+        });
+    }
+
+    /// Applies the changes the watcher already reported, then the ones it is
+    /// still debouncing, found by comparing the stamps of the analyzed files.
+    ///
+    /// Other messages received meanwhile are moved to `backlog`, keeping their order.
+    fn catch_up(&mut self, rx: &Receiver<Message>, backlog: &mut VecDeque<Message>) {
+        let mut changed = HashSet::new();
+        for message in rx.try_iter() {
+            match message {
+                Message::Changed(files) => changed.extend(files),
+                message => backlog.push_back(message),
+            }
+        }
+        changed.extend(
+            self.analyzer
+                .files()
+                .filter(|file| self.stamps.get(*file) != file_stamp(file).as_ref())
+                .cloned(),
+        );
+        if !changed.is_empty() {
+            self.on_files_changed(changed.into_iter().collect());
+        }
+    }
+
+    fn on_files_changed(&mut self, files: Vec<PathBuf>) {
+        let files: HashSet<PathBuf> = files.into_iter().collect();
+        let (existing, removed): (Vec<PathBuf>, Vec<PathBuf>) =
+            files.into_iter().partition(|f| f.exists());
+
+        if !removed.is_empty() {
+            for file in &removed {
+                self.stamps.remove(file);
+            }
+            self.analyzer.invalidate(&removed);
+            self.smells
+                .retain(|s| !s.files.iter().any(|f| removed.contains(f)));
+        }
+        if existing.is_empty() {
+            return;
+        }
+
+        // Taken before the files are read, so writes during the scan count as changes
+        let stamps: Vec<(PathBuf, FileStamp)> = existing
+            .iter()
+            .filter_map(|file| Some((file.clone(), file_stamp(file)?)))
+            .collect();
+        match self.analyzer.scan_incremental(existing) {
+            Ok(result) => {
+                self.stamps.extend(stamps);
+                let affected: HashSet<&PathBuf> = result.affected_files.iter().collect();
+                self.smells
+                    .retain(|s| !s.files.iter().any(|f| affected.contains(f)));
+                self.smells
+                    .extend(result.smells.into_iter().map(|s| s.smell));
+                debug!(
+                    "Updated {} affected files in {}ms",
+                    result.affected_count, result.analysis_time_ms
+                );
+            }
+            Err(e) => warn!("Incremental scan failed: {e}"),
+        }
+    }
+
+    fn handle(&self, request: &Request) -> MethodResult {
+        match request.method.as_str() {
+            "scan" => self.scan(params(&request.params)?),
+            "smells" => self.smells_for_file(&params::<FileParams>(&request.params)?.file),
+            "affected" => {
+                let FilesParams { files } = params(&request.params)?;
+                let files: Vec<PathBuf> = files.iter().map(|f| self.resolve(f)).collect();
+                let mut affected = self.analyzer.get_affected_files(&files);
+                affected.sort();
+                to_value(&affected)
+            }
+            "dependencies" => {
+                let FileParams { file } = params(&request.params)?;
+                to_value(&self.analyzer.get_dependencies(&self.resolve(&file)))
+            }
+            "dependents" => {
+                let FileParams { file } = params(&request.params)?;
+                to_value(&self.analyzer.get_dependents(&self.resolve(&file)))
+            }
+            "symbolUsages" => {
+                let SymbolParams { file, name } = params(&request.params)?;
+                let usages = self
+                    .analyzer
+                    .get_symbol_usages(&self.resolve(&file), &name)
+                    .ok_or_else(|| {
+                        RpcError::new(NOT_FOUND, format!("No exported symbol {name}"))
+                    })?;
+                to_value(&usages)
+            }
+            "explain" => self.explain(&params::<ExplainParams>(&request.params)?.id),
+            "status" => to_value(&self.status()),
+            "shutdown" => Ok(Value::Null),
+            method => Err(RpcError::new(
+                METHOD_NOT_FOUND,
+                format!("Unknown method: {method}"),
+            )),
+        }
+    }
+
+    fn scan(&self, params: ScanParams) -> MethodResult {
+        // Clients send the absolute path they scan, which must be the whole project
+        if let Some(path) = params.path {
+            if path != self.project_root() || path != self.target_path {
+                return Err(RpcError::new(
+                    PROJECT_MISMATCH,
+                    format!("Daemon analyzes {}", self.target_path.display()),
+                ));
+            }
+        }
+        if params
+            .config_hash
+            .is_some_and(|hash| hash != self.config_hash)
+        {
+            return Err(RpcError::new(
+                PROJECT_MISMATCH,
+                "Daemon runs with another config",
+            ));
+        }
+
+        let result = self
+            .analyzer
+            .build_result(self.smells.clone())
+            .map_err(|e| RpcError::new(INTERNAL_ERROR, e.to_string()))?;
+        to_value(&ScanReply {
+            result,
+            suppressed: self
+                .analyzer
+                .suppressed()
+                .iter()
+                .map(|(smell, suppression)| SuppressedEntry {
+                    smell: smell.clone(),
+                    suppression: suppression.clone(),
+                })
+                .collect(),
+            import_resolution: self.analyzer.import_resolution(),
+        })
+    }
+
+    fn smells_for_file(&self, file: &Path) -> MethodResult {
+        let file = self.resolve(file);
+        let entries: Vec<SmellEntry> = self
+            .smells
+            .iter()
+            .filter(|s| s.files.contains(&file))
+            .map(|s| self.entry(s))
+            .collect();
+        to_value(&entries)
+    }
+
+    fn explain(&self, id: &str) -> MethodResult {
+        let root = self.project_root();
+        let smell = self
+            .smells
+            .iter()
+            .find(|s| generate_smell_id(s, root) == id)
+            .ok_or_else(|| RpcError::new(NOT_FOUND, format!("No smell with ID {id}")))?;
+        to_value(&self.entry(smell))
+    }
+
+    fn entry(&self, smell: &ArchSmell) -> SmellEntry {
+        SmellEntry {
+            id: generate_smell_id(smell, self.project_root()),
+            smell: SmellWithExplanation {
+                explanation: crate::explain::ExplainEngine::explain(smell, self.analyzer.config()),
+                smell: smell.clone(),
+            },
+        }
+    }
+
+    fn status(&self) -> DaemonStatus {
+        let stats = self.analyzer.get_state_stats();
+        DaemonStatus {
+            project_root: self.project_root().to_path_buf(),
+            target_path: self.target_path.clone(),
+            config_hash: self.config_hash.clone(),
+            pid: std::process::id(),
+            files: stats.files_count,
+            graph_nodes: stats.graph_nodes,
+            graph_edges: stats.graph_edges,
+            smells: self.smells.len(),
+            uptime_secs: self.started_at.elapsed().as_secs(),
+        }
+    }
+
+    /// Resolves paths relative to the project root, the way the graph stores them.
+    fn resolve(&self, path: &Path) -> PathBuf {
+        let path = self.project_root().join(path);
+        path.canonicalize().unwrap_or(path)
+    }
+}
+
+fn file_stamp(path: &Path) -> Option<FileStamp> {
+    let metadata = fs::metadata(path).ok()?;
+    Some((metadata.modified().ok(), metadata.len()))
+}
+
+fn params<T: DeserializeOwned>(params: &Value) -> std::result::Result<T, RpcError> {
+    // Params may be omitted when every field is optional
+    let params = if params.is_null() {
+        Value::Object(serde_json::Map::new())
+    } else {
+        params.clone()
+    };
+    serde_json::from_value(params)
+        .map_err(|e| RpcError::new(INVALID_PARAMS, format!("Invalid params: {e}")))
+}
+
+fn to_value<T: Serialize>(value: &T) -> MethodResult {
+    serde_json::to_value(value).map_err(|e| RpcError::new(INTERNAL_ERROR, e.to_string()))
+}
+
+fn accept_connections(listener: &UnixListener, tx: &Sender<Message>) {
+    for stream in listener.incoming() {
+        match stream {
+            Ok(stream) => {
+                let tx = tx.clone();
+                thread::spawn(move || {
+                    if let Err(e) = serve_connection(stream, &tx) {
+                        debug!("Connection closed: {e}");
+                    }
+                });
+            }
+            Err(e) => debug!("Failed to accept connection: {e}"),
+        }
+    }
+}
+
+/// Answers newline-delimited requests until the client disconnects.
+fn serve_connection(stream: UnixStream, tx: &Sender<Message>) -> std::io::Result<()> {
+    let mut writer = stream.try_clone()?;
+    for line in BufReader::new(stream).lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let mut is_shutdown = false;
+        let response = match serde_json::from_str::<Request>(&line) {
+            Ok(request) => {
+                is_shutdown = request.method == "shutdown";
+                let (reply_tx, reply_rx) = channel();
+                if tx.send(Message::Request(request, reply_tx)).is_err() {
+                    return Ok(());
+                }
+                match reply_rx.recv() {
+                    Ok(response) => response,
+                    Err(_) => return Ok(()),
+                }
+            }
+            Err(e) => Response::failure(
+                Value::Null,
+                RpcError::new(PARSE_ERROR, format!("Invalid request: {e}")),
+            ),
+        };
+        let mut bytes = serde_json::to_vec(&response)?;
+        bytes.push(b'\n');
+        writer.write_all(&bytes)?;
+        if is_shutdown {
+            let _ = tx.send(Message::Stop);
+            return Ok(());
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,260 @@
+//! Technical debt estimation.
+//!
+//! Every smell kind has a remediation cost model: a fixed effort, plus effort
+//! per affected file, plus effort per unit its measured metric exceeds the
+//! threshold. Efforts are in minutes; the `debt.costs` config section
+//! overrides the built-in values per detector.
+
+use crate::codeowners::CodeOwners;
+use crate::config::DebtConfig;
+use crate::detectors::{ArchSmell, SmellKind, SmellMetric};
+use crate::package_metrics::folder_name;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use std::str::FromStr;
+
+/// Key used in snapshot metrics for the estimated debt of a smell.
+pub const DEBT_METRIC: &str = "debtMinutes";
+
+/// Owner bucket for files no `CODEOWNERS` rule covers.
+pub const UNOWNED: &str = "(unowned)";
+
+/// Remediation effort in minutes for one smell.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CostModel {
+    pub base: f64,
+    pub per_file: f64,
+    pub per_excess: f64,
+}
+
+impl CostModel {
+    const fn new(base: f64, per_file: f64, per_excess: f64) -> Self {
+        Self {
+            base,
+            per_file,
+            per_excess,
+        }
+    }
+
+    /// Built-in effort estimates, loosely following SQALE remediation functions.
+    #[must_use]
+    pub const fn default_for(kind: SmellKind) -> Self {
+        match kind {
+            SmellKind::CyclicDependency => Self::new(60.0, 30.0, 0.0),
+            SmellKind::CyclicDependencyCluster => Self::new(120.0, 30.0, 0.0),
+            SmellKind::PackageCycle => Self::new(240.0, 0.0, 0.0),
+            SmellKind::CircularModuleImport => Self::new(60.0, 30.0, 0.0),
+            SmellKind::GodModule | SmellKind::HubModule => Self::new(240.0, 0.0, 0.0),
+            SmellKind::LargeClass => Self::new(120.0, 0.0, 0.0),
+            SmellKind::LargeFile => Self::new(60.0, 0.0, 0.1),
+            SmellKind::LongFunction => Self::new(30.0, 0.0, 0.5),
+            SmellKind::HighCyclomaticComplexity | SmellKind::HighCognitiveComplexity => {
+                Self::new(15.0, 0.0, 5.0)
+            }
+            SmellKind::DeadCode | SmellKind::TestOnlyCode => Self::new(15.0, 0.0, 0.0),
+            SmellKind::DeadSymbol | SmellKind::OrphanType => Self::new(10.0, 0.0, 0.0),
+            SmellKind::UnusedClassMember | SmellKind::UnusedSuppression => Self::new(5.0, 0.0, 0.0),
+            SmellKind::NpmDependency | SmellKind::SideEffectImport => Self::new(10.0, 0.0, 0.0),
+            SmellKind::CodeClone => Self::new(20.0, 10.0, 0.0),
+            SmellKind::ShotgunSurgery | SmellKind::ScatteredConfiguration => {
+                Self::new(60.0, 10.0, 0.0)
+            }
+            _ => Self::new(30.0, 0.0, 0.0),
+        }
+    }
+}
+
+/// Cost models for all smell kinds, with config overrides applied.
+#[derive(Debug, Clone)]
+pub struct DebtModel {
+    overrides: HashMap<SmellKind, CostModel>,
+    folder_depth: usize,
+}
+
+impl Default for DebtModel {
+    fn default() -> Self {
+        Self::new(&DebtConfig::default())
+    }
+}
+
+impl DebtModel {
+    #[must_use]
+    pub fn new(config: &DebtConfig) -> Self {
+        let mut overrides = HashMap::new();
+        for (id, cost) in &config.costs {
+            let Ok(kind) = SmellKind::from_str(id) else {
+                log::warn!("Unknown detector '{id}' in debt.costs, ignoring");
+                continue;
+            };
+            let default = CostModel::default_for(kind);
+            overrides.insert(
+                kind,
+                CostModel {
+                    base: cost.base.unwrap_or(default.base),
+                    per_file: cost.per_file.unwrap_or(default.per_file),
+                    per_excess: cost.per_excess.unwrap_or(default.per_excess),
+                },
+            );
+        }
+
+        Self {
+            overrides,
+            folder_depth: config.folder_depth,
+        }
+    }
+
+    #[must_use]
+    pub fn cost(&self, kind: SmellKind) -> CostModel {
+        self.overrides
+            .get(&kind)
+            .copied()
+            .unwrap_or_else(|| CostModel::default_for(kind))
+    }
+
+    /// Estimated remediation effort for `smell`, in minutes.
+    #[must_use]
+    pub fn estimate(&self, smell: &ArchSmell) -> f64 {
+        let cost = self.cost(smell.smell_type.category());
+        let files = smell.files.len() as f64;
+        cost.per_excess
+            .mul_add(excess(smell), cost.per_file.mul_add(files, cost.base))
+    }
+}
+
+/// How far the smell's measured metric exceeds its threshold, if it has one.
+fn excess(smell: &ArchSmell) -> f64 {
+    let Some(threshold) = smell.threshold() else {
+        return 0.0;
+    };
+    let measured = smell.metrics.iter().find_map(|metric| match metric {
+        SmellMetric::CyclomaticComplexity(v)
+        | SmellMetric::CognitiveComplexity(v)
+        | SmellMetric::Lines(v) => Some(*v),
+        _ => None,
+    });
+    measured.map_or(0.0, |value| value.saturating_sub(threshold) as f64)
+}
+
+/// Debt aggregated over a set of smells.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DebtSummary {
+    /// Total estimated effort in minutes.
+    pub total_minutes: f64,
+    /// Minutes per detector ID.
+    pub by_type: BTreeMap<String, f64>,
+    /// Minutes per folder; multi-file smells are split evenly across their files.
+    pub by_folder: BTreeMap<String, f64>,
+    /// Minutes per `CODEOWNERS` owner set; empty without a `CODEOWNERS` file.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub by_owner: BTreeMap<String, f64>,
+}
+
+impl DebtSummary {
+    #[must_use]
+    pub fn compute<'a>(
+        smells: impl IntoIterator<Item = &'a ArchSmell>,
+        model: &DebtModel,
+        project_root: &Path,
+    ) -> Self {
+        let owners = CodeOwners::discover(project_root);
+        let mut summary = Self::default();
+
+        for smell in smells {
+            let minutes = model.estimate(smell);
+            summary.total_minutes += minutes;
+            *summary
+                .by_type
+                .entry(smell.smell_type.category().to_id().to_string())
+                .or_default() += minutes;
+
+            if smell.files.is_empty() {
+                continue;
+            }
+            let share = minutes / smell.files.len() as f64;
+            for file in &smell.files {
+                let folder = folder_name(file, project_root, model.folder_depth);
+                *summary.by_folder.entry(folder).or_default() += share;
+
+                if let Some(owners) = &owners {
+                    let relative = file.strip_prefix(project_root).unwrap_or(file);
+                    let owner = owners
+                        .owners_of(&relative.to_string_lossy().replace('\\', "/"))
+                        .map_or_else(|| UNOWNED.to_string(), |o| o.join(" "));
+                    *summary.by_owner.entry(owner).or_default() += share;
+                }
+            }
+        }
+
+        summary
+    }
+}
+
+/// Human-readable effort, e.g. `3h 20m`.
+#[must_use]
+pub fn format_minutes(minutes: f64) -> String {
+    let total = minutes.abs().round();
+    let sign = if minutes < 0.0 && total > 0.0 {
+        "-"
+    } else {
+        ""
+    };
+    let (hours, rest) = ((total / 60.0).floor(), total % 60.0);
+    if hours == 0.0 {
+        format!("{sign}{rest}m")
+    } else if rest == 0.0 {
+        format!("{sign}{hours}h")
+    } else {
+        format!("{sign}{hours}h {rest}m")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::RemediationCostConfig;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_estimate_uses_files_and_excess() {
+        let model = DebtModel::default();
+        let cycle = ArchSmell::new_cycle(vec![PathBuf::from("a.ts"), PathBuf::from("b.ts")]);
+        assert!((model.estimate(&cycle) - 120.0).abs() < f64::EPSILON);
+
+        let complex = ArchSmell::new_high_cyclomatic_complexity(
+            PathBuf::from("a.ts"),
+            "run".to_string(),
+            1,
+            25,
+            15,
+            None,
+        );
+        assert!((model.estimate(&complex) - 65.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_config_overrides_merge_with_defaults() {
+        let mut config = DebtConfig::default();
+        config.costs.insert(
+            "cycles".to_string(),
+            RemediationCostConfig {
+                per_file: Some(10.0),
+                ..Default::default()
+            },
+        );
+        let model = DebtModel::new(&config);
+        assert_eq!(
+            model.cost(SmellKind::CyclicDependency),
+            CostModel::new(60.0, 10.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn test_format_minutes() {
+        assert_eq!(format_minutes(45.0), "45m");
+        assert_eq!(format_minutes(120.0), "2h");
+        assert_eq!(format_minutes(200.4), "3h 20m");
+        assert_eq!(format_minutes(-90.0), "-1h 30m");
+    }
+}
@@ -1,11 +1,14 @@
-use super::types::{Cluster, Occurrence, WindowEntry};
+use super::types::{Alignment, Cluster, NearMiss, Occurrence, WindowEntry};
 use crate::parser::tokenizer::NormalizedToken;
 use rustc_hash::FxHashMap;
 use sha2::{Digest, Sha256};
 use std::path::{Path, PathBuf};
 
 /// Map to track processed ranges for each file pair.
-type ProcessedRangesMap = FxHashMap<(PathBuf, PathBuf), Vec<(usize, usize, usize)>>;
+type ProcessedRangesMap = FxHashMap<(PathBuf, PathBuf), Vec<(usize, usize, usize, usize)>>;
+
+/// Tokens that must match again after a gap for a near-miss alignment to continue.
+const RESYNC_TOKENS: usize = 3;
 
 /// Context for the clone detection process.
 struct DetectionContext<'a> {
@@ -14,10 +17,11 @@ struct DetectionContext<'a> {
     occ_to_cluster: &'a mut FxHashMap<(PathBuf, usize), usize>,
     /// Track already processed ranges for each file pair to avoid redundant expansions.
     /// Key is (file1, file2) where file1 <= file2 (alphabetically).
-    /// Value is a list of (start1, start2, length).
+    /// Value is a list of (start1, start2, length1, length2).
     processed_ranges: ProcessedRangesMap,
     min_tokens: usize,
     min_lines: usize,
+    near_miss: Option<NearMiss>,
 }
 
 impl DetectionContext<'_> {
@@ -46,12 +50,15 @@ impl DetectionContext<'_> {
             return;
         }
 
-        self.record_processed_range(file1, file2, start1, start2, length);
+        let mut alignment = Alignment::exact(start1, start2, length);
+        if let Some(near_miss) = self.near_miss {
+            extend_with_gaps(tokens1, tokens2, &mut alignment, near_miss, file1 == file2);
+        }
 
-        if let Some((occ1, occ2, hash)) =
-            self.validate_and_build_match(file1, file2, start1, start2, length)
-        {
-            self.update_clusters(occ1, occ2, hash, length);
+        self.record_processed_range(file1, file2, &alignment);
+
+        if let Some((occ1, occ2, hash)) = self.validate_and_build_match(file1, file2, &alignment) {
+            self.update_clusters(occ1, occ2, hash, &alignment);
         }
     }
 
@@ -64,48 +71,60 @@ impl DetectionContext<'_> {
         let pair_key = (key1.to_path_buf(), key2.to_path_buf());
 
         self.processed_ranges.get(&pair_key).is_some_and(|ranges| {
-            ranges.iter().any(|&(s1, s2, len)| {
+            ranges.iter().any(|&(s1, s2, len1, len2)| {
                 // Check if the new window starting at (off1, off2) would expand to overlap
-                // significantly with the already-processed range [s1, s2, len).
-                // We check if off1 falls within [s1, s1 + len - min_tokens], which ensures
+                // significantly with the already-processed ranges [s1, s1 + len1) and [s2, s2 + len2).
+                // We check if off1 falls within [s1, s1 + len1 - min_tokens], which ensures
                 // that the new window would mostly coincide with the processed range.
-                let end1 = s1 + len.saturating_sub(self.min_tokens);
-                let end2 = s2 + len.saturating_sub(self.min_tokens);
+                let end1 = s1 + len1.saturating_sub(self.min_tokens);
+                let end2 = s2 + len2.saturating_sub(self.min_tokens);
                 off1 >= s1 && off1 <= end1 && off2 >= s2 && off2 <= end2
             })
         })
     }
 
-    fn record_processed_range(&mut self, f1: &Path, f2: &Path, s1: usize, s2: usize, len: usize) {
-        let (key1, key2, start1, start2) = if f1 <= f2 {
-            (f1, f2, s1, s2)
+    fn record_processed_range(&mut self, f1: &Path, f2: &Path, alignment: &Alignment) {
+        let Alignment {
+            start1,
+            start2,
+            len1,
+            len2,
+            ..
+        } = *alignment;
+        let (key1, key2, entry) = if f1 <= f2 {
+            (f1, f2, (start1, start2, len1, len2))
         } else {
-            (f2, f1, s2, s1)
+            (f2, f1, (start2, start1, len2, len1))
         };
         let pair_key = (key1.to_path_buf(), key2.to_path_buf());
         self.processed_ranges
             .entry(pair_key)
             .or_default()
-            .push((start1, start2, len));
+            .push(entry);
     }
 
     fn validate_and_build_match(
         &self,
         file1: &Path,
         file2: &Path,
-        start1: usize,
-        start2: usize,
-        length: usize,
+        alignment: &Alignment,
     ) -> Option<(Occurrence, Occurrence, [u8; 32])> {
         let tokens1 = &self.file_tokens[file1];
         let tokens2 = &self.file_tokens[file2];
+        let Alignment {
+            start1,
+            start2,
+            len1,
+            len2,
+            ..
+        } = *alignment;
 
         if !starts_at_line_boundary(tokens1, start1) || !starts_at_line_boundary(tokens2, start2) {
             return None;
         }
 
-        let (sl1, sc1, el1, ec1) = calculate_range_bounds(tokens1, start1, length);
-        let (sl2, sc2, el2, ec2) = calculate_range_bounds(tokens2, start2, length);
+        let (sl1, sc1, el1, ec1) = calculate_range_bounds(tokens1, start1, len1);
+        let (sl2, sc2, el2, ec2) = calculate_range_bounds(tokens2, start2, len2);
 
         if el1.saturating_sub(sl1) + 1 < self.min_lines
             || el2.saturating_sub(sl2) + 1 < self.min_lines
@@ -113,7 +132,7 @@ impl DetectionContext<'_> {
             return None;
         }
 
-        let hash = hash_tokens(&tokens1[start1..start1 + length]);
+        let hash = hash_tokens(&tokens1[start1..start1 + len1]);
         let occ1 = Occurrence {
             file: file1.to_path_buf(),
             token_start: start1,
@@ -140,8 +159,10 @@ impl DetectionContext<'_> {
         occ1: Occurrence,
         occ2: Occurrence,
         range_hash: [u8; 32],
-        length: usize,
+        alignment: &Alignment,
     ) {
+        let length = alignment.matched;
+        let similarity = alignment.similarity();
         let key1 = (occ1.file.clone(), occ1.token_start);
         let key2 = (occ2.file.clone(), occ2.token_start);
 
@@ -152,6 +173,9 @@ impl DetectionContext<'_> {
             (Some(a), Some(b)) if a != b => {
                 let (keep, drop) = if a < b { (a, b) } else { (b, a) };
                 let dropped_occurrences = std::mem::take(&mut self.clusters[drop].occurrences);
+                self.clusters[keep].similarity = self.clusters[keep]
+                    .similarity
+                    .min(self.clusters[drop].similarity);
                 for occ in dropped_occurrences {
                     let k = (occ.file.clone(), occ.token_start);
                     self.add_to_cluster(keep, occ, k);
@@ -169,6 +193,7 @@ impl DetectionContext<'_> {
                 self.clusters.push(Cluster {
                     hash: range_hash,
                     token_count: length,
+                    similarity,
                     occurrences: vec![occ1, occ2],
                 });
                 self.occ_to_cluster.insert(key1, idx);
@@ -177,6 +202,8 @@ impl DetectionContext<'_> {
             }
         };
 
+        let cluster = &mut self.clusters[target_idx];
+        cluster.similarity = cluster.similarity.min(similarity);
         self.add_to_cluster(target_idx, occ1, key1);
         self.add_to_cluster(target_idx, occ2, key2);
     }
@@ -293,6 +320,131 @@ fn expand_match(
     (start1, start2, length)
 }
 
+/// Extends an exact match across small edit gaps, forward and then backward.
+///
+/// A gap of up to `max_gap` tokens in each range is skipped when the next
+/// [`RESYNC_TOKENS`] tokens match again and the alignment stays at least
+/// `min_similarity` percent similar. In the same file, the first range never
+/// grows into the second one.
+fn extend_with_gaps(
+    tokens1: &[NormalizedToken],
+    tokens2: &[NormalizedToken],
+    alignment: &mut Alignment,
+    near_miss: NearMiss,
+    is_same_file: bool,
+) {
+    let limit1 = if is_same_file {
+        alignment.start2
+    } else {
+        tokens1.len()
+    };
+    loop {
+        let i = alignment.start1 + alignment.len1;
+        let j = alignment.start2 + alignment.len2;
+        let matches = |d1: usize, d2: usize, k: usize| {
+            i + d1 + k < limit1
+                && j + d2 + k < tokens2.len()
+                && tokens1[i + d1 + k].normalized == tokens2[j + d2 + k].normalized
+        };
+
+        let (d1, d2, run) = if matches(0, 0, 0) {
+            (0, 0, 1)
+        } else if let Some((d1, d2)) = find_resync(alignment, near_miss, matches) {
+            (d1, d2, RESYNC_TOKENS)
+        } else {
+            break;
+        };
+        alignment.len1 += d1 + run;
+        alignment.len2 += d2 + run;
+        alignment.matched += run;
+    }
+
+    let lower2 = if is_same_file {
+        alignment.start1 + alignment.len1
+    } else {
+        0
+    };
+    loop {
+        let i = alignment.start1;
+        let j = alignment.start2;
+        let matches = |d1: usize, d2: usize, k: usize| {
+            i > d1 + k
+                && j > lower2 + d2 + k
+                && tokens1[i - 1 - d1 - k].normalized == tokens2[j - 1 - d2 - k].normalized
+        };
+
+        let (d1, d2, run) = if matches(0, 0, 0) {
+            (0, 0, 1)
+        } else if let Some((d1, d2)) = find_resync(alignment, near_miss, matches) {
+            (d1, d2, RESYNC_TOKENS)
+        } else {
+            break;
+        };
+        alignment.start1 -= d1 + run;
+        alignment.start2 -= d2 + run;
+        alignment.len1 += d1 + run;
+        alignment.len2 += d2 + run;
+        alignment.matched += run;
+    }
+
+    // Differing tokens at the start of the first line, like a renamed
+    // function, are one more gap
+    let back1 = tokens_before_on_line(tokens1, alignment.start1);
+    let back2 = tokens_before_on_line(tokens2, alignment.start2);
+    let extended = Alignment {
+        start1: alignment.start1 - back1,
+        start2: alignment.start2 - back2,
+        len1: alignment.len1 + back1,
+        len2: alignment.len2 + back2,
+        ..*alignment
+    };
+    if back1 <= near_miss.max_gap
+        && back2 <= near_miss.max_gap
+        && extended.start2 >= lower2
+        && extended.similarity() >= near_miss.min_similarity
+    {
+        *alignment = extended;
+    }
+}
+
+/// Number of tokens on the line of `tokens[start]` that come before it.
+fn tokens_before_on_line(tokens: &[NormalizedToken], start: usize) -> usize {
+    tokens.get(start).map_or(0, |token| {
+        tokens[..start]
+            .iter()
+            .rev()
+            .take_while(|t| t.line == token.line)
+            .count()
+    })
+}
+
+/// Finds the smallest gap `(d1, d2)` after which both ranges match again.
+///
+/// `matches(d1, d2, k)` compares the `k`-th tokens after skipping `d1` and `d2`
+/// tokens. Gaps lowering the similarity below the threshold are rejected.
+fn find_resync(
+    alignment: &Alignment,
+    near_miss: NearMiss,
+    matches: impl Fn(usize, usize, usize) -> bool,
+) -> Option<(usize, usize)> {
+    let max_gap = near_miss.max_gap;
+    (1..=max_gap * 2)
+        .find_map(|total| {
+            (total.saturating_sub(max_gap)..=total.min(max_gap))
+                .map(|d1| (d1, total - d1))
+                .find(|&(d1, d2)| (0..RESYNC_TOKENS).all(|k| matches(d1, d2, k)))
+        })
+        .filter(|&(d1, d2)| {
+            let extended = Alignment {
+                len1: alignment.len1 + d1 + RESYNC_TOKENS,
+                len2: alignment.len2 + d2 + RESYNC_TOKENS,
+                matched: alignment.matched + RESYNC_TOKENS,
+                ..*alignment
+            };
+            extended.similarity() >= near_miss.min_similarity
+        })
+}
+
 /// Calculates the precise line and column boundaries for a token range.
 fn calculate_range_bounds(
     tokens: &[NormalizedToken],
@@ -311,7 +463,8 @@ fn calculate_range_bounds(
 /// Main logic for detecting clone clusters.
 ///
 /// Iterates over matching token windows and expands them forward and backward
-/// to find the maximum possible duplicated range.
+/// to find the maximum possible duplicated range, across small edit gaps when
+/// `near_miss` is set.
 #[must_use]
 pub fn detect_clusters(
     file_tokens: &FxHashMap<PathBuf, Vec<NormalizedToken>>,
@@ -319,9 +472,10 @@ pub fn detect_clusters(
     min_tokens: usize,
     min_lines: usize,
     max_bucket_size: usize,
+    near_miss: Option<NearMiss>,
 ) -> Vec<Cluster> {
     let mut window_entries: Vec<WindowEntry> = window_map.into_iter().collect();
-    window_entries.sort_by(|a, b| a.0.cmp(&b.0));
+    window_entries.sort_by_key(|a| a.0);
 
     for entry in &mut window_entries {
         entry.1.sort();
@@ -338,6 +492,7 @@ pub fn detect_clusters(
             processed_ranges: FxHashMap::default(),
             min_tokens,
             min_lines,
+            near_miss,
         };
 
         for (_hash, locations) in window_entries {
@@ -0,0 +1,432 @@
+use super::functions::FunctionRef;
+use crate::detectors::CodeRange;
+use crate::parser::tokenizer::NormalizedToken;
+use crate::parser::{FunctionComplexity, LineIndex};
+use oxc_allocator::Allocator;
+use oxc_ast::AstKind;
+use oxc_ast_visit::Visit;
+use oxc_parser::Parser;
+use oxc_span::{SourceType, Span};
+use rustc_hash::{FxHashMap, FxHashSet, FxHasher};
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+
+/// Structural hash of a function, independent of local names and statement order.
+#[derive(Debug, Clone)]
+pub struct FunctionFingerprint {
+    pub function: FunctionRef,
+    pub hash: u64,
+    pub token_count: usize,
+}
+
+/// Functions of the project sharing a fingerprint.
+#[derive(Debug, Clone)]
+pub struct FingerprintClone {
+    pub hash: u64,
+    /// Sorted by file and position.
+    pub functions: Vec<FunctionRef>,
+    /// Tokens of the largest copy.
+    pub token_count: usize,
+}
+
+/// Fingerprints every function of the files in `file_tokens`.
+#[must_use]
+pub fn fingerprint_files(
+    file_tokens: &FxHashMap<PathBuf, Vec<NormalizedToken>>,
+    function_complexity: &HashMap<PathBuf, Vec<FunctionComplexity>>,
+    min_tokens: usize,
+    min_lines: usize,
+) -> Vec<FunctionFingerprint> {
+    file_tokens
+        .iter()
+        .filter_map(|(file, tokens)| {
+            let source = std::fs::read_to_string(file).ok()?;
+            let functions = function_complexity.get(file).map_or(&[][..], Vec::as_slice);
+            Some(fingerprint_file(
+                file, &source, tokens, functions, min_tokens, min_lines,
+            ))
+        })
+        .flatten()
+        .collect()
+}
+
+/// Fingerprints the functions of `file` that span at least `min_tokens` and `min_lines`.
+///
+/// Functions are named after the matching entries of `functions`, which hold
+/// the body ranges collected by the parser.
+#[must_use]
+pub fn fingerprint_file(
+    file: &Path,
+    source: &str,
+    tokens: &[NormalizedToken],
+    functions: &[FunctionComplexity],
+    min_tokens: usize,
+    min_lines: usize,
+) -> Vec<FunctionFingerprint> {
+    let allocator = Allocator::default();
+    let source_type = SourceType::from_path(file).unwrap_or_default();
+    let ret = Parser::new(&allocator, source, source_type).parse();
+    if !ret.errors.is_empty() {
+        return Vec::new();
+    }
+
+    let mut bindings = LocalBindings::default();
+    bindings.visit_program(&ret.program);
+
+    // First pass with anonymous locals to find the canonical statement order,
+    // second pass with locals numbered in that order
+    let mut numbering = SubtreeHasher::new(&bindings.locals, None);
+    numbering.visit_program(&ret.program);
+    let local_indices = numbering.local_indices();
+    let mut hasher = SubtreeHasher::new(&bindings.locals, Some(local_indices));
+    hasher.visit_program(&ret.program);
+
+    let line_index = LineIndex::new(source);
+    hasher
+        .functions
+        .into_iter()
+        .filter_map(|(span, hash)| {
+            let token_count = tokens
+                .iter()
+                .filter(|t| span.start <= t.span.start && t.span.end <= span.end)
+                .count();
+            let range = span_range(&line_index, span);
+            if token_count < min_tokens || range.end_line - range.start_line + 1 < min_lines {
+                return None;
+            }
+
+            let name = functions
+                .iter()
+                .find(|f| f.body_range == range)
+                .map_or_else(|| "anonymous".to_string(), |f| f.name.to_string());
+            Some(FunctionFingerprint {
+                function: FunctionRef {
+                    file: file.to_path_buf(),
+                    range,
+                    name,
+                },
+                hash,
+                token_count,
+            })
+        })
+        .collect()
+}
+
+/// Groups functions with the same fingerprint.
+///
+/// Functions nested in a larger clone are skipped: when two functions are
+/// duplicates, so are the closures inside them.
+#[must_use]
+pub fn group_by_fingerprint(fingerprints: Vec<FunctionFingerprint>) -> Vec<FingerprintClone> {
+    let mut groups: FxHashMap<u64, Vec<FunctionFingerprint>> = FxHashMap::default();
+    for fingerprint in fingerprints {
+        groups
+            .entry(fingerprint.hash)
+            .or_default()
+            .push(fingerprint);
+    }
+
+    let mut clones: Vec<FingerprintClone> = groups
+        .into_iter()
+        .filter(|(_, group)| group.len() >= 2)
+        .map(|(hash, group)| {
+            let token_count = group.iter().map(|f| f.token_count).max().unwrap_or(0);
+            let mut functions: Vec<FunctionRef> = group.into_iter().map(|f| f.function).collect();
+            functions.sort();
+            FingerprintClone {
+                hash,
+                functions,
+                token_count,
+            }
+        })
+        .collect();
+    clones.sort_by(|a, b| {
+        b.token_count
+            .cmp(&a.token_count)
+            .then_with(|| a.functions.cmp(&b.functions))
+    });
+
+    let mut kept: Vec<FingerprintClone> = Vec::new();
+    for clone in clones {
+        let nested = clone.functions.iter().all(|function| {
+            kept.iter()
+                .flat_map(|k| &k.functions)
+                .any(|outer| outer.file == function.file && encloses(&outer.range, &function.range))
+        });
+        if !nested {
+            kept.push(clone);
+        }
+    }
+    kept
+}
+
+const fn encloses(outer: &CodeRange, inner: &CodeRange) -> bool {
+    outer.start_line <= inner.start_line && inner.end_line <= outer.end_line
+}
+
+fn span_range(line_index: &LineIndex, span: Span) -> CodeRange {
+    let (start_line, start_column) = line_index.line_col(span.start as usize);
+    let (end_line, end_column) = line_index.line_col(span.end as usize);
+    CodeRange {
+        start_line,
+        start_column,
+        end_line,
+        end_column,
+    }
+}
+
+/// Collects names bound inside function parameters and bodies.
+///
+/// Module-level names (imports, top-level declarations) stay out, so calls to
+/// shared helpers keep their names in the fingerprint.
+#[derive(Default)]
+struct LocalBindings<'a> {
+    locals: FxHashSet<&'a str>,
+    depth: usize,
+}
+
+impl<'a> Visit<'a> for LocalBindings<'a> {
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        match kind {
+            AstKind::FormalParameters(_) | AstKind::FunctionBody(_) => self.depth += 1,
+            AstKind::BindingIdentifier(it) if self.depth > 0 => {
+                self.locals.insert(it.name.as_str());
+            }
+            _ => {}
+        }
+    }
+
+    fn leave_node(&mut self, kind: AstKind<'a>) {
+        if matches!(
+            kind,
+            AstKind::FormalParameters(_) | AstKind::FunctionBody(_)
+        ) {
+            self.depth -= 1;
+        }
+    }
+}
+
+/// A node being hashed, with the hashes of its children so far.
+struct Frame {
+    /// Number of the node in visiting order.
+    id: usize,
+    leaf: u64,
+    children: Vec<u64>,
+    params: Option<u64>,
+    body: Option<u64>,
+}
+
+/// Index of each local of a function, by function in visiting order.
+type LocalIndices<'a> = Vec<FxHashMap<&'a str, usize>>;
+
+/// Where a local occurs: the function it belongs to and the path from the
+/// root, as `(node id, child position)` pairs.
+struct Occurrence<'a> {
+    scope: usize,
+    name: &'a str,
+    path: Vec<(usize, usize)>,
+}
+
+/// Hashes every subtree bottom-up and records the fingerprint of each function.
+///
+/// Statement lists hash their statements in sorted order, so reordered copies
+/// match. Locals are numbered per function (`$0`, `$1`, ...) so consistently
+/// renamed copies match while swapped uses of two locals do not. The
+/// numbering follows first occurrence in the sorted statement order, found by
+/// a first pass that hashes every local alike (`local_indices` is `None`).
+struct SubtreeHasher<'a> {
+    locals: &'a FxHashSet<&'a str>,
+    local_indices: Option<LocalIndices<'a>>,
+    /// Functions being visited, innermost last, by number in visiting order.
+    scopes: Vec<usize>,
+    scope_count: usize,
+    /// Set on entering a function until its parameters open its scope, so the
+    /// function's own name is numbered in the enclosing function.
+    pending_scope: bool,
+    node_count: usize,
+    stack: Vec<Frame>,
+    functions: Vec<(Span, u64)>,
+    /// First pass only: positions of the locals and the sorted position of
+    /// the children of each statement list.
+    occurrences: Vec<Occurrence<'a>>,
+    sorted_positions: FxHashMap<usize, Vec<usize>>,
+}
+
+impl<'a> SubtreeHasher<'a> {
+    fn new(locals: &'a FxHashSet<&'a str>, local_indices: Option<LocalIndices<'a>>) -> Self {
+        Self {
+            locals,
+            local_indices,
+            scopes: Vec::new(),
+            scope_count: 0,
+            pending_scope: false,
+            node_count: 0,
+            stack: Vec::new(),
+            functions: Vec::new(),
+            occurrences: Vec::new(),
+            sorted_positions: FxHashMap::default(),
+        }
+    }
+
+    fn identifier(&mut self, name: &'a str) -> u64 {
+        if !self.locals.contains(name) {
+            return hash_of(name);
+        }
+        let Some(&scope) = self.scopes.last() else {
+            return hash_of("$local");
+        };
+        let Some(indices) = &self.local_indices else {
+            self.occurrences.push(Occurrence {
+                scope,
+                name,
+                path: self
+                    .stack
+                    .iter()
+                    .map(|frame| (frame.id, frame.children.len()))
+                    .collect(),
+            });
+            return hash_of("$local");
+        };
+        indices[scope]
+            .get(name)
+            .map_or_else(|| hash_of("$local"), |index| hash_of(("$local", index)))
+    }
+
+    /// Numbers the locals of each function by first occurrence, visiting
+    /// statement lists in sorted order.
+    fn local_indices(&self) -> LocalIndices<'a> {
+        let mut first: Vec<FxHashMap<&'a str, Vec<usize>>> =
+            vec![FxHashMap::default(); self.scope_count];
+        for occurrence in &self.occurrences {
+            let key: Vec<usize> = occurrence
+                .path
+                .iter()
+                .map(|&(id, position)| {
+                    self.sorted_positions
+                        .get(&id)
+                        .map_or(position, |sorted| sorted[position])
+                })
+                .collect();
+            let known = first[occurrence.scope]
+                .entry(occurrence.name)
+                .or_insert_with(|| key.clone());
+            if key < *known {
+                *known = key;
+            }
+        }
+
+        first
+            .into_iter()
+            .map(|locals| {
+                let mut ordered: Vec<_> = locals.into_iter().collect();
+                // Paths are distinct, so no two locals tie
+                ordered.sort_by(|(_, a), (_, b)| a.cmp(b));
+                ordered
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, (name, _))| (name, index))
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+impl<'a> Visit<'a> for SubtreeHasher<'a> {
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        match kind {
+            AstKind::Function(_) | AstKind::ArrowFunctionExpression(_) => {
+                self.pending_scope = true;
+            }
+            AstKind::FormalParameters(_) if self.pending_scope => {
+                self.pending_scope = false;
+                self.scopes.push(self.scope_count);
+                self.scope_count += 1;
+            }
+            _ => {}
+        }
+
+        let leaf = match kind {
+            AstKind::IdentifierReference(it) => self.identifier(it.name.as_str()),
+            AstKind::BindingIdentifier(it) => self.identifier(it.name.as_str()),
+            AstKind::IdentifierName(it) => hash_of(it.name.as_str()),
+            AstKind::PrivateIdentifier(it) => hash_of(it.name.as_str()),
+            AstKind::StringLiteral(it) => hash_of(it.value.as_str()),
+            AstKind::NumericLiteral(it) => hash_of(it.value.to_bits()),
+            AstKind::BooleanLiteral(it) => hash_of(it.value),
+            AstKind::TemplateElement(it) => hash_of(it.value.raw.as_str()),
+            AstKind::BinaryExpression(it) => hash_of(it.operator as u8),
+            AstKind::LogicalExpression(it) => hash_of(it.operator as u8),
+            AstKind::UnaryExpression(it) => hash_of(it.operator as u8),
+            AstKind::UpdateExpression(it) => hash_of((it.operator as u8, it.prefix)),
+            AstKind::AssignmentExpression(it) => hash_of(it.operator as u8),
+            AstKind::VariableDeclaration(it) => hash_of(it.kind as u8),
+            _ => 0,
+        };
+        self.node_count += 1;
+        self.stack.push(Frame {
+            id: self.node_count,
+            leaf,
+            children: Vec::new(),
+            params: None,
+            body: None,
+        });
+    }
+
+    fn leave_node(&mut self, kind: AstKind<'a>) {
+        let Some(mut frame) = self.stack.pop() else {
+            return;
+        };
+        if matches!(
+            kind,
+            AstKind::FunctionBody(_) | AstKind::BlockStatement(_) | AstKind::StaticBlock(_)
+        ) {
+            if self.local_indices.is_none() {
+                let mut order: Vec<usize> = (0..frame.children.len()).collect();
+                order.sort_by_key(|&i| frame.children[i]);
+                let mut sorted = vec![0; order.len()];
+                for (rank, child) in order.into_iter().enumerate() {
+                    sorted[child] = rank;
+                }
+                self.sorted_positions.insert(frame.id, sorted);
+            }
+            frame.children.sort_unstable();
+        }
+
+        let mut hasher = FxHasher::default();
+        kind.ty().hash(&mut hasher);
+        frame.leaf.hash(&mut hasher);
+        frame.children.hash(&mut hasher);
+        let hash = hasher.finish();
+
+        match kind {
+            // The name of a function is not part of its fingerprint
+            AstKind::Function(it) => {
+                self.scopes.pop();
+                self.functions
+                    .push((it.span, hash_of((frame.params, frame.body))));
+            }
+            AstKind::ArrowFunctionExpression(it) => {
+                self.scopes.pop();
+                self.functions
+                    .push((it.span, hash_of((frame.params, frame.body))));
+            }
+            _ => {}
+        }
+
+        if let Some(parent) = self.stack.last_mut() {
+            match kind {
+                AstKind::FormalParameters(_) => parent.params = Some(hash),
+                AstKind::FunctionBody(_) => parent.body = Some(hash),
+                _ => {}
+            }
+            parent.children.push(hash);
+        }
+    }
+}
+
+fn hash_of<T: Hash>(value: T) -> u64 {
+    let mut hasher = FxHasher::default();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
@@ -0,0 +1,194 @@
+use super::types::{Cluster, Occurrence};
+use crate::detectors::CodeRange;
+use crate::parser::tokenizer::NormalizedToken;
+use crate::parser::FunctionComplexity;
+use rustc_hash::FxHashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+/// A function containing a clone occurrence.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct FunctionRef {
+    pub file: PathBuf,
+    pub range: CodeRange,
+    pub name: String,
+}
+
+/// Clones shared by the same set of functions, reported as similar functions
+/// instead of raw line spans.
+#[derive(Debug, Clone)]
+pub struct FunctionClone {
+    /// Sorted by file and position.
+    pub functions: Vec<FunctionRef>,
+    /// Clone occurrences inside each function, in the order of `functions`.
+    pub occurrences: Vec<Vec<Occurrence>>,
+    /// Clusters the functions share, sorted by hash.
+    pub clusters: Vec<Cluster>,
+    /// Lowest similarity of two of the functions, in percent.
+    pub similarity: usize,
+}
+
+impl FunctionClone {
+    #[must_use]
+    pub fn token_count(&self) -> usize {
+        self.clusters.iter().map(|c| c.token_count).sum()
+    }
+}
+
+/// Splits clusters into groups of similar functions and the remaining block clones.
+///
+/// A cluster belongs to a function group when each of its occurrences lies in
+/// a different function. Clusters in the same functions are grouped, and the
+/// group is kept when the functions are at least `min_similarity` percent
+/// similar: the share of their tokens covered by the clones, scaled by the
+/// similarity of the clones themselves.
+#[must_use]
+pub fn group_by_function(
+    clusters: Vec<Cluster>,
+    function_complexity: &HashMap<PathBuf, Vec<FunctionComplexity>>,
+    file_tokens: &FxHashMap<PathBuf, Vec<NormalizedToken>>,
+    min_similarity: usize,
+) -> (Vec<FunctionClone>, Vec<Cluster>) {
+    let mut groups: BTreeMap<Vec<FunctionRef>, Vec<Cluster>> = BTreeMap::new();
+    let mut blocks = Vec::new();
+
+    for cluster in clusters {
+        let functions: Option<Vec<FunctionRef>> = cluster
+            .occurrences
+            .iter()
+            .map(|occ| containing_function(function_complexity.get(&occ.file)?, occ))
+            .collect();
+
+        match functions {
+            Some(mut functions) => {
+                functions.sort();
+                functions.dedup();
+                if functions.len() == cluster.occurrences.len() {
+                    groups.entry(functions).or_default().push(cluster);
+                } else {
+                    blocks.push(cluster);
+                }
+            }
+            None => blocks.push(cluster),
+        }
+    }
+
+    let mut function_clones = Vec::new();
+    for (functions, mut clusters) in groups {
+        clusters.sort_by_key(|c| c.hash);
+        let occurrences: Vec<Vec<Occurrence>> = functions
+            .iter()
+            .map(|function| {
+                let mut inside: Vec<Occurrence> = clusters
+                    .iter()
+                    .flat_map(|c| &c.occurrences)
+                    .filter(|occ| occ.file == function.file && contains(&function.range, occ))
+                    .cloned()
+                    .collect();
+                inside.sort_by_key(|occ| occ.start_line);
+                inside
+            })
+            .collect();
+
+        let clone_similarity = clusters.iter().map(|c| c.similarity).min().unwrap_or(100);
+        let coverage = functions
+            .iter()
+            .zip(&occurrences)
+            .map(|(function, inside)| coverage(file_tokens.get(&function.file), function, inside))
+            .min()
+            .unwrap_or(0);
+        let similarity = coverage * clone_similarity / 100;
+
+        if similarity >= min_similarity {
+            function_clones.push(FunctionClone {
+                functions,
+                occurrences,
+                clusters,
+                similarity,
+            });
+        } else {
+            blocks.extend(clusters);
+        }
+    }
+
+    (function_clones, blocks)
+}
+
+/// The innermost function whose range contains `occ`.
+fn containing_function(functions: &[FunctionComplexity], occ: &Occurrence) -> Option<FunctionRef> {
+    functions
+        .iter()
+        .filter(|f| contains(&f.body_range, occ))
+        .min_by_key(|f| f.body_range.end_line - f.body_range.start_line)
+        .map(|f| FunctionRef {
+            file: occ.file.clone(),
+            range: f.body_range,
+            name: f.name.to_string(),
+        })
+}
+
+const fn contains(range: &CodeRange, occ: &Occurrence) -> bool {
+    range.start_line <= occ.start_line && occ.end_line <= range.end_line
+}
+
+/// Share of the tokens of `function` on lines covered by `occurrences`, in percent.
+fn coverage(
+    tokens: Option<&Vec<NormalizedToken>>,
+    function: &FunctionRef,
+    occurrences: &[Occurrence],
+) -> usize {
+    let Some(tokens) = tokens else {
+        return 0;
+    };
+    let in_function: Vec<&NormalizedToken> = tokens
+        .iter()
+        .filter(|t| function.range.start_line <= t.line && t.line <= function.range.end_line)
+        .collect();
+    if in_function.is_empty() {
+        return 0;
+    }
+
+    let covered = in_function
+        .iter()
+        .filter(|t| {
+            occurrences
+                .iter()
+                .any(|occ| occ.start_line <= t.line && t.line <= occ.end_line)
+        })
+        .count();
+    covered * 100 / in_function.len()
+}
+
+/// `file:name` of a function, relative to `root`.
+#[must_use]
+pub fn function_label(function: &FunctionRef, root: &Path) -> String {
+    let file = function.file.strip_prefix(root).unwrap_or(&function.file);
+    format!("{}:{}", file.to_string_lossy(), function.name)
+}
+
+/// The deepest folder containing all of `functions`, relative to `root`:
+/// where a shared implementation can live without reaching into a sibling.
+#[must_use]
+pub fn extraction_target(functions: &[FunctionRef], root: &Path) -> String {
+    let mut folders = functions
+        .iter()
+        .filter_map(|f| f.file.parent())
+        .map(|folder| folder.strip_prefix(root).unwrap_or(folder));
+    let Some(first) = folders.next() else {
+        return ".".to_string();
+    };
+
+    let common = folders.fold(first.to_path_buf(), |common, folder| {
+        common
+            .components()
+            .zip(folder.components())
+            .take_while(|(a, b)| a == b)
+            .map(|(a, _)| a)
+            .collect()
+    });
+    if common.as_os_str().is_empty() {
+        ".".to_string()
+    } else {
+        format!("{}/", common.to_string_lossy())
+    }
+}
@@ -1,13 +1,20 @@
 pub mod engine;
+pub mod fingerprint;
+pub mod functions;
 pub mod tokenizer;
 pub mod types;
 
-use crate::detectors::{detector, ArchSmell, CodeRange, Detector, LocationDetail};
+use crate::detectors::{detector, ArchSmell, CodeRange, Detector, Explanation, LocationDetail};
 use crate::engine::AnalysisContext;
-use rustc_hash::FxHashSet;
+use crate::parser::tokenizer::NormalizedToken;
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::path::PathBuf;
 
 use self::engine::{build_window_map, detect_clusters, merge_overlapping_occurrences};
+use self::fingerprint::{fingerprint_files, group_by_fingerprint, FingerprintClone};
+use self::functions::{extraction_target, function_label, group_by_function, FunctionClone};
 use self::tokenizer::tokenize_files;
+use self::types::NearMiss;
 
 /// Main detector for code clones (duplicated code blocks).
 #[detector(SmellType::CodeClone, is_deep = true)]
@@ -20,67 +27,209 @@ impl CodeCloneDetector {
     }
 
     /// Resolves detector configuration options from the analysis context.
-    fn resolve_config(&self, ctx: &AnalysisContext) -> (usize, usize, usize) {
+    fn resolve_config(&self, ctx: &AnalysisContext) -> (usize, usize, usize, NearMiss, bool) {
         let global_rule = ctx.resolve_rule("code_clone", None);
         let min_tokens: usize = global_rule.get_option("min_tokens").unwrap_or(50);
         let min_lines: usize = global_rule.get_option("min_lines").unwrap_or(6);
         let max_bucket_size: usize = global_rule.get_option("max_bucket_size").unwrap_or(1000);
-        (min_tokens, min_lines, max_bucket_size)
+        let max_gap: usize = global_rule.get_option("max_gap").unwrap_or(0);
+        let min_similarity: usize = global_rule.get_option("min_similarity").unwrap_or(80);
+        let ast_fingerprints: bool = global_rule.get_option("ast_fingerprints").unwrap_or(false);
+        let near_miss = NearMiss {
+            max_gap,
+            min_similarity,
+        };
+        (
+            min_tokens,
+            min_lines,
+            max_bucket_size,
+            near_miss,
+            ast_fingerprints,
+        )
     }
 
-    /// Converts detected clusters into architectural smells (`ArchSmell`).
+    /// Converts detected clusters into architectural smells (`ArchSmell`),
+    /// reporting clones that make up most of their functions as similar functions.
+    ///
+    /// Fingerprint clones are reported unless the token clones already cover
+    /// the same functions.
     fn report_smells(
         &self,
         ctx: &AnalysisContext,
         clusters: Vec<types::Cluster>,
+        fingerprint_clones: Vec<FingerprintClone>,
+        file_tokens: &FxHashMap<PathBuf, Vec<NormalizedToken>>,
+        min_similarity: usize,
     ) -> Vec<ArchSmell> {
-        let mut smells = Vec::new();
+        let clusters: Vec<types::Cluster> = clusters
+            .into_iter()
+            .map(|mut cluster| {
+                cluster.occurrences = merge_overlapping_occurrences(cluster.occurrences);
+                cluster
+            })
+            .filter(|c| c.occurrences.len() >= 2)
+            .collect();
 
-        for cluster in clusters.into_iter().filter(|c| c.occurrences.len() >= 2) {
-            let hash_str = cluster.hash.iter().fold(String::new(), |mut acc, b| {
-                use std::fmt::Write;
-                let _ = write!(acc, "{b:02x}");
-                acc
-            });
+        let (function_clones, blocks) = group_by_function(
+            clusters,
+            &ctx.function_complexity,
+            file_tokens,
+            min_similarity,
+        );
 
-            let merged_occurrences = merge_overlapping_occurrences(cluster.occurrences);
+        let mut smells: Vec<ArchSmell> = function_clones
+            .iter()
+            .map(|clone| self.report_function_clone(ctx, clone))
+            .collect();
 
-            if merged_occurrences.len() < 2 {
-                continue;
-            }
+        smells.extend(
+            fingerprint_clones
+                .iter()
+                .filter(|clone| {
+                    !function_clones.iter().any(|reported| {
+                        clone
+                            .functions
+                            .iter()
+                            .all(|function| reported.functions.contains(function))
+                    })
+                })
+                .map(|clone| self.report_fingerprint_clone(ctx, clone)),
+        );
 
+        for cluster in blocks {
             let mut location_details = Vec::new();
-            for occ in &merged_occurrences {
-                let other_refs = self.format_other_refs(ctx, &merged_occurrences, occ);
+            for occ in &cluster.occurrences {
+                let other_refs = self.format_other_refs(ctx, &cluster.occurrences, occ);
 
+                let similarity = if cluster.similarity < 100 {
+                    format!(", {}% similar", cluster.similarity)
+                } else {
+                    String::new()
+                };
                 let description = format!(
-                    "Duplicated code ({} tokens, lines {}-{}). Also found in: {}",
+                    "Duplicated code ({} tokens, lines {}-{}{similarity}). Also found in: {}",
                     cluster.token_count, occ.start_line, occ.end_line, other_refs
                 );
 
-                let range = CodeRange {
-                    start_line: occ.start_line,
-                    start_column: occ.start_column,
-                    end_line: occ.end_line,
-                    end_column: occ.end_column,
-                };
-
                 location_details.push(
                     LocationDetail::new(occ.file.clone(), occ.start_line, description)
-                        .with_range(range),
+                        .with_range(occurrence_range(occ)),
                 );
             }
 
             smells.push(ArchSmell::new_code_clone(
                 location_details,
                 cluster.token_count,
-                hash_str,
+                format_hash(&cluster.hash),
+                cluster.similarity,
+                Vec::new(),
+                None,
             ));
         }
 
         smells
     }
 
+    /// One location per function, spanning the clones inside it.
+    fn report_function_clone(&self, ctx: &AnalysisContext, clone: &FunctionClone) -> ArchSmell {
+        let labels: Vec<String> = clone
+            .functions
+            .iter()
+            .map(|function| function_label(function, &ctx.project_path))
+            .collect();
+        let target = extraction_target(&clone.functions, &ctx.project_path);
+
+        let locations = clone
+            .functions
+            .iter()
+            .zip(&clone.occurrences)
+            .enumerate()
+            .filter_map(|(index, (function, inside))| {
+                let first = inside.first()?;
+                let last = inside.iter().max_by_key(|occ| occ.end_line)?;
+                let others: Vec<&str> = labels
+                    .iter()
+                    .enumerate()
+                    .filter(|&(other, _)| other != index)
+                    .map(|(_, label)| label.as_str())
+                    .collect();
+                let description = format!(
+                    "Function '{}' (lines {}-{}) is {}% similar to: {}. Suggested extraction target: {target}",
+                    function.name,
+                    function.range.start_line,
+                    function.range.end_line,
+                    clone.similarity,
+                    others.join(", ")
+                );
+                let range = CodeRange {
+                    start_line: first.start_line,
+                    start_column: first.start_column,
+                    end_line: last.end_line,
+                    end_column: last.end_column,
+                };
+                Some(
+                    LocationDetail::new(function.file.clone(), first.start_line, description)
+                        .with_range(range),
+                )
+            })
+            .collect();
+
+        ArchSmell::new_code_clone(
+            locations,
+            clone.token_count(),
+            format_hash(&clone.clusters[0].hash),
+            clone.similarity,
+            clone.functions.iter().map(|f| f.name.clone()).collect(),
+            Some(target),
+        )
+    }
+
+    /// One location per function with the same fingerprint, spanning the whole function.
+    fn report_fingerprint_clone(
+        &self,
+        ctx: &AnalysisContext,
+        clone: &FingerprintClone,
+    ) -> ArchSmell {
+        let labels: Vec<String> = clone
+            .functions
+            .iter()
+            .map(|function| function_label(function, &ctx.project_path))
+            .collect();
+        let target = extraction_target(&clone.functions, &ctx.project_path);
+
+        let locations = clone
+            .functions
+            .iter()
+            .enumerate()
+            .map(|(index, function)| {
+                let others: Vec<&str> = labels
+                    .iter()
+                    .enumerate()
+                    .filter(|&(other, _)| other != index)
+                    .map(|(_, label)| label.as_str())
+                    .collect();
+                let description = format!(
+                    "Function '{}' (lines {}-{}) has the same structure as: {}. Suggested extraction target: {target}",
+                    function.name,
+                    function.range.start_line,
+                    function.range.end_line,
+                    others.join(", ")
+                );
+                LocationDetail::new(function.file.clone(), function.range.start_line, description)
+                    .with_range(function.range)
+            })
+            .collect();
+
+        ArchSmell::new_code_clone(
+            locations,
+            clone.token_count,
+            format!("{:016x}", clone.hash),
+            100,
+            clone.functions.iter().map(|f| f.name.clone()).collect(),
+            Some(target),
+        )
+    }
+
     /// Formats the "Also found in" part of the smell description.
     fn format_other_refs(
         &self,
@@ -117,29 +266,68 @@ impl CodeCloneDetector {
 
 impl Detector for CodeCloneDetector {
     crate::impl_detector_report!(
-        explain: _smell => (
-            problem: "Code Clone",
-            reason: "Identical or near-identical code blocks found in multiple locations. This violates the DRY (Don't Repeat Yourself) principle.",
-            risks: [
-                "Increased maintenance effort",
-                "Bugs must be fixed in multiple places"
-            ],
-            recommendations: [
-                "Extract the duplicated code into a shared function, class, or module"
-            ]
-        ),
+        explain: smell => {
+            let (functions, target) = match &smell.smell_type {
+                crate::detectors::SmellType::CodeClone { functions, extraction_target, .. } => {
+                    (functions.as_slice(), extraction_target.as_deref())
+                }
+                _ => (&[][..], None),
+            };
+            let destination = target.map(|t| format!(" in `{t}`")).unwrap_or_default();
+            let similarity = smell.similarity().unwrap_or(100);
+            let names = functions
+                .iter()
+                .map(|name| format!("`{name}`"))
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            let (problem, recommendation) = match (functions.is_empty(), similarity) {
+                (false, 100) => (
+                    format!("{} functions are identical", functions.len()),
+                    format!("Keep a single implementation of {names}{destination} and import it where the copies are"),
+                ),
+                (false, _) => (
+                    format!("{} functions are {similarity}% similar", functions.len()),
+                    format!("Extract the shared logic of {names} into one function{destination}, passing the parts that differ as parameters or callbacks"),
+                ),
+                (true, 100) => (
+                    "Code Clone".to_string(),
+                    "Extract the duplicated code into a shared function, class, or module".to_string(),
+                ),
+                (true, _) => (
+                    format!("Near-miss code clone ({similarity}% similar)"),
+                    "Extract the duplicated block into a function, with parameters for the statements that differ".to_string(),
+                ),
+            };
+
+            Explanation {
+                problem,
+                reason: "Identical or near-identical code blocks found in multiple locations. This violates the DRY (Don't Repeat Yourself) principle.".to_string(),
+                risks: crate::strings![
+                    "Increased maintenance effort",
+                    "Bugs must be fixed in multiple places"
+                ],
+                recommendations: vec![recommendation],
+            }
+        },
         table: {
             title: "Code Clones",
-            columns: ["Clone Info", "pts"],
-            row: CodeClone { clone_hash, token_count } (smell, location, pts) => [
-                format!("Clone `{}` ({} tokens)", &clone_hash[..clone_hash.len().min(8)], token_count),
+            columns: ["Clone Info", "Similarity", "pts"],
+            row: CodeClone { clone_hash, token_count, functions } (smell, location, pts) => [
+                if functions.is_empty() {
+                    format!("Clone `{}` ({} tokens)", &clone_hash[..clone_hash.len().min(8)], token_count)
+                } else {
+                    format!("Functions {} ({} tokens)", functions.join(", "), token_count)
+                },
+                format!("{}%", smell.similarity().unwrap_or(100)),
                 pts
             ]
         }
     );
 
     fn detect(&self, ctx: &AnalysisContext) -> Vec<ArchSmell> {
-        let (min_tokens, min_lines, max_bucket_size) = self.resolve_config(ctx);
+        let (min_tokens, min_lines, max_bucket_size, near_miss, ast_fingerprints) =
+            self.resolve_config(ctx);
 
         let file_tokens = tokenize_files(ctx, min_tokens);
         if file_tokens.is_empty() {
@@ -153,9 +341,45 @@ impl Detector for CodeCloneDetector {
             min_tokens,
             min_lines,
             max_bucket_size,
+            // Without gaps, clones are exact
+            (near_miss.max_gap > 0).then_some(near_miss),
         );
 
-        self.report_smells(ctx, clusters)
+        let fingerprint_clones = if ast_fingerprints {
+            group_by_fingerprint(fingerprint_files(
+                &file_tokens,
+                &ctx.function_complexity,
+                min_tokens,
+                min_lines,
+            ))
+        } else {
+            Vec::new()
+        };
+
+        self.report_smells(
+            ctx,
+            clusters,
+            fingerprint_clones,
+            &file_tokens,
+            near_miss.min_similarity,
+        )
+    }
+}
+
+fn format_hash(hash: &[u8; 32]) -> String {
+    hash.iter().fold(String::new(), |mut acc, b| {
+        use std::fmt::Write;
+        let _ = write!(acc, "{b:02x}");
+        acc
+    })
+}
+
+const fn occurrence_range(occ: &types::Occurrence) -> CodeRange {
+    CodeRange {
+        start_line: occ.start_line,
+        start_column: occ.start_column,
+        end_line: occ.end_line,
+        end_column: occ.end_column,
     }
 }
 
@@ -58,9 +58,54 @@ pub struct Cluster {
     pub hash: [u8; 32],
     /// Number of tokens in the duplicated block.
     pub token_count: usize,
+    /// Lowest similarity between two occurrences, in percent.
+    pub similarity: usize,
     /// All detected occurrences of this duplicate.
     pub occurrences: Vec<Occurrence>,
 }
 
+/// Settings for near-miss (Type-3) clones, whose copies differ by a few
+/// inserted, deleted or changed tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NearMiss {
+    /// Maximum number of differing tokens skipped at once in each copy.
+    pub max_gap: usize,
+    /// Minimum similarity of two copies, in percent.
+    pub min_similarity: usize,
+}
+
+/// Two matching token ranges, possibly of different lengths when gaps were skipped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Alignment {
+    pub start1: usize,
+    pub start2: usize,
+    pub len1: usize,
+    pub len2: usize,
+    /// Number of tokens matched in each range.
+    pub matched: usize,
+}
+
+impl Alignment {
+    #[must_use]
+    pub const fn exact(start1: usize, start2: usize, length: usize) -> Self {
+        Self {
+            start1,
+            start2,
+            len1: length,
+            len2: length,
+            matched: length,
+        }
+    }
+
+    /// Share of matched tokens in both ranges, in percent.
+    #[must_use]
+    pub const fn similarity(&self) -> usize {
+        if self.len1 + self.len2 == 0 {
+            return 100;
+        }
+        self.matched * 200 / (self.len1 + self.len2)
+    }
+}
+
 /// Type alias for a entry in the window map: (Token Hash, Vec<(File, Offset)>).
 pub type WindowEntry = ([u8; 32], Vec<(PathBuf, usize)>);
@@ -0,0 +1,98 @@
+use crate::detectors::{detector, ArchSmell, Detector, LocationDetail};
+use crate::engine::AnalysisContext;
+use crate::injection_graph::InjectionGraph;
+use crate::parser::InjectionKind;
+
+/// Initializes the detector module.
+/// This function is used for module registration side-effects.
+pub const fn init() {}
+
+#[detector(SmellType::CircularModuleImport, default_enabled = false)]
+pub struct CircularModuleImportsDetector;
+
+impl CircularModuleImportsDetector {
+    #[must_use]
+    pub const fn new_default(_config: &crate::config::Config) -> Self {
+        Self
+    }
+
+    fn cycle_smells(graph: &InjectionGraph) -> Vec<ArchSmell> {
+        graph
+            .module_cycles()
+            .into_iter()
+            .map(|cycle| {
+                let mut locations = Vec::new();
+                for &module in &cycle {
+                    let node = graph.get(module);
+                    let Some(file) = &node.file else {
+                        continue;
+                    };
+                    for (imported, line) in graph.targets(module, InjectionKind::Import) {
+                        if cycle.contains(&imported) {
+                            locations.push(LocationDetail::new(
+                                file.clone(),
+                                line,
+                                format!("'{}' imports '{}'", node.name, graph.get(imported).name),
+                            ));
+                        }
+                    }
+                }
+                let modules = cycle.iter().map(|&m| graph.get(m).name.clone()).collect();
+                ArchSmell::new_circular_module_import(modules, locations)
+            })
+            .collect()
+    }
+}
+
+impl Detector for CircularModuleImportsDetector {
+    crate::impl_detector_report!(
+        explain: smell => (
+            problem: {
+                if let crate::detectors::SmellType::CircularModuleImport { modules } = &smell.smell_type {
+                    format!("Modules import each other: {}", modules.join(" ↔ "))
+                } else {
+                    "Circular module import detected".to_string()
+                }
+            },
+            reason: "The modules list each other in their `imports` metadata. The container can only resolve them with `forwardRef`, and none of them can be loaded, tested or extracted without the others.",
+            risks: [
+                "Module initialization order becomes fragile and fails with undefined providers",
+                "The modules cannot be reused or tested in isolation"
+            ],
+            recommendations: [
+                "Move the shared providers into a separate module that both import",
+                "Invert the dependency with an event or an interface token provided by the lower module"
+            ]
+        ),
+        table: {
+            title: "Circular Module Imports",
+            columns: ["Location", "Modules", "pts"],
+            row: CircularModuleImport { modules } (smell, location, pts) => [
+                location,
+                modules.join(" ↔ "),
+                pts
+            ]
+        }
+    );
+
+    fn detect(&self, ctx: &AnalysisContext) -> Vec<ArchSmell> {
+        let Some(rule) = ctx.get_rule("circular_module_imports") else {
+            return Vec::new();
+        };
+
+        let graph = InjectionGraph::build(ctx.file_symbols.as_ref());
+        Self::cycle_smells(&graph)
+            .into_iter()
+            .filter(|smell| {
+                smell.files.iter().any(|f| {
+                    ctx.get_rule_for_file("circular_module_imports", f)
+                        .is_some()
+                })
+            })
+            .map(|mut smell| {
+                smell.severity = rule.severity;
+                smell
+            })
+            .collect()
+    }
+}
@@ -5,6 +5,7 @@ use crate::detectors::{
 use crate::engine::AnalysisContext;
 use crate::explain::ExplainEngine;
 use crate::graph::DependencyGraph;
+use crate::symbol_graph::SymbolGraph;
 use petgraph::algo::tarjan_scc;
 use petgraph::graph::NodeIndex;
 use std::collections::{HashMap, HashSet};
@@ -319,6 +320,37 @@ impl CycleDetector {
             .collect()
     }
 
+    /// Files of each cycle between exported symbols that spans several files.
+    fn symbol_cycle_files(ctx: &AnalysisContext) -> Vec<HashSet<PathBuf>> {
+        let symbols = SymbolGraph::build(ctx.file_symbols.as_ref());
+        symbols
+            .cycles()
+            .into_iter()
+            .map(|cycle| {
+                cycle
+                    .into_iter()
+                    .map(|id| symbols.symbol(id).file.clone())
+                    .collect::<HashSet<_>>()
+            })
+            .filter(|files| files.len() > 1)
+            .collect()
+    }
+
+    /// Whether a file-level cycle contains an actual cycle between symbols.
+    fn contains_symbol_cycle(
+        graph: &DependencyGraph,
+        scc: &[NodeIndex],
+        symbol_cycles: &[HashSet<PathBuf>],
+    ) -> bool {
+        let files: HashSet<&PathBuf> = scc
+            .iter()
+            .filter_map(|&node| graph.get_file_path(node))
+            .collect();
+        symbol_cycles
+            .iter()
+            .any(|cycle| cycle.iter().all(|file| files.contains(file)))
+    }
+
     fn is_false_positive_scc(graph: &DependencyGraph, scc: &[NodeIndex]) -> bool {
         scc.iter().any(|&node| {
             if let Some(path) = graph.get_file_path(node) {
@@ -416,6 +448,11 @@ impl Detector for CycleDetector {
 
     fn detect(&self, ctx: &AnalysisContext) -> Vec<ArchSmell> {
         let sccs = tarjan_scc(ctx.graph.graph());
+        let symbol_cycles = ctx
+            .resolve_rule("cyclic_dependency", None)
+            .get_option("symbol_level")
+            .unwrap_or(false)
+            .then(|| Self::symbol_cycle_files(ctx));
 
         let cycle_sccs: Vec<_> = sccs
             .into_iter()
@@ -430,6 +467,11 @@ impl Detector for CycleDetector {
                     }
                 })
             })
+            .filter(|scc| {
+                symbol_cycles.as_ref().is_none_or(|cycles| {
+                    Self::contains_symbol_cycle(ctx.graph.as_ref(), scc, cycles)
+                })
+            })
             .collect();
 
         cycle_sccs
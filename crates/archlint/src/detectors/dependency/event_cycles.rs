@@ -0,0 +1,108 @@
+use crate::detectors::{detector, ArchSmell, Detector, LocationDetail};
+use crate::engine::AnalysisContext;
+use crate::event_graph::{EventCycle, EventGraph};
+
+/// Initializes the detector module.
+/// This function is used for module registration side-effects.
+pub const fn init() {}
+
+#[detector(SmellType::EventCycle, default_enabled = false)]
+pub struct EventCyclesDetector;
+
+impl EventCyclesDetector {
+    #[must_use]
+    pub const fn new_default(_config: &crate::config::Config) -> Self {
+        Self
+    }
+
+    /// One location per producing call of an event edge inside the cycle.
+    fn cycle_smell(graph: &EventGraph, cycle: EventCycle) -> ArchSmell {
+        let mut keys: Vec<String> = Vec::new();
+        let mut locations = Vec::new();
+        for edge in &cycle.edges {
+            if !keys.contains(&edge.key) {
+                keys.push(edge.key.clone());
+            }
+            let consumer = edge
+                .to
+                .file_name()
+                .map_or_else(|| edge.to.to_string_lossy(), |name| name.to_string_lossy());
+            for site in graph
+                .producers(&edge.key)
+                .iter()
+                .filter(|site| site.file == edge.from)
+            {
+                locations.push(LocationDetail::new(
+                    site.file.clone(),
+                    site.line,
+                    format!("Emits '{}' consumed by {consumer}", edge.key),
+                ));
+            }
+        }
+        keys.sort();
+        locations.dedup();
+
+        ArchSmell::new_event_cycle(cycle.files, keys, locations)
+    }
+}
+
+impl Detector for EventCyclesDetector {
+    crate::impl_detector_report!(
+        explain: smell => (
+            problem: {
+                if let crate::detectors::SmellType::EventCycle { keys } = &smell.smell_type {
+                    format!(
+                        "{} files depend on each other through imports and the events {}",
+                        smell.files.len(),
+                        keys.join(", ")
+                    )
+                } else {
+                    "Event cycle detected".to_string()
+                }
+            },
+            reason: "A file reacts to an event whose producer depends on it, directly or through other imports. The cycle is invisible in the import graph, but a change on either side can trigger the other at runtime, including infinite event loops.",
+            risks: [
+                "Re-entrant handlers and event storms that are hard to reproduce",
+                "Modules that look independent cannot be changed or tested in isolation"
+            ],
+            recommendations: [
+                "Let the lower module expose a callback or return value instead of emitting back",
+                "Move the handler into a module that the producer does not depend on",
+                "Document the event flow and break the loop with a distinct event name"
+            ]
+        ),
+        table: {
+            title: "Event Cycles",
+            columns: ["Location", "Files", "Events", "pts"],
+            row: EventCycle { keys } (smell, location, pts) => [
+                location,
+                smell.files.len(),
+                keys.join(", "),
+                pts
+            ]
+        }
+    );
+
+    fn detect(&self, ctx: &AnalysisContext) -> Vec<ArchSmell> {
+        let Some(rule) = ctx.get_rule("event_cycles") else {
+            return Vec::new();
+        };
+
+        let graph = EventGraph::build(ctx.file_symbols.as_ref(), &ctx.config.events);
+        graph
+            .cycles(&ctx.graph)
+            .into_iter()
+            .filter(|cycle| {
+                cycle
+                    .files
+                    .iter()
+                    .any(|f| ctx.get_rule_for_file("event_cycles", f).is_some())
+            })
+            .map(|cycle| {
+                let mut smell = Self::cycle_smell(&graph, cycle);
+                smell.severity = rule.severity;
+                smell
+            })
+            .collect()
+    }
+}
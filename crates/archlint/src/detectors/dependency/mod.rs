@@ -1,19 +1,29 @@
+pub mod circular_module_imports;
 pub mod circular_type_deps;
 pub mod cycles;
+pub mod event_cycles;
 pub mod high_coupling;
 pub mod hub_dependency;
 pub mod hub_module;
 pub mod layer_violation;
+pub mod npm_dependencies;
 pub mod package_cycle;
+pub mod provider_outside_module;
+pub mod server_code_in_client;
 pub mod vendor_coupling;
 
 pub const fn init() {
+    circular_module_imports::init();
     circular_type_deps::init();
     cycles::init();
+    event_cycles::init();
     high_coupling::init();
     hub_dependency::init();
     hub_module::init();
     layer_violation::init();
+    npm_dependencies::init();
     package_cycle::init();
+    provider_outside_module::init();
+    server_code_in_client::init();
     vendor_coupling::init();
 }
@@ -0,0 +1,596 @@
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use regex::Regex;
+use serde_json::Value;
+
+use crate::detectors::hygiene::test_leakage::TestLeakageDetector;
+use crate::detectors::{detector, ArchSmell, DependencyIssue, Detector, LocationDetail};
+use crate::engine::AnalysisContext;
+use crate::package_json::{PackageJsonParser, PackageManifest};
+use crate::parser::ImportedSymbol;
+use crate::utils::package::PackageUtils;
+
+/// Initializes the detector module.
+/// This function is used for module registration side-effects.
+pub const fn init() {}
+
+const RULE_ID: &str = "npm_dependencies";
+
+/// Config files whose string literals may reference packages (plugins, presets, parsers).
+const CONFIG_FILE_PREFIXES: &[&str] = &[
+    ".eslintrc",
+    "eslint.config.",
+    ".prettierrc",
+    "prettier.config.",
+    ".babelrc",
+    "babel.config.",
+    ".stylelintrc",
+    "stylelint.config.",
+    ".commitlintrc",
+    "commitlint.config.",
+    ".lintstagedrc",
+    "lint-staged.config.",
+    ".mocharc",
+    ".swcrc",
+    "jest.config.",
+    "vitest.config.",
+    "vite.config.",
+    "postcss.config.",
+    "tailwind.config.",
+    "webpack.config.",
+    "rollup.config.",
+    "playwright.config.",
+    "cypress.config.",
+    "tsconfig",
+];
+
+/// `package.json` fields that hold tool configuration.
+const MANIFEST_CONFIG_FIELDS: &[&str] = &[
+    "eslintConfig",
+    "prettier",
+    "babel",
+    "jest",
+    "stylelint",
+    "commitlint",
+    "lint-staged",
+    "mocha",
+];
+
+/// Well-known binaries whose name differs from the package providing them.
+/// Used when `node_modules` is not installed.
+const KNOWN_BINARIES: &[(&str, &str)] = &[
+    ("tsc", "typescript"),
+    ("tsserver", "typescript"),
+    ("ng", "@angular/cli"),
+    ("nest", "@nestjs/cli"),
+    ("vue-cli-service", "@vue/cli-service"),
+    ("sb", "storybook"),
+    ("storybook", "storybook"),
+    ("playwright", "@playwright/test"),
+    ("changeset", "@changesets/cli"),
+    ("biome", "@biomejs/biome"),
+    ("swc", "@swc/cli"),
+    ("tsx", "tsx"),
+];
+
+#[detector(SmellType::NpmDependency, default_enabled = false)]
+pub struct NpmDependenciesDetector;
+
+/// Per-manifest usage collected from imports, scripts and config files.
+#[derive(Default)]
+struct ManifestUsage {
+    used: HashSet<String>,
+    missing: BTreeMap<String, Vec<LocationDetail>>,
+    dev_in_runtime: BTreeMap<String, Vec<LocationDetail>>,
+}
+
+struct Options {
+    ignore_packages: Vec<String>,
+    dev_file_patterns: Vec<glob::Pattern>,
+    allow_root_dependencies: bool,
+}
+
+impl NpmDependenciesDetector {
+    #[must_use]
+    pub const fn new_default(_config: &crate::config::Config) -> Self {
+        Self
+    }
+
+    fn load_options(ctx: &AnalysisContext) -> Option<Options> {
+        let rule = ctx.get_rule(RULE_ID)?;
+
+        let ignore_packages: Vec<String> = rule.get_option("ignore_packages").unwrap_or_default();
+        let dev_file_patterns: Vec<String> =
+            rule.get_option("dev_file_patterns").unwrap_or_else(|| {
+                vec![
+                    "**/*.config.*".to_string(),
+                    "**/*.stories.*".to_string(),
+                    "**/scripts/**".to_string(),
+                    "**/e2e/**".to_string(),
+                ]
+            });
+
+        Some(Options {
+            ignore_packages,
+            dev_file_patterns: dev_file_patterns
+                .iter()
+                .filter_map(|p| glob::Pattern::new(p).ok())
+                .collect(),
+            allow_root_dependencies: rule.get_option("allow_root_dependencies").unwrap_or(true),
+        })
+    }
+
+    /// Finds the manifest of the closest enclosing workspace package.
+    fn owner_of(manifests: &[PackageManifest], file: &Path) -> Option<usize> {
+        manifests
+            .iter()
+            .enumerate()
+            .filter(|(_, m)| file.starts_with(&m.dir))
+            .max_by_key(|(_, m)| m.dir.components().count())
+            .map(|(idx, _)| idx)
+    }
+
+    /// Files that never ship to production: tests, config files, stories, scripts.
+    fn is_dev_file(ctx: &AnalysisContext, path: &Path, options: &Options) -> bool {
+        if TestLeakageDetector.is_test_file(path, None) {
+            return true;
+        }
+        let rel = path.strip_prefix(&ctx.project_path).unwrap_or(path);
+        options
+            .dev_file_patterns
+            .iter()
+            .any(|pattern| pattern.matches_path(rel))
+    }
+
+    /// Returns the package name an import refers to, or `None` for local,
+    /// aliased, builtin and virtual modules.
+    fn imported_package(ctx: &AnalysisContext, import: &ImportedSymbol) -> Option<String> {
+        let source = import.source.as_str();
+        if !PackageUtils::is_external_package(source)
+            || source.starts_with('#')
+            || source.starts_with('~')
+            || Path::new(source).is_absolute()
+        {
+            return None;
+        }
+
+        let is_alias = ctx.config.aliases.keys().any(|alias| {
+            let prefix = alias.trim_end_matches('*');
+            !prefix.is_empty() && source.starts_with(prefix)
+        });
+        if is_alias {
+            return None;
+        }
+
+        let package = PackageUtils::extract_package_name(source);
+        if PackageUtils::is_builtin_package(&package) || package.contains(':') {
+            return None;
+        }
+        Some(package)
+    }
+
+    /// Name of the workspace package, other than `owner`, whose files `import` was resolved to.
+    fn workspace_package_of(
+        manifests: &[PackageManifest],
+        owner: usize,
+        import: &ImportedSymbol,
+    ) -> Option<String> {
+        let source = Path::new(import.source.as_str());
+        if !source.is_absolute() {
+            return None;
+        }
+        let target = Self::owner_of(manifests, source).filter(|&target| target != owner)?;
+        manifests[target].name.clone()
+    }
+
+    /// Marks a package as used by the owning manifest, falling back to the
+    /// root manifest for hoisted workspace dependencies.
+    fn mark_used(
+        manifests: &[PackageManifest],
+        usage: &mut [ManifestUsage],
+        owner: usize,
+        root: Option<usize>,
+        package: &str,
+    ) -> bool {
+        if manifests[owner].declares(package) {
+            usage[owner].used.insert(package.to_string());
+            return true;
+        }
+        if let Some(root) = root {
+            if manifests[root].declares(package) {
+                usage[root].used.insert(package.to_string());
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Records an import of `package` by a file of `owner`: a use when a
+    /// manifest declares it, a missing dependency otherwise, and a dev-only
+    /// dependency when runtime code imports a package declared only for development.
+    fn record_import(
+        manifests: &[PackageManifest],
+        usage: &mut [ManifestUsage],
+        owner: usize,
+        root: Option<usize>,
+        package: String,
+        location: LocationDetail,
+        is_runtime: bool,
+    ) {
+        if !Self::mark_used(manifests, usage, owner, root, &package) {
+            usage[owner]
+                .missing
+                .entry(package)
+                .or_default()
+                .push(location);
+        } else if is_runtime
+            && manifests[owner].dev_dependencies.contains_key(&package)
+            && !manifests[owner].declares_runtime(&package)
+        {
+            usage[owner]
+                .dev_in_runtime
+                .entry(package)
+                .or_default()
+                .push(location);
+        }
+    }
+
+    fn collect_import_usage(
+        ctx: &AnalysisContext,
+        manifests: &[PackageManifest],
+        usage: &mut [ManifestUsage],
+        root: Option<usize>,
+        options: &Options,
+    ) {
+        let root = root.filter(|_| options.allow_root_dependencies);
+        let mut files: Vec<&PathBuf> = ctx.file_symbols.keys().collect();
+        files.sort();
+
+        for file in files {
+            if ctx.get_rule_for_file(RULE_ID, file).is_none() {
+                continue;
+            }
+            let Some(owner) = Self::owner_of(manifests, file) else {
+                continue;
+            };
+            let is_dev_file = Self::is_dev_file(ctx, file, options);
+            let symbols = &ctx.file_symbols[file];
+
+            for import in &symbols.imports {
+                let location = LocationDetail::new(
+                    file.clone(),
+                    import.line,
+                    format!("import from '{}'", import.source),
+                )
+                .with_range(import.range);
+                let is_runtime = !is_dev_file && !import.is_type_only;
+
+                // Workspace packages resolved through their `exports` point at
+                // files of another package
+                if let Some(package) = Self::workspace_package_of(manifests, owner, import) {
+                    if !PackageUtils::should_ignore_package(&package, &options.ignore_packages) {
+                        Self::record_import(
+                            manifests, usage, owner, root, package, location, is_runtime,
+                        );
+                    }
+                    continue;
+                }
+                let Some(package) = Self::imported_package(ctx, import) else {
+                    continue;
+                };
+                if manifests[owner].name.as_deref() == Some(package.as_str())
+                    || PackageUtils::should_ignore_package(&package, &options.ignore_packages)
+                {
+                    continue;
+                }
+
+                // Importing `foo` also covers its `@types/foo` companion
+                Self::mark_used(
+                    manifests,
+                    usage,
+                    owner,
+                    root,
+                    &Self::types_package_for(&package),
+                );
+                Self::record_import(manifests, usage, owner, root, package, location, is_runtime);
+            }
+        }
+    }
+
+    fn types_package_for(package: &str) -> String {
+        match package.strip_prefix('@') {
+            Some(scoped) => format!("@types/{}", scoped.replacen('/', "__", 1)),
+            None => format!("@types/{package}"),
+        }
+    }
+
+    /// Binaries provided by a package, read from its installed manifest when available.
+    fn binaries_of(manifest_dir: &Path, package: &str) -> Vec<String> {
+        let mut bins = vec![package.rsplit('/').next().unwrap_or(package).to_string()];
+        bins.extend(
+            KNOWN_BINARIES
+                .iter()
+                .filter(|(_, pkg)| *pkg == package)
+                .map(|(bin, _)| (*bin).to_string()),
+        );
+
+        let installed = manifest_dir
+            .ancestors()
+            .map(|dir| dir.join("node_modules").join(package).join("package.json"))
+            .find(|p| p.is_file());
+        let bin_field = installed
+            .and_then(|p| fs::read_to_string(p).ok())
+            .and_then(|content| serde_json::from_str::<Value>(&content).ok())
+            .and_then(|json| json.get("bin").cloned());
+
+        // A string `bin` is exposed under the package name, which is already included
+        if let Some(Value::Object(map)) = bin_field {
+            bins.extend(map.keys().cloned());
+        }
+        bins
+    }
+
+    fn collect_script_usage(
+        manifests: &[PackageManifest],
+        usage: &mut [ManifestUsage],
+        root: Option<usize>,
+    ) {
+        for (idx, manifest) in manifests.iter().enumerate() {
+            if manifest.scripts.is_empty() {
+                continue;
+            }
+
+            let tokens: HashSet<&str> = manifest
+                .scripts
+                .values()
+                .flat_map(|script| script.split(|c: char| c.is_whitespace() || "&|;()".contains(c)))
+                .map(|token| token.trim_matches(|c| c == '"' || c == '\''))
+                .filter(|token| !token.is_empty())
+                .collect();
+
+            // Binaries of hoisted root dependencies are available to every workspace package
+            let candidates: HashSet<&String> = manifest
+                .declared_packages()
+                .chain(
+                    root.into_iter()
+                        .flat_map(|r| manifests[r].declared_packages()),
+                )
+                .collect();
+
+            for package in candidates {
+                let referenced = tokens.iter().any(|token| {
+                    PackageUtils::is_external_package(token)
+                        && PackageUtils::extract_package_name(token) == *package
+                }) || Self::binaries_of(&manifest.dir, package)
+                    .iter()
+                    .any(|bin| tokens.contains(bin.as_str()));
+
+                if referenced {
+                    Self::mark_used(manifests, usage, idx, root, package);
+                }
+            }
+        }
+    }
+
+    /// Candidate package names referenced by a config string such as an
+    /// eslint `plugins` entry or a babel preset shorthand.
+    fn config_references(value: &str) -> Vec<String> {
+        let value = value.strip_prefix("plugin:").unwrap_or(value);
+        if value.is_empty() || !PackageUtils::is_external_package(value) {
+            return Vec::new();
+        }
+
+        let base = PackageUtils::extract_package_name(value);
+        let mut candidates = vec![base.clone()];
+
+        if let Some((scope, name)) = base.split_once('/') {
+            for kind in ["eslint-plugin", "eslint-config"] {
+                candidates.push(format!("{scope}/{kind}-{name}"));
+            }
+        } else if base.starts_with('@') {
+            for kind in ["eslint-plugin", "eslint-config"] {
+                candidates.push(format!("{base}/{kind}"));
+            }
+        } else {
+            for kind in [
+                "eslint-plugin",
+                "eslint-config",
+                "prettier-plugin",
+                "babel-plugin",
+                "babel-preset",
+            ] {
+                candidates.push(format!("{kind}-{base}"));
+            }
+            candidates.push(format!("@babel/preset-{base}"));
+            candidates.push(format!("@babel/plugin-{base}"));
+        }
+
+        candidates
+    }
+
+    fn collect_json_strings(value: &Value, out: &mut Vec<String>) {
+        match value {
+            Value::String(s) => out.push(s.clone()),
+            Value::Array(arr) => arr.iter().for_each(|v| Self::collect_json_strings(v, out)),
+            Value::Object(map) => {
+                for (k, v) in map {
+                    out.push(k.clone());
+                    Self::collect_json_strings(v, out);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn collect_config_usage(manifests: &[PackageManifest], usage: &mut [ManifestUsage]) {
+        let Ok(string_literal) = Regex::new(r#"["'`]([^"'`\s]+)["'`]"#) else {
+            return;
+        };
+
+        for (idx, manifest) in manifests.iter().enumerate() {
+            let mut references = Vec::new();
+
+            if let Ok(json) = serde_json::from_str::<Value>(&manifest.raw) {
+                for field in MANIFEST_CONFIG_FIELDS {
+                    if let Some(value) = json.get(field) {
+                        Self::collect_json_strings(value, &mut references);
+                    }
+                }
+            }
+
+            let config_files = fs::read_dir(&manifest.dir)
+                .into_iter()
+                .flatten()
+                .flatten()
+                .map(|entry| entry.path())
+                .filter(|path| {
+                    path.is_file()
+                        && path
+                            .file_name()
+                            .and_then(|n| n.to_str())
+                            .is_some_and(|name| {
+                                CONFIG_FILE_PREFIXES.iter().any(|p| name.starts_with(p))
+                            })
+                });
+
+            for path in config_files {
+                if let Ok(content) = fs::read_to_string(&path) {
+                    references.extend(
+                        string_literal
+                            .captures_iter(&content)
+                            .map(|cap| cap[1].to_string()),
+                    );
+                }
+            }
+
+            let used: Vec<String> = references
+                .iter()
+                .flat_map(|r| Self::config_references(r))
+                .filter(|candidate| manifest.declares(candidate))
+                .collect();
+            usage[idx].used.extend(used);
+        }
+    }
+
+    fn build_smells(
+        manifests: &[PackageManifest],
+        usage: Vec<ManifestUsage>,
+        options: &Options,
+    ) -> Vec<ArchSmell> {
+        let mut smells = Vec::new();
+
+        for (manifest, usage) in manifests.iter().zip(usage) {
+            for (package, locations) in usage.missing {
+                smells.push(ArchSmell::new_npm_dependency(
+                    manifest.path.clone(),
+                    package,
+                    DependencyIssue::Missing,
+                    locations,
+                ));
+            }
+
+            for (package, locations) in usage.dev_in_runtime {
+                smells.push(ArchSmell::new_npm_dependency(
+                    manifest.path.clone(),
+                    package,
+                    DependencyIssue::DevOnlyInRuntime,
+                    locations,
+                ));
+            }
+
+            let mut unused: Vec<&String> = manifest
+                .declared_packages()
+                .filter(|package| {
+                    !usage.used.contains(*package)
+                        && !package.starts_with("@types/")
+                        && !PackageUtils::should_ignore_package(package, &options.ignore_packages)
+                })
+                .collect();
+            unused.sort();
+            unused.dedup();
+
+            for package in unused {
+                let location = LocationDetail::new(
+                    manifest.path.clone(),
+                    manifest.declaration_line(package),
+                    format!("'{package}' is declared but never used"),
+                );
+                smells.push(ArchSmell::new_npm_dependency(
+                    manifest.path.clone(),
+                    package.clone(),
+                    DependencyIssue::Unused,
+                    vec![location],
+                ));
+            }
+        }
+
+        smells
+    }
+}
+
+impl Detector for NpmDependenciesDetector {
+    crate::impl_detector_report!(
+        explain: smell => (
+            problem: {
+                match &smell.smell_type {
+                    crate::detectors::SmellType::NpmDependency { package, issue } => match issue {
+                        DependencyIssue::Unused => format!("Unused dependency `{package}`"),
+                        DependencyIssue::Missing => format!("Undeclared dependency `{package}`"),
+                        DependencyIssue::DevOnlyInRuntime => {
+                            format!("Dev-only dependency `{package}` used in runtime code")
+                        }
+                    },
+                    _ => "npm dependency issue".to_string(),
+                }
+            },
+            reason: "The package manifest does not match what the code actually imports. Unused dependencies slow installs and widen the attack surface; undeclared ones only work thanks to hoisting of transitive dependencies; dev-only ones are missing in production installs.",
+            risks: [
+                "Runtime crashes after `npm install --production` or a lockfile change",
+                "Bloated installs and unnecessary security advisories",
+                "Builds that break when a transitive dependency is dropped"
+            ],
+            recommendations: [
+                "Declare every imported package in the package that imports it",
+                "Move packages used by runtime code from devDependencies to dependencies",
+                "Remove dependencies that are no longer imported or referenced by scripts and configs"
+            ]
+        ),
+        table: {
+            title: "npm Dependency Issues",
+            columns: ["Package", "Issue", "Location", "pts"],
+            row: NpmDependency { package, issue } (smell, location, pts) => [
+                package,
+                issue,
+                location,
+                pts
+            ]
+        }
+    );
+
+    fn detect(&self, ctx: &AnalysisContext) -> Vec<ArchSmell> {
+        let Some(options) = Self::load_options(ctx) else {
+            return Vec::new();
+        };
+
+        let manifests = PackageJsonParser::find_manifests(&ctx.project_path);
+        if manifests.is_empty() {
+            return Vec::new();
+        }
+
+        let project_dir = ctx
+            .project_path
+            .canonicalize()
+            .unwrap_or_else(|_| ctx.project_path.clone());
+        let root = manifests.iter().position(|m| m.dir == project_dir);
+
+        let mut usage: Vec<ManifestUsage> =
+            manifests.iter().map(|_| ManifestUsage::default()).collect();
+        Self::collect_import_usage(ctx, &manifests, &mut usage, root, &options);
+        Self::collect_script_usage(&manifests, &mut usage, root);
+        Self::collect_config_usage(&manifests, &mut usage);
+
+        Self::build_smells(&manifests, usage, &options)
+    }
+}
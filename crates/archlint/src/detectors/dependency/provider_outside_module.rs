@@ -0,0 +1,94 @@
+use crate::detectors::{detector, ArchSmell, Detector, LocationDetail};
+use crate::engine::AnalysisContext;
+use crate::injection_graph::{InjectionGraph, ScopeViolation};
+
+/// Initializes the detector module.
+/// This function is used for module registration side-effects.
+pub const fn init() {}
+
+#[detector(SmellType::ProviderOutsideModule, default_enabled = false)]
+pub struct ProviderOutsideModuleDetector;
+
+impl ProviderOutsideModuleDetector {
+    #[must_use]
+    pub const fn new_default(_config: &crate::config::Config) -> Self {
+        Self
+    }
+
+    fn violation_smell(graph: &InjectionGraph, violation: &ScopeViolation) -> Option<ArchSmell> {
+        let consumer = graph.get(violation.consumer);
+        let provider = graph.get(violation.provider);
+        let module = graph.get(violation.consumer_modules[0]);
+
+        let mut smell = ArchSmell::new_provider_outside_module(
+            consumer.file.clone()?,
+            provider.name.clone(),
+            consumer.name.clone(),
+            module.name.clone(),
+            violation.line,
+        );
+        for &owner in &violation.owner_modules {
+            let owner = graph.get(owner);
+            if let Some(file) = &owner.file {
+                smell.locations.push(LocationDetail::new(
+                    file.clone(),
+                    owner.line,
+                    format!("'{}' is provided by '{}'", provider.name, owner.name),
+                ));
+            }
+        }
+        Some(smell)
+    }
+}
+
+impl Detector for ProviderOutsideModuleDetector {
+    crate::impl_detector_report!(
+        explain: smell => (
+            problem: {
+                if let crate::detectors::SmellType::ProviderOutsideModule { provider, consumer, module } = &smell.smell_type {
+                    format!("'{consumer}' injects '{provider}', which is not available in module '{module}'")
+                } else {
+                    "Provider used outside its module".to_string()
+                }
+            },
+            reason: "The provider is registered by another module that either does not export it or is not imported by the consumer's module. The injection only works by accident, for example through a module that happens to be loaded globally, or fails at startup.",
+            risks: [
+                "Dependency resolution fails as soon as the module is loaded on its own",
+                "Module boundaries no longer describe which services a feature depends on"
+            ],
+            recommendations: [
+                "Export the provider from its module and import that module where it is used",
+                "Move the provider to a shared module if several features need it"
+            ]
+        ),
+        table: {
+            title: "Providers Outside Their Module",
+            columns: ["Location", "Consumer", "Provider", "Module", "pts"],
+            row: ProviderOutsideModule { provider, consumer, module } (smell, location, pts) => [
+                location,
+                consumer,
+                provider,
+                module,
+                pts
+            ]
+        }
+    );
+
+    fn detect(&self, ctx: &AnalysisContext) -> Vec<ArchSmell> {
+        if ctx.get_rule("provider_outside_module").is_none() {
+            return Vec::new();
+        }
+
+        let graph = InjectionGraph::build(ctx.file_symbols.as_ref());
+        graph
+            .scope_violations()
+            .iter()
+            .filter_map(|violation| Self::violation_smell(&graph, violation))
+            .filter_map(|mut smell| {
+                let rule = ctx.get_rule_for_file("provider_outside_module", &smell.files[0])?;
+                smell.severity = rule.severity;
+                Some(smell)
+            })
+            .collect()
+    }
+}
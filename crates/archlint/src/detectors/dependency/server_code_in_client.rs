@@ -0,0 +1,239 @@
+use crate::detectors::{detector, ArchSmell, Detector};
+use crate::engine::AnalysisContext;
+use crate::parser::{FileDirective, FileSymbols};
+use crate::utils::package::PackageUtils;
+use petgraph::graph::NodeIndex;
+use std::collections::{HashMap, VecDeque};
+use std::path::{Path, PathBuf};
+
+/// Initializes the detector module.
+/// This function is used for module registration side-effects.
+pub const fn init() {}
+
+#[detector(SmellType::ServerCodeInClient, default_enabled = false)]
+pub struct ServerCodeInClientDetector;
+
+impl ServerCodeInClientDetector {
+    #[must_use]
+    pub const fn new_default(_config: &crate::config::Config) -> Self {
+        Self
+    }
+
+    /// Packages used for `server_only_packages` when the rule does not set them.
+    #[must_use]
+    pub fn default_server_only_packages() -> Vec<String> {
+        [
+            "server-only",
+            "next/headers",
+            "node:*",
+            "fs",
+            "fs/*",
+            "child_process",
+            "net",
+            "tls",
+            "dgram",
+            "cluster",
+            "worker_threads",
+            "@prisma/client",
+            "pg",
+            "mysql",
+            "mysql2",
+            "mysql2/*",
+            "mongodb",
+            "mongoose",
+            "redis",
+            "ioredis",
+            "better-sqlite3",
+            "sqlite3",
+            "knex",
+            "typeorm",
+            "sequelize",
+        ]
+        .into_iter()
+        .map(String::from)
+        .collect()
+    }
+
+    /// The first runtime import of a server-only package, with its line.
+    fn server_only_import(symbols: &FileSymbols, packages: &[String]) -> Option<(String, usize)> {
+        symbols
+            .imports
+            .iter()
+            .filter(|import| {
+                !import.is_type_only && PackageUtils::is_external_package(&import.source)
+            })
+            .find(|import| {
+                packages
+                    .iter()
+                    .any(|pattern| PackageUtils::matches_ignore_pattern(&import.source, pattern))
+            })
+            .map(|import| (import.source.to_string(), import.line))
+    }
+
+    /// Whether every import of `to` only brings in types, which are erased from the bundle.
+    fn is_type_only_import(symbols: &FileSymbols, to: &Path) -> bool {
+        let to = to.to_string_lossy();
+        let mut imports = symbols
+            .imports
+            .iter()
+            .filter(|import| import.source.as_str() == to)
+            .peekable();
+        imports.peek().is_some() && imports.all(|import| import.is_type_only)
+    }
+
+    /// Modules bundled together with `node`, ordered by path for stable chains.
+    fn client_dependencies(ctx: &AnalysisContext, node: NodeIndex) -> Vec<(NodeIndex, &PathBuf)> {
+        let Some(symbols) = ctx
+            .graph
+            .get_file_path(node)
+            .and_then(|path| ctx.file_symbols.get(path))
+        else {
+            return Vec::new();
+        };
+
+        let mut dependencies: Vec<(NodeIndex, &PathBuf)> = ctx
+            .graph
+            .dependencies(node)
+            .filter_map(|dep| Some((dep, ctx.graph.get_file_path(dep)?)))
+            .filter(|(_, path)| {
+                // Server actions are called over the network and other
+                // client entries are checked on their own
+                let directive = ctx.file_symbols.get(*path).and_then(|s| s.directive);
+                directive.is_none() && !Self::is_type_only_import(symbols, path)
+            })
+            .collect();
+        dependencies.sort_by(|a, b| a.1.cmp(b.1));
+        dependencies.dedup_by_key(|(dep, _)| *dep);
+        dependencies
+    }
+
+    /// Import chain from the client entry to `node`, each file with the line
+    /// importing the next one and the server-only module with `last_line`.
+    fn chain(
+        ctx: &AnalysisContext,
+        parents: &HashMap<NodeIndex, NodeIndex>,
+        node: NodeIndex,
+        last_line: usize,
+    ) -> Vec<(PathBuf, usize)> {
+        let mut chain = Vec::new();
+        let mut current = node;
+        let mut line = last_line;
+        loop {
+            if let Some(path) = ctx.graph.get_file_path(current) {
+                chain.push((path.clone(), line));
+            }
+            let Some(&parent) = parents.get(&current) else {
+                break;
+            };
+            line = ctx
+                .graph
+                .get_edge_data(parent, current)
+                .map_or(0, |edge| edge.import_line);
+            current = parent;
+        }
+        chain.reverse();
+        chain
+    }
+
+    /// Walk the modules bundled with a client entry breadth-first and report
+    /// the shortest chain to every server-only module.
+    fn check_entry(ctx: &AnalysisContext, entry: NodeIndex, packages: &[String]) -> Vec<ArchSmell> {
+        let mut smells = Vec::new();
+        let mut parents: HashMap<NodeIndex, NodeIndex> = HashMap::new();
+        let mut queue = VecDeque::from([entry]);
+
+        while let Some(node) = queue.pop_front() {
+            let server_import = ctx
+                .graph
+                .get_file_path(node)
+                .and_then(|path| ctx.file_symbols.get(path))
+                .and_then(|symbols| Self::server_only_import(symbols, packages));
+            if let Some((package, line)) = server_import {
+                let chain = Self::chain(ctx, &parents, node, line);
+                smells.push(ArchSmell::new_server_code_in_client(chain, package));
+                continue;
+            }
+
+            for (dep, _) in Self::client_dependencies(ctx, node) {
+                if dep != entry && !parents.contains_key(&dep) {
+                    parents.insert(dep, node);
+                    queue.push_back(dep);
+                }
+            }
+        }
+
+        smells
+    }
+}
+
+impl Detector for ServerCodeInClientDetector {
+    crate::impl_detector_report!(
+        explain: smell => (
+            problem: {
+                if let crate::detectors::SmellType::ServerCodeInClient { module, package } = &smell.smell_type {
+                    format!(
+                        "Client component reaches server-only module {} ('{package}') through {} import(s)",
+                        module.display(),
+                        smell.files.len().saturating_sub(1)
+                    )
+                } else {
+                    "Server code in client component".to_string()
+                }
+            },
+            reason: "A file marked with \"use client\" is bundled for the browser together with everything it imports. One of those modules uses a server-only package, so the build fails or server code, credentials and database clients end up in the client bundle.",
+            risks: [
+                "Build errors from `server-only` or Node.js modules in the browser bundle",
+                "Server secrets and internal logic shipped to the browser",
+                "Larger client bundles"
+            ],
+            recommendations: [
+                "Fetch the data in a Server Component and pass it to the client component as props",
+                "Expose the operation as a server action in a \"use server\" file",
+                "Split shared helpers so the client only imports the parts without server dependencies"
+            ]
+        ),
+        table: {
+            title: "Server Code in Client Components",
+            columns: ["Client Component", "Server-Only Module", "Package", "Chain", "pts"],
+            row: ServerCodeInClient { module, package } (smell, location, pts) => [
+                location,
+                module.to_string_lossy(),
+                package,
+                smell.files.len(),
+                pts
+            ]
+        }
+    );
+
+    fn detect(&self, ctx: &AnalysisContext) -> Vec<ArchSmell> {
+        if ctx.get_rule("server_code_in_client").is_none() {
+            return Vec::new();
+        }
+
+        let mut entries: Vec<&PathBuf> = ctx
+            .file_symbols
+            .iter()
+            .filter(|(_, symbols)| symbols.directive == Some(FileDirective::UseClient))
+            .map(|(path, _)| path)
+            .collect();
+        entries.sort();
+
+        entries
+            .into_iter()
+            .filter_map(|path| {
+                let rule = ctx.get_rule_for_file("server_code_in_client", path)?;
+                let entry = ctx.graph.get_node(path)?;
+                let packages: Vec<String> = rule
+                    .get_option("server_only_packages")
+                    .unwrap_or_else(Self::default_server_only_packages);
+
+                let mut smells = Self::check_entry(ctx, entry, &packages);
+                for smell in &mut smells {
+                    smell.severity = rule.severity;
+                }
+                Some(smells)
+            })
+            .flatten()
+            .collect()
+    }
+}
@@ -495,7 +495,7 @@ impl DeadCodeDetector {
                 || self.is_reexporter_imported(file_symbols, reexporter_path))
     }
 
-    fn matches_dynamic_load_pattern(&self, path: &Path) -> bool {
+    pub(crate) fn matches_dynamic_load_pattern(&self, path: &Path) -> bool {
         let path_str = path.to_string_lossy();
         for pattern in &self.dynamic_load_patterns {
             if self.matches_glob_pattern(&path_str, pattern) {
@@ -1,6 +1,7 @@
-use crate::detectors::{detector, ArchSmell, Detector};
+use crate::detectors::{detector, ArchSmell, Detector, LocationDetail};
 use crate::engine::AnalysisContext;
 use crate::parser::{FileSymbols, MethodAccessibility, SymbolKind};
+use crate::symbol_graph::{SymbolGraph, SymbolId};
 use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 
@@ -11,11 +12,12 @@ pub const fn init() {}
 #[detector(SmellType::DeadSymbol, is_deep = true)]
 pub struct DeadSymbolsDetector;
 
+/// Class hierarchy and star re-export relations across the project.
 #[derive(Default)]
-struct InheritanceContext {
-    parents: HashMap<(PathBuf, String), (PathBuf, String)>,
-    children: HashMap<(PathBuf, String), Vec<(PathBuf, String)>>,
-    reexports: HashMap<PathBuf, HashSet<PathBuf>>,
+pub(crate) struct InheritanceContext {
+    pub(crate) parents: HashMap<(PathBuf, String), (PathBuf, String)>,
+    pub(crate) children: HashMap<(PathBuf, String), Vec<(PathBuf, String)>>,
+    pub(crate) reexports: HashMap<PathBuf, HashSet<PathBuf>>,
 }
 
 struct MethodCheckContext<'a> {
@@ -53,17 +55,147 @@ impl DeadSymbolsDetector {
             entry_points,
             ctx,
         ));
-        all_smells.extend(Self::check_dead_exports(
+        let dead_exports = Self::check_dead_exports(
             file_symbols,
             entry_points,
             &symbol_usages,
             &all_project_usages,
-        ));
+        );
+        if ctx
+            .resolve_rule("dead_symbols", None)
+            .get_option("transitive")
+            .unwrap_or(false)
+        {
+            all_smells.extend(Self::check_transitively_dead_exports(
+                file_symbols,
+                entry_points,
+                &dead_exports,
+            ));
+        }
+        all_smells.extend(dead_exports);
+        if ctx
+            .resolve_rule("dead_symbols", None)
+            .get_option("local_exports")
+            .unwrap_or(false)
+        {
+            let local_exports =
+                Self::check_local_exports(file_symbols, entry_points, &symbol_usages);
+            let reported: HashSet<(PathBuf, String)> = all_smells
+                .iter()
+                .filter_map(Self::dead_symbol_key)
+                .collect();
+            all_smells.extend(local_exports.into_iter().filter(|smell| {
+                Self::dead_symbol_key(smell).is_none_or(|key| !reported.contains(&key))
+            }));
+        }
 
         all_smells
     }
 
-    fn build_inheritance_context(
+    fn dead_symbol_key(smell: &ArchSmell) -> Option<(PathBuf, String)> {
+        match (&smell.smell_type, smell.files.first()) {
+            (crate::detectors::SmellType::DeadSymbol { name, .. }, Some(file)) => {
+                Some((file.clone(), name.clone()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Exports no other file imports or mentions, but which their own file uses,
+    /// so only the `export` keyword is unused.
+    fn check_local_exports(
+        file_symbols: &HashMap<PathBuf, FileSymbols>,
+        entry_points: &HashSet<PathBuf>,
+        symbol_usages: &HashMap<(PathBuf, String), HashSet<PathBuf>>,
+    ) -> Vec<ArchSmell> {
+        let mut files_using: HashMap<&str, usize> = HashMap::new();
+        for symbols in file_symbols.values() {
+            for name in &symbols.local_usages {
+                *files_using.entry(name.as_str()).or_default() += 1;
+            }
+        }
+
+        let mut smells = Vec::new();
+        for (file_path, symbols) in file_symbols {
+            if entry_points.contains(file_path) {
+                continue;
+            }
+            for export in &symbols.exports {
+                let name = export.name.as_str();
+                if export.is_reexport
+                    || name == "default"
+                    || name == "*"
+                    || !symbols.local_usages.contains(name)
+                    || files_using.get(name).copied().unwrap_or(0) > 1
+                    || Self::is_symbol_imported(symbol_usages, file_path, name)
+                {
+                    continue;
+                }
+
+                let kind = format!("{} Export", Self::format_symbol_kind(&export.kind));
+                let mut smell = ArchSmell::new_dead_symbol_with_line(
+                    file_path.clone(),
+                    name.to_string(),
+                    kind,
+                    export.line,
+                );
+                if let Some(loc) = smell.locations.first_mut() {
+                    *loc = LocationDetail::new(
+                        file_path.clone(),
+                        export.line,
+                        format!("'{name}' is exported but only used in this file"),
+                    )
+                    .with_range(export.range);
+                }
+                smells.push(smell);
+            }
+        }
+        smells
+    }
+
+    /// Exports referenced only by dead exports, found through the symbol graph.
+    fn check_transitively_dead_exports(
+        file_symbols: &HashMap<PathBuf, FileSymbols>,
+        entry_points: &HashSet<PathBuf>,
+        dead_exports: &[ArchSmell],
+    ) -> Vec<ArchSmell> {
+        let graph = SymbolGraph::build(file_symbols);
+        let dead: HashSet<SymbolId> = dead_exports
+            .iter()
+            .filter_map(|smell| match (&smell.smell_type, smell.files.first()) {
+                (crate::detectors::SmellType::DeadSymbol { name, .. }, Some(file)) => {
+                    graph.find(file, name)
+                }
+                _ => None,
+            })
+            .collect();
+
+        graph
+            .propagate_dead(&dead, entry_points)
+            .into_iter()
+            .filter(|&id| graph.symbol(id).name != "default")
+            .map(|id| {
+                let symbol = graph.symbol(id);
+                let mut smell = ArchSmell::new_dead_symbol_with_line(
+                    symbol.file.clone(),
+                    symbol.name.clone(),
+                    Self::format_symbol_kind(&symbol.kind),
+                    symbol.line,
+                );
+                for user in graph.dependents(id) {
+                    let user = graph.symbol(user);
+                    smell.locations.push(LocationDetail::new(
+                        user.file.clone(),
+                        user.line,
+                        format!("only used by dead symbol '{}'", user.name),
+                    ));
+                }
+                smell
+            })
+            .collect()
+    }
+
+    pub(crate) fn build_inheritance_context(
         file_symbols: &HashMap<PathBuf, FileSymbols>,
     ) -> InheritanceContext {
         let mut ctx = InheritanceContext::default();
@@ -149,7 +281,7 @@ impl DeadSymbolsDetector {
             .collect()
     }
 
-    fn build_symbol_imports_map(
+    pub(crate) fn build_symbol_imports_map(
         file_symbols: &HashMap<PathBuf, FileSymbols>,
     ) -> HashMap<(PathBuf, String), HashSet<PathBuf>> {
         let mut symbol_usages: HashMap<(PathBuf, String), HashSet<PathBuf>> = HashMap::new();
@@ -421,7 +553,7 @@ impl DeadSymbolsDetector {
         }
     }
 
-    fn collect_class_importers(
+    pub(crate) fn collect_class_importers(
         file_path: &Path,
         class: &crate::parser::ClassSymbol,
         symbol_usages: &HashMap<(PathBuf, String), HashSet<PathBuf>>,
@@ -767,10 +899,19 @@ mod tests {
                     false,
                 )],
                 is_abstract: false,
+                field_symbols: vec![],
+                used_members: FxHashSet::default(),
+                line: 0,
+                range: CodeRange::default(),
+                loc: 0,
             }],
             imports: vec![],
             local_definitions: vec![],
             local_usages: FxHashSet::default(),
+            usages_outside_exports: FxHashSet::default(),
+            injectables: Vec::new(),
+            directive: None,
+            keyed_calls: Vec::new(),
             has_runtime_code: true,
             env_vars: FxHashSet::default(),
         };
@@ -797,6 +938,11 @@ mod tests {
                 fields: smallvec![],
                 methods: smallvec![],
                 is_abstract: false,
+                field_symbols: vec![],
+                used_members: FxHashSet::default(),
+                line: 0,
+                range: CodeRange::default(),
+                loc: 0,
             }],
             imports: vec![ImportedSymbol {
                 name: CompactString::new("*"),
@@ -811,6 +957,10 @@ mod tests {
             }],
             local_definitions: vec![],
             local_usages: FxHashSet::default(),
+            usages_outside_exports: FxHashSet::default(),
+            injectables: Vec::new(),
+            directive: None,
+            keyed_calls: Vec::new(),
             has_runtime_code: true,
             env_vars: FxHashSet::default(),
         };
@@ -836,6 +986,10 @@ mod tests {
             }],
             local_definitions: vec![],
             local_usages,
+            usages_outside_exports: FxHashSet::default(),
+            injectables: Vec::new(),
+            directive: None,
+            keyed_calls: Vec::new(),
             has_runtime_code: true,
             env_vars: FxHashSet::default(),
         };
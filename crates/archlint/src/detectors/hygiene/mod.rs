@@ -1,11 +1,21 @@
 pub mod dead_code;
 pub mod dead_symbols;
+pub mod orphan_events;
 pub mod side_effect_import;
 pub mod test_leakage;
+pub mod test_only_code;
+pub mod unresolved_import;
+pub mod unused_class_members;
+pub mod unused_suppression;
 
 pub const fn init() {
     dead_code::init();
     dead_symbols::init();
+    orphan_events::init();
     side_effect_import::init();
     test_leakage::init();
+    test_only_code::init();
+    unresolved_import::init();
+    unused_class_members::init();
+    unused_suppression::init();
 }
@@ -0,0 +1,96 @@
+use crate::config::EventRole;
+use crate::detectors::{detector, ArchSmell, Detector, EventIssue, LocationDetail};
+use crate::engine::AnalysisContext;
+use crate::event_graph::{EventGraph, EventSite};
+
+/// Initializes the detector module.
+/// This function is used for module registration side-effects.
+pub const fn init() {}
+
+#[detector(SmellType::OrphanEvent, default_enabled = false)]
+pub struct OrphanEventsDetector;
+
+impl OrphanEventsDetector {
+    #[must_use]
+    pub const fn new_default(_config: &crate::config::Config) -> Self {
+        Self
+    }
+
+    fn locations(sites: &[EventSite], key: &str, role: EventRole) -> Vec<LocationDetail> {
+        let verb = match role {
+            EventRole::Producer => "emits",
+            EventRole::Consumer => "listens to",
+        };
+        sites
+            .iter()
+            .map(|site| {
+                LocationDetail::new(
+                    site.file.clone(),
+                    site.line,
+                    format!("{} {verb} '{key}'", site.callee),
+                )
+            })
+            .collect()
+    }
+}
+
+impl Detector for OrphanEventsDetector {
+    crate::impl_detector_report!(
+        explain: smell => (
+            problem: {
+                if let crate::detectors::SmellType::OrphanEvent { key, issue } = &smell.smell_type {
+                    format!("Event '{key}' is {issue}")
+                } else {
+                    "Orphan event detected".to_string()
+                }
+            },
+            reason: "Producers and consumers of an event are only connected by its name. When one side is missing, the event is either dead code or a handler waits for something that never happens, often after a rename or a typo.",
+            risks: [
+                "Features silently stop working because nobody reacts to the event",
+                "Dead handlers and emitters accumulate and mislead readers"
+            ],
+            recommendations: [
+                "Remove the unused emitter or handler",
+                "Share event names through constants so renames reach both sides",
+                "Add the key to `events.ignore_keys` if it is handled outside the project"
+            ]
+        ),
+        table: {
+            title: "Orphan Events",
+            columns: ["Location", "Event", "Issue", "pts"],
+            row: OrphanEvent { key, issue } (smell, location, pts) => [
+                location,
+                key,
+                issue,
+                pts
+            ]
+        }
+    );
+
+    fn detect(&self, ctx: &AnalysisContext) -> Vec<ArchSmell> {
+        if ctx.get_rule("orphan_events").is_none() {
+            return Vec::new();
+        }
+
+        let events = &ctx.config.events;
+        let graph = EventGraph::build(ctx.file_symbols.as_ref(), events);
+        graph
+            .orphans(&events.ignore_keys)
+            .into_iter()
+            .filter_map(|(key, role)| {
+                let (sites, issue) = match role {
+                    EventRole::Producer => (graph.producers(key), EventIssue::NeverConsumed),
+                    EventRole::Consumer => (graph.consumers(key), EventIssue::NeverProduced),
+                };
+                let rule = sites
+                    .iter()
+                    .find_map(|site| ctx.get_rule_for_file("orphan_events", &site.file))?;
+
+                let locations = Self::locations(sites, key, role);
+                let mut smell = ArchSmell::new_orphan_event(key.to_string(), issue, locations);
+                smell.severity = rule.severity;
+                Some(smell)
+            })
+            .collect()
+    }
+}
@@ -47,7 +47,31 @@ impl TestLeakageDetector {
         smells
     }
 
-    fn is_test_file(&self, path: &Path, patterns: Option<&[String]>) -> bool {
+    /// Patterns used for `test_patterns` when the rule does not set them.
+    #[must_use]
+    pub fn default_test_patterns() -> Vec<String> {
+        vec![
+            "**/*.test.ts".to_string(),
+            "**/*.test.js".to_string(),
+            "**/*.spec.ts".to_string(),
+            "**/*.spec.js".to_string(),
+            "**/__tests__/**".to_string(),
+            "**/__mocks__/**".to_string(),
+        ]
+    }
+
+    /// `test_patterns` of the `test_leakage` rule, or the defaults.
+    #[must_use]
+    pub fn configured_test_patterns(config: &crate::config::Config) -> Vec<String> {
+        crate::rule_resolver::ResolvedRuleConfig::resolve(config, "test_leakage", None)
+            .get_option("test_patterns")
+            .unwrap_or_else(Self::default_test_patterns)
+    }
+
+    /// Classifies a file as test code, using custom patterns when provided
+    /// and the built-in conventions (`*.spec.ts`, `__tests__/`, ...) otherwise.
+    #[must_use]
+    pub fn is_test_file(&self, path: &Path, patterns: Option<&[String]>) -> bool {
         if let Some(patterns) = patterns {
             return self.matches_custom_patterns(path, patterns);
         }
@@ -160,16 +184,9 @@ impl Detector for TestLeakageDetector {
             None => return Vec::new(),
         };
 
-        let test_patterns: Vec<String> = rule.get_option("test_patterns").unwrap_or_else(|| {
-            vec![
-                "**/*.test.ts".to_string(),
-                "**/*.test.js".to_string(),
-                "**/*.spec.ts".to_string(),
-                "**/*.spec.js".to_string(),
-                "**/__tests__/**".to_string(),
-                "**/__mocks__/**".to_string(),
-            ]
-        });
+        let test_patterns: Vec<String> = rule
+            .get_option("test_patterns")
+            .unwrap_or_else(Self::default_test_patterns);
 
         ctx.graph
             .nodes()
@@ -0,0 +1,272 @@
+use crate::detectors::dead_code::DeadCodeDetector;
+use crate::detectors::dead_symbols::DeadSymbolsDetector;
+use crate::detectors::test_leakage::TestLeakageDetector;
+use crate::detectors::{detector, ArchSmell, Detector};
+use crate::engine::AnalysisContext;
+use petgraph::graph::NodeIndex;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+/// Initializes the detector module.
+/// This function is used for module registration side-effects.
+pub const fn init() {}
+
+#[detector(SmellType::TestOnlyCode, default_enabled = false, is_deep = true)]
+pub struct TestOnlyCodeDetector;
+
+/// Files reachable from production entry points and from test files.
+struct Reachability {
+    test_files: HashSet<PathBuf>,
+    production: HashSet<PathBuf>,
+    from_tests: HashSet<PathBuf>,
+}
+
+impl TestOnlyCodeDetector {
+    #[must_use]
+    pub const fn new_default(_config: &crate::config::Config) -> Self {
+        Self
+    }
+
+    /// Test patterns come from this rule, then from `test_leakage`, so both
+    /// detectors agree on what a test file is.
+    fn test_patterns(ctx: &AnalysisContext) -> Vec<String> {
+        ctx.resolve_rule("test_only_code", None)
+            .get_option("test_patterns")
+            .unwrap_or_else(|| TestLeakageDetector::configured_test_patterns(&ctx.config))
+    }
+
+    fn compute_reachability(
+        ctx: &AnalysisContext,
+        entry_detector: &DeadCodeDetector,
+        test_patterns: &[String],
+    ) -> Reachability {
+        let classifier = TestLeakageDetector;
+        let mut test_files = HashSet::new();
+        let mut production_roots = Vec::new();
+        let mut test_roots = Vec::new();
+
+        for node in ctx.graph.nodes() {
+            let Some(path) = ctx.graph.get_file_path(node) else {
+                continue;
+            };
+            // Test-like folders (`test/`, `__fixtures__/`) are entry points for
+            // dead code analysis, but must not make their imports production code.
+            let is_test_like = classifier.is_test_file(path, None);
+            if classifier.is_test_file(path, Some(test_patterns)) {
+                test_files.insert(path.clone());
+                test_roots.push(node);
+            } else if !is_test_like
+                && (entry_detector.is_entry_point(path)
+                    || entry_detector.matches_dynamic_load_pattern(path))
+            {
+                production_roots.push(node);
+            }
+        }
+
+        let production = Self::walk(ctx, production_roots, &test_files);
+        let from_tests = Self::walk(ctx, test_roots, &HashSet::new());
+
+        Reachability {
+            test_files,
+            production,
+            from_tests,
+        }
+    }
+
+    /// Breadth-first walk over import edges that never enters `blocked` files.
+    fn walk(
+        ctx: &AnalysisContext,
+        roots: Vec<NodeIndex>,
+        blocked: &HashSet<PathBuf>,
+    ) -> HashSet<PathBuf> {
+        let mut visited: HashSet<NodeIndex> = roots.iter().copied().collect();
+        let mut queue: std::collections::VecDeque<NodeIndex> = roots.into();
+
+        while let Some(node) = queue.pop_front() {
+            for dep in ctx.graph.dependencies(node) {
+                let is_blocked = ctx
+                    .graph
+                    .get_file_path(dep)
+                    .is_some_and(|p| blocked.contains(p));
+                if !is_blocked && visited.insert(dep) {
+                    queue.push_back(dep);
+                }
+            }
+        }
+
+        visited
+            .into_iter()
+            .filter_map(|node| ctx.graph.get_file_path(node).cloned())
+            .collect()
+    }
+
+    fn test_importers(ctx: &AnalysisContext, path: &Path, reach: &Reachability) -> Vec<PathBuf> {
+        let Some(node) = ctx.graph.get_node(path) else {
+            return Vec::new();
+        };
+        let mut importers: Vec<PathBuf> = ctx
+            .graph
+            .nodes()
+            .filter(|&from| ctx.graph.get_edge_data(from, node).is_some())
+            .filter_map(|from| ctx.graph.get_file_path(from))
+            .filter(|from| reach.test_files.contains(*from))
+            .cloned()
+            .collect();
+        importers.sort();
+        importers
+    }
+
+    fn check_modules(ctx: &AnalysisContext, reach: &Reachability) -> Vec<ArchSmell> {
+        let mut smells = Vec::new();
+
+        for path in &reach.from_tests {
+            if reach.test_files.contains(path) || reach.production.contains(path) {
+                continue;
+            }
+            let Some(rule) = ctx.get_rule_for_file("test_only_code", path) else {
+                continue;
+            };
+
+            let mut smell = ArchSmell::new_test_only_module(
+                path.clone(),
+                Self::test_importers(ctx, path, reach),
+                &ctx.project_path,
+            );
+            smell.severity = rule.severity;
+            smells.push(smell);
+        }
+
+        smells
+    }
+
+    /// Report exports of production modules that only tests import by name.
+    fn check_symbols(
+        ctx: &AnalysisContext,
+        entry_detector: &DeadCodeDetector,
+        reach: &Reachability,
+    ) -> Vec<ArchSmell> {
+        let symbol_usages = DeadSymbolsDetector::build_symbol_imports_map(&ctx.file_symbols);
+        let is_production_importer = |importer: &PathBuf| {
+            reach.production.contains(importer) && !reach.test_files.contains(importer)
+        };
+        let mut smells = Vec::new();
+
+        for (path, symbols) in ctx.file_symbols.as_ref() {
+            if !reach.production.contains(path)
+                || reach.test_files.contains(path)
+                || entry_detector.is_entry_point(path)
+            {
+                continue;
+            }
+            let Some(rule) = ctx.get_rule_for_file("test_only_code", path) else {
+                continue;
+            };
+
+            // Namespace imports may use any export
+            let star_importers = symbol_usages.get(&(path.clone(), "*".to_string()));
+            if star_importers.is_some_and(|importers| importers.iter().any(is_production_importer))
+            {
+                continue;
+            }
+
+            for export in &symbols.exports {
+                if export.is_reexport
+                    || export.name == "*"
+                    || symbols.local_usages.contains(&export.name)
+                {
+                    continue;
+                }
+                let Some(importers) = symbol_usages.get(&(path.clone(), export.name.to_string()))
+                else {
+                    continue;
+                };
+                if importers.iter().any(is_production_importer) {
+                    continue;
+                }
+
+                let mut test_files: Vec<PathBuf> = importers
+                    .iter()
+                    .filter(|importer| reach.test_files.contains(*importer))
+                    .cloned()
+                    .collect();
+                if test_files.is_empty() {
+                    continue;
+                }
+                test_files.sort();
+
+                let mut smell = ArchSmell::new_test_only_symbol(
+                    path.clone(),
+                    export.name.to_string(),
+                    export.range,
+                    test_files,
+                    &ctx.project_path,
+                );
+                smell.severity = rule.severity;
+                smells.push(smell);
+            }
+        }
+
+        smells
+    }
+}
+
+impl Detector for TestOnlyCodeDetector {
+    crate::impl_detector_report!(
+        explain: smell => (
+            problem: {
+                match &smell.smell_type {
+                    crate::detectors::SmellType::TestOnlyCode { symbol: Some(symbol) } => {
+                        format!("Export '{symbol}' is used only by tests")
+                    }
+                    _ => "Module is reachable only from tests".to_string(),
+                }
+            },
+            reason: "No production entry point reaches this code; it is kept alive solely by test files, so it looks used while shipping no behavior.",
+            risks: [
+                "Tests keep obsolete code compiling and passing",
+                "Maintenance effort is spent on code that never runs in production",
+                "Coverage numbers overstate how much production code is tested"
+            ],
+            recommendations: [
+                "Delete the code together with its tests if it is no longer needed",
+                "Move test helpers next to the tests or into a test-only folder",
+                "Add the module to entry_points if it is loaded in a way archlint cannot see"
+            ]
+        ),
+        table: {
+            title: "Test-Only Code",
+            columns: ["Location", "Symbol", "pts"],
+            row: TestOnlyCode { symbol } (smell, location, pts) => [
+                location,
+                symbol.as_deref().unwrap_or("(module)"),
+                pts
+            ]
+        }
+    );
+
+    fn detect(&self, ctx: &AnalysisContext) -> Vec<ArchSmell> {
+        let Some(rule) = ctx.get_rule("test_only_code") else {
+            return Vec::new();
+        };
+
+        let mut exclude = rule.exclude.clone();
+        exclude.extend_from_slice(&ctx.config.ignore);
+        let entry_detector = DeadCodeDetector::new(
+            &ctx.config,
+            ctx.script_entry_points.clone(),
+            ctx.dynamic_load_patterns.clone(),
+            &exclude,
+            ctx.project_path.clone(),
+        );
+
+        let test_patterns = Self::test_patterns(ctx);
+        let reach = Self::compute_reachability(ctx, &entry_detector, &test_patterns);
+        let mut smells = Self::check_modules(ctx, &reach);
+
+        if rule.get_option("check_symbols").unwrap_or(true) {
+            smells.extend(Self::check_symbols(ctx, &entry_detector, &reach));
+        }
+
+        smells
+    }
+}
@@ -0,0 +1,76 @@
+use crate::detectors::{detector, ArchSmell, Detector};
+use crate::engine::AnalysisContext;
+
+/// Initializes the detector module.
+/// This function is used for module registration side-effects.
+pub const fn init() {}
+
+#[detector(SmellType::UnresolvedImport, default_enabled = false)]
+pub struct UnresolvedImportDetector;
+
+impl UnresolvedImportDetector {
+    #[must_use]
+    pub const fn new_default(_config: &crate::config::Config) -> Self {
+        Self
+    }
+}
+
+impl Detector for UnresolvedImportDetector {
+    crate::impl_detector_report!(
+        explain: smell => (
+            problem: {
+                if let crate::detectors::SmellType::UnresolvedImport { specifier, reason } = &smell.smell_type {
+                    format!("Import '{specifier}' cannot be resolved: {reason}")
+                } else {
+                    "Unresolved import detected".to_string()
+                }
+            },
+            reason: "The import points into the project, but no file matches it, so it is missing from the dependency graph. Cycles through it go unreported and the files it imports may look dead.",
+            risks: [
+                "Cycles and layer violations hidden by the missing edge",
+                "Live files reported as dead code"
+            ],
+            recommendations: [
+                "Fix the import path or the file name",
+                "Align `aliases` or tsconfig `paths` with the directory layout",
+                "Add the specifier to `ignore_patterns` if a bundler resolves it"
+            ]
+        ),
+        table: {
+            title: "Unresolved Imports",
+            columns: ["Location", "Import", "Reason", "pts"],
+            row: UnresolvedImport { specifier, reason } (smell, location, pts) => [
+                location,
+                specifier,
+                reason,
+                pts
+            ]
+        }
+    );
+
+    fn detect(&self, ctx: &AnalysisContext) -> Vec<ArchSmell> {
+        if ctx.get_rule("unresolved_import").is_none() {
+            return Vec::new();
+        }
+
+        ctx.import_diagnostics
+            .unresolved
+            .iter()
+            .filter_map(|import| {
+                let rule = ctx.get_rule_for_file("unresolved_import", &import.file)?;
+                let ignore_patterns: Vec<String> =
+                    rule.get_option("ignore_patterns").unwrap_or_default();
+                if ignore_patterns
+                    .iter()
+                    .any(|pattern| import.specifier.contains(pattern.as_str()))
+                {
+                    return None;
+                }
+
+                let mut smell = ArchSmell::new_unresolved_import(import.clone());
+                smell.severity = rule.severity;
+                Some(smell)
+            })
+            .collect()
+    }
+}
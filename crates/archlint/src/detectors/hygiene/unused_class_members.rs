@@ -0,0 +1,380 @@
+use crate::detectors::hygiene::dead_symbols::{DeadSymbolsDetector, InheritanceContext};
+use crate::detectors::{detector, ArchSmell, Detector};
+use crate::engine::AnalysisContext;
+use crate::parser::{ClassSymbol, FileSymbols, MethodAccessibility, SymbolKind};
+use crate::CodeRange;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+/// Initializes the detector module.
+/// This function is used for module registration side-effects.
+pub const fn init() {}
+
+#[detector(SmellType::UnusedClassMember, default_enabled = false, is_deep = true)]
+pub struct UnusedClassMembersDetector;
+
+type ClassId = (PathBuf, String);
+
+/// Where a class's base class is defined.
+enum BaseClass {
+    None,
+    Local(ClassId),
+    External,
+}
+
+struct MemberOptions {
+    ignored: HashSet<String>,
+    contract_methods: HashMap<String, Vec<String>>,
+    check_fields: bool,
+    check_protected: bool,
+    check_public_methods: bool,
+}
+
+/// A method or field reduced to what the usage checks need.
+struct Member<'a> {
+    name: &'a str,
+    accessibility: Option<&'a MethodAccessibility>,
+    is_method: bool,
+    range: CodeRange,
+}
+
+struct ProjectIndex<'a> {
+    file_symbols: &'a HashMap<PathBuf, FileSymbols>,
+    classes: HashMap<ClassId, &'a ClassSymbol>,
+    symbol_usages: HashMap<(PathBuf, String), HashSet<PathBuf>>,
+    inheritance_ctx: InheritanceContext,
+    /// Subclasses per class, including ones declared in the same file.
+    children: HashMap<ClassId, Vec<ClassId>>,
+    entry_points: &'a HashSet<PathBuf>,
+}
+
+impl UnusedClassMembersDetector {
+    #[must_use]
+    pub const fn new_default(_config: &crate::config::Config) -> Self {
+        Self
+    }
+
+    /// Merge `ignore_methods` and `contract_methods` from this rule and from
+    /// `dead_symbols`, so framework presets apply to both detectors.
+    fn build_options(ctx: &AnalysisContext) -> MemberOptions {
+        let own = ctx.resolve_rule("unused_class_members", None);
+        let dead_symbols = ctx.resolve_rule("dead_symbols", None);
+
+        let mut ignored: HashSet<String> = std::iter::once("constructor".to_string()).collect();
+        let mut contract_methods: HashMap<String, Vec<String>> = HashMap::new();
+        for rule in [&dead_symbols, &own] {
+            if let Some(methods) = rule.get_option::<Vec<String>>("ignore_methods") {
+                ignored.extend(methods);
+            }
+            if let Some(contracts) =
+                rule.get_option::<HashMap<String, Vec<String>>>("contract_methods")
+            {
+                for (interface, methods) in contracts {
+                    contract_methods
+                        .entry(interface)
+                        .or_default()
+                        .extend(methods);
+                }
+            }
+        }
+
+        MemberOptions {
+            ignored,
+            contract_methods,
+            check_fields: own.get_option("check_fields").unwrap_or(true),
+            check_protected: own.get_option("check_protected").unwrap_or(true),
+            check_public_methods: own.get_option("check_public_methods").unwrap_or(true),
+        }
+    }
+
+    fn build_index(ctx: &AnalysisContext) -> ProjectIndex<'_> {
+        let file_symbols = ctx.file_symbols.as_ref();
+        let classes: HashMap<ClassId, &ClassSymbol> = file_symbols
+            .iter()
+            .flat_map(|(path, symbols)| {
+                symbols
+                    .classes
+                    .iter()
+                    .map(move |class| ((path.clone(), class.name.to_string()), class))
+            })
+            .collect();
+
+        let inheritance_ctx = DeadSymbolsDetector::build_inheritance_context(file_symbols);
+        let mut children = inheritance_ctx.children.clone();
+        for ((path, _), class) in &classes {
+            if let Some(super_name) = class.super_class.as_deref() {
+                let parent_id = (path.clone(), super_name.to_string());
+                if classes.contains_key(&parent_id) {
+                    children
+                        .entry(parent_id)
+                        .or_default()
+                        .push((path.clone(), class.name.to_string()));
+                }
+            }
+        }
+
+        ProjectIndex {
+            file_symbols,
+            classes,
+            symbol_usages: DeadSymbolsDetector::build_symbol_imports_map(file_symbols),
+            inheritance_ctx,
+            children,
+            entry_points: &ctx.script_entry_points,
+        }
+    }
+
+    fn base_class(index: &ProjectIndex<'_>, path: &Path, class: &ClassSymbol) -> BaseClass {
+        let Some(super_name) = class.super_class.as_deref() else {
+            return BaseClass::None;
+        };
+
+        let local_id = (path.to_path_buf(), super_name.to_string());
+        if index.classes.contains_key(&local_id) {
+            return BaseClass::Local(local_id);
+        }
+
+        let class_id = (path.to_path_buf(), class.name.to_string());
+        match index.inheritance_ctx.parents.get(&class_id) {
+            Some(parent_id) if index.classes.contains_key(parent_id) => {
+                BaseClass::Local(parent_id.clone())
+            }
+            _ => BaseClass::External,
+        }
+    }
+
+    /// Collect the class, its ancestors and its descendants. Returns `None`
+    /// when an ancestor lives outside the project, since overridden members
+    /// may then be called by code we cannot see.
+    fn collect_hierarchy(
+        index: &ProjectIndex<'_>,
+        path: &Path,
+        class: &ClassSymbol,
+    ) -> Option<Vec<ClassId>> {
+        let class_id = (path.to_path_buf(), class.name.to_string());
+        let mut visited = HashSet::new();
+        visited.insert(class_id.clone());
+        let mut hierarchy = vec![class_id.clone()];
+
+        let (mut current_path, mut current) = (path.to_path_buf(), class);
+        loop {
+            match Self::base_class(index, &current_path, current) {
+                BaseClass::None => break,
+                BaseClass::External => return None,
+                BaseClass::Local(parent_id) => {
+                    if !visited.insert(parent_id.clone()) {
+                        break;
+                    }
+                    let parent = index.classes[&parent_id];
+                    hierarchy.push(parent_id.clone());
+                    current_path = parent_id.0;
+                    current = parent;
+                }
+            }
+        }
+
+        let mut stack = vec![class_id];
+        while let Some(id) = stack.pop() {
+            for child in index.children.get(&id).into_iter().flatten() {
+                if visited.insert(child.clone()) {
+                    hierarchy.push(child.clone());
+                    stack.push(child.clone());
+                }
+            }
+        }
+
+        Some(hierarchy)
+    }
+
+    fn is_contract_member(class: &ClassSymbol, name: &str, options: &MemberOptions) -> bool {
+        class.implements.iter().any(|interface| {
+            let interface_name = interface.rsplit('.').next().unwrap_or(interface);
+            options
+                .contract_methods
+                .get(interface_name)
+                .is_some_and(|methods| methods.iter().any(|m| m == name))
+        })
+    }
+
+    fn used_in_hierarchy(index: &ProjectIndex<'_>, hierarchy: &[ClassId], name: &str) -> bool {
+        hierarchy.iter().any(|id| {
+            index
+                .classes
+                .get(id)
+                .is_some_and(|class| class.used_members.contains(name))
+        })
+    }
+
+    fn used_by_importers(
+        index: &ProjectIndex<'_>,
+        path: &Path,
+        class: &ClassSymbol,
+        name: &str,
+    ) -> bool {
+        let importers = DeadSymbolsDetector::collect_class_importers(
+            path,
+            class,
+            &index.symbol_usages,
+            &index.inheritance_ctx,
+        );
+
+        importers.iter().any(|importer| {
+            index.entry_points.contains(importer)
+                || index
+                    .file_symbols
+                    .get(importer)
+                    .is_some_and(|symbols| symbols.local_usages.contains(name))
+        })
+    }
+
+    fn is_exported_class(symbols: &FileSymbols, class: &ClassSymbol) -> bool {
+        symbols
+            .exports
+            .iter()
+            .any(|e| e.kind == SymbolKind::Class && e.name == class.name)
+    }
+
+    fn class_members(class: &ClassSymbol) -> Vec<Member<'_>> {
+        let methods = class
+            .methods
+            .iter()
+            .filter(|m| !m.has_decorators && !m.is_accessor && !m.is_abstract)
+            .map(|m| Member {
+                name: m.name.as_str(),
+                accessibility: m.accessibility.as_ref(),
+                is_method: true,
+                range: m.range,
+            });
+        let fields = class
+            .field_symbols
+            .iter()
+            .filter(|f| !f.has_decorators && !f.is_abstract)
+            .map(|f| Member {
+                name: f.name.as_str(),
+                accessibility: f.accessibility.as_ref(),
+                is_method: false,
+                range: f.range,
+            });
+        methods.chain(fields).collect()
+    }
+
+    fn check_class(
+        index: &ProjectIndex<'_>,
+        path: &Path,
+        symbols: &FileSymbols,
+        class: &ClassSymbol,
+        options: &MemberOptions,
+    ) -> Vec<ArchSmell> {
+        let hierarchy = Self::collect_hierarchy(index, path, class);
+        let is_exported = Self::is_exported_class(symbols, class);
+        let mut smells = Vec::new();
+
+        for member in Self::class_members(class) {
+            if options.ignored.contains(member.name)
+                || (member.is_method && Self::is_contract_member(class, member.name, options))
+                || (!member.is_method && !options.check_fields)
+                || class.used_members.contains(member.name)
+            {
+                continue;
+            }
+
+            let visibility = match member.accessibility {
+                Some(MethodAccessibility::Private) => "Private",
+                Some(MethodAccessibility::Protected) => {
+                    let used = hierarchy.as_deref().is_none_or(|hierarchy| {
+                        Self::used_in_hierarchy(index, hierarchy, member.name)
+                    });
+                    if !options.check_protected || used {
+                        continue;
+                    }
+                    "Protected"
+                }
+                Some(MethodAccessibility::Public) | None => {
+                    if !member.is_method
+                        || !options.check_public_methods
+                        || !is_exported
+                        || index.entry_points.contains(path)
+                    {
+                        continue;
+                    }
+                    let used = hierarchy.as_deref().is_none_or(|hierarchy| {
+                        Self::used_in_hierarchy(index, hierarchy, member.name)
+                    }) || Self::used_by_importers(index, path, class, member.name);
+                    if used {
+                        continue;
+                    }
+                    "Public"
+                }
+            };
+
+            let kind = if member.is_method { "Method" } else { "Field" };
+            smells.push(ArchSmell::new_unused_class_member(
+                path.to_path_buf(),
+                class.name.to_string(),
+                member.name.to_string(),
+                format!("{visibility} {kind}"),
+                member.range,
+            ));
+        }
+
+        smells
+    }
+}
+
+impl Detector for UnusedClassMembersDetector {
+    crate::impl_detector_report!(
+        explain: smell => (
+            problem: {
+                if let crate::detectors::SmellType::UnusedClassMember { class_name, member, kind } = &smell.smell_type {
+                    format!("{kind} '{class_name}.{member}' is never used")
+                } else {
+                    "Unused class member detected".to_string()
+                }
+            },
+            reason: "The member is declared but never referenced inside its class hierarchy, and no importer of the class calls it.",
+            risks: [
+                "Unused members make classes look larger and more complex than they are",
+                "Readers may assume the member is part of a contract and keep maintaining it"
+            ],
+            recommendations: [
+                "Remove the member if it is no longer needed",
+                "If it is a framework hook, add it to `ignore_methods` or `contract_methods`"
+            ]
+        ),
+        table: {
+            title: "Unused Class Members",
+            columns: ["Location", "Class", "Member", "Kind", "pts"],
+            row: UnusedClassMember { class_name, member, kind } (smell, location, pts) => [
+                location,
+                class_name,
+                member,
+                kind,
+                pts
+            ]
+        }
+    );
+
+    fn detect(&self, ctx: &AnalysisContext) -> Vec<ArchSmell> {
+        if ctx.get_rule("unused_class_members").is_none() {
+            return Vec::new();
+        }
+
+        let options = Self::build_options(ctx);
+        let index = Self::build_index(ctx);
+        let mut smells = Vec::new();
+
+        for (path, symbols) in ctx.file_symbols.as_ref() {
+            let Some(rule) = ctx.get_rule_for_file("unused_class_members", path) else {
+                continue;
+            };
+
+            for class in &symbols.classes {
+                for mut smell in Self::check_class(&index, path, symbols, class, &options) {
+                    smell.severity = rule.severity;
+                    smells.push(smell);
+                }
+            }
+        }
+
+        smells
+    }
+}
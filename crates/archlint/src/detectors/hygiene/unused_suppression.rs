@@ -0,0 +1,134 @@
+use crate::detectors::{detector, ArchSmell, Detector, SmellKind};
+use crate::engine::AnalysisContext;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// Initializes the detector module.
+/// This function is used for module registration side-effects.
+pub const fn init() {}
+
+/// Reports `archlint-disable*` comments that suppress nothing.
+///
+/// Whether a comment is used is only known once every other detector has run
+/// and inline suppressions are applied, so the engine calls
+/// [`UnusedSuppressionDetector::find_unused`] while building the report and
+/// `detect` itself returns nothing.
+#[detector(SmellType::UnusedSuppression, default_enabled = false)]
+pub struct UnusedSuppressionDetector;
+
+/// A suppression comment reassembled from the per-line ignore map.
+#[derive(Default)]
+struct SuppressionComment {
+    rules: BTreeSet<String>,
+    has_reason: bool,
+}
+
+impl UnusedSuppressionDetector {
+    #[must_use]
+    pub const fn new_default(_config: &crate::config::Config) -> Self {
+        Self
+    }
+
+    /// Find suppression comments that matched no smell.
+    ///
+    /// `used` holds `(file, comment line)` pairs of comments that suppressed at
+    /// least one smell. Comments listing only rules that did not run are
+    /// skipped, since they could not have matched anything. With
+    /// `require_reason`, comments without a reason are always reported.
+    #[must_use]
+    pub fn find_unused(
+        ctx: &AnalysisContext,
+        used: &HashSet<(PathBuf, usize)>,
+        active_detectors: &HashSet<String>,
+        require_reason: bool,
+    ) -> Vec<ArchSmell> {
+        let mut smells = Vec::new();
+
+        for (path, lines) in ctx.ignored_lines.iter() {
+            let Some(rule) = ctx.get_rule_for_file("unused_suppression", path) else {
+                continue;
+            };
+
+            let mut comments: BTreeMap<usize, SuppressionComment> = BTreeMap::new();
+            for ignored in lines.values() {
+                for (rule_id, source) in ignored.iter() {
+                    let comment = comments.entry(source.line).or_default();
+                    comment.rules.insert(rule_id.to_string());
+                    comment.has_reason |= source.reason.is_some();
+                }
+            }
+
+            for (line, comment) in comments {
+                let missing_reason = require_reason && !comment.has_reason;
+                let is_used = used.contains(&(path.clone(), line));
+                let can_match = comment
+                    .rules
+                    .iter()
+                    .any(|r| Self::is_rule_active(r, active_detectors));
+                if !missing_reason && (is_used || !can_match) {
+                    continue;
+                }
+
+                let mut smell = ArchSmell::new_unused_suppression(
+                    path.clone(),
+                    line,
+                    comment.rules.into_iter().collect(),
+                    missing_reason,
+                );
+                smell.severity = rule.severity;
+                smells.push(smell);
+            }
+        }
+
+        smells.sort_by(|a, b| {
+            (&a.files[0], a.locations[0].line).cmp(&(&b.files[0], b.locations[0].line))
+        });
+        smells
+    }
+
+    fn is_rule_active(rule: &str, active_detectors: &HashSet<String>) -> bool {
+        rule == "*"
+            || active_detectors.contains(rule)
+            || SmellKind::from_str(rule).is_ok_and(|kind| active_detectors.contains(kind.to_id()))
+    }
+}
+
+impl Detector for UnusedSuppressionDetector {
+    crate::impl_detector_report!(
+        explain: smell => (
+            problem: {
+                match &smell.smell_type {
+                    crate::detectors::SmellType::UnusedSuppression { missing_reason: true, .. } => {
+                        "Suppression comment has no reason".to_string()
+                    }
+                    _ => "Suppression comment suppresses nothing".to_string(),
+                }
+            },
+            reason: "Suppressions outlive the problems they were added for. A stale or unexplained archlint-disable comment hides future smells on the same lines without anyone deciding to accept them.",
+            risks: [
+                "New smells are silently hidden by old comments",
+                "Nobody remembers why a rule was turned off"
+            ],
+            recommendations: [
+                "Remove the comment if the smell is gone",
+                "Add '-- reason' explaining why the smell is accepted",
+                "Narrow '*' to the specific rule being suppressed"
+            ]
+        ),
+        table: {
+            title: "Unused Suppressions",
+            columns: ["Location", "Rules", "Problem", "pts"],
+            row: UnusedSuppression { rules, missing_reason } (smell, location, pts) => [
+                location,
+                rules.join(", "),
+                if *missing_reason { "missing reason" } else { "unused" },
+                pts
+            ]
+        }
+    );
+
+    fn detect(&self, _ctx: &AnalysisContext) -> Vec<ArchSmell> {
+        Vec::new()
+    }
+}
@@ -0,0 +1,90 @@
+use crate::detectors::{detector, ArchSmell, Detector};
+use crate::engine::AnalysisContext;
+use crate::parser::ClassSymbol;
+
+/// Initializes the detector module.
+/// This function is used for module registration side-effects.
+pub const fn init() {}
+
+#[detector(SmellType::LargeClass, default_enabled = false)]
+pub struct LargeClassDetector;
+
+impl LargeClassDetector {
+    #[must_use]
+    pub const fn new_default(_config: &crate::config::Config) -> Self {
+        Self
+    }
+
+    fn method_count(class: &ClassSymbol) -> usize {
+        class
+            .methods
+            .iter()
+            .filter(|m| m.name != "constructor")
+            .count()
+    }
+}
+
+impl Detector for LargeClassDetector {
+    crate::impl_detector_report!(
+        explain: smell => (
+            problem: if let crate::detectors::SmellType::LargeClass { name, loc, methods, fields } = &smell.smell_type {
+                format!("Class `{name}` is too large ({loc} lines, {methods} methods, {fields} fields)")
+            } else {
+                "Class is too large".into()
+            },
+            reason: "Large classes accumulate unrelated responsibilities. Every change risks breaking behavior that has nothing to do with it.",
+            risks: [
+                "Violation of SRP",
+                "Hard to test in isolation",
+                "Frequent merge conflicts between unrelated changes"
+            ],
+            recommendations: [
+                "Split the class along groups of methods that share fields",
+                "Extract value objects for related fields",
+                "Move behavior closer to the data it uses"
+            ]
+        ),
+        table: {
+            title: "Large Classes",
+            columns: ["Location", "Class", "LOC", "Methods", "Fields", "pts"],
+            row: LargeClass { name, loc, methods, fields } (smell, location, pts) => [location, name, loc, methods, fields, pts]
+        }
+    );
+
+    fn detect(&self, ctx: &AnalysisContext) -> Vec<ArchSmell> {
+        let mut smells = Vec::new();
+
+        for (path, symbols) in ctx.file_symbols.as_ref() {
+            let Some(rule) = ctx.get_rule_for_file("large_class", path) else {
+                continue;
+            };
+
+            let max_lines: usize = rule.get_option("max_lines").unwrap_or(300);
+            let max_methods: usize = rule.get_option("max_methods").unwrap_or(20);
+            let max_fields: usize = rule.get_option("max_fields").unwrap_or(15);
+
+            for class in &symbols.classes {
+                let methods = Self::method_count(class);
+                let fields = class.field_symbols.len();
+
+                if class.loc <= max_lines && methods <= max_methods && fields <= max_fields {
+                    continue;
+                }
+
+                let mut smell = ArchSmell::new_large_class(
+                    path.clone(),
+                    class.name.to_string(),
+                    class.loc,
+                    methods,
+                    fields,
+                    class.line,
+                    class.range,
+                );
+                smell.severity = rule.severity;
+                smells.push(smell);
+            }
+        }
+
+        smells
+    }
+}
@@ -15,7 +15,9 @@ impl LcomDetector {
         Self
     }
 
-    fn calculate_lcom4(&self, class: &crate::parser::ClassSymbol) -> usize {
+    /// LCOM4: connected components among non-constructor, non-accessor methods.
+    #[must_use]
+    pub fn calculate_lcom4(class: &crate::parser::ClassSymbol) -> usize {
         let methods: Vec<_> = class
             .methods
             .iter()
@@ -103,7 +105,7 @@ impl Detector for LcomDetector {
                     continue;
                 }
 
-                let lcom4 = self.calculate_lcom4(class);
+                let lcom4 = Self::calculate_lcom4(class);
 
                 if lcom4 > max_lcom {
                     let mut smell =
@@ -0,0 +1,78 @@
+use crate::detectors::{detector, ArchSmell, Detector};
+use crate::engine::AnalysisContext;
+
+/// Initializes the detector module.
+/// This function is used for module registration side-effects.
+pub const fn init() {}
+
+#[detector(SmellType::LongFunction, default_enabled = false)]
+pub struct LongFunctionDetector;
+
+impl LongFunctionDetector {
+    #[must_use]
+    pub const fn new_default(_config: &crate::config::Config) -> Self {
+        Self
+    }
+}
+
+impl Detector for LongFunctionDetector {
+    crate::impl_detector_report!(
+        explain: smell => (
+            problem: if let crate::detectors::SmellType::LongFunction { name, loc } = &smell.smell_type {
+                format!("Function `{name}` has {loc} lines of code")
+            } else {
+                "Function is too long".into()
+            },
+            reason: "Long functions usually do several things at once. They are hard to read, name, test and reuse.",
+            risks: [
+                "Violation of SRP",
+                "Bugs hide in code that nobody reads end to end",
+                "Changes cause merge conflicts more often"
+            ],
+            recommendations: [
+                "Extract cohesive blocks into well-named helper functions",
+                "Replace long conditional chains with lookup tables or polymorphism"
+            ]
+        ),
+        table: {
+            title: "Long Functions",
+            columns: ["Location", "Function", "LOC", "pts"],
+            row: LongFunction { name, loc } (smell, location, pts) => [location, name, loc, pts]
+        }
+    );
+
+    fn detect(&self, ctx: &AnalysisContext) -> Vec<ArchSmell> {
+        let mut smells = Vec::new();
+
+        for (path, functions) in ctx.function_complexity.as_ref() {
+            let Some(rule) = ctx.get_rule_for_file("long_function", path) else {
+                continue;
+            };
+
+            let max_lines: usize = rule
+                .get_option("max_lines")
+                .or(rule.get_option("lines"))
+                .unwrap_or(50);
+            let ignore_constructors: bool = rule.get_option("ignore_constructors").unwrap_or(false);
+
+            for func in functions {
+                if func.loc <= max_lines || (ignore_constructors && func.is_constructor) {
+                    continue;
+                }
+
+                let mut smell = ArchSmell::new_long_function(
+                    path.clone(),
+                    func.name.to_string(),
+                    func.loc,
+                    max_lines,
+                    func.line,
+                    func.range,
+                );
+                smell.severity = rule.severity;
+                smells.push(smell);
+            }
+        }
+
+        smells
+    }
+}
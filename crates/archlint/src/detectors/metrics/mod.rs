@@ -7,16 +7,20 @@ use std::path::Path;
 pub mod cognitive_complexity;
 pub mod cyclomatic_complexity;
 pub mod deep_nesting;
+pub mod large_class;
 pub mod large_file;
 pub mod lcom;
+pub mod long_function;
 pub mod long_params;
 
 pub const fn init() {
     cognitive_complexity::init();
     cyclomatic_complexity::init();
     deep_nesting::init();
+    large_class::init();
     large_file::init();
     lcom::init();
+    long_function::init();
     long_params::init();
 }
 
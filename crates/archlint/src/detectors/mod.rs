@@ -14,23 +14,28 @@ pub mod metrics;
 pub use registry::{DetectorFactory, DetectorInfo, DetectorRegistry};
 pub use smell::{ArchSmell, CodeRange, CriticalEdge, CycleCluster, HotspotInfo, LocationDetail};
 pub use types::{
-    DetectorCategory, Explanation, Severity, SmellKind, SmellMetric, SmellType,
-    SmellWithExplanation,
+    DependencyIssue, DetectorCategory, EventIssue, Explanation, Severity, SmellKind, SmellMetric,
+    SmellType, SmellWithExplanation,
 };
 
 // Re-export detectors for convenience and backward compatibility
 pub use dependency::{
-    circular_type_deps, cycles, high_coupling, hub_dependency, hub_module, layer_violation,
-    package_cycle, vendor_coupling,
+    circular_module_imports, circular_type_deps, cycles, event_cycles, high_coupling,
+    hub_dependency, hub_module, layer_violation, npm_dependencies, package_cycle,
+    provider_outside_module, server_code_in_client, vendor_coupling,
 };
 pub use design::{
     abstractness, barrel_abuse, feature_envy, god_module, orphan_types, primitive_obsession,
     scattered_config, scattered_module, sdp_violation, shared_mutable_state, shotgun_surgery,
     unstable_interface,
 };
-pub use hygiene::{dead_code, dead_symbols, side_effect_import, test_leakage};
+pub use hygiene::{
+    dead_code, dead_symbols, orphan_events, side_effect_import, test_leakage, test_only_code,
+    unresolved_import, unused_class_members, unused_suppression,
+};
 pub use metrics::{
-    cognitive_complexity, cyclomatic_complexity, deep_nesting, large_file, lcom, long_params,
+    cognitive_complexity, cyclomatic_complexity, deep_nesting, large_class, large_file, lcom,
+    long_function, long_params,
 };
 
 /// Ensures all detectors are registered.
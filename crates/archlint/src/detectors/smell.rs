@@ -1,9 +1,10 @@
 use crate::config::SeverityConfig;
-use crate::detectors::types::{Severity, SmellMetric, SmellType};
+use crate::detectors::types::{DependencyIssue, EventIssue, Severity, SmellMetric, SmellType};
+use crate::resolver::UnresolvedImport;
 use crate::snapshot::{MetricValue, SnapshotSmell};
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Detailed information about a specific location in a source file.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
@@ -129,6 +130,7 @@ impl ArchSmell {
     impl_metric_accessor!(cbo, Cbo, usize);
     impl_metric_accessor!(depth, Depth, usize);
     impl_metric_accessor!(token_count, TokenCount, usize);
+    impl_metric_accessor!(similarity, Similarity, usize);
     impl_metric_accessor!(parameter_count, ParameterCount, usize);
     impl_metric_accessor!(primitive_count, PrimitiveCount, usize);
     impl_metric_accessor!(internal_refs, InternalRefs, usize);
@@ -271,6 +273,35 @@ impl ArchSmell {
         }
     }
 
+    #[must_use]
+    pub fn new_unused_class_member(
+        file: PathBuf,
+        class_name: String,
+        member: String,
+        kind: String,
+        range: CodeRange,
+    ) -> Self {
+        let location = LocationDetail::new(
+            file.clone(),
+            range.start_line,
+            format!("{kind} '{class_name}.{member}' is never used"),
+        )
+        .with_range(range);
+
+        Self {
+            smell_type: SmellType::UnusedClassMember {
+                class_name,
+                member,
+                kind,
+            },
+            severity: Severity::Low,
+            files: vec![file],
+            metrics: Vec::new(),
+            locations: vec![location],
+            cluster: None,
+        }
+    }
+
     fn new_complexity_smell(
         file: PathBuf,
         name: String,
@@ -332,7 +363,7 @@ impl ArchSmell {
             smell_type,
             severity,
             files: vec![file],
-            metrics: vec![metric],
+            metrics: vec![metric, SmellMetric::Threshold(threshold)],
             locations,
             cluster: None,
         }
@@ -535,6 +566,111 @@ impl ArchSmell {
         }
     }
 
+    /// `chain` holds the client entry, the intermediate modules and the
+    /// server-only module, each with the line importing the next one.
+    #[must_use]
+    pub fn new_server_code_in_client(chain: Vec<(PathBuf, usize)>, package: String) -> Self {
+        let module = chain
+            .last()
+            .map(|(path, _)| path.clone())
+            .unwrap_or_default();
+        let last = chain.len().saturating_sub(1);
+        let file_name = |path: &PathBuf| {
+            path.file_name().map_or_else(
+                || path.display().to_string(),
+                |n| n.to_string_lossy().to_string(),
+            )
+        };
+        let locations: Vec<LocationDetail> = chain
+            .iter()
+            .enumerate()
+            .map(|(i, (path, line))| {
+                let description = if i == last {
+                    format!("Server-only: imports '{package}'")
+                } else if i == 0 {
+                    format!("Client component imports {}", file_name(&chain[1].0))
+                } else {
+                    format!("Imports {}", file_name(&chain[i + 1].0))
+                };
+                LocationDetail::new(path.clone(), *line, description)
+            })
+            .collect();
+
+        Self {
+            smell_type: SmellType::ServerCodeInClient { module, package },
+            severity: Severity::High,
+            files: chain.into_iter().map(|(path, _)| path).collect(),
+            metrics: Vec::new(),
+            locations,
+            cluster: None,
+        }
+    }
+
+    #[must_use]
+    pub fn new_test_only_module(
+        path: PathBuf,
+        test_files: Vec<PathBuf>,
+        project_root: &Path,
+    ) -> Self {
+        let mut locations = vec![LocationDetail::new(
+            path.clone(),
+            0,
+            "Module is reachable only from tests".to_string(),
+        )];
+        locations.extend(test_files.iter().map(|test| {
+            let relative = test.strip_prefix(project_root).unwrap_or(test);
+            LocationDetail::new(
+                test.clone(),
+                0,
+                format!("Used by test {}", relative.display()),
+            )
+        }));
+
+        Self {
+            smell_type: SmellType::TestOnlyCode { symbol: None },
+            severity: Severity::Low,
+            files: vec![path],
+            metrics: vec![SmellMetric::DependentCount(test_files.len())],
+            locations,
+            cluster: None,
+        }
+    }
+
+    #[must_use]
+    pub fn new_test_only_symbol(
+        path: PathBuf,
+        symbol: String,
+        range: CodeRange,
+        test_files: Vec<PathBuf>,
+        project_root: &Path,
+    ) -> Self {
+        let mut locations = vec![LocationDetail::new(
+            path.clone(),
+            range.start_line,
+            format!("'{symbol}' is imported only by tests"),
+        )
+        .with_range(range)];
+        locations.extend(test_files.iter().map(|test| {
+            let relative = test.strip_prefix(project_root).unwrap_or(test);
+            LocationDetail::new(
+                test.clone(),
+                0,
+                format!("Imported by test {}", relative.display()),
+            )
+        }));
+
+        Self {
+            smell_type: SmellType::TestOnlyCode {
+                symbol: Some(symbol),
+            },
+            severity: Severity::Low,
+            files: vec![path],
+            metrics: vec![SmellMetric::DependentCount(test_files.len())],
+            locations,
+            cluster: None,
+        }
+    }
+
     #[must_use]
     pub fn new_layer_violation(
         from: PathBuf,
@@ -640,6 +776,132 @@ impl ArchSmell {
         }
     }
 
+    #[must_use]
+    pub fn new_circular_module_import(
+        modules: Vec<String>,
+        locations: Vec<LocationDetail>,
+    ) -> Self {
+        let mut files: Vec<PathBuf> = Vec::new();
+        for loc in &locations {
+            if !files.contains(&loc.file) {
+                files.push(loc.file.clone());
+            }
+        }
+        let count = modules.len();
+
+        Self {
+            smell_type: SmellType::CircularModuleImport { modules },
+            severity: Severity::High,
+            files,
+            metrics: vec![SmellMetric::CycleLength(count)],
+            locations,
+            cluster: None,
+        }
+    }
+
+    /// `locations` are the calls on the side of the event that exists.
+    #[must_use]
+    pub fn new_orphan_event(
+        key: String,
+        issue: EventIssue,
+        locations: Vec<LocationDetail>,
+    ) -> Self {
+        let mut files: Vec<PathBuf> = Vec::new();
+        for loc in &locations {
+            if !files.contains(&loc.file) {
+                files.push(loc.file.clone());
+            }
+        }
+
+        Self {
+            smell_type: SmellType::OrphanEvent { key, issue },
+            severity: Severity::Low,
+            files,
+            metrics: Vec::new(),
+            locations,
+            cluster: None,
+        }
+    }
+
+    #[must_use]
+    pub fn new_event_cycle(
+        files: Vec<PathBuf>,
+        keys: Vec<String>,
+        locations: Vec<LocationDetail>,
+    ) -> Self {
+        let count = files.len();
+
+        Self {
+            smell_type: SmellType::EventCycle { keys },
+            severity: Severity::Medium,
+            files,
+            metrics: vec![SmellMetric::CycleLength(count)],
+            locations,
+            cluster: None,
+        }
+    }
+
+    #[must_use]
+    pub fn new_provider_outside_module(
+        path: PathBuf,
+        provider: String,
+        consumer: String,
+        module: String,
+        line: usize,
+    ) -> Self {
+        let location = LocationDetail::new(
+            path.clone(),
+            line,
+            format!("'{consumer}' injects '{provider}', which module '{module}' cannot see"),
+        );
+
+        Self {
+            smell_type: SmellType::ProviderOutsideModule {
+                provider,
+                consumer,
+                module,
+            },
+            severity: Severity::Medium,
+            files: vec![path],
+            metrics: Vec::new(),
+            locations: vec![location],
+            cluster: None,
+        }
+    }
+
+    /// `manifest` is the `package.json` the dependency belongs in and comes
+    /// first in `files`, followed by the files importing the package.
+    #[must_use]
+    pub fn new_npm_dependency(
+        manifest: PathBuf,
+        package: String,
+        issue: DependencyIssue,
+        locations: Vec<LocationDetail>,
+    ) -> Self {
+        let mut files: Vec<PathBuf> = Vec::new();
+        for loc in &locations {
+            if !files.contains(&loc.file) {
+                files.push(loc.file.clone());
+            }
+        }
+        let count = files.len();
+        files.retain(|f| *f != manifest);
+        files.insert(0, manifest);
+
+        Self {
+            smell_type: SmellType::NpmDependency { package, issue },
+            severity: match issue {
+                DependencyIssue::Missing => Severity::High,
+                DependencyIssue::DevOnlyInRuntime => Severity::Medium,
+                DependencyIssue::Unused => Severity::Low,
+            },
+            files,
+            metrics: vec![SmellMetric::FilesCount(count)],
+            locations,
+            cluster: None,
+        }
+    }
+
     #[must_use]
     pub fn new_side_effect_import(path: PathBuf, source: String, line: usize) -> Self {
         Self {
@@ -656,6 +918,22 @@ impl ArchSmell {
         }
     }
 
+    #[must_use]
+    pub fn new_unresolved_import(import: UnresolvedImport) -> Self {
+        let description = format!("Cannot resolve '{}': {}", import.specifier, import.reason);
+        Self {
+            smell_type: SmellType::UnresolvedImport {
+                specifier: import.specifier,
+                reason: import.reason.to_string(),
+            },
+            severity: Severity::Medium,
+            files: vec![import.file.clone()],
+            metrics: Vec::new(),
+            locations: vec![LocationDetail::new(import.file, import.line, description)],
+            cluster: None,
+        }
+    }
+
     #[must_use]
     pub fn new_hub_module(path: PathBuf, fan_in: usize, fan_out: usize, complexity: usize) -> Self {
         Self {
@@ -776,6 +1054,95 @@ impl ArchSmell {
         }
     }
 
+    #[must_use]
+    pub fn new_unused_suppression(
+        path: PathBuf,
+        line: usize,
+        rules: Vec<String>,
+        missing_reason: bool,
+    ) -> Self {
+        let description = if missing_reason {
+            format!("Suppression of {} has no reason", rules.join(", "))
+        } else {
+            format!("Suppression of {} matched no smell", rules.join(", "))
+        };
+
+        Self {
+            smell_type: SmellType::UnusedSuppression {
+                rules,
+                missing_reason,
+            },
+            severity: Severity::Low,
+            files: vec![path.clone()],
+            metrics: Vec::new(),
+            locations: vec![LocationDetail::new(path, line, description)],
+            cluster: None,
+        }
+    }
+
+    #[must_use]
+    pub fn new_long_function(
+        path: PathBuf,
+        name: String,
+        loc: usize,
+        threshold: usize,
+        line: usize,
+        range: CodeRange,
+    ) -> Self {
+        Self {
+            smell_type: SmellType::LongFunction {
+                name: name.clone(),
+                loc,
+            },
+            severity: Severity::Low,
+            files: vec![path.clone()],
+            metrics: vec![SmellMetric::Lines(loc), SmellMetric::Threshold(threshold)],
+            locations: vec![LocationDetail::new(
+                path,
+                line,
+                format!("Function '{name}' has {loc} lines of code"),
+            )
+            .with_range(range)],
+            cluster: None,
+        }
+    }
+
+    #[must_use]
+    pub fn new_large_class(
+        path: PathBuf,
+        name: String,
+        loc: usize,
+        methods: usize,
+        fields: usize,
+        line: usize,
+        range: CodeRange,
+    ) -> Self {
+        Self {
+            smell_type: SmellType::LargeClass {
+                name: name.clone(),
+                loc,
+                methods,
+                fields,
+            },
+            severity: Severity::Medium,
+            files: vec![path.clone()],
+            metrics: vec![
+                SmellMetric::Lines(loc),
+                SmellMetric::MethodCount(methods),
+                SmellMetric::FieldCount(fields),
+            ],
+            locations: vec![LocationDetail::new(
+                path,
+                line,
+                format!(
+                    "Class '{name}' has {loc} lines of code, {methods} methods and {fields} fields"
+                ),
+            )
+            .with_range(range)],
+            cluster: None,
+        }
+    }
+
     #[must_use]
     pub fn new_long_params(
         path: PathBuf,
@@ -888,11 +1255,15 @@ impl ArchSmell {
         }
     }
 
+    /// `functions` names the cloned functions when each location is a whole function.
     #[must_use]
     pub fn new_code_clone(
         locations: Vec<LocationDetail>,
         token_count: usize,
         clone_hash: String,
+        similarity: usize,
+        functions: Vec<String>,
+        extraction_target: Option<String>,
     ) -> Self {
         let files = locations
             .iter()
@@ -905,6 +1276,8 @@ impl ArchSmell {
             smell_type: SmellType::CodeClone {
                 clone_hash,
                 token_count,
+                functions,
+                extraction_target,
             },
             severity: if token_count >= 100 {
                 Severity::High
@@ -917,6 +1290,7 @@ impl ArchSmell {
             metrics: vec![
                 SmellMetric::TokenCount(token_count),
                 SmellMetric::CloneInstances(locations.len()),
+                SmellMetric::Similarity(similarity),
             ],
             locations,
             cluster: None,
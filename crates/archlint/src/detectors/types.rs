@@ -111,6 +111,23 @@ pub enum SmellType {
     ))]
     DeadSymbol { name: String, kind: String },
 
+    /// A class member that is never referenced by its class, subclasses or importers.
+    #[strum_discriminants(strum(
+        to_string = "unused_class_members",
+        message = "Unused Class Member",
+        serialize = "unused_class_member",
+        serialize = "unusedclassmember",
+        props(
+            category = "Global",
+            description = "A class method or field that is never referenced"
+        )
+    ))]
+    UnusedClassMember {
+        class_name: String,
+        member: String,
+        kind: String,
+    },
+
     /// A function with high cyclomatic complexity.
     #[strum_discriminants(strum(
         to_string = "cyclomatic_complexity",
@@ -218,6 +235,59 @@ pub enum SmellType {
     ))]
     TestLeakage { test_file: PathBuf },
 
+    /// A server-only module reachable from a `"use client"` file.
+    #[strum_discriminants(strum(
+        to_string = "server_code_in_client",
+        message = "Server Code in Client",
+        serialize = "servercodeinclient",
+        props(
+            category = "GraphBased",
+            description = "Server-only modules imported, directly or transitively, by client components"
+        )
+    ))]
+    ServerCodeInClient { module: PathBuf, package: String },
+
+    /// Production code that is reachable only from test files.
+    #[strum_discriminants(strum(
+        to_string = "test_only_code",
+        message = "Test-Only Code",
+        serialize = "testonlycode",
+        serialize = "test_only",
+        props(
+            category = "Global",
+            description = "Production code that is reachable only from test files"
+        )
+    ))]
+    TestOnlyCode { symbol: Option<String> },
+
+    /// An `archlint-disable` comment that suppresses no smell.
+    #[strum_discriminants(strum(
+        to_string = "unused_suppression",
+        message = "Unused Suppression",
+        serialize = "unusedsuppression",
+        props(
+            category = "Global",
+            description = "An archlint-disable comment that suppresses no smell"
+        )
+    ))]
+    UnusedSuppression {
+        rules: Vec<String>,
+        missing_reason: bool,
+    },
+
+    /// An event key that is produced but never consumed, or the reverse.
+    #[strum_discriminants(strum(
+        to_string = "orphan_events",
+        message = "Orphan Event",
+        serialize = "orphan_event",
+        serialize = "orphanevent",
+        props(
+            category = "Global",
+            description = "An event, topic or action type that is emitted but never handled, or the reverse"
+        )
+    ))]
+    OrphanEvent { key: String, issue: EventIssue },
+
     /// A dependency that violates defined architectural layers.
     #[strum_discriminants(strum(
         to_string = "layer_violation",
@@ -282,6 +352,18 @@ pub enum SmellType {
     ))]
     SideEffectImport,
 
+    /// A local import that does not resolve to a file.
+    #[strum_discriminants(strum(
+        to_string = "unresolved_import",
+        message = "Unresolved Import",
+        serialize = "unresolvedimport",
+        props(
+            category = "ImportBased",
+            description = "A relative or aliased import that does not resolve to a file of the project"
+        )
+    ))]
+    UnresolvedImport { specifier: String, reason: String },
+
     /// A module that acts as a central hub for many other modules.
     #[strum_discriminants(strum(
         to_string = "hub_module",
@@ -345,6 +427,64 @@ pub enum SmellType {
     ))]
     PackageCycle { packages: Vec<String> },
 
+    /// Dependency injection modules importing each other.
+    #[strum_discriminants(strum(
+        to_string = "circular_module_imports",
+        message = "Circular Module Import",
+        serialize = "circular_module_import",
+        serialize = "circularmoduleimport",
+        props(
+            category = "Global",
+            description = "NestJS or Angular modules that import each other"
+        )
+    ))]
+    CircularModuleImport { modules: Vec<String> },
+
+    /// A provider injected into a class whose modules neither provide nor import it.
+    #[strum_discriminants(strum(
+        to_string = "provider_outside_module",
+        message = "Provider Outside Module",
+        serialize = "provideroutsidemodule",
+        props(
+            category = "Global",
+            description = "A provider injected outside the modules that provide or export it"
+        )
+    ))]
+    ProviderOutsideModule {
+        provider: String,
+        consumer: String,
+        module: String,
+    },
+
+    /// Files that depend on each other once event producers are linked to their consumers.
+    #[strum_discriminants(strum(
+        to_string = "event_cycles",
+        message = "Event Cycle",
+        serialize = "event_cycle",
+        serialize = "eventcycle",
+        props(
+            category = "Global",
+            description = "A dependency cycle that goes through at least one event"
+        )
+    ))]
+    EventCycle { keys: Vec<String> },
+
+    /// A mismatch between the imports of a workspace package and its `package.json` manifest.
+    #[strum_discriminants(strum(
+        to_string = "npm_dependencies",
+        message = "npm Dependency Issue",
+        serialize = "npm_dependency",
+        serialize = "npmdependencies",
+        props(
+            category = "Global",
+            description = "Unused, undeclared, or dev-only npm dependencies used by a workspace package"
+        )
+    ))]
+    NpmDependency {
+        package: String,
+        issue: DependencyIssue,
+    },
+
     /// A shared global state that is modified from multiple locations.
     #[strum_discriminants(strum(
         to_string = "shared_mutable_state",
@@ -391,6 +531,35 @@ pub enum SmellType {
         name: String,
     },
 
+    /// A function with too many lines of code.
+    #[strum_discriminants(strum(
+        to_string = "long_function",
+        message = "Long Function",
+        serialize = "longfunction",
+        props(
+            category = "FileLocal",
+            description = "A function with too many lines of code"
+        )
+    ))]
+    LongFunction { name: String, loc: usize },
+
+    /// A class with too many lines of code, methods or fields.
+    #[strum_discriminants(strum(
+        to_string = "large_class",
+        message = "Large Class",
+        serialize = "largeclass",
+        props(
+            category = "FileLocal",
+            description = "A class with too many lines of code, methods or fields"
+        )
+    ))]
+    LargeClass {
+        name: String,
+        loc: usize,
+        methods: usize,
+        fields: usize,
+    },
+
     /// Excessive use of primitive types instead of domain-specific objects.
     #[strum_discriminants(strum(
         to_string = "primitive_obsession",
@@ -473,6 +642,12 @@ pub enum SmellType {
     CodeClone {
         clone_hash: String,
         token_count: usize,
+        /// Names of the cloned functions, empty when the clone is a block of code.
+        #[serde(default)]
+        functions: Vec<String>,
+        /// Folder where the shared implementation of the functions could live.
+        #[serde(default)]
+        extraction_target: Option<String>,
     },
 
     /// Unknown smell type encountered during deserialization.
@@ -517,6 +692,37 @@ impl SmellType {
     }
 }
 
+/// Kind of mismatch reported by the `npm_dependencies` detector.
+#[derive(
+    Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord, Display,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum DependencyIssue {
+    /// Declared in the manifest but never imported or referenced.
+    #[strum(to_string = "unused")]
+    Unused,
+    /// Imported but not declared in the owning package manifest.
+    #[strum(to_string = "missing")]
+    Missing,
+    /// Declared only in `devDependencies` but imported from runtime code.
+    #[strum(to_string = "dev-only in runtime")]
+    DevOnlyInRuntime,
+}
+
+/// Side missing from an event reported by the `orphan_events` detector.
+#[derive(
+    Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord, Display,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum EventIssue {
+    /// Produced, but no call consumes it.
+    #[strum(to_string = "never consumed")]
+    NeverConsumed,
+    /// Consumed, but no call produces it.
+    #[strum(to_string = "never produced")]
+    NeverProduced,
+}
+
 impl From<&SnapshotSmell> for SmellType {
     fn from(smell: &SnapshotSmell) -> Self {
         if let Some(details) = &smell.details {
@@ -752,6 +958,8 @@ pub enum SmellMetric {
     Abstractness(f64),
     TokenCount(usize),
     CloneInstances(usize),
+    /// Similarity of near-miss clones, in percent.
+    Similarity(usize),
     ParameterCount(usize),
     PrimitiveCount(usize),
     FilesCount(usize),
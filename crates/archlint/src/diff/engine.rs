@@ -1,8 +1,9 @@
 use super::fuzzy::FuzzyMatcher;
 use super::metrics::MetricComparator;
 use super::types::{
-    DiffResult, DiffSummary, Improvement, ImprovementType, Regression, RegressionType,
+    DebtDiff, DiffResult, DiffSummary, Improvement, ImprovementType, Regression, RegressionType,
 };
+use crate::debt::DEBT_METRIC;
 use crate::snapshot::{Snapshot, SnapshotSmell};
 use log::debug;
 use std::collections::{HashMap, HashSet};
@@ -89,6 +90,15 @@ impl DiffEngine {
             &mut improvements,
         );
 
+        let debt = Self::compute_debt_diff(
+            baseline,
+            current,
+            &baseline_map,
+            &current_map,
+            &regressions,
+            &improvements,
+        );
+
         Self::sort_results(&mut regressions, &mut improvements);
 
         let summary = Self::build_summary(&regressions, &improvements);
@@ -100,7 +110,62 @@ impl DiffEngine {
             summary,
             baseline_commit: baseline.commit.clone(),
             current_commit: current.commit.clone(),
+            debt,
+            gates: None,
+        }
+    }
+
+    /// Debt added and removed between snapshots.
+    ///
+    /// New smells add their full debt and fixed smells remove it; smells present
+    /// in both contribute the change in their estimate. Smells that only shifted
+    /// location are treated as unchanged.
+    fn compute_debt_diff(
+        baseline: &Snapshot,
+        current: &Snapshot,
+        baseline_map: &SmellMap<'_>,
+        current_map: &SmellMap<'_>,
+        regressions: &[Regression],
+        improvements: &[Improvement],
+    ) -> Option<DebtDiff> {
+        let (baseline_debt, current_debt) = (baseline.debt.as_ref()?, current.debt.as_ref()?);
+        let debt_of = |smell: &SnapshotSmell| {
+            smell
+                .metrics
+                .get(DEBT_METRIC)
+                .map_or(0.0, crate::snapshot::MetricValue::as_f64)
+        };
+
+        let mut added: f64 = regressions
+            .iter()
+            .filter(|r| matches!(r.regression_type, RegressionType::NewSmell))
+            .map(|r| debt_of(&r.smell))
+            .sum();
+        let mut removed: f64 = improvements
+            .iter()
+            .filter(|i| matches!(i.improvement_type, ImprovementType::Fixed))
+            .filter_map(|i| baseline_map.get(i.id.as_str()))
+            .map(|smell| debt_of(smell))
+            .sum();
+
+        for (id, current_smell) in current_map {
+            if let Some(baseline_smell) = baseline_map.get(id) {
+                let delta = debt_of(current_smell) - debt_of(baseline_smell);
+                if delta > 0.0 {
+                    added += delta;
+                } else {
+                    removed -= delta;
+                }
+            }
         }
+
+        Some(DebtDiff {
+            baseline: baseline_debt.total_minutes,
+            current: current_debt.total_minutes,
+            added,
+            removed,
+            net: current_debt.total_minutes - baseline_debt.total_minutes,
+        })
     }
 
     fn build_smell_maps<'a>(
@@ -360,6 +425,7 @@ mod tests {
             smells,
             summary: SnapshotSummary::default(),
             grade: "B".to_string(),
+            debt: None,
         }
     }
 
@@ -444,4 +510,48 @@ mod tests {
         assert!(result.regressions.is_empty());
         assert!(result.improvements.is_empty());
     }
+
+    #[test]
+    fn test_debt_diff_counts_new_fixed_and_changed_smells() {
+        let with_debt = |id: &str, minutes: f64| {
+            let mut smell = make_smell(id, "LongFunction", "Low");
+            smell
+                .metrics
+                .insert(DEBT_METRIC.to_string(), MetricValue::Float(minutes));
+            smell
+        };
+        let with_total = |smells: Vec<SnapshotSmell>, total: f64| {
+            let mut snapshot = make_snapshot(smells);
+            snapshot.debt = Some(crate::debt::DebtSummary {
+                total_minutes: total,
+                ..Default::default()
+            });
+            snapshot
+        };
+
+        let baseline = with_total(
+            vec![with_debt("fixed", 30.0), with_debt("kept", 40.0)],
+            70.0,
+        );
+        let current = with_total(vec![with_debt("kept", 50.0), with_debt("new", 15.0)], 65.0);
+
+        let debt = DiffEngine::default()
+            .diff(&baseline, &current)
+            .debt
+            .unwrap();
+        assert_eq!(
+            debt,
+            DebtDiff {
+                baseline: 70.0,
+                current: 65.0,
+                added: 25.0,
+                removed: 30.0,
+                net: -5.0,
+            }
+        );
+
+        // Snapshots from versions without debt estimates produce no debt diff
+        let result = DiffEngine::default().diff(&make_snapshot(vec![]), &current);
+        assert!(result.debt.is_none());
+    }
 }
@@ -167,7 +167,13 @@ impl FuzzyMatcher {
                 SmellType::HighCyclomaticComplexity { name, .. } => Some(name.clone()),
                 SmellType::HighCognitiveComplexity { name, .. } => Some(name.clone()),
                 SmellType::DeadSymbol { name, .. } => Some(name.clone()),
-                SmellType::LongParameterList { name, .. } => Some(name.clone()),
+                SmellType::TestOnlyCode { symbol } => symbol.clone(),
+                SmellType::UnusedClassMember {
+                    class_name, member, ..
+                } => Some(format!("{class_name}.{member}")),
+                SmellType::LongParameterList { name, .. }
+                | SmellType::LongFunction { name, .. }
+                | SmellType::LargeClass { name, .. } => Some(name.clone()),
                 SmellType::PrimitiveObsession { name, .. } => Some(name.clone()),
                 SmellType::SharedMutableState { symbol } => Some(symbol.clone()),
                 SmellType::OrphanType { name } => Some(name.clone()),
@@ -184,15 +190,23 @@ impl FuzzyMatcher {
                 | SmellType::ShotgunSurgery
                 | SmellType::HubDependency { .. }
                 | SmellType::TestLeakage { .. }
+                | SmellType::ServerCodeInClient { .. }
+                | SmellType::UnusedSuppression { .. }
                 | SmellType::LayerViolation { .. }
                 | SmellType::SdpViolation
                 | SmellType::BarrelFileAbuse
                 | SmellType::VendorCoupling { .. }
                 | SmellType::SideEffectImport
+                | SmellType::UnresolvedImport { .. }
                 | SmellType::HubModule
                 | SmellType::ScatteredModule { .. }
                 | SmellType::HighCoupling { .. }
                 | SmellType::PackageCycle { .. }
+                | SmellType::CircularModuleImport { .. }
+                | SmellType::ProviderOutsideModule { .. }
+                | SmellType::OrphanEvent { .. }
+                | SmellType::EventCycle { .. }
+                | SmellType::NpmDependency { .. }
                 | SmellType::CircularTypeDependency
                 | SmellType::AbstractnessViolation
                 | SmellType::ScatteredConfiguration { .. }
@@ -1,3 +1,4 @@
+use crate::gates::GateReport;
 use crate::snapshot::SnapshotSmell;
 use serde::{Deserialize, Serialize};
 
@@ -21,6 +22,28 @@ pub struct DiffResult {
 
     /// Current commit (if available)
     pub current_commit: Option<String>,
+
+    /// Technical debt change, when both snapshots carry debt estimates
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub debt: Option<DebtDiff>,
+
+    /// Quality gate results, if any gates are configured
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gates: Option<GateReport>,
+}
+
+/// Technical debt change between two snapshots. All values are in minutes.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DebtDiff {
+    pub baseline: f64,
+    pub current: f64,
+    /// Debt of new smells plus growth of existing ones
+    pub added: f64,
+    /// Debt of fixed smells plus reduction of existing ones
+    pub removed: f64,
+    /// `current - baseline`
+    pub net: f64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
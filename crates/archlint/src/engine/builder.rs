@@ -6,13 +6,13 @@ use crate::graph::{DependencyGraph, EdgeData};
 #[cfg(not(feature = "cli"))]
 use crate::no_cli_mocks::console::style;
 use crate::parser::FileSymbols;
-use crate::resolver::PathResolver;
+use crate::resolver::{ImportDiagnostics, PathResolver};
 use crate::Result;
 #[cfg(feature = "cli")]
 use console::style;
 use log::info;
 use rayon::prelude::*;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::path::{Path, PathBuf};
 
 pub struct EngineBuilder<'a> {
@@ -34,6 +34,19 @@ impl<'a> EngineBuilder<'a> {
         runtime_files: &HashSet<PathBuf>,
         file_symbols: &HashMap<PathBuf, FileSymbols>,
         use_progress: bool,
+    ) -> Result<DependencyGraph> {
+        let resolver = PathResolver::new(self.project_root, self.config);
+        self.build_graph_with(&resolver, runtime_files, file_symbols, use_progress)
+    }
+
+    /// Build the graph resolving imports with `resolver`, whose deleted files
+    /// become nodes of the graph.
+    pub fn build_graph_with(
+        &self,
+        resolver: &PathResolver,
+        runtime_files: &HashSet<PathBuf>,
+        file_symbols: &HashMap<PathBuf, FileSymbols>,
+        use_progress: bool,
     ) -> Result<DependencyGraph> {
         info!(
             "{}  Building dependency graph...",
@@ -53,7 +66,6 @@ impl<'a> EngineBuilder<'a> {
             )
         });
 
-        let resolver = PathResolver::new(self.project_root, self.config);
         let mut resolved_count = 0;
 
         for file in runtime_files {
@@ -65,7 +77,7 @@ impl<'a> EngineBuilder<'a> {
 
             resolved_count += self.process_file_dependencies(
                 file,
-                &resolver,
+                resolver,
                 &mut graph,
                 runtime_files,
                 file_symbols,
@@ -106,7 +118,7 @@ impl<'a> EngineBuilder<'a> {
 
         for import in &symbols.imports {
             if let Some(resolved) = resolver.resolve(import.source.as_str(), file)? {
-                if runtime_files.contains(&resolved) {
+                if runtime_files.contains(&resolved) || resolver.is_deleted_file(&resolved) {
                     let to_node = graph.add_file(&resolved);
                     let edge_data = EdgeData::with_all(
                         import.line,
@@ -121,12 +133,14 @@ impl<'a> EngineBuilder<'a> {
         Ok(count)
     }
 
+    /// Rewrite import and re-export sources to resolved paths, and collect the
+    /// imports that did not resolve.
     #[must_use]
     pub fn resolve_symbols(
         &self,
         file_symbols: HashMap<PathBuf, FileSymbols>,
         use_progress: bool,
-    ) -> HashMap<PathBuf, FileSymbols> {
+    ) -> (HashMap<PathBuf, FileSymbols>, ImportDiagnostics) {
         info!("{} Resolving symbols...", style("🔗").cyan().bold());
         let resolver = PathResolver::new(self.project_root, self.config);
 
@@ -153,7 +167,53 @@ impl<'a> EngineBuilder<'a> {
         if let Some(pb) = pb {
             pb.finish_and_clear();
         }
-        resolved_file_symbols
+
+        let diagnostics = ImportDiagnostics::collect(&resolved_file_symbols, &resolver);
+        self.report_unresolved(&diagnostics, &resolver);
+        (resolved_file_symbols, diagnostics)
+    }
+
+    /// Log the share of internal imports that resolved and the ones that did
+    /// not, grouped by the tsconfig governing the importing file, so that
+    /// misconfigured `paths` stand out per project.
+    fn report_unresolved(&self, diagnostics: &ImportDiagnostics, resolver: &PathResolver) {
+        let stats = diagnostics.stats;
+
+        info!(
+            "   {} Imports: {:.1}% of internal imports resolved ({}/{})",
+            style("↳").dim(),
+            stats.resolved_percent(),
+            stats.resolved_imports,
+            stats.internal_imports
+        );
+
+        let mut unresolved: BTreeMap<Option<PathBuf>, Vec<String>> = BTreeMap::new();
+        for import in &diagnostics.unresolved {
+            unresolved
+                .entry(resolver.governing_tsconfig(&import.file))
+                .or_default()
+                .push(format!(
+                    "'{}' in {}:{}: {}",
+                    import.specifier,
+                    import.file.display(),
+                    import.line,
+                    import.reason
+                ));
+        }
+
+        for (tsconfig, imports) in unresolved {
+            let project = tsconfig.as_deref().map_or_else(
+                || "outside any tsconfig".to_string(),
+                |path| {
+                    let path = path.strip_prefix(self.project_root).unwrap_or(path);
+                    format!("in {}", path.display())
+                },
+            );
+            log::warn!("{} unresolved import(s) {project}", imports.len());
+            for import in imports {
+                log::debug!("  {import}");
+            }
+        }
     }
 
     fn resolve_single_file_symbols(
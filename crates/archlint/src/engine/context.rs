@@ -3,6 +3,7 @@ use crate::framework::presets::FrameworkPreset;
 use crate::framework::Framework;
 use crate::graph::DependencyGraph;
 use crate::parser::{FileIgnoredLines, FileSymbols, FunctionComplexity};
+use crate::resolver::ImportDiagnostics;
 use crate::rule_resolver::ResolvedRuleConfig;
 use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
@@ -30,6 +31,8 @@ pub struct AnalysisContext {
     pub file_metrics: Arc<HashMap<PathBuf, FileMetrics>>,
     /// Map of line numbers to ignore rules for each file.
     pub ignored_lines: Arc<FileIgnoredLines>,
+    /// Local imports that did not resolve, collected while resolving symbols.
+    pub import_diagnostics: Arc<ImportDiagnostics>,
     /// Map of file paths to their churn count (number of commits).
     pub churn_map: HashMap<PathBuf, usize>,
     /// Global configuration for the analysis.
@@ -103,6 +106,7 @@ impl AnalysisContext {
             function_complexity: Arc::new(HashMap::new()),
             file_metrics: Arc::new(HashMap::new()),
             ignored_lines: Arc::new(FileIgnoredLines::default()),
+            import_diagnostics: Arc::new(ImportDiagnostics::default()),
             churn_map: HashMap::new(),
             config: Config::default(),
             script_entry_points: HashSet::new(),
@@ -8,6 +8,7 @@ pub mod context;
 pub mod detector_runner;
 pub mod progress;
 pub mod runner;
+pub mod suppressions;
 
 pub use builder::EngineBuilder;
 pub use context::AnalysisContext;
@@ -8,26 +8,29 @@ use crate::engine::detector_runner::{apply_arg_overrides, DetectorRunner};
 use crate::engine::progress::{
     create_progress_bar, default_progress_chars, default_spinner_template,
 };
+use crate::engine::suppressions::{apply_suppressions, SuppressionPass};
 use crate::engine::AnalysisContext;
 use crate::framework::detector::FrameworkDetector;
 use crate::framework::preset_loader::PresetLoader;
 use crate::framework::presets::FrameworkPreset;
 use crate::framework::Framework;
 use crate::git_cache::GitHistoryCache;
+use crate::graph::DependencyGraph;
 #[cfg(not(feature = "cli"))]
 use crate::no_cli_mocks::console::{style, Term};
 use crate::package_json;
 use crate::parser::{FileIgnoredLines, ImportParser, ParsedFile, ParserConfig};
 use crate::project_root::detect_project_root;
 use crate::report::{AnalysisReport, AnalysisReportBuilder};
+use crate::resolver::PathResolver;
 use crate::scanner::FileScanner;
 use crate::Result;
 #[cfg(feature = "cli")]
 use console::{style, Term};
-use log::{debug, info};
+use log::{debug, info, warn};
 use rayon::prelude::*;
 use std::collections::{HashMap, HashSet};
-use std::path::{Path, PathBuf};
+use std::path::PathBuf;
 use std::sync::Arc;
 
 type ParsedData = (
@@ -42,6 +45,8 @@ pub struct AnalysisEngine {
     pub config: Config,
     pub project_root: PathBuf,
     pub target_path: PathBuf,
+    /// Collect per-export symbol usages even when no active rule needs them.
+    pub collect_symbol_usages: bool,
 }
 
 impl AnalysisEngine {
@@ -60,6 +65,7 @@ impl AnalysisEngine {
             config,
             project_root,
             target_path,
+            collect_symbol_usages: false,
         })
     }
 
@@ -86,9 +92,10 @@ impl AnalysisEngine {
 
         let detector_runner = DetectorRunner::new(&self.args);
         let active_ids = detector_runner.get_active_detectors(&final_config, &presets);
-        let parser_config = ParserConfig::from_active_detectors(&active_ids);
+        let mut parser_config = ParserConfig::from_active_detectors(&active_ids, &final_config);
+        parser_config.collect_used_symbols |= self.collect_symbol_usages;
 
-        let mut cache = self.load_cache()?;
+        let mut cache = self.load_cache(&parser_config);
         let parsed_files =
             self.parse_files(&files, &parser_config, use_progress, cache.as_ref())?;
         self.update_cache(&mut cache, &parsed_files)?;
@@ -102,7 +109,13 @@ impl AnalysisEngine {
         let builder = EngineBuilder::new(&self.project_root, &final_config);
         let graph = builder.build_graph(&runtime_files, &file_symbols, use_progress)?;
         let churn_map = self.get_churn_map(&files, use_progress, &mut cache);
-        let resolved_file_symbols = builder.resolve_symbols(file_symbols, use_progress);
+        // Release the cache before detection so other archlint processes can use it
+        if let Some(c) = cache.take() {
+            debug!("Saving cache...");
+            c.save()?;
+        }
+        let (resolved_file_symbols, import_diagnostics) =
+            builder.resolve_symbols(file_symbols, use_progress);
 
         let pkg_config = package_json::PackageJsonParser::parse(&self.project_root)?;
 
@@ -113,6 +126,7 @@ impl AnalysisEngine {
             function_complexity: Arc::new(function_complexity),
             file_metrics: Arc::new(file_metrics),
             ignored_lines: Arc::new(ignored_lines),
+            import_diagnostics: Arc::new(import_diagnostics),
             churn_map,
             config: final_config.clone(),
             script_entry_points: pkg_config.entry_points,
@@ -123,23 +137,55 @@ impl AnalysisEngine {
 
         let all_smells = detector_runner.run_detectors(&ctx, use_progress, &presets)?;
 
-        let report = self.create_report(ctx, all_smells, files.len(), presets)?;
+        let report = self.create_report(ctx, all_smells, &active_ids, files.len(), presets)?;
 
+        Ok(report)
+    }
+
+    /// Build the dependency graph of the project without running detectors.
+    ///
+    /// Imports of `deleted_files` still resolve, so their importers stay
+    /// connected to them.
+    pub fn dependency_graph(&self, deleted_files: &[PathBuf]) -> Result<DependencyGraph> {
+        let files = self.discover_files()?;
+        let (presets, _) = self.load_presets_and_detect()?;
+        let final_config = self.apply_presets(&presets);
+
+        // Imports are all the graph needs
+        let parser_config = ParserConfig::minimal();
+        let mut cache = self.load_cache(&parser_config);
+        let parsed_files = self.parse_files(&files, &parser_config, false, cache.as_ref())?;
+        self.update_cache(&mut cache, &parsed_files)?;
         if let Some(c) = cache {
-            debug!("Saving cache...");
             c.save()?;
         }
 
-        Ok(report)
+        let (file_symbols, ..) = self.extract_parsed_data(parsed_files);
+        let runtime_files = self.get_runtime_files(&file_symbols);
+        let resolver = PathResolver::new(&self.project_root, &final_config)
+            .with_deleted_files(deleted_files.iter().cloned());
+        EngineBuilder::new(&self.project_root, &final_config).build_graph_with(
+            &resolver,
+            &runtime_files,
+            &file_symbols,
+            false,
+        )
     }
 
     fn create_report(
         &self,
         ctx: AnalysisContext,
         all_smells: Vec<detectors::ArchSmell>,
+        active_ids: &HashSet<String>,
         files_len: usize,
         presets: Vec<FrameworkPreset>,
     ) -> Result<AnalysisReport> {
+        let SuppressionPass {
+            smells: filtered_smells,
+            suppressed,
+        } = apply_suppressions(&ctx, all_smells, active_ids);
+
+        let resolution_stats = ctx.import_diagnostics.stats;
         let AnalysisContext {
             graph,
             file_symbols,
@@ -150,21 +196,9 @@ impl AnalysisEngine {
             ..
         } = ctx;
 
-        let filtered_smells: Vec<_> = all_smells
-            .into_iter()
-            .filter(|smell| {
-                // Check if smell is ignored by inline comments
-                if self.is_smell_ignored_by_comments(smell, &ignored_lines) {
-                    return false;
-                }
-
-                // Keep the smell if at least one of the files it's associated with is NOT ignored via config
-                smell.files.is_empty() || smell.files.iter().any(|f| !self.is_file_ignored(f))
-            })
-            .collect();
-
         let mut report = AnalysisReportBuilder::new()
             .with_smells(filtered_smells)
+            .with_suppressed(suppressed)
             .with_graph(Some(
                 Arc::try_unwrap(graph).unwrap_or_else(|arc| (*arc).clone()),
             ))
@@ -178,6 +212,7 @@ impl AnalysisEngine {
             .with_presets(presets)
             .with_config(self.config.clone())
             .with_files_analyzed(files_len)
+            .with_import_resolution(resolution_stats)
             .build();
 
         if let Some(ref min_sev) = self.args.min_severity {
@@ -191,6 +226,8 @@ impl AnalysisEngine {
         }
 
         report.apply_severity_config(&self.config.scoring);
+        report.compute_debt(&self.project_root);
+        report.evaluate_gates(&self.project_root);
 
         Ok(report)
     }
@@ -334,21 +371,6 @@ impl AnalysisEngine {
         Ok(files)
     }
 
-    fn is_file_ignored(&self, path: &Path) -> bool {
-        if self.config.ignore.is_empty() {
-            return false;
-        }
-        let rel_path = path
-            .strip_prefix(&self.project_root)
-            .unwrap_or(path)
-            .to_string_lossy();
-        self.config.ignore.iter().any(|p| {
-            glob::Pattern::new(p)
-                .map(|pattern| pattern.matches(&rel_path))
-                .unwrap_or(false)
-        })
-    }
-
     #[must_use]
     pub fn apply_presets(&self, presets: &[FrameworkPreset]) -> Config {
         let mut final_config = self.config.clone();
@@ -358,12 +380,17 @@ impl AnalysisEngine {
         final_config
     }
 
-    fn load_cache(&self) -> Result<Option<AnalysisCache>> {
+    fn load_cache(&self, parser_config: &ParserConfig) -> Option<AnalysisCache> {
         if self.args.no_cache {
-            Ok(None)
-        } else {
-            debug!("Loading cache...");
-            Ok(Some(AnalysisCache::load(&self.project_root, &self.config)?))
+            return None;
+        }
+        debug!("Loading cache...");
+        match AnalysisCache::load(&self.project_root, &self.config, parser_config) {
+            Ok(cache) => Some(cache),
+            Err(e) => {
+                warn!("Cache unavailable, parsing all files: {e}");
+                None
+            }
         }
     }
 
@@ -385,30 +412,38 @@ impl AnalysisEngine {
             None
         };
 
+        // Look up every file at once so the cache is released before parsing
+        let mut parsed_files = match cache {
+            Some(c) => {
+                let hashes = files
+                    .par_iter()
+                    .map(|file| Ok((file.as_path(), file_content_hash(file)?)))
+                    .collect::<Result<Vec<_>>>()?;
+                c.get_many(hashes.iter().map(|(file, hash)| (*file, hash.as_str())))
+            }
+            None => HashMap::new(),
+        };
+        if let Some(ref pb) = pb {
+            pb.inc(parsed_files.len() as u64);
+        }
+
         let result = files
             .par_iter()
+            .filter(|file| !parsed_files.contains_key(*file))
             .map(|file| {
-                let hash = file_content_hash(file)?;
-                if let Some(c) = cache {
-                    if let Some(cached) = c.get(file, &hash) {
-                        if let Some(ref pb) = pb {
-                            pb.inc(1);
-                        }
-                        return Ok((file.clone(), (*cached).clone()));
-                    }
-                }
                 let parsed = parser.parse_file_with_config(file, config)?;
                 if let Some(ref pb) = pb {
                     pb.inc(1);
                 }
                 Ok((file.clone(), parsed))
             })
-            .collect::<Result<HashMap<_, _>>>();
+            .collect::<Result<Vec<_>>>();
 
         if let Some(pb) = pb {
             pb.finish_and_clear();
         }
-        result
+        parsed_files.extend(result?);
+        Ok(parsed_files)
     }
 
     fn update_cache(
@@ -419,7 +454,7 @@ impl AnalysisEngine {
         if let Some(ref mut c) = cache {
             for (file, parsed) in parsed_files {
                 let hash = file_content_hash(file)?;
-                c.insert(file.clone(), hash, (*parsed).clone());
+                c.insert(file, &hash, parsed.clone());
             }
         }
         Ok(())
@@ -444,53 +479,6 @@ impl AnalysisEngine {
         (symbols, complexity, metrics, ignored)
     }
 
-    fn is_smell_ignored_by_comments(
-        &self,
-        smell: &detectors::ArchSmell,
-        ignored_lines: &FileIgnoredLines,
-    ) -> bool {
-        let rule_id = smell.smell_type.category().to_id();
-
-        for loc in &smell.locations {
-            if self.is_ignored(&loc.file, loc.line, rule_id, ignored_lines) {
-                return true;
-            }
-        }
-
-        if smell.locations.is_empty() {
-            for file in &smell.files {
-                if self.is_ignored(file, 0, rule_id, ignored_lines) {
-                    return true;
-                }
-            }
-        }
-
-        false
-    }
-
-    fn is_ignored(
-        &self,
-        file: &PathBuf,
-        line: usize,
-        rule_id: &str,
-        ignored_lines: &FileIgnoredLines,
-    ) -> bool {
-        let file_ignores = match ignored_lines.get(file) {
-            Some(ignores) => ignores,
-            None => return false,
-        };
-
-        if line == 0 {
-            return file_ignores
-                .get(&0)
-                .is_some_and(|rules| rules.contains("*") || rules.contains(rule_id));
-        }
-
-        file_ignores
-            .get(&line)
-            .is_some_and(|rules| rules.contains("*") || rules.contains(rule_id))
-    }
-
     fn get_runtime_files(
         &self,
         symbols: &HashMap<PathBuf, crate::parser::FileSymbols>,
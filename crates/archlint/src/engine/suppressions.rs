@@ -0,0 +1,130 @@
+//! Inline suppressions and config ignores applied to detector results.
+
+use crate::config::Config;
+use crate::detectors::unused_suppression::UnusedSuppressionDetector;
+use crate::detectors::ArchSmell;
+use crate::engine::AnalysisContext;
+use crate::parser::FileIgnoredLines;
+use crate::report::Suppression;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+/// Smells left after suppressions, with the ones hidden by inline comments.
+pub struct SuppressionPass {
+    pub smells: Vec<ArchSmell>,
+    /// Kept when `suppressions.include_suppressed` is set.
+    pub suppressed: Vec<(ArchSmell, Suppression)>,
+}
+
+/// Drops smells hidden by inline comments or only found in files ignored by
+/// the config, then reports unused comments when `unused_suppression` is active.
+///
+/// Needs every smell of the files whose comments should be checked, since a
+/// comment is unused when none of them hits it.
+#[must_use]
+pub fn apply_suppressions(
+    ctx: &AnalysisContext,
+    smells: Vec<ArchSmell>,
+    active_ids: &HashSet<String>,
+) -> SuppressionPass {
+    let settings = &ctx.config.suppressions;
+    let mut used_suppressions = HashSet::new();
+    let mut suppressed = Vec::new();
+    let mut filtered_smells = Vec::new();
+
+    for smell in smells {
+        let suppressions = find_suppressions(&smell, &ctx.ignored_lines, settings.require_reason);
+        if let Some(first) = suppressions.first() {
+            used_suppressions.extend(suppressions.iter().map(|s| (s.file.clone(), s.line)));
+            if settings.include_suppressed {
+                suppressed.push((smell, first.clone()));
+            }
+            continue;
+        }
+
+        // Keep the smell if at least one of the files it's associated with is NOT ignored via config
+        if smell.files.is_empty()
+            || smell
+                .files
+                .iter()
+                .any(|f| !is_file_ignored(&ctx.config, &ctx.project_path, f))
+        {
+            filtered_smells.push(smell);
+        }
+    }
+
+    if active_ids.contains("unused_suppression") {
+        filtered_smells.extend(UnusedSuppressionDetector::find_unused(
+            ctx,
+            &used_suppressions,
+            active_ids,
+            settings.require_reason,
+        ));
+    }
+
+    SuppressionPass {
+        smells: filtered_smells,
+        suppressed,
+    }
+}
+
+/// Whether `path` matches an `ignore` pattern of the config.
+fn is_file_ignored(config: &Config, project_root: &Path, path: &Path) -> bool {
+    if config.ignore.is_empty() {
+        return false;
+    }
+    let rel_path = path
+        .strip_prefix(project_root)
+        .unwrap_or(path)
+        .to_string_lossy();
+    config
+        .ignore
+        .iter()
+        .any(|p| glob::Pattern::new(p).is_ok_and(|pattern| pattern.matches(&rel_path)))
+}
+
+/// Inline comments suppressing `smell`, in location order; the first one is
+/// reported as the suppression. With `require_reason`, comments without a
+/// reason are not honored.
+fn find_suppressions(
+    smell: &ArchSmell,
+    ignored_lines: &FileIgnoredLines,
+    require_reason: bool,
+) -> Vec<Suppression> {
+    let rule_id = smell.smell_type.category().to_id();
+    let lookup = |file: &PathBuf, line: usize| {
+        let Some(rules) = ignored_lines.get(file).and_then(|lines| lines.get(&line)) else {
+            return Vec::new();
+        };
+        // A rule-specific comment takes precedence over `*` on the same line,
+        // then comments that give a reason
+        let mut sources: Vec<_> = rules
+            .iter()
+            .filter(|(rule, _)| *rule == rule_id || *rule == "*")
+            .collect();
+        sources.sort_by_key(|(rule, source)| (*rule == "*", source.reason.is_none()));
+        sources
+            .into_iter()
+            .filter(|(_, source)| !require_reason || source.reason.is_some())
+            .map(|(_, source)| Suppression {
+                file: file.clone(),
+                line: source.line,
+                reason: source.reason.clone(),
+            })
+            .collect::<Vec<_>>()
+    };
+
+    if smell.locations.is_empty() {
+        smell
+            .files
+            .iter()
+            .flat_map(|file| lookup(file, 0))
+            .collect()
+    } else {
+        smell
+            .locations
+            .iter()
+            .flat_map(|loc| lookup(&loc.file, loc.line))
+            .collect()
+    }
+}
@@ -38,6 +38,9 @@ pub enum AnalysisError {
     #[error("Invalid configuration: {0}")]
     InvalidConfig(String),
 
+    #[error("Not found: {0}")]
+    NotFound(String),
+
     #[error("Snapshot error: {0}")]
     Snapshot(#[from] crate::snapshot::SnapshotError),
 
@@ -0,0 +1,231 @@
+//! Coupling through string keys such as event names, topics and action types.
+//!
+//! A key connects a producer (`emit('order.created')`) with its consumers
+//! (`on('order.created')`) without an import between their files.
+//!
+//! Call sites are matched against the `events.patterns` of the configuration.
+//! Every file producing a key gets a synthetic edge to every file consuming it,
+//! which together with the import graph reveals cycles that only exist at
+//! runtime.
+//!
+//! Requires symbols parsed with `ParserConfig::collect_keyed_calls`.
+
+use crate::config::{EventPatternConfig, EventRole, EventsConfig};
+use crate::graph::DependencyGraph;
+use crate::parser::{FileSymbols, KeyedCall};
+use petgraph::algo::tarjan_scc;
+use petgraph::graph::{DiGraph, NodeIndex};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+
+/// A call producing or consuming a key.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct EventSite {
+    pub file: PathBuf,
+    pub line: usize,
+    pub callee: String,
+}
+
+/// A synthetic dependency from a file producing `key` to a file consuming it.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct EventEdge {
+    pub from: PathBuf,
+    pub to: PathBuf,
+    pub key: String,
+}
+
+/// Files that reach each other only when event edges are added to the imports.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventCycle {
+    pub files: Vec<PathBuf>,
+    /// Event edges between the files of the cycle.
+    pub edges: Vec<EventEdge>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct EventGraph {
+    producers: BTreeMap<String, Vec<EventSite>>,
+    consumers: BTreeMap<String, Vec<EventSite>>,
+}
+
+impl EventGraph {
+    #[must_use]
+    pub fn build(file_symbols: &HashMap<PathBuf, FileSymbols>, config: &EventsConfig) -> Self {
+        let mut graph = Self::default();
+        for (path, symbols) in file_symbols {
+            for call in &symbols.keyed_calls {
+                for pattern in config.patterns.iter().filter(|p| matches(p, call)) {
+                    let sites = match pattern.role {
+                        EventRole::Producer => &mut graph.producers,
+                        EventRole::Consumer => &mut graph.consumers,
+                    };
+                    sites
+                        .entry(call.key.to_string())
+                        .or_default()
+                        .push(EventSite {
+                            file: path.clone(),
+                            line: call.line,
+                            callee: call.callee.to_string(),
+                        });
+                }
+            }
+        }
+        for sites in graph
+            .producers
+            .values_mut()
+            .chain(graph.consumers.values_mut())
+        {
+            sites.sort();
+            sites.dedup();
+        }
+        graph
+    }
+
+    /// All produced or consumed keys, sorted.
+    #[must_use]
+    pub fn keys(&self) -> BTreeSet<&str> {
+        self.producers
+            .keys()
+            .chain(self.consumers.keys())
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// Calls producing `key`, ordered by file and line.
+    #[must_use]
+    pub fn producers(&self, key: &str) -> &[EventSite] {
+        self.producers.get(key).map_or(&[], Vec::as_slice)
+    }
+
+    /// Calls consuming `key`, ordered by file and line.
+    #[must_use]
+    pub fn consumers(&self, key: &str) -> &[EventSite] {
+        self.consumers.get(key).map_or(&[], Vec::as_slice)
+    }
+
+    /// Keys that are only produced or only consumed, with the side that exists.
+    /// Keys matching one of the `ignore_keys` globs are skipped.
+    #[must_use]
+    pub fn orphans(&self, ignore_keys: &[String]) -> Vec<(&str, EventRole)> {
+        let ignored: Vec<glob::Pattern> = ignore_keys
+            .iter()
+            .filter_map(|pattern| glob::Pattern::new(pattern).ok())
+            .collect();
+
+        self.keys()
+            .into_iter()
+            .filter(|key| !ignored.iter().any(|pattern| pattern.matches(key)))
+            .filter_map(|key| {
+                match (
+                    self.producers(key).is_empty(),
+                    self.consumers(key).is_empty(),
+                ) {
+                    (false, true) => Some((key, EventRole::Producer)),
+                    (true, false) => Some((key, EventRole::Consumer)),
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+
+    /// One edge per key from each producing file to each other consuming file.
+    #[must_use]
+    pub fn edges(&self) -> Vec<EventEdge> {
+        let mut edges = BTreeSet::new();
+        for (key, producers) in &self.producers {
+            for producer in producers {
+                for consumer in self.consumers(key) {
+                    if producer.file != consumer.file {
+                        edges.insert(EventEdge {
+                            from: producer.file.clone(),
+                            to: consumer.file.clone(),
+                            key: key.clone(),
+                        });
+                    }
+                }
+            }
+        }
+        edges.into_iter().collect()
+    }
+
+    /// Groups of files that depend on each other through imports and events,
+    /// but not through imports alone.
+    #[must_use]
+    pub fn cycles(&self, dependencies: &DependencyGraph) -> Vec<EventCycle> {
+        let event_edges = self.edges();
+        if event_edges.is_empty() {
+            return Vec::new();
+        }
+
+        let mut graph: DiGraph<PathBuf, ()> = DiGraph::new();
+        let mut nodes: HashMap<PathBuf, NodeIndex> = HashMap::new();
+        let mut node = |graph: &mut DiGraph<PathBuf, ()>, path: &Path| {
+            *nodes
+                .entry(path.to_path_buf())
+                .or_insert_with(|| graph.add_node(path.to_path_buf()))
+        };
+        for (from, to) in dependencies.edges() {
+            if let (Some(from), Some(to)) = (
+                dependencies.get_file_path(from),
+                dependencies.get_file_path(to),
+            ) {
+                let (from, to) = (node(&mut graph, from), node(&mut graph, to));
+                graph.update_edge(from, to, ());
+            }
+        }
+        let import_components = component_ids(&graph);
+
+        for edge in &event_edges {
+            let (from, to) = (node(&mut graph, &edge.from), node(&mut graph, &edge.to));
+            graph.update_edge(from, to, ());
+        }
+
+        let mut cycles: Vec<EventCycle> = tarjan_scc(&graph)
+            .into_iter()
+            .filter(|component| component.len() > 1)
+            .filter(|component| {
+                // Cycles through imports alone are reported by `cyclic_dependency`
+                let first = import_components.get(&component[0]);
+                component
+                    .iter()
+                    .any(|id| import_components.get(id) != first)
+            })
+            .map(|component| {
+                let mut files: Vec<PathBuf> =
+                    component.iter().map(|&id| graph[id].clone()).collect();
+                files.sort();
+                let edges = event_edges
+                    .iter()
+                    .filter(|e| {
+                        files.binary_search(&e.from).is_ok() && files.binary_search(&e.to).is_ok()
+                    })
+                    .cloned()
+                    .collect();
+                EventCycle { files, edges }
+            })
+            .collect();
+        cycles.sort_by(|a, b| a.files.cmp(&b.files));
+        cycles
+    }
+}
+
+/// Strongly connected component of every node reached through imports.
+fn component_ids(graph: &DiGraph<PathBuf, ()>) -> HashMap<NodeIndex, usize> {
+    tarjan_scc(graph)
+        .into_iter()
+        .enumerate()
+        .flat_map(|(id, component)| component.into_iter().map(move |node| (node, id)))
+        .collect()
+}
+
+/// `emit` matches `emit`, `bus.emit` and `this.bus.emit`; `bus.emit` only the latter two.
+fn matches(pattern: &EventPatternConfig, call: &KeyedCall) -> bool {
+    let callee = call.callee.as_str();
+    let callee_matches = callee == pattern.callee
+        || callee
+            .strip_suffix(pattern.callee.as_str())
+            .is_some_and(|object| object.ends_with('.'));
+    callee_matches
+        && call.argument == pattern.argument
+        && call.property.as_deref() == pattern.property.as_deref()
+}
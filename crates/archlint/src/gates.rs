@@ -0,0 +1,434 @@
+//! Declarative quality gates.
+//!
+//! Gates from the `gates` config section are checked against a scan report or
+//! a diff result. When gates are configured they decide the exit code: `0` if
+//! all pass and [`EXIT_GATE_FAILED`] if any fails, so CI can tell a failed
+//! gate apart from a tool error (exit code `1`).
+
+use crate::config::{GateCondition, GateConfig, SmellFilter};
+use crate::debt::format_minutes;
+use crate::detectors::{Severity, SmellKind};
+use crate::diff::{DiffResult, RegressionType};
+use crate::report::{AnalysisReport, GradeLevel};
+use crate::snapshot::{Snapshot, SnapshotSmell};
+use glob::Pattern;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::str::FromStr;
+
+/// Process exit code when at least one quality gate fails.
+pub const EXIT_GATE_FAILED: i32 = 3;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum GateStatus {
+    Passed,
+    Failed,
+    /// The condition needs data this run does not have, e.g. a baseline.
+    Skipped,
+}
+
+/// Outcome of a single gate.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct GateResult {
+    pub name: String,
+    pub status: GateStatus,
+    /// What was measured, e.g. `5 smells`, or why the gate was skipped.
+    pub message: String,
+}
+
+/// Outcome of all configured gates.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct GateReport {
+    pub passed: bool,
+    pub results: Vec<GateResult>,
+}
+
+impl GateReport {
+    fn new(results: Vec<GateResult>) -> Self {
+        Self {
+            passed: results.iter().all(|r| r.status != GateStatus::Failed),
+            results,
+        }
+    }
+
+    pub fn failed(&self) -> impl Iterator<Item = &GateResult> {
+        self.results
+            .iter()
+            .filter(|r| r.status == GateStatus::Failed)
+    }
+
+    #[must_use]
+    pub const fn exit_code(&self) -> i32 {
+        if self.passed {
+            0
+        } else {
+            EXIT_GATE_FAILED
+        }
+    }
+}
+
+/// The parts of a smell gates filter on.
+struct SmellFacts<'a> {
+    kind: Option<SmellKind>,
+    severity: Option<Severity>,
+    files: Vec<&'a str>,
+}
+
+impl SmellFacts<'_> {
+    fn from_snapshot(smell: &SnapshotSmell) -> SmellFacts<'_> {
+        SmellFacts {
+            kind: smell.details.as_ref().map_or_else(
+                || SmellKind::from_str(&smell.smell_type).ok(),
+                |details| Some(details.category()),
+            ),
+            severity: Severity::from_str(&smell.severity).ok(),
+            files: smell.files.iter().map(String::as_str).collect(),
+        }
+    }
+}
+
+struct Filter {
+    rule: Option<SmellKind>,
+    severity: Option<Severity>,
+    paths: Vec<Pattern>,
+}
+
+impl Filter {
+    /// Compile a config filter; fails with a message for unknown rules or invalid globs.
+    fn new(filter: &SmellFilter) -> Result<Self, String> {
+        let rule = filter
+            .rule
+            .as_ref()
+            .map(|rule| SmellKind::from_str(rule).map_err(|_| format!("unknown rule '{rule}'")))
+            .transpose()?;
+        let paths = filter
+            .paths
+            .iter()
+            .map(|p| Pattern::new(p).map_err(|e| format!("invalid pattern '{p}': {e}")))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            rule,
+            severity: filter.severity,
+            paths,
+        })
+    }
+
+    fn matches(&self, smell: &SmellFacts<'_>) -> bool {
+        let rule_ok = self.rule.is_none_or(|rule| {
+            smell.kind.is_some_and(|kind| {
+                // The cycles detector reports cycles as clusters
+                kind == rule
+                    || (rule == SmellKind::CyclicDependency
+                        && kind == SmellKind::CyclicDependencyCluster)
+            })
+        });
+        let severity_ok = self
+            .severity
+            .is_none_or(|min| smell.severity.is_some_and(|s| s >= min));
+        let paths_ok = self.paths.is_empty()
+            || smell
+                .files
+                .iter()
+                .any(|file| self.paths.iter().any(|p| p.matches(file)));
+
+        rule_ok && severity_ok && paths_ok
+    }
+}
+
+/// Human-readable description of a gate, used when it has no name.
+#[must_use]
+pub fn describe(gate: &GateConfig) -> String {
+    if let Some(name) = &gate.name {
+        return name.clone();
+    }
+
+    match &gate.condition {
+        GateCondition::MaxSmells(g) => {
+            format!("{} smells <= {}", describe_filter(&g.filter), g.max)
+        }
+        GateCondition::NoNewSmells(f) => format!("no new {} smells", describe_filter(f)),
+        GateCondition::MinGrade(level) => format!("grade >= {level}"),
+        GateCondition::MaxDebt(effort) => format!("debt <= {}", format_minutes(effort.minutes())),
+        GateCondition::MaxDebtIncrease(effort) => {
+            format!("debt increase <= {}", format_minutes(effort.minutes()))
+        }
+    }
+}
+
+fn describe_filter(filter: &SmellFilter) -> String {
+    let mut parts = Vec::new();
+    if let Some(severity) = filter.severity {
+        parts.push(format!("{severity}+"));
+    }
+    parts.push(filter.rule.clone().unwrap_or_else(|| "all".to_string()));
+    if !filter.paths.is_empty() {
+        parts.push(format!("in {}", filter.paths.join(", ")));
+    }
+    parts.join(" ")
+}
+
+/// Check gates against a scan report. Baseline-only gates are skipped.
+#[must_use]
+pub fn evaluate_scan(
+    gates: &[GateConfig],
+    report: &AnalysisReport,
+    project_root: &Path,
+) -> GateReport {
+    let relative: Vec<Vec<String>> = report
+        .smells
+        .iter()
+        .map(|(smell, _)| {
+            smell
+                .files
+                .iter()
+                .map(|f| {
+                    f.strip_prefix(project_root)
+                        .unwrap_or(f)
+                        .to_string_lossy()
+                        .replace('\\', "/")
+                })
+                .collect()
+        })
+        .collect();
+    let facts: Vec<SmellFacts<'_>> = report
+        .smells
+        .iter()
+        .zip(&relative)
+        .map(|((smell, _), files)| SmellFacts {
+            kind: Some(smell.smell_type.category()),
+            severity: Some(smell.severity),
+            files: files.iter().map(String::as_str).collect(),
+        })
+        .collect();
+    let grade = report.grade(&report.config.scoring).level;
+
+    let results = gates
+        .iter()
+        .map(|gate| {
+            let name = describe(gate);
+            match &gate.condition {
+                GateCondition::MaxSmells(g) => check_max_smells(name, &facts, &g.filter, g.max),
+                GateCondition::MinGrade(min) => check_grade(name, Some(grade), *min),
+                GateCondition::MaxDebt(max) => {
+                    check_max_debt(name, Some(report.debt.total_minutes), max.minutes())
+                }
+                GateCondition::NoNewSmells(_) | GateCondition::MaxDebtIncrease(_) => {
+                    skipped(name, "requires a baseline; checked by `archlint diff`")
+                }
+            }
+        })
+        .collect();
+
+    GateReport::new(results)
+}
+
+/// Check gates against a diff between two snapshots.
+#[must_use]
+pub fn evaluate_diff(gates: &[GateConfig], diff: &DiffResult, current: &Snapshot) -> GateReport {
+    let current_facts: Vec<_> = current
+        .smells
+        .iter()
+        .map(SmellFacts::from_snapshot)
+        .collect();
+    let new_facts: Vec<_> = diff
+        .regressions
+        .iter()
+        .filter(|r| r.regression_type == RegressionType::NewSmell)
+        .map(|r| SmellFacts::from_snapshot(&r.smell))
+        .collect();
+    let grade = parse_snapshot_grade(&current.grade);
+
+    let results = gates
+        .iter()
+        .map(|gate| {
+            let name = describe(gate);
+            match &gate.condition {
+                GateCondition::MaxSmells(g) => {
+                    check_max_smells(name, &current_facts, &g.filter, g.max)
+                }
+                GateCondition::NoNewSmells(filter) => match count_matching(&new_facts, filter) {
+                    Ok(count) => result(name, count == 0, smells(count, "new ")),
+                    // A misconfigured gate must not pass silently
+                    Err(reason) => result(name, false, reason),
+                },
+                GateCondition::MinGrade(min) => check_grade(name, grade, *min),
+                GateCondition::MaxDebt(max) => check_max_debt(
+                    name,
+                    current.debt.as_ref().map(|d| d.total_minutes),
+                    max.minutes(),
+                ),
+                GateCondition::MaxDebtIncrease(max) => match &diff.debt {
+                    Some(debt) => result(
+                        name,
+                        debt.net <= max.minutes(),
+                        format!("{} change", signed_minutes(debt.net)),
+                    ),
+                    None => skipped(name, "snapshots have no debt estimates"),
+                },
+            }
+        })
+        .collect();
+
+    GateReport::new(results)
+}
+
+fn count_matching(facts: &[SmellFacts<'_>], filter: &SmellFilter) -> Result<usize, String> {
+    let filter = Filter::new(filter)?;
+    Ok(facts.iter().filter(|f| filter.matches(f)).count())
+}
+
+fn check_max_smells(
+    name: String,
+    facts: &[SmellFacts<'_>],
+    filter: &SmellFilter,
+    max: usize,
+) -> GateResult {
+    match count_matching(facts, filter) {
+        Ok(count) => result(name, count <= max, smells(count, "")),
+        Err(reason) => result(name, false, reason),
+    }
+}
+
+fn check_grade(name: String, grade: Option<GradeLevel>, min: GradeLevel) -> GateResult {
+    match grade {
+        // Levels are ordered from best to worst
+        Some(level) => result(name, level <= min, format!("grade {level}")),
+        None => skipped(name, "grade unavailable"),
+    }
+}
+
+fn check_max_debt(name: String, total: Option<f64>, max: f64) -> GateResult {
+    match total {
+        Some(total) => result(
+            name,
+            total <= max,
+            format!("{} debt", format_minutes(total)),
+        ),
+        None => skipped(name, "snapshot has no debt estimate"),
+    }
+}
+
+const fn result(name: String, passed: bool, message: String) -> GateResult {
+    GateResult {
+        name,
+        status: if passed {
+            GateStatus::Passed
+        } else {
+            GateStatus::Failed
+        },
+        message,
+    }
+}
+
+fn skipped(name: String, reason: &str) -> GateResult {
+    GateResult {
+        name,
+        status: GateStatus::Skipped,
+        message: reason.to_string(),
+    }
+}
+
+fn smells(count: usize, qualifier: &str) -> String {
+    let noun = if count == 1 { "smell" } else { "smells" };
+    format!("{count} {qualifier}{noun}")
+}
+
+fn signed_minutes(minutes: f64) -> String {
+    if minutes > 0.0 {
+        format!("+{}", format_minutes(minutes))
+    } else {
+        format_minutes(minutes)
+    }
+}
+
+/// Extract the grade level from a snapshot's grade field.
+fn parse_snapshot_grade(grade: &str) -> Option<GradeLevel> {
+    let level = grade
+        .split_once("level:")
+        .map_or(grade, |(_, rest)| rest)
+        .trim_start()
+        .split(|c: char| !c.is_ascii_alphabetic())
+        .next()?;
+    serde_json::from_value(serde_json::Value::String(level.to_string())).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{Effort, MaxSmellsGate};
+
+    fn gate(condition: GateCondition) -> GateConfig {
+        GateConfig {
+            name: None,
+            condition,
+        }
+    }
+
+    fn snapshot_smell(id: &str, smell_type: &str, severity: &str, file: &str) -> SnapshotSmell {
+        SnapshotSmell {
+            id: id.to_string(),
+            smell_type: smell_type.to_string(),
+            severity: severity.to_string(),
+            files: vec![file.to_string()],
+            metrics: std::collections::HashMap::new(),
+            details: None,
+            locations: vec![],
+        }
+    }
+
+    #[test]
+    fn test_filter_matches_rule_severity_and_paths() {
+        let smell = snapshot_smell("l1", "LayerViolation", "High", "src/core/user.ts");
+        let facts = SmellFacts::from_snapshot(&smell);
+
+        let matches = |filter: SmellFilter| Filter::new(&filter).unwrap().matches(&facts);
+        assert!(matches(SmellFilter::default()));
+        assert!(matches(SmellFilter {
+            rule: Some("layer_violation".to_string()),
+            severity: Some(Severity::Medium),
+            paths: vec!["src/core/**".to_string()],
+        }));
+        assert!(!matches(SmellFilter {
+            severity: Some(Severity::Critical),
+            ..Default::default()
+        }));
+        assert!(!matches(SmellFilter {
+            paths: vec!["src/ui/**".to_string()],
+            ..Default::default()
+        }));
+    }
+
+    #[test]
+    fn test_describe_defaults() {
+        let max = gate(GateCondition::MaxSmells(MaxSmellsGate {
+            filter: SmellFilter {
+                rule: Some("cyclic_dependency".to_string()),
+                ..Default::default()
+            },
+            max: 3,
+        }));
+        assert_eq!(describe(&max), "cyclic_dependency smells <= 3");
+        assert_eq!(
+            describe(&gate(GateCondition::MaxDebtIncrease(Effort(120.0)))),
+            "debt increase <= 2h"
+        );
+        assert_eq!(
+            describe(&gate(GateCondition::MinGrade(GradeLevel::Good))),
+            "grade >= Good"
+        );
+    }
+
+    #[test]
+    fn test_parse_snapshot_grade() {
+        assert_eq!(
+            parse_snapshot_grade("ArchitectureGrade { score: 8.5, level: Good, density: 0.4 }"),
+            Some(GradeLevel::Good)
+        );
+        assert_eq!(parse_snapshot_grade("Poor"), Some(GradeLevel::Poor));
+        assert_eq!(parse_snapshot_grade("B"), None);
+    }
+}
@@ -176,6 +176,11 @@ impl DependencyGraph {
             .neighbors_directed(node, petgraph::Direction::Outgoing)
     }
 
+    pub fn dependents(&self, node: NodeIndex) -> impl Iterator<Item = NodeIndex> + '_ {
+        self.graph
+            .neighbors_directed(node, petgraph::Direction::Incoming)
+    }
+
     pub fn remove_outgoing_edges(&mut self, node: NodeIndex) {
         let edge_indices: Vec<_> = self
             .graph
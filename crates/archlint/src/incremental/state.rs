@@ -4,6 +4,8 @@ use crate::framework::presets::FrameworkPreset;
 use crate::framework::Framework;
 use crate::graph::DependencyGraph;
 use crate::parser::{FileIgnoredLines, FileSymbols, FunctionComplexity};
+use crate::report::Suppression;
+use crate::resolver::ImportDiagnostics;
 use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -16,6 +18,7 @@ pub struct IncrementalState {
     pub file_metrics: Arc<HashMap<PathBuf, FileMetrics>>,
     pub function_complexity: Arc<HashMap<PathBuf, Vec<FunctionComplexity>>>,
     pub ignored_lines: Arc<FileIgnoredLines>,
+    pub import_diagnostics: Arc<ImportDiagnostics>,
 
     // Light structures remain owned
     pub file_hashes: HashMap<PathBuf, String>,
@@ -38,6 +41,9 @@ pub struct IncrementalState {
 
     /// Cache for file-local detector results: (`detector_id`, `file_path`) -> smells
     pub file_local_cache: HashMap<(String, PathBuf), Vec<ArchSmell>>,
+
+    /// Smells hidden by inline comments, kept when `suppressions.include_suppressed` is set.
+    pub suppressed: Vec<(ArchSmell, Suppression)>,
 }
 
 impl IncrementalState {
@@ -49,6 +55,7 @@ impl IncrementalState {
             file_metrics: Arc::new(HashMap::new()),
             function_complexity: Arc::new(HashMap::new()),
             ignored_lines: Arc::new(FileIgnoredLines::default()),
+            import_diagnostics: Arc::new(ImportDiagnostics::default()),
             file_hashes: HashMap::new(),
             churn_map: HashMap::new(),
             reverse_deps: HashMap::new(),
@@ -61,6 +68,7 @@ impl IncrementalState {
             script_entry_points: HashSet::new(),
             dynamic_load_patterns: Vec::new(),
             file_local_cache: HashMap::new(),
+            suppressed: Vec::new(),
         }
     }
 
@@ -70,6 +78,7 @@ impl IncrementalState {
         self.file_metrics = Arc::new(HashMap::new());
         self.function_complexity = Arc::new(HashMap::new());
         self.ignored_lines = Arc::new(FileIgnoredLines::default());
+        self.import_diagnostics = Arc::new(ImportDiagnostics::default());
         self.file_hashes.clear();
         self.churn_map.clear();
         self.reverse_deps.clear();
@@ -79,6 +88,7 @@ impl IncrementalState {
         self.script_entry_points.clear();
         self.dynamic_load_patterns.clear();
         self.file_local_cache.clear();
+        self.suppressed.clear();
     }
 
     /// Get mutable access to graph via `Arc::make_mut` (copy-on-write)
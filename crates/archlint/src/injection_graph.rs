@@ -0,0 +1,377 @@
+//! Dependency injection graph of Nest and Angular applications.
+//!
+//! Nodes are decorated classes (modules, providers, controllers, components)
+//! and the classes or tokens they refer to. Edges are typed by
+//! [`InjectionKind`]: constructor injection, and the `imports`, `providers`,
+//! `exports` and `controllers`/`declarations` entries of module metadata.
+//! These edges are kept apart from the file dependency graph, because most of
+//! the coupling they describe goes through the container, not through imports.
+//!
+//! Requires symbols parsed with `ParserConfig::collect_injections` and import
+//! sources resolved to file paths.
+
+use crate::parser::{FileSymbols, InjectableRole, InjectionKind};
+use crate::symbol_graph::Resolver;
+use petgraph::algo::tarjan_scc;
+use petgraph::graph::{DiGraph, NodeIndex};
+use petgraph::visit::{EdgeFiltered, EdgeRef};
+use petgraph::Direction;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+pub type InjectableId = NodeIndex;
+
+/// A class or injection token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InjectionNode {
+    pub name: String,
+    /// Declaring file; `None` for classes from packages and string tokens.
+    pub file: Option<PathBuf>,
+    /// `None` for classes and tokens without an injection decorator.
+    pub role: Option<InjectableRole>,
+    pub is_global: bool,
+    /// Line of the class declaration, 0 when unknown.
+    pub line: usize,
+}
+
+/// A reference from a decorated class, made at `line` of its file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InjectionEdge {
+    pub kind: InjectionKind,
+    pub line: usize,
+}
+
+/// A provider injected into a class whose modules cannot see it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScopeViolation {
+    pub consumer: InjectableId,
+    pub provider: InjectableId,
+    /// Line of the injection in the consumer's file.
+    pub line: usize,
+    /// Modules that list the consumer.
+    pub consumer_modules: Vec<InjectableId>,
+    /// Modules that list the provider.
+    pub owner_modules: Vec<InjectableId>,
+}
+
+type NodeKey = (Option<PathBuf>, String);
+
+#[derive(Debug, Clone, Default)]
+pub struct InjectionGraph {
+    graph: DiGraph<InjectionNode, InjectionEdge>,
+    index: HashMap<NodeKey, InjectableId>,
+}
+
+impl InjectionGraph {
+    #[must_use]
+    pub fn build(file_symbols: &HashMap<PathBuf, FileSymbols>) -> Self {
+        let mut graph = Self::default();
+
+        let mut files: Vec<&PathBuf> = file_symbols.keys().collect();
+        files.sort();
+        for &file in &files {
+            for class in &file_symbols[file].injectables {
+                let id = graph.node(Some(file.clone()), class.name.to_string());
+                let node = &mut graph.graph[id];
+                node.role = Some(class.role);
+                node.is_global = class.is_global;
+                node.line = class.line;
+            }
+        }
+
+        let resolver = Resolver { file_symbols };
+        for &file in &files {
+            let symbols = &file_symbols[file];
+            for class in &symbols.injectables {
+                let from = graph.index[&(Some(file.clone()), class.name.to_string())];
+                for injection in &class.injections {
+                    let (target_file, name) = locate(&resolver, file, symbols, &injection.target);
+                    let to = graph.node(target_file, name);
+                    let edge = InjectionEdge {
+                        kind: injection.kind,
+                        line: injection.line,
+                    };
+                    let exists = graph
+                        .graph
+                        .edges_connecting(from, to)
+                        .any(|e| e.weight().kind == edge.kind);
+                    if !exists {
+                        graph.graph.add_edge(from, to, edge);
+                    }
+                }
+            }
+        }
+
+        graph
+    }
+
+    fn node(&mut self, file: Option<PathBuf>, name: String) -> InjectableId {
+        let key = (file, name);
+        if let Some(&id) = self.index.get(&key) {
+            return id;
+        }
+        let id = self.graph.add_node(InjectionNode {
+            name: key.1.clone(),
+            file: key.0.clone(),
+            role: None,
+            is_global: false,
+            line: 0,
+        });
+        self.index.insert(key, id);
+        id
+    }
+
+    #[must_use]
+    pub fn node_count(&self) -> usize {
+        self.graph.node_count()
+    }
+
+    #[must_use]
+    pub fn edge_count(&self) -> usize {
+        self.graph.edge_count()
+    }
+
+    #[must_use]
+    pub fn get(&self, id: InjectableId) -> &InjectionNode {
+        &self.graph[id]
+    }
+
+    #[must_use]
+    pub fn find(&self, file: Option<&Path>, name: &str) -> Option<InjectableId> {
+        self.index
+            .get(&(file.map(Path::to_path_buf), name.to_string()))
+            .copied()
+    }
+
+    /// All nodes, ordered by file, line and name.
+    #[must_use]
+    pub fn nodes(&self) -> Vec<InjectableId> {
+        let mut nodes: Vec<InjectableId> = self.graph.node_indices().collect();
+        nodes.sort_by(|&a, &b| self.sort_key(a).cmp(&self.sort_key(b)));
+        nodes
+    }
+
+    /// Decorated classes with the given role, ordered by file, line and name.
+    #[must_use]
+    pub fn with_role(&self, role: InjectableRole) -> Vec<InjectableId> {
+        let mut nodes = self.nodes();
+        nodes.retain(|&id| self.graph[id].role == Some(role));
+        nodes
+    }
+
+    /// Classes and tokens `id` refers to with `kind`, in source order.
+    #[must_use]
+    pub fn targets(&self, id: InjectableId, kind: InjectionKind) -> Vec<(InjectableId, usize)> {
+        let mut targets: Vec<(InjectableId, usize)> = self
+            .graph
+            .edges_directed(id, Direction::Outgoing)
+            .filter(|e| e.weight().kind == kind)
+            .map(|e| (e.target(), e.weight().line))
+            .collect();
+        targets.sort_by_key(|&(target, line)| (line, target));
+        targets
+    }
+
+    /// Classes referring to `id` with `kind`, ordered by file, line and name.
+    #[must_use]
+    pub fn sources(&self, id: InjectableId, kind: InjectionKind) -> Vec<InjectableId> {
+        let mut sources: Vec<InjectableId> = self
+            .graph
+            .edges_directed(id, Direction::Incoming)
+            .filter(|e| e.weight().kind == kind)
+            .map(|e| e.source())
+            .collect();
+        sources.sort_by(|&a, &b| self.sort_key(a).cmp(&self.sort_key(b)));
+        sources.dedup();
+        sources
+    }
+
+    /// Modules that list `id` as a provider, controller or declaration.
+    #[must_use]
+    pub fn modules_of(&self, id: InjectableId) -> Vec<InjectableId> {
+        let mut modules = self.sources(id, InjectionKind::Provide);
+        modules.extend(self.sources(id, InjectionKind::Declare));
+        modules.retain(|&m| self.graph[m].role == Some(InjectableRole::Module));
+        modules.sort_by(|&a, &b| self.sort_key(a).cmp(&self.sort_key(b)));
+        modules.dedup();
+        modules
+    }
+
+    /// Groups of modules importing each other, each ordered by file, line and name.
+    #[must_use]
+    pub fn module_cycles(&self) -> Vec<Vec<InjectableId>> {
+        let imports = EdgeFiltered::from_fn(&self.graph, |e| {
+            e.weight().kind == InjectionKind::Import
+                && e.source() != e.target()
+                && self.graph[e.source()].role == Some(InjectableRole::Module)
+                && self.graph[e.target()].role == Some(InjectableRole::Module)
+        });
+        let mut cycles: Vec<Vec<InjectableId>> = tarjan_scc(&imports)
+            .into_iter()
+            .filter(|scc| scc.len() > 1)
+            .map(|mut scc| {
+                scc.sort_by(|&a, &b| self.sort_key(a).cmp(&self.sort_key(b)));
+                scc
+            })
+            .collect();
+        cycles.sort_by(|a, b| self.sort_key(a[0]).cmp(&self.sort_key(b[0])));
+        cycles
+    }
+
+    /// Providers `module` can inject: its own providers, the exports of
+    /// imported modules and everything made visible by global modules.
+    #[must_use]
+    pub fn visible_providers(&self, module: InjectableId) -> HashSet<InjectableId> {
+        let mut visible: HashSet<InjectableId> = self
+            .targets(module, InjectionKind::Provide)
+            .into_iter()
+            .map(|(provider, _)| provider)
+            .collect();
+        let mut seen = HashSet::new();
+        for (imported, _) in self.targets(module, InjectionKind::Import) {
+            self.collect_exports(imported, &mut visible, &mut seen);
+        }
+        visible
+    }
+
+    /// Providers exported by `module`, following re-exported modules.
+    fn collect_exports(
+        &self,
+        module: InjectableId,
+        exports: &mut HashSet<InjectableId>,
+        seen: &mut HashSet<InjectableId>,
+    ) {
+        if !seen.insert(module) {
+            return;
+        }
+        for (exported, _) in self.targets(module, InjectionKind::Export) {
+            exports.insert(exported);
+            if self.graph[exported].role == Some(InjectableRole::Module) {
+                self.collect_exports(exported, exports, seen);
+            }
+        }
+    }
+
+    /// Providers visible from every module.
+    fn global_providers(&self) -> HashSet<InjectableId> {
+        let mut global = HashSet::new();
+        let mut seen = HashSet::new();
+        for id in self.graph.node_indices() {
+            let node = &self.graph[id];
+            if !node.is_global {
+                continue;
+            }
+            global.insert(id);
+            if node.role == Some(InjectableRole::Module) {
+                global.extend(
+                    self.targets(id, InjectionKind::Provide)
+                        .into_iter()
+                        .map(|(provider, _)| provider),
+                );
+                self.collect_exports(id, &mut global, &mut seen);
+            }
+        }
+        global
+    }
+
+    /// Injections of providers that none of the consumer's modules can see.
+    ///
+    /// Only consumers listed by at least one module and providers registered
+    /// by at least one module are checked.
+    #[must_use]
+    pub fn scope_violations(&self) -> Vec<ScopeViolation> {
+        let global = self.global_providers();
+        let mut visible_cache: HashMap<InjectableId, HashSet<InjectableId>> = HashMap::new();
+        let mut violations = Vec::new();
+
+        for consumer in self.nodes() {
+            let consumer_modules = self.modules_of(consumer);
+            if consumer_modules.is_empty() {
+                continue;
+            }
+            let own: HashSet<InjectableId> = self
+                .targets(consumer, InjectionKind::Provide)
+                .into_iter()
+                .map(|(provider, _)| provider)
+                .collect();
+
+            for (provider, line) in self.targets(consumer, InjectionKind::Inject) {
+                if global.contains(&provider) || own.contains(&provider) {
+                    continue;
+                }
+                let owner_modules: Vec<InjectableId> = self
+                    .sources(provider, InjectionKind::Provide)
+                    .into_iter()
+                    .filter(|&m| self.graph[m].role == Some(InjectableRole::Module))
+                    .collect();
+                if owner_modules.is_empty() {
+                    continue;
+                }
+                let visible = consumer_modules.iter().any(|&module| {
+                    visible_cache
+                        .entry(module)
+                        .or_insert_with(|| self.visible_providers(module))
+                        .contains(&provider)
+                });
+                if !visible {
+                    violations.push(ScopeViolation {
+                        consumer,
+                        provider,
+                        line,
+                        consumer_modules: consumer_modules.clone(),
+                        owner_modules,
+                    });
+                }
+            }
+        }
+
+        violations
+    }
+
+    fn sort_key(&self, id: InjectableId) -> (Option<&Path>, usize, &str) {
+        let node = &self.graph[id];
+        (node.file.as_deref(), node.line, &node.name)
+    }
+}
+
+/// Declaring file and name of `target` as used in `file`. Classes from
+/// packages keep their imported name without a file; names neither imported
+/// nor declared in `file` are treated as string tokens.
+fn locate(
+    resolver: &Resolver,
+    file: &Path,
+    symbols: &FileSymbols,
+    target: &str,
+) -> (Option<PathBuf>, String) {
+    let (local, member) = target
+        .split_once('.')
+        .map_or((target, None), |(local, member)| (local, Some(member)));
+
+    if let Some(import) = Resolver::local_import(symbols, local) {
+        let name = match member {
+            Some(member) if import.name == "*" => member,
+            _ => import.name.as_str(),
+        };
+        return resolver
+            .resolve(Path::new(import.source.as_str()), name, 0)
+            .map_or_else(|| (None, name.to_string()), |(f, n)| (Some(f), n));
+    }
+
+    let declared: BTreeSet<&str> = symbols
+        .injectables
+        .iter()
+        .map(|c| c.name.as_str())
+        .chain(symbols.exports.iter().map(|e| e.name.as_str()))
+        .chain(
+            symbols
+                .local_definitions
+                .iter()
+                .map(compact_str::CompactString::as_str),
+        )
+        .collect();
+    if member.is_none() && declared.contains(target) {
+        (Some(file.to_path_buf()), target.to_string())
+    } else {
+        (None, target.to_string())
+    }
+}
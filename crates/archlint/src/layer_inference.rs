@@ -0,0 +1,343 @@
+//! Inference of a layer configuration from the dependency graph.
+//!
+//! Files are grouped into folders and the folder graph is condensed into its
+//! strongly connected components. Folders inside a component are ordered so
+//! that the fewest imports point against the order; those imports become the
+//! reported violations. Each folder's layer level is then the length of the
+//! longest remaining dependency chain below it, and a layer may import every
+//! layer on a lower level.
+
+use crate::config::LayerConfig;
+use crate::graph::DependencyGraph;
+use crate::package_metrics::folder_name;
+use petgraph::algo::kosaraju_scc;
+use petgraph::graph::{DiGraph, EdgeIndex, NodeIndex};
+use petgraph::visit::EdgeRef;
+use petgraph::Direction;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+/// Largest component refined by [`sift`], which is cubic in its size.
+const MAX_SIFT_FOLDERS: usize = 64;
+
+/// Folders with the imports between them.
+type FolderGraph = DiGraph<String, Vec<(PathBuf, PathBuf)>>;
+
+/// Folder name used for files directly in the project root; these get no layer.
+const ROOT_FOLDER: &str = "root";
+
+/// A proposed layer: one folder and its level in the ordering.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InferredLayer {
+    pub name: String,
+    pub path: String,
+    /// 0 for layers that import no other layer.
+    pub level: usize,
+    pub allowed_imports: Vec<String>,
+}
+
+/// Imports from one layer into another that the proposed ordering forbids.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LayerViolation {
+    pub from: String,
+    pub to: String,
+    /// Importing and imported file of each offending import.
+    pub imports: Vec<(PathBuf, PathBuf)>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct LayerInference {
+    /// Layers ordered from the lowest level up.
+    pub layers: Vec<InferredLayer>,
+    /// Violations ordered by number of imports, largest first.
+    pub violations: Vec<LayerViolation>,
+}
+
+impl LayerInference {
+    /// The layers in the form the `layer_violation` rule expects.
+    #[must_use]
+    pub fn to_config(&self) -> Vec<LayerConfig> {
+        self.layers
+            .iter()
+            .map(|layer| LayerConfig {
+                name: layer.name.clone(),
+                path: layer.path.clone(),
+                allowed_imports: layer.allowed_imports.clone(),
+            })
+            .collect()
+    }
+}
+
+/// Propose layers for folders `depth` levels below `project_root`.
+#[must_use]
+pub fn infer_layers(graph: &DependencyGraph, project_root: &Path, depth: usize) -> LayerInference {
+    let mut folders = FolderGraph::new();
+    let mut folder_nodes: HashMap<String, NodeIndex> = HashMap::new();
+    let mut node_for = |folders: &mut FolderGraph, name: String| {
+        *folder_nodes
+            .entry(name.clone())
+            .or_insert_with(|| folders.add_node(name))
+    };
+
+    let mut edges: BTreeMap<(String, String), Vec<(PathBuf, PathBuf)>> = BTreeMap::new();
+    for node in graph.nodes() {
+        let Some(path) = graph.get_file_path(node) else {
+            continue;
+        };
+        let from = folder_name(path, project_root, depth);
+        if from == ROOT_FOLDER {
+            continue;
+        }
+        node_for(&mut folders, from.clone());
+
+        for dep in graph.dependencies(node) {
+            let Some(dep_path) = graph.get_file_path(dep) else {
+                continue;
+            };
+            let to = folder_name(dep_path, project_root, depth);
+            if to != from && to != ROOT_FOLDER {
+                edges
+                    .entry((from.clone(), to))
+                    .or_default()
+                    .push((path.clone(), dep_path.clone()));
+            }
+        }
+    }
+    for ((from, to), imports) in edges {
+        let (a, b) = (node_for(&mut folders, from), node_for(&mut folders, to));
+        folders.add_edge(a, b, imports);
+    }
+
+    let back_edges = break_cycles(&folders);
+    let levels = compute_levels(&folders, &back_edges);
+    build_result(&folders, &levels, &back_edges)
+}
+
+/// Edges pointing against the order chosen inside each strongly connected component.
+fn break_cycles(folders: &FolderGraph) -> HashSet<EdgeIndex> {
+    let mut back_edges = HashSet::new();
+
+    for scc in kosaraju_scc(folders) {
+        if scc.len() < 2 {
+            continue;
+        }
+        let position: HashMap<NodeIndex, usize> = order_component(folders, &scc)
+            .into_iter()
+            .enumerate()
+            .map(|(i, node)| (node, i))
+            .collect();
+
+        for &node in &scc {
+            for edge in folders.edges_directed(node, Direction::Outgoing) {
+                if let Some(&target) = position.get(&edge.target()) {
+                    if target < position[&node] {
+                        back_edges.insert(edge.id());
+                    }
+                }
+            }
+        }
+    }
+
+    back_edges
+}
+
+/// Order a component importers-first, keeping the weight of imports that
+/// point backwards small (greedy heuristic of Eades, Lin and Smyth).
+fn order_component(folders: &FolderGraph, scc: &[NodeIndex]) -> Vec<NodeIndex> {
+    // Sorted by name so the result does not depend on traversal order
+    let mut remaining: BTreeSet<(&str, NodeIndex)> =
+        scc.iter().map(|&n| (folders[n].as_str(), n)).collect();
+    let weight = |remaining: &BTreeSet<(&str, NodeIndex)>, node: NodeIndex, dir: Direction| {
+        folders
+            .edges_directed(node, dir)
+            .filter(|e| {
+                let other = if dir == Direction::Outgoing {
+                    e.target()
+                } else {
+                    e.source()
+                };
+                remaining.contains(&(folders[other].as_str(), other))
+            })
+            .map(|e| e.weight().len())
+            .sum::<usize>()
+    };
+
+    let (mut head, mut tail) = (Vec::new(), Vec::new());
+    while !remaining.is_empty() {
+        if let Some(&sink) = remaining
+            .iter()
+            .find(|(_, n)| weight(&remaining, *n, Direction::Outgoing) == 0)
+        {
+            remaining.remove(&sink);
+            tail.push(sink.1);
+            continue;
+        }
+        if let Some(&source) = remaining
+            .iter()
+            .find(|(_, n)| weight(&remaining, *n, Direction::Incoming) == 0)
+        {
+            remaining.remove(&source);
+            head.push(source.1);
+            continue;
+        }
+        // Largest `out - in`, compared without going negative; earlier names win ties
+        let balance = |n| {
+            (
+                weight(&remaining, n, Direction::Outgoing),
+                weight(&remaining, n, Direction::Incoming),
+            )
+        };
+        let Some(&best) = remaining.iter().max_by(|(a_name, a), (b_name, b)| {
+            let ((a_out, a_in), (b_out, b_in)) = (balance(*a), balance(*b));
+            (a_out + b_in)
+                .cmp(&(b_out + a_in))
+                .then_with(|| b_name.cmp(a_name))
+        }) else {
+            break;
+        };
+        remaining.remove(&best);
+        head.push(best.1);
+    }
+
+    head.extend(tail.into_iter().rev());
+    if head.len() <= MAX_SIFT_FOLDERS {
+        head = sift(folders, head);
+    }
+    head
+}
+
+/// Improve an order by moving single folders to the position with the
+/// fewest backward imports until no move helps.
+fn sift(folders: &FolderGraph, mut order: Vec<NodeIndex>) -> Vec<NodeIndex> {
+    let backward_weight = |order: &[NodeIndex]| {
+        let position: HashMap<NodeIndex, usize> =
+            order.iter().enumerate().map(|(i, &n)| (n, i)).collect();
+        order
+            .iter()
+            .flat_map(|&n| folders.edges_directed(n, Direction::Outgoing))
+            .filter(|e| {
+                position
+                    .get(&e.target())
+                    .is_some_and(|&target| target < position[&e.source()])
+            })
+            .map(|e| e.weight().len())
+            .sum::<usize>()
+    };
+
+    let mut best = backward_weight(&order);
+    let mut improved = true;
+    while improved && best > 0 {
+        improved = false;
+        for from in 0..order.len() {
+            for to in 0..order.len() {
+                let mut candidate = order.clone();
+                let node = candidate.remove(from);
+                candidate.insert(to, node);
+                let weight = backward_weight(&candidate);
+                if weight < best {
+                    (order, best, improved) = (candidate, weight, true);
+                }
+            }
+        }
+    }
+
+    order
+}
+
+/// Length of the longest dependency chain below each folder, ignoring back edges.
+///
+/// Without the back edges the folder graph is acyclic, so levels are assigned
+/// in reverse topological order: a folder once every folder it imports has one.
+fn compute_levels(
+    folders: &FolderGraph,
+    back_edges: &HashSet<EdgeIndex>,
+) -> HashMap<NodeIndex, usize> {
+    let forward = |node: NodeIndex, dir: Direction| {
+        folders
+            .edges_directed(node, dir)
+            .filter(|e| !back_edges.contains(&e.id()))
+    };
+
+    let mut pending: HashMap<NodeIndex, usize> = folders
+        .node_indices()
+        .map(|node| (node, forward(node, Direction::Outgoing).count()))
+        .collect();
+    let mut ready: Vec<NodeIndex> = folders
+        .node_indices()
+        .filter(|node| pending[node] == 0)
+        .collect();
+
+    let mut levels = HashMap::new();
+    while let Some(node) = ready.pop() {
+        let level = forward(node, Direction::Outgoing)
+            .map(|e| levels[&e.target()] + 1)
+            .max()
+            .unwrap_or(0);
+        levels.insert(node, level);
+
+        for edge in forward(node, Direction::Incoming) {
+            if let Some(count) = pending.get_mut(&edge.source()) {
+                *count -= 1;
+                if *count == 0 {
+                    ready.push(edge.source());
+                }
+            }
+        }
+    }
+    levels
+}
+
+fn build_result(
+    folders: &FolderGraph,
+    levels: &HashMap<NodeIndex, usize>,
+    back_edges: &HashSet<EdgeIndex>,
+) -> LayerInference {
+    let mut nodes: Vec<NodeIndex> = folders.node_indices().collect();
+    nodes.sort_by(|a, b| {
+        levels[a]
+            .cmp(&levels[b])
+            .then_with(|| folders[*a].cmp(&folders[*b]))
+    });
+
+    let layers = nodes
+        .iter()
+        .map(|&node| InferredLayer {
+            name: folders[node].clone(),
+            path: format!("{}/**", folders[node]),
+            level: levels[&node],
+            allowed_imports: nodes
+                .iter()
+                .filter(|other| levels[*other] < levels[&node])
+                .map(|&other| folders[other].clone())
+                .collect(),
+        })
+        .collect();
+
+    // A back edge may still point to a lower level; only the rest are violations
+    let mut violations: Vec<LayerViolation> = back_edges
+        .iter()
+        .filter_map(|&edge| {
+            let (from, to) = folders.edge_endpoints(edge)?;
+            (levels[&from] <= levels[&to]).then(|| LayerViolation {
+                from: folders[from].clone(),
+                to: folders[to].clone(),
+                imports: {
+                    let mut imports = folders[edge].clone();
+                    imports.sort();
+                    imports
+                },
+            })
+        })
+        .collect();
+    violations.sort_by(|a, b| {
+        b.imports
+            .len()
+            .cmp(&a.imports.len())
+            .then_with(|| (&a.from, &a.to).cmp(&(&b.from, &b.to)))
+    });
+
+    LayerInference { layers, violations }
+}
@@ -5,24 +5,35 @@
 
 pub mod args;
 pub mod cache;
+pub mod calibrate;
 #[cfg(feature = "cli")]
 pub mod cli;
+pub mod codeowners;
 pub mod commands;
 pub mod config;
+#[cfg(all(feature = "cli", unix))]
+pub mod daemon;
+pub mod debt;
 pub mod detectors;
 pub mod diff;
 pub mod engine;
 pub mod error;
+pub mod event_graph;
 pub mod explain;
 pub mod framework;
+pub mod gates;
 pub mod git_cache;
 pub mod glob_expand;
 pub mod graph;
 pub mod incremental;
+pub mod injection_graph;
+pub mod layer_inference;
 pub mod metrics;
+pub mod modularize;
 #[cfg(not(feature = "cli"))]
 pub mod no_cli_mocks;
 pub mod package_json;
+pub mod package_metrics;
 pub mod parser;
 pub mod project_root;
 pub mod report;
@@ -30,6 +41,7 @@ pub mod resolver;
 pub mod rule_resolver;
 pub mod scanner;
 pub mod snapshot;
+pub mod symbol_graph;
 pub mod tsconfig;
 pub mod utils;
 #[cfg(feature = "cli")]
@@ -41,7 +53,8 @@ pub mod api;
 // Convenient re-exports for common use
 pub use api::{clear_cache, get_detectors, load_config, scan};
 pub use api::{
-    Analyzer, IncrementalResult, ScanOptions, ScanResult, SmellWithExplanation, StateStats, Summary,
+    Analyzer, IncrementalResult, ScanOptions, ScanResult, SmellWithExplanation, StateStats,
+    Summary, SymbolRef, SymbolUsages,
 };
 pub use api::{ExportInfo, ExportKind, FileInfo, FileMetrics, ImportInfo};
 
@@ -53,3 +66,4 @@ pub use error::{AnalysisError, Result};
 pub use explain::Explanation;
 pub use framework::Framework;
 pub use report::{AnalysisReport, ArchitectureGrade, GradeLevel};
+pub use symbol_graph::{Symbol, SymbolGraph, SymbolId};
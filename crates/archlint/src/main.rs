@@ -1,6 +1,7 @@
 use archlint::args::{OutputFormat, ScanArgs};
 use archlint::framework::detector::FrameworkDetector;
 use archlint::framework::Framework;
+use archlint::project_root::detect_project_root;
 use archlint::{
     cache, cli, config, detectors, engine, glob_expand, report, watch, AnalysisError, Result,
 };
@@ -68,6 +69,16 @@ fn set_initial_log_level(builder: &mut env_logger::Builder, cli: &cli::Cli) {
         Some(cli::Command::Snapshot(_)) => {
             // Default log level is handled by the common initialization
         }
+        Some(
+            cli::Command::Metrics(_)
+            | cli::Command::Calibrate(_)
+            | cli::Command::Modularize(_)
+            | cli::Command::Injections(_)
+            | cli::Command::Affected(_)
+            | cli::Command::Explain(_),
+        ) => {
+            builder.filter_level(log::LevelFilter::Error);
+        }
         Some(cli::Command::Diff(args)) => {
             if args.json {
                 builder.filter_level(log::LevelFilter::Error);
@@ -94,6 +105,14 @@ fn set_final_log_level(builder: &mut env_logger::Builder, cli: &cli::Cli) {
             builder.filter_level(log::LevelFilter::Debug)
         }
         Some(cli::Command::Snapshot(_)) => builder.filter_level(log::LevelFilter::Info),
+        Some(
+            cli::Command::Metrics(_)
+            | cli::Command::Calibrate(_)
+            | cli::Command::Modularize(_)
+            | cli::Command::Injections(_)
+            | cli::Command::Affected(_)
+            | cli::Command::Explain(_),
+        ) => builder.filter_level(log::LevelFilter::Error),
         Some(cli::Command::Diff(args)) => {
             if args.json {
                 builder.filter_level(log::LevelFilter::Error)
@@ -194,6 +213,13 @@ fn run(cli: cli::Cli) -> Result<()> {
         Some(cli::Command::Snapshot(args)) => handle_snapshot_command(args),
         Some(cli::Command::Diff(args)) => handle_diff_command(args),
         Some(cli::Command::Init(args)) => handle_init_command(args),
+        Some(cli::Command::Metrics(args)) => handle_metrics_command(args),
+        Some(cli::Command::Calibrate(args)) => handle_calibrate_command(args),
+        Some(cli::Command::Modularize(args)) => handle_modularize_command(args),
+        Some(cli::Command::Injections(args)) => handle_injections_command(args),
+        Some(cli::Command::Affected(args)) => handle_affected_command(args),
+        Some(cli::Command::Explain(args)) => handle_explain_command(args),
+        Some(cli::Command::Daemon(args)) => handle_daemon_command(args),
         None => handle_default_command(cli),
     }
 }
@@ -202,6 +228,74 @@ fn handle_snapshot_command(args: cli::SnapshotArgs) -> Result<()> {
     archlint::commands::run_snapshot(args.output, args.include_commit, args.path)
 }
 
+fn handle_metrics_command(args: cli::MetricsArgs) -> Result<()> {
+    archlint::commands::run_metrics(&archlint::commands::metrics::MetricsOptions {
+        path: args.path,
+        config: args.config,
+        group_by: args.by,
+        depth: args.depth,
+        format: args.format,
+        output: args.output,
+        no_diagram: args.no_diagram,
+    })
+}
+
+fn handle_calibrate_command(args: cli::CalibrateArgs) -> Result<()> {
+    archlint::commands::run_calibrate(&archlint::commands::calibrate::CalibrateOptions {
+        path: args.path,
+        config: args.config,
+        top: usize::from(args.top),
+        format: args.format,
+        output: args.output,
+    })
+}
+
+fn handle_modularize_command(args: cli::ModularizeArgs) -> Result<()> {
+    archlint::commands::run_modularize(&archlint::commands::modularize::ModularizeOptions {
+        path: args.path,
+        config: args.config,
+        settings: archlint::modularize::ModularizeSettings {
+            depth: args.depth,
+            resolution: args.resolution,
+            ..Default::default()
+        },
+        co_change_commits: args.co_change.then_some(args.lookback),
+        format: args.format,
+        output: args.output,
+    })
+}
+
+fn handle_injections_command(args: cli::InjectionsArgs) -> Result<()> {
+    archlint::commands::run_injections(&archlint::commands::injections::InjectionsOptions {
+        path: args.path,
+        config: args.config,
+        format: args.format,
+        output: args.output,
+    })
+}
+
+fn handle_affected_command(args: cli::AffectedArgs) -> Result<()> {
+    archlint::commands::run_affected(&archlint::commands::affected::AffectedOptions {
+        path: args.path,
+        config: args.config,
+        since: args.since,
+        files: args.files,
+        tests_only: args.tests_only,
+        format: args.format,
+        output: args.output,
+    })
+}
+
+fn handle_explain_command(args: cli::ExplainArgs) -> Result<()> {
+    archlint::commands::run_explain(&archlint::commands::explain::ExplainOptions {
+        path: args.path,
+        config: args.config,
+        target: args.target,
+        format: args.format,
+        output: args.output,
+    })
+}
+
 fn handle_diff_command(args: cli::DiffArgs) -> Result<()> {
     let exit_code = archlint::commands::run_diff(
         args.baseline,
@@ -223,13 +317,31 @@ fn handle_scan_command(args: ScanArgs) -> Result<()> {
     let start = Instant::now();
     let engine = engine::AnalysisEngine::new_with_args(args.clone())?;
     let config = engine.config.clone();
-    let report = engine.run()?;
+    let report = run_engine(&args, &engine)?;
 
     write_report(&args, &report, &config, &engine.project_root)?;
     print_scan_results(&args, &report, &config, start);
     exit_with_code(&report)
 }
 
+/// Runs the analysis, asking the project daemon for its results when one is running.
+fn run_engine(args: &ScanArgs, engine: &engine::AnalysisEngine) -> Result<report::AnalysisReport> {
+    #[cfg(unix)]
+    if let Some(mut client) = archlint::daemon::DaemonClient::connect(&engine.project_root) {
+        match client.scan_report(args, &engine.config, &engine.project_root) {
+            Ok(Some(report)) => {
+                log::debug!("Using results from the archlint daemon");
+                return Ok(report);
+            }
+            Ok(None) => {}
+            Err(e) => log::debug!("Daemon unavailable, analyzing locally: {e}"),
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = args;
+    engine.run()
+}
+
 fn handle_watch_command(args: cli::WatchArgs) -> Result<()> {
     let engine = engine::AnalysisEngine::new_with_args(args.scan.clone())?;
     let config = engine.config.clone();
@@ -271,7 +383,7 @@ fn handle_default_command(cli: cli::Cli) -> Result<()> {
     let start = Instant::now();
     let engine = engine::AnalysisEngine::new_with_args(args.clone())?;
     let config = engine.config.clone();
-    let report = engine.run()?;
+    let report = run_engine(&args, &engine)?;
 
     write_report(&args, &report, &config, &engine.project_root)?;
     print_scan_results(&args, &report, &config, start);
@@ -330,6 +442,14 @@ fn print_scan_results(
             style(total_score.to_string()).yellow().bold()
         );
 
+        if report.debt.total_minutes > 0.0 {
+            info!(
+                "{} Technical debt: {}",
+                style("⏱").bold(),
+                style(archlint::debt::format_minutes(report.debt.total_minutes)).yellow()
+            );
+        }
+
         let (grade_styled, emoji) = format_grade(&grade);
         let level_styled = format_grade_level(&grade);
 
@@ -339,6 +459,10 @@ fn print_scan_results(
             grade_styled,
             level_styled
         );
+
+        if let Some(gates) = &report.gates {
+            archlint::commands::gates_output::print_gate_report(gates);
+        }
     }
 }
 
@@ -374,7 +498,11 @@ fn format_grade_level(grade: &report::ArchitectureGrade) -> console::StyledObjec
 }
 
 fn exit_with_code(report: &report::AnalysisReport) -> Result<()> {
-    let exit_code = determine_exit_code(report);
+    // Configured gates replace the severity-based exit code
+    let exit_code = report.gates.as_ref().map_or_else(
+        || determine_exit_code(report),
+        archlint::gates::GateReport::exit_code,
+    );
     if exit_code != 0 {
         process::exit(exit_code);
     }
@@ -426,14 +554,126 @@ fn handle_detectors_command(args: cli::DetectorArgs) -> Result<()> {
 
 fn handle_cache_command(args: cli::CacheArgs) -> Result<()> {
     match args.command {
-        cli::CacheCommand::Clear => {
-            cache::AnalysisCache::clear(Path::new("."))?;
+        cli::CacheCommand::Clear { path } => {
+            cache::AnalysisCache::clear(&detect_project_root(&path))?;
             info!("{}  Cache cleared successfully", style("✔").green());
             Ok(())
         }
+        cli::CacheCommand::Export { file, path } => {
+            let root = detect_project_root(&path);
+            let files = cache::AnalysisCache::export(&root, &file)?;
+            info!(
+                "{}  Exported cache for {files} files to {}",
+                style("✔").green(),
+                file.display()
+            );
+            Ok(())
+        }
+        cli::CacheCommand::Import { file, path } => {
+            let root = detect_project_root(&path);
+            let files = cache::AnalysisCache::import(&root, &file)?;
+            info!(
+                "{}  Imported cache for {files} files from {}",
+                style("✔").green(),
+                file.display()
+            );
+            Ok(())
+        }
+        cli::CacheCommand::Stats { path } => {
+            let root = detect_project_root(&path);
+            match cache::AnalysisCache::stats(&root)? {
+                Some(stats) => print_cache_stats(&stats),
+                None => println!("No cache found for {}", root.display()),
+            }
+            Ok(())
+        }
     }
 }
 
+fn print_cache_stats(stats: &cache::CacheStats) {
+    #[allow(clippy::cast_precision_loss)]
+    let size_mb = stats.size as f64 / (1024.0 * 1024.0);
+    println!("{}", style("Analysis Cache").bold().underlined());
+    println!("  Location:  {}", stats.path.display());
+    println!("  Size:      {size_mb:.2} MB");
+    println!("  Entries:   {}", stats.entries);
+    println!("  Files:     {}", stats.files);
+    let version = stats.version.as_deref().unwrap_or("unknown");
+    if stats.is_outdated {
+        println!("  Version:   {version} (outdated, cleared on the next scan)");
+    } else {
+        println!("  Version:   {version}");
+    }
+    match stats.last_run {
+        Some(run) => {
+            let ratio = run
+                .hit_ratio()
+                .map_or_else(|| "-".to_string(), |r| format!("{:.1}%", r * 100.0));
+            println!(
+                "  Hit ratio: {ratio} ({} hits, {} misses in the last run)",
+                run.hits, run.misses
+            );
+        }
+        None => println!("  Hit ratio: -"),
+    }
+}
+
+#[cfg(unix)]
+fn handle_daemon_command(args: cli::DaemonArgs) -> Result<()> {
+    use archlint::daemon::protocol::DaemonStatus;
+    use archlint::daemon::{DaemonClient, DaemonServer};
+
+    match args.command {
+        cli::DaemonCommand::Start { path } => {
+            info!("{}  Analyzing {}...", style("🔍").dim(), path.display());
+            let server = DaemonServer::new(&path)?;
+            info!(
+                "{}  Daemon ready for {}",
+                style("✔").green(),
+                style(server.project_root().display()).bold()
+            );
+            server.run()
+        }
+        cli::DaemonCommand::Stop { path } => {
+            let root = detect_project_root(&path);
+            match DaemonClient::connect(&root) {
+                Some(mut client) => {
+                    client.call::<_, serde_json::Value>("shutdown", &serde_json::Value::Null)?;
+                    info!("{}  Daemon stopped", style("✔").green());
+                }
+                None => println!("No daemon running for {}", root.display()),
+            }
+            Ok(())
+        }
+        cli::DaemonCommand::Status { path } => {
+            let root = detect_project_root(&path);
+            let Some(mut client) = DaemonClient::connect(&root) else {
+                println!("No daemon running for {}", root.display());
+                return Ok(());
+            };
+            let status: DaemonStatus = client.call("status", &serde_json::Value::Null)?;
+            println!("{}", style("Archlint Daemon").bold().underlined());
+            println!("  Project: {}", status.project_root.display());
+            println!("  PID:     {}", status.pid);
+            println!("  Uptime:  {}s", status.uptime_secs);
+            println!("  Files:   {}", status.files);
+            println!(
+                "  Graph:   {} nodes, {} edges",
+                status.graph_nodes, status.graph_edges
+            );
+            println!("  Smells:  {}", status.smells);
+            Ok(())
+        }
+    }
+}
+
+#[cfg(not(unix))]
+fn handle_daemon_command(_args: cli::DaemonArgs) -> Result<()> {
+    Err(AnalysisError::Anyhow(anyhow::anyhow!(
+        "The daemon needs Unix domain sockets and is not available on this platform"
+    )))
+}
+
 fn handle_completions_command(args: cli::CompletionsArgs) -> Result<()> {
     let mut cmd = cli::Cli::command();
     clap_complete::generate(args.shell, &mut cmd, "archlint", &mut std::io::stdout());
@@ -477,13 +717,39 @@ fn handle_init_command(args: cli::InitArgs) -> Result<()> {
         config.extends = Some(selected_presets);
     }
 
-    // 3. Write config
+    // 3. Infer layers from the dependency graph
+    let inference = if args.infer_layers {
+        let (inference, root) =
+            archlint::commands::infer_layers::collect_layers(&cwd, None, args.layer_depth)?;
+        if !inference.layers.is_empty() {
+            let options = serde_yaml::to_value(serde_yaml::Mapping::from_iter([(
+                "layers".into(),
+                serde_yaml::to_value(inference.to_config())?,
+            )]))?;
+            config.rules.insert(
+                "layer_violation".to_string(),
+                config::RuleConfig::Full(config::RuleFullConfig {
+                    options,
+                    ..Default::default()
+                }),
+            );
+        }
+        Some((inference, root))
+    } else {
+        None
+    };
+
+    // 4. Write config
     let yaml = serde_yaml::to_string(&config)?;
     let yaml_with_schema = format!(
         "# yaml-language-server: $schema=https://raw.githubusercontent.com/archlinter/archlint/main/resources/archlint.schema.json\n{yaml}"
     );
     std::fs::write(config_path, yaml_with_schema)?;
 
+    if let Some((inference, root)) = &inference {
+        archlint::commands::infer_layers::print_layer_inference(inference, root);
+    }
+
     let success_msg = format!("Created {}", style(".archlint.yaml").bold());
     #[cfg(feature = "cli")]
     cliclack::outro(style(&success_msg).green()).map_err(|e| anyhow::anyhow!("UI error: {e}"))?;
@@ -4,9 +4,12 @@ use crate::Result;
 use git2::{DiffOptions, Repository};
 #[cfg(feature = "cli")]
 use indicatif::{ProgressBar, ProgressStyle};
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::path::{Path, PathBuf};
 
+/// Commits changing this many tracked files or more are ignored for co-change.
+pub const MAX_CO_CHANGE_FILES: usize = 50;
+
 pub struct GitChurn {
     repo: Option<Repository>,
 }
@@ -130,6 +133,69 @@ impl GitChurn {
         }
     }
 
+    /// How often each pair of `files` changed in the same commit within the
+    /// last `lookback` commits, keyed with the smaller path first. Commits
+    /// touching `MAX_CO_CHANGE_FILES` or more of them are bulk edits and skipped.
+    pub fn co_changes(
+        &self,
+        files: &[PathBuf],
+        lookback: usize,
+    ) -> Result<HashMap<(PathBuf, PathBuf), usize>> {
+        let mut pairs = HashMap::new();
+        let Some(repo) = &self.repo else {
+            return Ok(pairs);
+        };
+        let Some(workdir) = repo.workdir() else {
+            return Ok(pairs);
+        };
+        let mut revwalk = repo.revwalk()?;
+        if revwalk.push_head().is_err() {
+            return Ok(pairs);
+        }
+
+        let known: HashSet<&PathBuf> = files.iter().collect();
+        for oid in revwalk.take(lookback) {
+            let commit = repo.find_commit(oid?)?;
+            if commit.parent_count() == 0 {
+                continue;
+            }
+            let diff = repo.diff_tree_to_tree(
+                Some(&commit.parent(0)?.tree()?),
+                Some(&commit.tree()?),
+                None,
+            )?;
+
+            let mut changed = BTreeSet::new();
+            diff.foreach(
+                &mut |delta, _| {
+                    if let Some(path) = delta.new_file().path() {
+                        if let Ok(path) = workdir.join(path).canonicalize() {
+                            if known.contains(&path) {
+                                changed.insert(path);
+                            }
+                        }
+                    }
+                    true
+                },
+                None,
+                None,
+                None,
+            )?;
+
+            if changed.len() < 2 || changed.len() >= MAX_CO_CHANGE_FILES {
+                continue;
+            }
+            let changed: Vec<PathBuf> = changed.into_iter().collect();
+            for (i, a) in changed.iter().enumerate() {
+                for b in &changed[i + 1..] {
+                    *pairs.entry((a.clone(), b.clone())).or_insert(0) += 1;
+                }
+            }
+        }
+
+        Ok(pairs)
+    }
+
     #[must_use]
     pub const fn is_available(&self) -> bool {
         self.repo.is_some()
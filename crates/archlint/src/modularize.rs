@@ -0,0 +1,463 @@
+//! Suggested module boundaries from community detection.
+//!
+//! The file graph is treated as undirected, each import weighted by the
+//! number of symbols it brings in and optionally by how often both files
+//! changed together. Louvain's method groups files into communities that
+//! depend on each other more than on the rest of the project. Comparing the
+//! communities with the folder structure points at files that live apart
+//! from the code they work with, and the modularity of both partitions
+//! measures how well the folders match the actual dependencies.
+
+use crate::graph::DependencyGraph;
+use crate::package_metrics::folder_name;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+/// Smallest modularity gain that counts as an improvement.
+const MIN_GAIN: f64 = 1e-9;
+
+/// Upper bound on passes over the nodes of one level.
+const MAX_PASSES: usize = 100;
+
+/// Settings for [`modularize`].
+#[derive(Debug, Clone, Copy)]
+pub struct ModularizeSettings {
+    /// Folder depth below the project root that files are compared at.
+    pub depth: usize,
+    /// Louvain resolution; higher values produce more, smaller modules.
+    pub resolution: f64,
+    /// Edge weight added per commit that changed both files.
+    pub co_change_weight: f64,
+}
+
+impl Default for ModularizeSettings {
+    fn default() -> Self {
+        Self {
+            depth: 2,
+            resolution: 1.0,
+            co_change_weight: 1.0,
+        }
+    }
+}
+
+/// A group of files that belong together.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SuggestedModule {
+    /// The folder most of the files live in, numbered when several modules share it.
+    pub name: String,
+    pub folder: String,
+    /// Share of the module's files inside `folder`, from 0 to 1.
+    pub folder_share: f64,
+    pub files: Vec<String>,
+}
+
+/// A file whose folder differs from the folder of its module.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MisplacedFile {
+    pub file: String,
+    pub folder: String,
+    pub module: String,
+    pub suggested_folder: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Modularization {
+    /// Modularity of the suggested modules, from -0.5 to 1.
+    pub modularity: f64,
+    /// Modularity of the current folders, for comparison.
+    pub folder_modularity: f64,
+    pub files: usize,
+    /// Modules with more than one file, largest first.
+    pub modules: Vec<SuggestedModule>,
+    pub misplaced: Vec<MisplacedFile>,
+}
+
+/// Undirected weighted graph; `adjacency[i][i]` holds self-loop weight.
+#[derive(Debug, Clone)]
+struct Network {
+    adjacency: Vec<BTreeMap<usize, f64>>,
+}
+
+impl Network {
+    fn with_nodes(count: usize) -> Self {
+        Self {
+            adjacency: vec![BTreeMap::new(); count],
+        }
+    }
+
+    const fn len(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    fn connect(&mut self, a: usize, b: usize, weight: f64) {
+        *self.adjacency[a].entry(b).or_default() += weight;
+        *self.adjacency[b].entry(a).or_default() += weight;
+    }
+
+    fn degrees(&self) -> Vec<f64> {
+        self.adjacency
+            .iter()
+            .map(|row| row.values().sum())
+            .collect()
+    }
+
+    /// Network with one node per community and the summed weights between them.
+    fn aggregate(&self, community: &[usize], count: usize) -> Self {
+        let mut aggregated = Self::with_nodes(count);
+        for (i, row) in self.adjacency.iter().enumerate() {
+            for (&j, &weight) in row {
+                *aggregated.adjacency[community[i]]
+                    .entry(community[j])
+                    .or_default() += weight;
+            }
+        }
+        aggregated
+    }
+
+    /// Newman-Girvan modularity of a partition.
+    fn modularity(&self, community: &[usize], resolution: f64) -> f64 {
+        let degrees = self.degrees();
+        let total: f64 = degrees.iter().sum();
+        if total <= 0.0 {
+            return 0.0;
+        }
+
+        let count = community.iter().max().map_or(0, |c| c + 1);
+        let (mut internal, mut tot) = (vec![0.0; count], vec![0.0; count]);
+        for (i, row) in self.adjacency.iter().enumerate() {
+            tot[community[i]] += degrees[i];
+            for (&j, &weight) in row {
+                if community[i] == community[j] {
+                    internal[community[i]] += weight;
+                }
+            }
+        }
+
+        internal
+            .iter()
+            .zip(&tot)
+            .map(|(inside, tot)| resolution.mul_add(-(tot / total).powi(2), inside / total))
+            .sum()
+    }
+}
+
+/// Suggest modules for the files in `graph`.
+///
+/// `co_changes` counts commits per file pair, as returned by
+/// [`GitChurn::co_changes`](crate::metrics::GitChurn::co_changes).
+#[must_use]
+pub fn modularize(
+    graph: &DependencyGraph,
+    co_changes: &HashMap<(PathBuf, PathBuf), usize>,
+    project_root: &Path,
+    settings: &ModularizeSettings,
+) -> Modularization {
+    // Sorted paths keep the result independent of graph construction order
+    let mut files: Vec<&PathBuf> = graph
+        .nodes()
+        .filter_map(|n| graph.get_file_path(n))
+        .collect();
+    files.sort();
+    let index: HashMap<&PathBuf, usize> = files.iter().enumerate().map(|(i, p)| (*p, i)).collect();
+
+    let mut network = Network::with_nodes(files.len());
+    for (from, to) in graph.edges() {
+        let (Some(a), Some(b)) = (graph.get_file_path(from), graph.get_file_path(to)) else {
+            continue;
+        };
+        if a == b {
+            continue;
+        }
+        let symbols = graph
+            .get_edge_data(from, to)
+            .map_or(0, |edge| edge.imported_symbols.len());
+        network.connect(index[a], index[b], symbols.max(1) as f64);
+    }
+    for ((a, b), &count) in co_changes {
+        if let (Some(&a), Some(&b)) = (index.get(a), index.get(b)) {
+            if a != b {
+                network.connect(a, b, count as f64 * settings.co_change_weight);
+            }
+        }
+    }
+
+    let communities = louvain(&network, settings.resolution);
+    let folders: Vec<String> = files
+        .iter()
+        .map(|path| folder_name(path, project_root, settings.depth))
+        .collect();
+    let folder_partition = partition_by(&folders);
+
+    let relative: Vec<String> = files
+        .iter()
+        .map(|path| {
+            path.strip_prefix(project_root)
+                .unwrap_or(path)
+                .to_string_lossy()
+                .replace('\\', "/")
+        })
+        .collect();
+    let (modules, misplaced) = describe_modules(&communities, &folders, &relative);
+
+    Modularization {
+        modularity: network.modularity(&communities, settings.resolution),
+        folder_modularity: network.modularity(&folder_partition, settings.resolution),
+        files: files.len(),
+        modules,
+        misplaced,
+    }
+}
+
+/// Community of every node after repeated moving and aggregation.
+fn louvain(network: &Network, resolution: f64) -> Vec<usize> {
+    let mut membership: Vec<usize> = (0..network.len()).collect();
+    let mut level = network.clone();
+
+    loop {
+        let (community, moved) = move_nodes(&level, resolution);
+        if !moved {
+            break;
+        }
+        for node in &mut membership {
+            *node = community[*node];
+        }
+        let count = community.iter().max().map_or(0, |c| c + 1);
+        level = level.aggregate(&community, count);
+    }
+
+    membership
+}
+
+/// Move each node to the neighbouring community with the largest modularity
+/// gain until no move helps. Communities are numbered in order of appearance.
+fn move_nodes(network: &Network, resolution: f64) -> (Vec<usize>, bool) {
+    let degrees = network.degrees();
+    let total: f64 = degrees.iter().sum();
+    let mut community: Vec<usize> = (0..network.len()).collect();
+    if total <= 0.0 {
+        return (community, false);
+    }
+
+    let mut tot = degrees.clone();
+    let mut moved_any = false;
+    for _ in 0..MAX_PASSES {
+        let mut moved = false;
+        for node in 0..network.len() {
+            let current = community[node];
+            let mut links: BTreeMap<usize, f64> = BTreeMap::new();
+            for (&neighbour, &weight) in &network.adjacency[node] {
+                if neighbour != node {
+                    *links.entry(community[neighbour]).or_default() += weight;
+                }
+            }
+
+            tot[current] -= degrees[node];
+            let gain = |c: usize, tot: &[f64]| {
+                links.get(&c).copied().unwrap_or(0.0) - resolution * tot[c] * degrees[node] / total
+            };
+            let mut best = (current, gain(current, &tot));
+            for &candidate in links.keys() {
+                let candidate_gain = gain(candidate, &tot);
+                if candidate_gain > best.1 + MIN_GAIN {
+                    best = (candidate, candidate_gain);
+                }
+            }
+            tot[best.0] += degrees[node];
+            community[node] = best.0;
+            moved |= best.0 != current;
+        }
+        moved_any |= moved;
+        if !moved {
+            break;
+        }
+    }
+
+    (partition_by(&community), moved_any)
+}
+
+/// Number the distinct values of `labels` in order of first appearance.
+fn partition_by<T: Eq + std::hash::Hash>(labels: &[T]) -> Vec<usize> {
+    let mut numbers: HashMap<&T, usize> = HashMap::new();
+    labels
+        .iter()
+        .map(|label| {
+            let next = numbers.len();
+            *numbers.entry(label).or_insert(next)
+        })
+        .collect()
+}
+
+fn describe_modules(
+    communities: &[usize],
+    folders: &[String],
+    files: &[String],
+) -> (Vec<SuggestedModule>, Vec<MisplacedFile>) {
+    let mut members: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+    for (file, &community) in communities.iter().enumerate() {
+        members.entry(community).or_default().push(file);
+    }
+    let mut groups: Vec<Vec<usize>> = members.into_values().filter(|m| m.len() > 1).collect();
+    groups.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+
+    let mut modules = Vec::new();
+    let mut misplaced = Vec::new();
+    let mut names_per_folder: HashMap<String, usize> = HashMap::new();
+    for group in groups {
+        let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+        for &file in &group {
+            *counts.entry(folders[file].as_str()).or_default() += 1;
+        }
+        // Most files first, then the alphabetically first folder
+        let (folder, in_folder) = counts
+            .iter()
+            .max_by(|a, b| a.1.cmp(b.1).then_with(|| b.0.cmp(a.0)))
+            .map(|(folder, count)| ((*folder).to_string(), *count))
+            .unwrap_or_default();
+
+        let seen = names_per_folder.entry(folder.clone()).or_default();
+        *seen += 1;
+        let name = if *seen == 1 {
+            folder.clone()
+        } else {
+            format!("{folder} ({seen})")
+        };
+
+        for &file in &group {
+            if folders[file] != folder {
+                misplaced.push(MisplacedFile {
+                    file: files[file].clone(),
+                    folder: folders[file].clone(),
+                    module: name.clone(),
+                    suggested_folder: folder.clone(),
+                });
+            }
+        }
+        modules.push(SuggestedModule {
+            name,
+            folder_share: in_folder as f64 / group.len() as f64,
+            folder,
+            files: group.iter().map(|&file| files[file].clone()).collect(),
+        });
+    }
+    misplaced.sort_by(|a, b| a.file.cmp(&b.file));
+
+    (modules, misplaced)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::EdgeData;
+
+    fn import(graph: &mut DependencyGraph, from: &str, to: &str, symbols: usize) {
+        let (a, b) = (graph.add_file(from), graph.add_file(to));
+        let symbols = (0..symbols).map(|i| format!("s{i}")).collect();
+        graph.add_dependency(a, b, EdgeData::with_symbols(1, symbols));
+    }
+
+    /// Two triangles joined by a single weak import.
+    fn two_clusters(graph: &mut DependencyGraph) {
+        for (from, to) in [("a1", "a2"), ("a2", "a3"), ("a3", "a1")] {
+            import(
+                graph,
+                &format!("/p/src/a/{from}.ts"),
+                &format!("/p/src/a/{to}.ts"),
+                3,
+            );
+        }
+        for (from, to) in [("b1", "b2"), ("b2", "b3"), ("b3", "b1")] {
+            import(
+                graph,
+                &format!("/p/src/b/{from}.ts"),
+                &format!("/p/src/b/{to}.ts"),
+                3,
+            );
+        }
+        import(graph, "/p/src/a/a1.ts", "/p/src/b/b1.ts", 1);
+    }
+
+    #[test]
+    fn test_louvain_separates_clusters() {
+        let mut graph = DependencyGraph::new();
+        two_clusters(&mut graph);
+
+        let result = modularize(
+            &graph,
+            &HashMap::new(),
+            Path::new("/p"),
+            &ModularizeSettings::default(),
+        );
+        assert_eq!(result.files, 6);
+        assert_eq!(result.modules.len(), 2);
+        assert_eq!(result.modules[0].name, "src/a");
+        assert_eq!(result.modules[1].name, "src/b");
+        assert!(result.misplaced.is_empty());
+        // 9 inside each triangle and 1 between; each holds half of all degree
+        let expected = 2.0 * (9.0 / 19.0 - 0.25);
+        assert!((result.modularity - expected).abs() < 1e-9);
+        assert!((result.folder_modularity - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_misplaced_file_and_co_change() {
+        let mut graph = DependencyGraph::new();
+        two_clusters(&mut graph);
+        // Lives in `src/b` but only talks to `src/a`
+        import(&mut graph, "/p/src/b/helper.ts", "/p/src/a/a2.ts", 4);
+        import(&mut graph, "/p/src/a/a3.ts", "/p/src/b/helper.ts", 4);
+
+        let result = modularize(
+            &graph,
+            &HashMap::new(),
+            Path::new("/p"),
+            &ModularizeSettings::default(),
+        );
+        assert_eq!(
+            result.misplaced,
+            vec![MisplacedFile {
+                file: "src/b/helper.ts".to_string(),
+                folder: "src/b".to_string(),
+                module: "src/a".to_string(),
+                suggested_folder: "src/a".to_string(),
+            }]
+        );
+        assert!(result.modularity > result.folder_modularity);
+
+        // Frequent changes together with `src/b` pull it back
+        let co_changes = HashMap::from([(
+            (
+                PathBuf::from("/p/src/b/b1.ts"),
+                PathBuf::from("/p/src/b/helper.ts"),
+            ),
+            20,
+        )]);
+        let result = modularize(
+            &graph,
+            &co_changes,
+            Path::new("/p"),
+            &ModularizeSettings::default(),
+        );
+        assert!(result.misplaced.is_empty());
+    }
+
+    #[test]
+    fn test_graph_without_imports() {
+        let mut graph = DependencyGraph::new();
+        graph.add_file("/p/src/a.ts");
+        graph.add_file("/p/src/b.ts");
+
+        let result = modularize(
+            &graph,
+            &HashMap::new(),
+            Path::new("/p"),
+            &ModularizeSettings::default(),
+        );
+        assert_eq!(result.files, 2);
+        assert!(result.modules.is_empty());
+        assert!(result.modularity.abs() < f64::EPSILON);
+    }
+}
@@ -2,7 +2,7 @@ use crate::Result;
 use ignore::WalkBuilder;
 use regex::Regex;
 use serde_json::Value;
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
 
@@ -14,7 +14,117 @@ pub struct PackageConfig {
     pub dynamic_load_patterns: Vec<String>,
 }
 
+/// Dependency declarations of a single `package.json` (one workspace package).
+#[derive(Debug, Clone, Default)]
+pub struct PackageManifest {
+    /// Path to the `package.json` file.
+    pub path: PathBuf,
+    /// Directory containing the manifest.
+    pub dir: PathBuf,
+    /// Value of the `name` field, if any.
+    pub name: Option<String>,
+    pub dependencies: BTreeMap<String, String>,
+    pub dev_dependencies: BTreeMap<String, String>,
+    pub peer_dependencies: BTreeMap<String, String>,
+    pub optional_dependencies: BTreeMap<String, String>,
+    pub scripts: BTreeMap<String, String>,
+    /// Raw manifest content, used to locate declarations and tool config blocks.
+    pub raw: String,
+}
+
+impl PackageManifest {
+    /// Returns true if the package is declared in any dependency section.
+    #[must_use]
+    pub fn declares(&self, package: &str) -> bool {
+        self.dependencies.contains_key(package)
+            || self.dev_dependencies.contains_key(package)
+            || self.peer_dependencies.contains_key(package)
+            || self.optional_dependencies.contains_key(package)
+    }
+
+    /// Returns true if the package is available at runtime (not only in `devDependencies`).
+    #[must_use]
+    pub fn declares_runtime(&self, package: &str) -> bool {
+        self.dependencies.contains_key(package)
+            || self.peer_dependencies.contains_key(package)
+            || self.optional_dependencies.contains_key(package)
+    }
+
+    /// All declared packages across dependency sections.
+    pub fn declared_packages(&self) -> impl Iterator<Item = &String> {
+        self.dependencies
+            .keys()
+            .chain(self.dev_dependencies.keys())
+            .chain(self.peer_dependencies.keys())
+            .chain(self.optional_dependencies.keys())
+    }
+
+    /// 1-based line of the first `"package":` key in the manifest, or 0 if not found.
+    #[must_use]
+    pub fn declaration_line(&self, package: &str) -> usize {
+        let needle = format!("\"{package}\"");
+        self.raw
+            .lines()
+            .position(|line| line.trim_start().starts_with(&needle))
+            .map_or(0, |idx| idx + 1)
+    }
+
+    fn from_json(path: &Path, json: &Value, raw: String) -> Self {
+        let section = |key: &str| -> BTreeMap<String, String> {
+            json.get(key)
+                .and_then(|v| v.as_object())
+                .map(|obj| {
+                    obj.iter()
+                        .map(|(k, v)| (k.clone(), v.as_str().unwrap_or_default().to_string()))
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+
+        Self {
+            path: path.to_path_buf(),
+            dir: path.parent().map(Path::to_path_buf).unwrap_or_default(),
+            name: json
+                .get("name")
+                .and_then(|v| v.as_str())
+                .map(ToString::to_string),
+            dependencies: section("dependencies"),
+            dev_dependencies: section("devDependencies"),
+            peer_dependencies: section("peerDependencies"),
+            optional_dependencies: section("optionalDependencies"),
+            scripts: section("scripts"),
+            raw,
+        }
+    }
+}
+
 impl PackageJsonParser {
+    /// Collects every `package.json` under the root (outside `node_modules`),
+    /// one per workspace package.
+    pub fn find_manifests<P: AsRef<Path>>(root: P) -> Vec<PackageManifest> {
+        let root = root.as_ref();
+        let walker = WalkBuilder::new(root)
+            .standard_filters(true)
+            .hidden(false)
+            .filter_entry(|e| e.file_name() != "node_modules")
+            .build();
+
+        let mut manifests: Vec<PackageManifest> = walker
+            .flatten()
+            .filter(|entry| entry.file_name() == "package.json")
+            .filter_map(|entry| {
+                let path = entry.path();
+                let raw = fs::read_to_string(path).ok()?;
+                let json: Value = serde_json::from_str(&raw).ok()?;
+                let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+                Some(PackageManifest::from_json(&path, &json, raw))
+            })
+            .collect();
+
+        manifests.sort_by(|a, b| a.path.cmp(&b.path));
+        manifests
+    }
+
     pub fn parse<P: AsRef<Path>>(root: P) -> Result<PackageConfig> {
         let root = root.as_ref();
         let mut config = PackageConfig {
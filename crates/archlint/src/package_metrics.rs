@@ -0,0 +1,226 @@
+//! Robert C. Martin's package metrics computed per component.
+//!
+//! A component is either a folder (grouped at a fixed depth below the project
+//! root) or a workspace package. For each component we report afferent and
+//! efferent coupling, instability, abstractness and the distance from the
+//! main sequence.
+
+use crate::graph::DependencyGraph;
+use crate::package_json::PackageJsonParser;
+use crate::parser::{FileSymbols, SymbolKind};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+/// How files are grouped into components.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComponentGrouping {
+    /// Group by the first `depth` folders below the project root.
+    Folder { depth: usize },
+    /// Group by the nearest workspace `package.json`.
+    Package,
+}
+
+/// Martin metrics for a single component.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentMetrics {
+    /// Folder path or package name.
+    pub name: String,
+    /// Number of files in the component.
+    pub files: usize,
+    /// Afferent coupling: files outside the component that import it.
+    pub ca: usize,
+    /// Efferent coupling: files outside the component it imports.
+    pub ce: usize,
+    /// Instability `I = Ce / (Ca + Ce)`.
+    pub instability: f64,
+    /// Abstractness `A`: share of abstract exports (interfaces, types,
+    /// abstract classes) among classes, functions, interfaces and types.
+    pub abstractness: f64,
+    /// Distance from the main sequence `D = |A + I - 1|`.
+    pub distance: f64,
+}
+
+#[derive(Default)]
+struct ComponentAccumulator {
+    files: usize,
+    afferent: HashSet<PathBuf>,
+    efferent: HashSet<PathBuf>,
+    abstract_exports: usize,
+    total_exports: usize,
+}
+
+/// Assign every file to a component according to `grouping`.
+#[must_use]
+pub fn assign_components<'a>(
+    files: impl IntoIterator<Item = &'a PathBuf>,
+    project_root: &Path,
+    grouping: ComponentGrouping,
+) -> HashMap<PathBuf, String> {
+    match grouping {
+        ComponentGrouping::Folder { depth } => files
+            .into_iter()
+            .map(|path| (path.clone(), folder_name(path, project_root, depth)))
+            .collect(),
+        ComponentGrouping::Package => {
+            let packages: Vec<(PathBuf, String)> = PackageJsonParser::find_manifests(project_root)
+                .into_iter()
+                .map(|manifest| {
+                    let name = manifest
+                        .name
+                        .clone()
+                        .unwrap_or_else(|| folder_name(&manifest.dir, project_root, usize::MAX));
+                    (manifest.dir, name)
+                })
+                .collect();
+
+            files
+                .into_iter()
+                .filter_map(|path| {
+                    packages
+                        .iter()
+                        .filter(|(dir, _)| path.starts_with(dir))
+                        .max_by_key(|(dir, _)| dir.components().count())
+                        .map(|(_, name)| (path.clone(), name.clone()))
+                })
+                .collect()
+        }
+    }
+}
+
+/// Folder of `path` relative to the root, cut to `depth` components.
+pub(crate) fn folder_name(path: &Path, project_root: &Path, depth: usize) -> String {
+    let dir = if path.extension().is_some() {
+        path.parent().unwrap_or(path)
+    } else {
+        path
+    };
+
+    let rel_path = dir.strip_prefix(project_root).unwrap_or(dir);
+    let components: Vec<_> = rel_path
+        .components()
+        .take(depth)
+        .filter_map(|c| c.as_os_str().to_str())
+        .collect();
+
+    if components.is_empty() {
+        "root".to_string()
+    } else {
+        components.join("/")
+    }
+}
+
+/// Compute metrics for every component, sorted by name.
+#[must_use]
+pub fn compute_metrics(
+    graph: &DependencyGraph,
+    file_symbols: &HashMap<PathBuf, FileSymbols>,
+    components: &HashMap<PathBuf, String>,
+) -> Vec<ComponentMetrics> {
+    let mut acc: BTreeMap<&str, ComponentAccumulator> = BTreeMap::new();
+
+    for (path, component) in components {
+        let entry = acc.entry(component.as_str()).or_default();
+        entry.files += 1;
+        if let Some(symbols) = file_symbols.get(path) {
+            let (abstract_exports, total_exports) = count_exports(symbols);
+            entry.abstract_exports += abstract_exports;
+            entry.total_exports += total_exports;
+        }
+    }
+
+    for from in graph.nodes() {
+        let Some(from_path) = graph.get_file_path(from) else {
+            continue;
+        };
+        let from_component = components.get(from_path);
+
+        for to in graph.dependencies(from) {
+            let Some(to_path) = graph.get_file_path(to) else {
+                continue;
+            };
+            let to_component = components.get(to_path);
+            if from_component == to_component {
+                continue;
+            }
+
+            if let Some(entry) = from_component.and_then(|c| acc.get_mut(c.as_str())) {
+                entry.efferent.insert(to_path.clone());
+            }
+            if let Some(entry) = to_component.and_then(|c| acc.get_mut(c.as_str())) {
+                entry.afferent.insert(from_path.clone());
+            }
+        }
+    }
+
+    acc.into_iter()
+        .map(|(name, entry)| {
+            let ca = entry.afferent.len();
+            let ce = entry.efferent.len();
+            let instability = if ca + ce == 0 {
+                0.0
+            } else {
+                ce as f64 / (ca + ce) as f64
+            };
+            let abstractness = if entry.total_exports == 0 {
+                0.0
+            } else {
+                entry.abstract_exports as f64 / entry.total_exports as f64
+            };
+
+            ComponentMetrics {
+                name: name.to_string(),
+                files: entry.files,
+                ca,
+                ce,
+                instability,
+                abstractness,
+                distance: (abstractness + instability - 1.0).abs(),
+            }
+        })
+        .collect()
+}
+
+/// Returns `(abstract, total)` counts of the file's own exports.
+fn count_exports(symbols: &FileSymbols) -> (usize, usize) {
+    let mut abstract_exports = 0;
+    let mut total_exports = 0;
+
+    for export in symbols.exports.iter().filter(|e| !e.is_reexport) {
+        let is_abstract = match export.kind {
+            SymbolKind::Interface | SymbolKind::Type => true,
+            SymbolKind::Class => symbols
+                .classes
+                .iter()
+                .any(|c| c.name == export.name && c.is_abstract),
+            SymbolKind::Function => false,
+            _ => continue,
+        };
+        total_exports += 1;
+        if is_abstract {
+            abstract_exports += 1;
+        }
+    }
+
+    (abstract_exports, total_exports)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_folder_name_respects_depth() {
+        let root = Path::new("/project");
+        assert_eq!(
+            folder_name(Path::new("/project/src/core/user/model.ts"), root, 2),
+            "src/core"
+        );
+        assert_eq!(
+            folder_name(Path::new("/project/src/index.ts"), root, 2),
+            "src"
+        );
+        assert_eq!(folder_name(Path::new("/project/index.ts"), root, 2), "root");
+    }
+}
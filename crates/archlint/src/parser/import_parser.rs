@@ -1,5 +1,7 @@
+use crate::parser::line_index::CodeLines;
 use crate::parser::types::{
-    FileSymbols, FunctionComplexity, IgnoredRulesMap, ParsedFile, ParserConfig, SymbolSet,
+    FileDirective, FileSymbols, FunctionComplexity, IgnoredRulesMap, ParsedFile, ParserConfig,
+    SuppressionSource, SymbolSet,
 };
 use crate::parser::visitor::UnifiedVisitor;
 use crate::Result;
@@ -13,6 +15,16 @@ use std::path::Path;
 
 pub struct ImportParser;
 
+/// Open `archlint-disable` blocks keyed by rule.
+type ActiveBlocks = FxHashMap<String, SuppressionSource>;
+
+/// A parsed `archlint-*` comment.
+struct IgnoreCommand {
+    name: String,
+    rules: SymbolSet,
+    reason: Option<String>,
+}
+
 impl ImportParser {
     #[inline]
     pub const fn new() -> Result<Self> {
@@ -58,7 +70,14 @@ impl ImportParser {
 
         let ret = Parser::new(&allocator, content, source_type).parse();
 
-        let mut visitor = UnifiedVisitor::new(content, *config);
+        let comment_spans: Vec<(u32, u32)> = ret
+            .program
+            .comments
+            .iter()
+            .map(|c| (c.span.start, c.span.end))
+            .collect();
+        let mut visitor = UnifiedVisitor::new(content, *config)
+            .with_code_lines(CodeLines::new(content, &comment_spans));
         visitor.visit_program(&ret.program);
 
         let export_names: SymbolSet = visitor.exports.iter().map(|e| e.name.clone()).collect();
@@ -76,6 +95,14 @@ impl ImportParser {
                 classes: visitor.classes,
                 local_definitions: visitor.local_definitions,
                 local_usages: visitor.local_usages,
+                usages_outside_exports: visitor.usages_outside_exports,
+                injectables: visitor.injectables,
+                keyed_calls: visitor.keyed_calls,
+                directive: ret
+                    .program
+                    .directives
+                    .iter()
+                    .find_map(|d| FileDirective::from_directive(d.directive.as_str())),
                 has_runtime_code: visitor.has_runtime_code,
                 env_vars: visitor.env_vars,
             },
@@ -92,7 +119,7 @@ impl ImportParser {
         visitor: &UnifiedVisitor,
     ) -> IgnoredRulesMap {
         let mut ignored = IgnoredRulesMap::default();
-        let mut active_blocks: FxHashMap<String, usize> = FxHashMap::default();
+        let mut active_blocks: ActiveBlocks = FxHashMap::default();
         let total_lines = visitor.line_count();
 
         for comment in comments {
@@ -103,20 +130,18 @@ impl ImportParser {
             // Support multiline comments by checking each line
             if comment_text.contains('\n') {
                 for (offset, line_text) in comment_text.lines().enumerate() {
-                    if let Some((command, rules)) = self.parse_ignore_command(line_text) {
+                    if let Some(command) = self.parse_ignore_command(line_text) {
                         self.process_ignore_command(
                             command,
-                            rules,
                             comment_line + offset,
                             &mut active_blocks,
                             &mut ignored,
                         );
                     }
                 }
-            } else if let Some((command, rules)) = self.parse_ignore_command(comment_text) {
+            } else if let Some(command) = self.parse_ignore_command(comment_text) {
                 self.process_ignore_command(
                     command,
-                    rules,
                     comment_line,
                     &mut active_blocks,
                     &mut ignored,
@@ -130,27 +155,32 @@ impl ImportParser {
 
     fn process_ignore_command(
         &self,
-        command: String,
-        rules: SymbolSet,
+        command: IgnoreCommand,
         line: usize,
-        active_blocks: &mut FxHashMap<String, usize>,
+        active_blocks: &mut ActiveBlocks,
         ignored: &mut IgnoredRulesMap,
     ) {
-        match command.as_str() {
-            "archlint-disable" => self.handle_disable(rules, line, active_blocks),
+        let source = SuppressionSource {
+            line,
+            reason: command.reason,
+        };
+        let rules = command.rules;
+
+        match command.name.as_str() {
+            "archlint-disable" => self.handle_disable(rules, &source, active_blocks),
             "archlint-enable" => self.handle_enable(rules, line, active_blocks, ignored),
             "archlint-disable-line" => {
-                ignored
-                    .entry(line)
-                    .or_default()
-                    .extend(rules.into_iter().map(|r| r.to_string()));
+                let entry = ignored.entry(line).or_default();
+                for rule in rules {
+                    entry.insert(rule.as_str(), source.clone());
+                }
             }
             "archlint-disable-next-line" => {
                 if let Some(next_line) = line.checked_add(1) {
-                    ignored
-                        .entry(next_line)
-                        .or_default()
-                        .extend(rules.into_iter().map(|r| r.to_string()));
+                    let entry = ignored.entry(next_line).or_default();
+                    for rule in rules {
+                        entry.insert(rule.as_str(), source.clone());
+                    }
                 }
             }
             _ => {}
@@ -160,11 +190,11 @@ impl ImportParser {
     fn handle_disable(
         &self,
         rules: SymbolSet,
-        line: usize,
-        active_blocks: &mut FxHashMap<String, usize>,
+        source: &SuppressionSource,
+        active_blocks: &mut ActiveBlocks,
     ) {
         for rule in rules {
-            active_blocks.insert(rule.to_string(), line);
+            active_blocks.insert(rule.to_string(), source.clone());
         }
     }
 
@@ -172,7 +202,7 @@ impl ImportParser {
         &self,
         rules: SymbolSet,
         line: usize,
-        active_blocks: &mut FxHashMap<String, usize>,
+        active_blocks: &mut ActiveBlocks,
         ignored: &mut IgnoredRulesMap,
     ) {
         let rules_to_close: Vec<String> = if rules.contains("*") {
@@ -182,39 +212,40 @@ impl ImportParser {
         };
 
         for rule in rules_to_close {
-            if let Some(start_line) = active_blocks.remove(&rule) {
-                self.mark_range_ignored(ignored, start_line, line, &rule);
+            if let Some(source) = active_blocks.remove(&rule) {
+                self.mark_range_ignored(ignored, line, &rule, &source);
             }
         }
     }
 
     fn close_remaining_blocks(
         &self,
-        active_blocks: FxHashMap<String, usize>,
+        active_blocks: ActiveBlocks,
         total_lines: usize,
         ignored: &mut IgnoredRulesMap,
     ) {
-        for (rule, start_line) in active_blocks {
-            if rule == "*" && start_line <= 1 {
-                ignored.entry(0).or_default().insert("*".to_string());
+        for (rule, source) in active_blocks {
+            if rule == "*" && source.line <= 1 {
+                ignored.entry(0).or_default().insert("*", source.clone());
             }
-            self.mark_range_ignored(ignored, start_line, total_lines, &rule);
+            self.mark_range_ignored(ignored, total_lines, &rule, &source);
         }
     }
 
     fn mark_range_ignored(
         &self,
         ignored: &mut IgnoredRulesMap,
-        start: usize,
         end: usize,
         rule: &str,
+        source: &SuppressionSource,
     ) {
-        for l in start..=end {
-            ignored.entry(l).or_default().insert(rule.to_string());
+        for l in source.line..=end {
+            ignored.entry(l).or_default().insert(rule, source.clone());
         }
     }
 
-    fn parse_ignore_command(&self, text: &str) -> Option<(String, SymbolSet)> {
+    /// Parses `archlint-<command> [rule, ...] [-- reason]`.
+    fn parse_ignore_command(&self, text: &str) -> Option<IgnoreCommand> {
         let text = text.trim();
         let text = if let Some(stripped) = text.strip_prefix("//") {
             stripped
@@ -233,12 +264,20 @@ impl ImportParser {
             return None;
         }
 
+        let (text, reason) = match text.split_once("--") {
+            Some((head, reason)) => {
+                let reason = reason.trim().trim_end_matches("*/").trim_end();
+                (head, (!reason.is_empty()).then(|| reason.to_string()))
+            }
+            None => (text, None),
+        };
+
         let parts: Vec<&str> = text.split_whitespace().collect();
         if parts.is_empty() {
             return None;
         }
 
-        let command = parts[0].to_string();
+        let name = parts[0].to_string();
         let mut rules = SymbolSet::default();
 
         if parts.len() > 1 {
@@ -255,7 +294,11 @@ impl ImportParser {
             rules.insert("*".into());
         }
 
-        Some((command, rules))
+        Some(IgnoreCommand {
+            name,
+            rules,
+            reason,
+        })
     }
 
     #[inline]
@@ -336,4 +379,25 @@ mod tests {
         let res_js = parser.parse_code(js, "script.js").unwrap();
         assert!(res_js.symbols.has_runtime_code);
     }
+
+    #[test]
+    fn test_lines_of_code_for_functions_and_classes() {
+        let parser = ImportParser::new().unwrap();
+        let code = "class Service {\n  // cache\n  private items = [];\n\n  load() {\n    /* noop */\n    return this.items;\n  }\n}\n\nconst run = () => {\n  return 1;\n};\n";
+        let result = parser.parse_code(code, "service.ts").unwrap();
+
+        let class = &result.symbols.classes[0];
+        assert_eq!(class.loc, 6);
+        assert_eq!(class.line, 1);
+
+        let load = result
+            .functions
+            .iter()
+            .find(|f| f.name == "Service.load")
+            .unwrap();
+        assert_eq!(load.loc, 3);
+
+        let run = result.functions.iter().find(|f| f.name == "run").unwrap();
+        assert_eq!(run.loc, 3);
+    }
 }
@@ -56,10 +56,83 @@ impl LineIndex {
     }
 }
 
+/// Prefix sums of lines that contain code, for O(1) lines-of-code queries.
+/// Blank lines and lines holding only comments are not counted.
+pub struct CodeLines {
+    /// `prefix[n]` is the number of code lines among the first `n` lines
+    prefix: Vec<u32>,
+}
+
+impl CodeLines {
+    /// Build from source text and the byte spans `(start, end)` of all
+    /// comments, sorted by start offset.
+    #[must_use]
+    pub fn new(text: &str, comments: &[(u32, u32)]) -> Self {
+        let mut has_code = vec![false];
+        let mut comments = comments.iter().peekable();
+
+        for (offset, byte) in text.bytes().enumerate() {
+            if byte == b'\n' {
+                has_code.push(false);
+                continue;
+            }
+            let offset = offset as u32;
+            while comments.next_if(|(_, end)| *end <= offset).is_some() {}
+            let in_comment = comments
+                .peek()
+                .is_some_and(|(start, end)| *start <= offset && offset < *end);
+            if !in_comment && !byte.is_ascii_whitespace() {
+                if let Some(line) = has_code.last_mut() {
+                    *line = true;
+                }
+            }
+        }
+
+        let mut prefix = Vec::with_capacity(has_code.len() + 1);
+        prefix.push(0);
+        let mut total = 0;
+        for line in has_code {
+            total += u32::from(line);
+            prefix.push(total);
+        }
+
+        Self { prefix }
+    }
+
+    /// Number of code lines between two 1-based lines, inclusive
+    #[must_use]
+    pub fn count(&self, start_line: usize, end_line: usize) -> usize {
+        let last = self.prefix.len() - 1;
+        let end = end_line.min(last);
+        let start = start_line.max(1);
+        if start > end {
+            return 0;
+        }
+        (self.prefix[end] - self.prefix[start - 1]) as usize
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_code_lines_skip_blank_and_comments() {
+        let text = "function f() {\n\n  // comment\n  /* a\n   b */\n  return 1; // trailing\n}\n";
+        let comments: Vec<(u32, u32)> = ["// comment", "/* a\n   b */", "// trailing"]
+            .iter()
+            .map(|c| {
+                let start = text.find(c).unwrap() as u32;
+                (start, start + c.len() as u32)
+            })
+            .collect();
+        let lines = CodeLines::new(text, &comments);
+
+        assert_eq!(lines.count(1, 7), 3);
+        assert_eq!(lines.count(2, 5), 0);
+        assert_eq!(lines.count(6, 100), 2);
+    }
+
     #[test]
     fn test_line_index() {
         let text = "line1\nline2\nline3";
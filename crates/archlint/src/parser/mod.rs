@@ -12,8 +12,9 @@ pub use import_parser::ImportParser;
 pub use line_index::LineIndex;
 pub use tokenizer::{tokenize_and_normalize, NormalizedToken};
 pub use types::{
-    ClassSymbol, ExportedSymbol, FileIgnoredLines, FileSymbols, FunctionComplexity,
-    IgnoredRulesMap, ImportedSymbol, MethodAccessibility, MethodSymbol, ParsedFile, ParserConfig,
-    SymbolKind, SymbolName, SymbolSet,
+    ClassSymbol, ExportedSymbol, FieldSymbol, FileDirective, FileIgnoredLines, FileSymbols,
+    FunctionComplexity, IgnoredRules, IgnoredRulesMap, ImportedSymbol, InjectableClass,
+    InjectableRole, Injection, InjectionKind, KeyedCall, MethodAccessibility, MethodSymbol,
+    ParsedFile, ParserConfig, SuppressionSource, SymbolKind, SymbolName, SymbolSet,
 };
 pub use visitor::UnifiedVisitor;
@@ -1,4 +1,6 @@
+use crate::config::Config;
 use crate::detectors::CodeRange;
+use crate::rule_resolver::ResolvedRuleConfig;
 use compact_str::CompactString;
 use rustc_hash::{FxHashMap, FxHashSet};
 use serde::{Deserialize, Serialize};
@@ -11,12 +13,83 @@ pub type SymbolName = CompactString;
 /// Fast hash set optimized for string keys
 pub type SymbolSet = FxHashSet<SymbolName>;
 
-/// Map of line number to set of ignored rule IDs
-pub type IgnoredRulesMap = FxHashMap<usize, FxHashSet<String>>;
+/// Map of line number to the rules ignored on it
+pub type IgnoredRulesMap = FxHashMap<usize, IgnoredRules>;
 
 /// Map of file path to its ignored lines and rules
 pub type FileIgnoredLines = FxHashMap<std::path::PathBuf, IgnoredRulesMap>;
 
+/// The `archlint-disable*` comment that suppresses a rule on a line.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SuppressionSource {
+    /// Line of the suppression comment (1-based).
+    pub line: usize,
+    /// Text after `--` in the comment, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+/// Rules ignored on a single line, each with every comment that ignores it.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IgnoredRules {
+    rules: FxHashMap<String, Vec<SuppressionSource>>,
+}
+
+impl IgnoredRules {
+    /// Record `rule` as ignored by the comment `source`. A line covered by
+    /// several comments keeps all of them.
+    pub fn insert(&mut self, rule: impl Into<String>, source: SuppressionSource) {
+        let sources = self.rules.entry(rule.into()).or_default();
+        if !sources.contains(&source) {
+            sources.push(source);
+        }
+    }
+
+    /// Whether `rule` (or `*`) was literally listed for this line.
+    #[must_use]
+    pub fn contains(&self, rule: &str) -> bool {
+        self.rules.contains_key(rule)
+    }
+
+    /// The comment suppressing `rule_id` on this line, preferring an explicit
+    /// rule over `*` and, among comments for the same rule, one with a reason.
+    #[must_use]
+    pub fn source_for(&self, rule_id: &str) -> Option<&SuppressionSource> {
+        self.rules
+            .get(rule_id)
+            .and_then(|sources| Self::preferred(sources))
+            .or_else(|| {
+                self.rules
+                    .get("*")
+                    .and_then(|sources| Self::preferred(sources))
+            })
+    }
+
+    fn preferred(sources: &[SuppressionSource]) -> Option<&SuppressionSource> {
+        sources
+            .iter()
+            .find(|s| s.reason.is_some())
+            .or_else(|| sources.first())
+    }
+
+    /// Every `(rule, comment)` pair of this line.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &SuppressionSource)> {
+        self.rules
+            .iter()
+            .flat_map(|(rule, sources)| sources.iter().map(move |source| (rule.as_str(), source)))
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+}
+
 /// The kind of a code symbol (e.g., function, class, type).
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum SymbolKind {
@@ -206,6 +279,25 @@ impl MethodSymbol {
     }
 }
 
+/// Detailed information about a class field or parameter property.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FieldSymbol {
+    /// Name of the field (without the `#` prefix for private names).
+    pub name: SymbolName,
+    /// Line number of the field definition.
+    pub line: usize,
+    /// Column number of the field definition.
+    pub column: usize,
+    /// Exact code range of the field key.
+    pub range: CodeRange,
+    /// Optional accessibility level (`#name` fields are reported as private).
+    pub accessibility: Option<MethodAccessibility>,
+    /// Whether the field has any decorators.
+    pub has_decorators: bool,
+    /// Whether the field is abstract.
+    pub is_abstract: bool,
+}
+
 /// Information about a class definition.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ClassSymbol {
@@ -221,6 +313,21 @@ pub struct ClassSymbol {
     pub methods: SmallVec<[MethodSymbol; 8]>,
     /// Whether the class is abstract.
     pub is_abstract: bool,
+    /// Declared fields and parameter properties with their locations.
+    #[serde(default)]
+    pub field_symbols: Vec<FieldSymbol>,
+    /// Member names accessed anywhere inside the class body.
+    #[serde(default)]
+    pub used_members: SymbolSet,
+    /// Line number of the class declaration.
+    #[serde(default)]
+    pub line: usize,
+    /// Exact code range of the class name.
+    #[serde(default)]
+    pub range: CodeRange,
+    /// Lines of code in the class, excluding blank and comment-only lines.
+    #[serde(default)]
+    pub loc: usize,
 }
 
 impl ClassSymbol {
@@ -234,10 +341,104 @@ impl ClassSymbol {
             fields: SmallVec::new(),
             methods: SmallVec::new(),
             is_abstract: false,
+            field_symbols: Vec::new(),
+            used_members: SymbolSet::default(),
+            line: 0,
+            range: CodeRange::default(),
+            loc: 0,
+        }
+    }
+}
+
+/// A call passing a string literal, such as an event name or a queue topic.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct KeyedCall {
+    /// Called function with its static object path: `emit`, `this.events.emit`.
+    pub callee: SymbolName,
+    /// Index of the argument holding the key.
+    pub argument: usize,
+    /// Property of an object argument holding the key: `type` in `dispatch({ type: 'x' })`.
+    pub property: Option<SymbolName>,
+    /// The string literal.
+    pub key: SymbolName,
+    /// Line number of the call (1-based).
+    pub line: usize,
+}
+
+/// Module-level directive marking a React Server Components boundary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum FileDirective {
+    /// `"use client"`: the file and everything it imports is bundled for the browser.
+    UseClient,
+    /// `"use server"`: the exports are server actions the client calls over the network.
+    UseServer,
+}
+
+impl FileDirective {
+    /// Parse the text of a directive prologue entry.
+    #[must_use]
+    pub fn from_directive(directive: &str) -> Option<Self> {
+        match directive {
+            "use client" => Some(Self::UseClient),
+            "use server" => Some(Self::UseServer),
+            _ => None,
         }
     }
 }
 
+/// Role of a class in a dependency injection container, taken from its decorator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum InjectableRole {
+    /// `@Module` (Nest) or `@NgModule` (Angular).
+    Module,
+    /// `@Injectable` services.
+    Provider,
+    /// `@Controller`, `@Resolver` and `@WebSocketGateway`.
+    Controller,
+    /// `@Component`, `@Directive` and `@Pipe`.
+    Declarable,
+}
+
+/// How a decorated class refers to another class or injection token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum InjectionKind {
+    /// Constructor parameter type, `@Inject(TOKEN)` or `inject(Token)`.
+    Inject,
+    /// Entry of the `imports` metadata array.
+    Import,
+    /// Entry of the `providers` metadata array.
+    Provide,
+    /// Entry of the `exports` metadata array.
+    Export,
+    /// Entry of the `controllers` or `declarations` metadata array.
+    Declare,
+}
+
+/// A reference from a decorated class to another class or token.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Injection {
+    /// Local name of the referenced class or token; string tokens keep their value.
+    pub target: SymbolName,
+    pub kind: InjectionKind,
+    /// Line of the reference (1-based).
+    pub line: usize,
+}
+
+/// A class managed by a dependency injection container.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InjectableClass {
+    /// Name of the class.
+    pub name: SymbolName,
+    pub role: InjectableRole,
+    /// Whether the class is visible everywhere: `@Global()` modules, Angular
+    /// `@NgModule`s and providers with `providedIn: 'root'`.
+    pub is_global: bool,
+    /// Line number of the class declaration.
+    pub line: usize,
+    /// Injected dependencies and module metadata entries, in source order.
+    pub injections: Vec<Injection>,
+}
+
 /// Collection of all symbols extracted from a single source file.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct FileSymbols {
@@ -251,6 +452,14 @@ pub struct FileSymbols {
     pub local_definitions: Vec<SymbolName>,
     /// Set of all symbol names used in the file.
     pub local_usages: SymbolSet,
+    /// Symbol names used outside of any exported declaration.
+    pub usages_outside_exports: SymbolSet,
+    /// Decorated classes taking part in dependency injection.
+    pub injectables: Vec<InjectableClass>,
+    /// `"use client"` or `"use server"` directive at the top of the file.
+    pub directive: Option<FileDirective>,
+    /// Calls passing string literals, matched against event patterns.
+    pub keyed_calls: Vec<KeyedCall>,
     /// Whether the file contains any executable runtime code.
     pub has_runtime_code: bool,
     /// Environment variables accessed in the file.
@@ -266,6 +475,9 @@ pub struct FunctionComplexity {
     pub line: usize,
     /// Exact code range of the function.
     pub range: CodeRange,
+    /// Code range of the whole function, including its body.
+    #[serde(default)]
+    pub body_range: CodeRange,
     /// Cyclomatic complexity score.
     pub cyclomatic_complexity: usize,
     /// Cognitive complexity score.
@@ -278,6 +490,9 @@ pub struct FunctionComplexity {
     pub primitive_params: usize,
     /// Whether the function is a constructor.
     pub is_constructor: bool,
+    /// Lines of code, excluding blank and comment-only lines.
+    #[serde(default)]
+    pub loc: usize,
 }
 
 /// Full results of parsing a single file.
@@ -306,6 +521,10 @@ pub struct ParserConfig {
     pub collect_env_vars: bool,
     /// Whether to track all symbol usages (required for some design detectors).
     pub collect_used_symbols: bool,
+    /// Whether to extract dependency injection metadata from decorators.
+    pub collect_injections: bool,
+    /// Whether to record calls passing string literals (event names, topics).
+    pub collect_keyed_calls: bool,
 }
 
 impl Default for ParserConfig {
@@ -325,6 +544,8 @@ impl ParserConfig {
             collect_classes: true,
             collect_env_vars: true,
             collect_used_symbols: true,
+            collect_injections: true,
+            collect_keyed_calls: true,
         }
     }
 
@@ -337,11 +558,22 @@ impl ParserConfig {
             collect_classes: false,
             collect_env_vars: false,
             collect_used_symbols: false,
+            collect_injections: false,
+            collect_keyed_calls: false,
         }
     }
 
+    /// Parser settings needed by `active_ids`, with options read from `config`.
     #[must_use]
-    pub fn from_active_detectors(active_ids: &HashSet<String>) -> Self {
+    pub fn from_active_detectors(active_ids: &HashSet<String>, config: &Config) -> Self {
+        // Symbol-level graphs need per-export usages; file-level analysis does not
+        let option_set = |id: &str, option: &str| {
+            active_ids.contains(id)
+                && ResolvedRuleConfig::resolve(config, id, None)
+                    .get_option(option)
+                    .unwrap_or(false)
+        };
+
         Self {
             collect_complexity: active_ids.iter().any(|id| {
                 matches!(
@@ -359,10 +591,18 @@ impl ParserConfig {
             collect_primitive_params: active_ids.contains("primitive_obsession"),
             collect_classes: active_ids.contains("lcom")
                 || active_ids.contains("dead_symbols")
-                || active_ids.contains("abstractness"),
+                || active_ids.contains("abstractness")
+                || active_ids.contains("unused_class_members")
+                || active_ids.contains("large_class"),
             collect_env_vars: active_ids.contains("scattered_config"),
             collect_used_symbols: active_ids.contains("scattered_module")
-                || active_ids.contains("lcom"),
+                || active_ids.contains("lcom")
+                || option_set("cyclic_dependency", "symbol_level")
+                || option_set("dead_symbols", "transitive"),
+            collect_injections: active_ids.contains("circular_module_imports")
+                || active_ids.contains("provider_outside_module"),
+            collect_keyed_calls: active_ids.contains("orphan_events")
+                || active_ids.contains("event_cycles"),
         }
     }
 }
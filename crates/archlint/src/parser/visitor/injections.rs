@@ -0,0 +1,214 @@
+use crate::parser::types::{InjectableClass, InjectableRole, Injection, InjectionKind, SymbolName};
+use crate::parser::visitor::UnifiedVisitor;
+use compact_str::CompactString;
+use oxc_ast::ast::{
+    Argument, Class, ClassElement, Decorator, Expression, FormalParameter, MethodDefinitionKind,
+    ObjectExpression, ObjectPropertyKind, TSType,
+};
+use oxc_span::GetSpan;
+
+impl<'a> UnifiedVisitor {
+    /// Record the class if one of its decorators registers it for dependency injection.
+    pub(crate) fn collect_injectable(&mut self, it: &Class<'a>) {
+        let Some(id) = &it.id else {
+            return;
+        };
+
+        let mut role = None;
+        let mut is_global = false;
+        let mut injections = Vec::new();
+        for decorator in &it.decorators {
+            let Some((name, arguments)) = decorator_call(decorator) else {
+                continue;
+            };
+            match name {
+                "Module" => role = Some(InjectableRole::Module),
+                // NgModule providers are registered with the application injector
+                "NgModule" => {
+                    role = Some(InjectableRole::Module);
+                    is_global = true;
+                }
+                "Injectable" => {
+                    role.get_or_insert(InjectableRole::Provider);
+                }
+                "Controller" | "Resolver" | "WebSocketGateway" => {
+                    role = Some(InjectableRole::Controller);
+                }
+                "Component" | "Directive" | "Pipe" => role = Some(InjectableRole::Declarable),
+                "Global" => is_global = true,
+                _ => continue,
+            }
+            if let Some(Expression::ObjectExpression(metadata)) =
+                arguments.first().and_then(Argument::as_expression)
+            {
+                is_global |= self.collect_metadata(metadata, &mut injections);
+            }
+        }
+        let Some(role) = role else {
+            return;
+        };
+
+        self.collect_class_injections(it, &mut injections);
+
+        self.injectables.push(InjectableClass {
+            name: Self::atom_to_compact(&id.name),
+            role,
+            is_global,
+            line: self.get_line_number(id.span),
+            injections,
+        });
+    }
+
+    /// Collect constructor parameters and `inject()` field initializers.
+    fn collect_class_injections(&self, it: &Class<'a>, injections: &mut Vec<Injection>) {
+        for element in &it.body.body {
+            match element {
+                ClassElement::MethodDefinition(m)
+                    if m.kind == MethodDefinitionKind::Constructor =>
+                {
+                    for param in &m.value.params.items {
+                        if let Some(target) = injected_parameter(param) {
+                            injections.push(Injection {
+                                target,
+                                kind: InjectionKind::Inject,
+                                line: self.get_line_number(param.span),
+                            });
+                        }
+                    }
+                }
+                // Angular's `private service = inject(Service);`
+                ClassElement::PropertyDefinition(p) => {
+                    if let Some(Expression::CallExpression(call)) = &p.value {
+                        if call.callee.is_specific_id("inject") {
+                            if let Some(target) = call
+                                .arguments
+                                .first()
+                                .and_then(Argument::as_expression)
+                                .and_then(injection_target)
+                            {
+                                injections.push(Injection {
+                                    target,
+                                    kind: InjectionKind::Inject,
+                                    line: self.get_line_number(p.span),
+                                });
+                            }
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Collect entries of the metadata arrays; returns whether the metadata
+    /// makes the class available everywhere (`providedIn: 'root'`).
+    fn collect_metadata(
+        &self,
+        metadata: &ObjectExpression<'a>,
+        injections: &mut Vec<Injection>,
+    ) -> bool {
+        let mut is_global = false;
+        for property in &metadata.properties {
+            let ObjectPropertyKind::ObjectProperty(property) = property else {
+                continue;
+            };
+            let kind = match property.key.static_name().as_deref() {
+                Some("imports") => InjectionKind::Import,
+                Some("providers") => InjectionKind::Provide,
+                Some("exports") => InjectionKind::Export,
+                Some("controllers" | "declarations") => InjectionKind::Declare,
+                Some("providedIn") => {
+                    is_global |= matches!(
+                        property.value.get_inner_expression(),
+                        Expression::StringLiteral(s) if matches!(s.value.as_str(), "root" | "platform" | "any")
+                    );
+                    continue;
+                }
+                _ => continue,
+            };
+            let Expression::ArrayExpression(array) = property.value.get_inner_expression() else {
+                continue;
+            };
+            for element in &array.elements {
+                let Some(expression) = element.as_expression() else {
+                    continue;
+                };
+                if let Some(target) = injection_target(expression) {
+                    injections.push(Injection {
+                        target,
+                        kind,
+                        line: self.get_line_number(expression.span()),
+                    });
+                }
+            }
+        }
+        is_global
+    }
+}
+
+/// Name and arguments of `@Name(...)` or `@Name`.
+fn decorator_call<'d, 'a>(decorator: &'d Decorator<'a>) -> Option<(&'d str, &'d [Argument<'a>])> {
+    match &decorator.expression {
+        Expression::Identifier(id) => Some((id.name.as_str(), &[])),
+        Expression::CallExpression(call) => match &call.callee {
+            Expression::Identifier(id) => Some((id.name.as_str(), call.arguments.as_slice())),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Token of `@Inject(TOKEN)`, otherwise the type of a constructor parameter.
+fn injected_parameter(param: &FormalParameter<'_>) -> Option<SymbolName> {
+    for decorator in &param.decorators {
+        if let Some(("Inject", arguments)) = decorator_call(decorator) {
+            return arguments
+                .first()
+                .and_then(Argument::as_expression)
+                .and_then(injection_target);
+        }
+    }
+    match &param.type_annotation.as_ref()?.type_annotation {
+        TSType::TSTypeReference(reference) => Some(CompactString::new(
+            UnifiedVisitor::ts_type_name_to_string(&reference.type_name),
+        )),
+        _ => None,
+    }
+}
+
+/// Class or token named by a metadata entry or an `@Inject` argument:
+/// `Service`, `'TOKEN'`, `forwardRef(() => Service)`, `ConfigModule.forRoot()`
+/// and `{ provide: TOKEN, useClass: Service }`.
+fn injection_target(expression: &Expression<'_>) -> Option<SymbolName> {
+    match expression.get_inner_expression() {
+        Expression::Identifier(id) => Some(CompactString::new(id.name.as_str())),
+        Expression::StringLiteral(s) => Some(CompactString::new(s.value.as_str())),
+        Expression::StaticMemberExpression(member) => match &member.object {
+            Expression::Identifier(object) => Some(CompactString::new(format!(
+                "{}.{}",
+                object.name, member.property.name
+            ))),
+            _ => None,
+        },
+        Expression::CallExpression(call) => match &call.callee {
+            Expression::Identifier(id) if id.name == "forwardRef" => {
+                match call.arguments.first().and_then(Argument::as_expression)? {
+                    Expression::ArrowFunctionExpression(arrow) => {
+                        arrow.get_expression().and_then(injection_target)
+                    }
+                    _ => None,
+                }
+            }
+            // Dynamic modules: `TypeOrmModule.forFeature([...])`
+            Expression::StaticMemberExpression(member) => injection_target(&member.object),
+            _ => None,
+        },
+        Expression::ObjectExpression(object) => object.properties.iter().find_map(|p| match p {
+            ObjectPropertyKind::ObjectProperty(p) if p.key.is_specific_static_name("provide") => {
+                injection_target(&p.value)
+            }
+            _ => None,
+        }),
+        _ => None,
+    }
+}
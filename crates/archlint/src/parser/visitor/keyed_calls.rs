@@ -0,0 +1,78 @@
+use crate::parser::types::{KeyedCall, SymbolName};
+use crate::parser::visitor::UnifiedVisitor;
+use compact_str::CompactString;
+use oxc_ast::ast::{CallExpression, Expression, ObjectPropertyKind};
+
+impl<'a> UnifiedVisitor {
+    /// Record the string literals passed to a call, directly or as properties
+    /// of an object argument. Patterns are applied after parsing, so changing
+    /// them does not invalidate cached files.
+    pub(crate) fn collect_keyed_call(&mut self, call: &CallExpression<'a>) {
+        let Some(callee) = callee_path(&call.callee) else {
+            return;
+        };
+        let line = self.get_line_number(call.span);
+
+        for (argument, arg) in call.arguments.iter().enumerate() {
+            let Some(expression) = arg.as_expression() else {
+                continue;
+            };
+            if let Some(key) = string_value(expression) {
+                self.keyed_calls.push(KeyedCall {
+                    callee: callee.clone(),
+                    argument,
+                    property: None,
+                    key,
+                    line,
+                });
+            } else if let Expression::ObjectExpression(object) = expression.get_inner_expression() {
+                for property in &object.properties {
+                    let ObjectPropertyKind::ObjectProperty(property) = property else {
+                        continue;
+                    };
+                    if let (Some(name), Some(key)) =
+                        (property.key.static_name(), string_value(&property.value))
+                    {
+                        self.keyed_calls.push(KeyedCall {
+                            callee: callee.clone(),
+                            argument,
+                            property: Some(CompactString::new(name)),
+                            key,
+                            line,
+                        });
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// `emit`, `bus.emit` or `this.events.emit`; `None` for computed callees.
+fn callee_path(callee: &Expression<'_>) -> Option<SymbolName> {
+    match callee.get_inner_expression() {
+        Expression::Identifier(id) => Some(CompactString::new(id.name.as_str())),
+        Expression::ThisExpression(_) => Some(CompactString::const_new("this")),
+        Expression::StaticMemberExpression(member) => {
+            let property = member.property.name.as_str();
+            // Keep the method name even when the object is a call or an index
+            Some(callee_path(&member.object).map_or_else(
+                || CompactString::new(property),
+                |object| CompactString::new(format!("{object}.{property}")),
+            ))
+        }
+        _ => None,
+    }
+}
+
+/// Value of a string literal or a template literal without substitutions.
+fn string_value(expression: &Expression<'_>) -> Option<SymbolName> {
+    match expression.get_inner_expression() {
+        Expression::StringLiteral(s) => Some(CompactString::new(s.value.as_str())),
+        Expression::TemplateLiteral(t) if t.expressions.is_empty() => t
+            .quasis
+            .first()
+            .and_then(|q| q.value.cooked.as_ref())
+            .map(|cooked| CompactString::new(cooked.as_str())),
+        _ => None,
+    }
+}
@@ -1,5 +1,6 @@
 use crate::parser::types::ImportedSymbol;
 use crate::parser::visitor::{interned, UnifiedVisitor};
+use compact_str::CompactString;
 use oxc_ast::ast::{Argument, Expression};
 
 impl<'a> UnifiedVisitor {
@@ -14,14 +15,16 @@ impl<'a> UnifiedVisitor {
                 method.used_fields.insert(name.clone());
                 method.used_methods.insert(name.clone());
             }
-            if let Some(idx) = self.current_top_level_export {
-                self.exports[idx].used_symbols.insert(name);
-            }
+            self.record_symbol_usage(name);
         }
     }
 
     pub(crate) fn handle_jsx_identifier(&mut self, it: &oxc_ast::ast::JSXIdentifier<'a>) {
-        self.local_usages.insert(Self::atom_to_compact(&it.name));
+        let name = Self::atom_to_compact(&it.name);
+        self.local_usages.insert(name.clone());
+        if self.config.collect_used_symbols {
+            self.record_symbol_usage(name);
+        }
     }
 
     pub(crate) fn handle_member_expression(&mut self, it: &oxc_ast::ast::MemberExpression<'a>) {
@@ -38,8 +41,11 @@ impl<'a> UnifiedVisitor {
 
     pub(crate) fn handle_ts_type_name(&mut self, it: &oxc_ast::ast::TSTypeName<'a>) {
         if let oxc_ast::ast::TSTypeName::QualifiedName(qn) = it {
-            self.local_usages
-                .insert(Self::atom_to_compact(&qn.right.name));
+            let name = Self::atom_to_compact(&qn.right.name);
+            self.local_usages.insert(name.clone());
+            if self.config.collect_used_symbols {
+                self.record_symbol_usage(name);
+            }
         }
         oxc_ast_visit::walk::walk_ts_type_name(self, it);
     }
@@ -64,6 +70,9 @@ impl<'a> UnifiedVisitor {
             }
             Expression::CallExpression(call) => {
                 self.has_runtime_code = true;
+                if self.config.collect_keyed_calls {
+                    self.collect_keyed_call(call);
+                }
                 if let Expression::Identifier(ident) = &call.callee {
                     if ident.name == "require" && call.arguments.len() == 1 {
                         if let Argument::StringLiteral(source) = &call.arguments[0] {
@@ -91,6 +100,7 @@ impl<'a> UnifiedVisitor {
     pub(crate) fn handle_static_member(&mut self, s: &oxc_ast::ast::StaticMemberExpression<'_>) {
         let name = Self::atom_to_compact(&s.property.name);
         self.local_usages.insert(name.clone());
+        self.record_member_usage(&name);
 
         if self.config.collect_used_symbols {
             if let Expression::ThisExpression(_) = &s.object {
@@ -99,9 +109,7 @@ impl<'a> UnifiedVisitor {
                     method.used_methods.insert(name.clone());
                 }
             }
-            if let Some(idx) = self.current_top_level_export {
-                self.exports[idx].used_symbols.insert(name.clone());
-            }
+            self.record_symbol_usage(name.clone());
         }
 
         if self.config.collect_env_vars && Self::is_env_object(&s.object) {
@@ -116,6 +124,7 @@ impl<'a> UnifiedVisitor {
         if let Expression::StringLiteral(s) = &c.expression {
             let name = Self::atom_to_compact(&s.value);
             self.local_usages.insert(name.clone());
+            self.record_member_usage(&name);
 
             if self.config.collect_env_vars && Self::is_env_object(&c.object) {
                 self.env_vars.insert(name);
@@ -128,6 +137,7 @@ impl<'a> UnifiedVisitor {
         // The field.name contains the name without #
         let name = Self::atom_to_compact(&p.field.name);
         self.local_usages.insert(name.clone());
+        self.record_member_usage(&name);
 
         if self.config.collect_used_symbols {
             if let Expression::ThisExpression(_) = &p.object {
@@ -136,9 +146,25 @@ impl<'a> UnifiedVisitor {
                     method.used_methods.insert(name.clone());
                 }
             }
-            if let Some(idx) = self.current_top_level_export {
-                self.exports[idx].used_symbols.insert(name);
-            }
+            self.record_symbol_usage(name);
+        }
+    }
+
+    /// Attribute a used name to the enclosing top-level export, or to the
+    /// file itself outside of exports.
+    #[inline]
+    pub(crate) fn record_symbol_usage(&mut self, name: CompactString) {
+        match self.current_top_level_export {
+            Some(idx) => self.exports[idx].used_symbols.insert(name),
+            None => self.usages_outside_exports.insert(name),
+        };
+    }
+
+    /// Record a member access made anywhere inside the current class body.
+    #[inline]
+    fn record_member_usage(&mut self, name: &CompactString) {
+        if self.config.collect_classes && self.current_class.is_some() {
+            self.temp_member_usages.insert(name.clone());
         }
     }
 
@@ -1,5 +1,6 @@
 use crate::parser::types::{
-    ClassSymbol, FunctionComplexity, MethodAccessibility, MethodSymbol, SymbolName, SymbolSet,
+    ClassSymbol, FieldSymbol, FunctionComplexity, MethodAccessibility, MethodSymbol, SymbolName,
+    SymbolSet,
 };
 use crate::parser::visitor::{interned, UnifiedVisitor};
 use crate::parser::{calculate_arrow_complexity, calculate_complexity, ComplexityMetrics};
@@ -12,10 +13,15 @@ use smallvec::SmallVec;
 impl<'a> UnifiedVisitor {
     pub(crate) fn handle_class(&mut self, it: &Class<'a>) {
         let (class_name, old_fields, old_methods, old_class) = self.enter_class_scope(it);
+        let old_field_symbols = std::mem::take(&mut self.temp_field_symbols);
+        let old_member_usages = std::mem::take(&mut self.temp_member_usages);
 
         if self.config.collect_classes {
             self.collect_class_fields(it);
         }
+        if self.config.collect_injections {
+            self.collect_injectable(it);
+        }
 
         oxc_ast_visit::walk::walk_class(self, it);
 
@@ -24,9 +30,25 @@ impl<'a> UnifiedVisitor {
         }
 
         self.exit_class_scope(old_fields, old_methods, old_class);
+        self.temp_field_symbols = old_field_symbols;
+        self.temp_member_usages = old_member_usages;
     }
 
     pub(crate) fn handle_variable_declarator(&mut self, it: &oxc_ast::ast::VariableDeclarator<'a>) {
+        // `const { a, b } = this;` reads members without a member expression.
+        if let (
+            oxc_ast::ast::BindingPattern::ObjectPattern(pattern),
+            Some(Expression::ThisExpression(_)),
+        ) = (&it.id, &it.init)
+        {
+            if self.config.collect_classes && self.current_class.is_some() {
+                for prop in &pattern.properties {
+                    if let Some(name) = prop.key.static_name() {
+                        self.temp_member_usages.insert(CompactString::new(&name));
+                    }
+                }
+            }
+        }
         if let oxc_ast::ast::BindingPattern::BindingIdentifier(ref id) = &it.id {
             if let Some(ref init) = it.init {
                 if matches!(
@@ -61,11 +83,11 @@ impl<'a> UnifiedVisitor {
                 it.kind,
                 MethodDefinitionKind::Get | MethodDefinitionKind::Set
             );
-            let accessibility = it.accessibility.map(|a| match a {
-                oxc_ast::ast::TSAccessibility::Public => MethodAccessibility::Public,
-                oxc_ast::ast::TSAccessibility::Protected => MethodAccessibility::Protected,
-                oxc_ast::ast::TSAccessibility::Private => MethodAccessibility::Private,
-            });
+            let accessibility = if it.key.is_private_identifier() {
+                Some(MethodAccessibility::Private)
+            } else {
+                it.accessibility.map(Self::map_accessibility)
+            };
             self.current_method = Some(MethodSymbol::new(
                 name.clone(),
                 line_num,
@@ -114,14 +136,7 @@ impl<'a> UnifiedVisitor {
             .or(self.current_span_override.take())
             .unwrap_or(it.span);
 
-        self.collect_function_metrics(
-            name,
-            span,
-            metrics.cyclomatic,
-            metrics.cognitive,
-            metrics.max_depth,
-            &it.params,
-        );
+        self.collect_function_metrics(name, span, it.span, &metrics, &it.params);
 
         oxc_ast_visit::walk::walk_function(self, it, flags);
     }
@@ -144,14 +159,7 @@ impl<'a> UnifiedVisitor {
 
         let span = self.current_span_override.take().unwrap_or(it.span);
 
-        self.collect_function_metrics(
-            name,
-            span,
-            metrics.cyclomatic,
-            metrics.cognitive,
-            metrics.max_depth,
-            &it.params,
-        );
+        self.collect_function_metrics(name, span, it.span, &metrics, &it.params);
 
         oxc_ast_visit::walk::walk_arrow_function_expression(self, it);
     }
@@ -171,13 +179,13 @@ impl<'a> UnifiedVisitor {
         &mut self,
         name: CompactString,
         span: oxc_span::Span,
-        cyclomatic_complexity: usize,
-        cognitive_complexity: usize,
-        max_depth: usize,
+        body_span: oxc_span::Span,
+        metrics: &ComplexityMetrics,
         params: &oxc_ast::ast::FormalParameters<'a>,
     ) {
         let line = self.get_line_number(span);
         let range = self.get_range(span);
+        let body_range = self.get_range(body_span);
 
         let param_count = params.items.len();
         let primitive_params = if self.config.collect_primitive_params {
@@ -192,12 +200,14 @@ impl<'a> UnifiedVisitor {
             name,
             line,
             range,
-            cyclomatic_complexity,
-            cognitive_complexity,
-            max_depth,
+            body_range,
+            cyclomatic_complexity: metrics.cyclomatic,
+            cognitive_complexity: metrics.cognitive,
+            max_depth: metrics.max_depth,
             param_count,
             primitive_params,
             is_constructor,
+            loc: self.lines_of_code(body_span),
         });
     }
 
@@ -250,12 +260,54 @@ impl<'a> UnifiedVisitor {
         (class_name, old_fields, old_methods, old_class)
     }
 
+    const fn map_accessibility(a: oxc_ast::ast::TSAccessibility) -> MethodAccessibility {
+        match a {
+            oxc_ast::ast::TSAccessibility::Public => MethodAccessibility::Public,
+            oxc_ast::ast::TSAccessibility::Protected => MethodAccessibility::Protected,
+            oxc_ast::ast::TSAccessibility::Private => MethodAccessibility::Private,
+        }
+    }
+
+    fn push_field_symbol(
+        &mut self,
+        name: SymbolName,
+        span: oxc_span::Span,
+        accessibility: Option<MethodAccessibility>,
+        has_decorators: bool,
+        is_abstract: bool,
+    ) {
+        let (line, column) = self.line_index.line_col(span.start as usize);
+        let range = self.get_range(span);
+        self.temp_field_symbols.push(FieldSymbol {
+            name,
+            line,
+            column,
+            range,
+            accessibility,
+            has_decorators,
+            is_abstract,
+        });
+    }
+
     fn collect_class_fields(&mut self, it: &Class<'a>) {
         for item in &it.body.body {
             match item {
                 ClassElement::PropertyDefinition(p) => {
                     if let Some(name) = p.key.name() {
-                        self.temp_fields.insert(CompactString::new(&name));
+                        let name = CompactString::new(&name);
+                        let accessibility = if p.key.is_private_identifier() {
+                            Some(MethodAccessibility::Private)
+                        } else {
+                            p.accessibility.map(Self::map_accessibility)
+                        };
+                        self.push_field_symbol(
+                            name.clone(),
+                            p.key.span(),
+                            accessibility,
+                            !p.decorators.is_empty(),
+                            p.r#type.is_abstract(),
+                        );
+                        self.temp_fields.insert(name);
                     }
                 }
                 ClassElement::MethodDefinition(m)
@@ -269,7 +321,18 @@ impl<'a> UnifiedVisitor {
                         // We collect all constructor parameters as fields to support both cases.
                         if let oxc_ast::ast::BindingPattern::BindingIdentifier(id) = &param.pattern
                         {
-                            self.temp_fields.insert(Self::atom_to_compact(&id.name));
+                            let name = Self::atom_to_compact(&id.name);
+                            // Only real parameter properties are tracked as declared members.
+                            if param.accessibility.is_some() || param.readonly {
+                                self.push_field_symbol(
+                                    name.clone(),
+                                    id.span,
+                                    param.accessibility.map(Self::map_accessibility),
+                                    !param.decorators.is_empty(),
+                                    false,
+                                );
+                            }
+                            self.temp_fields.insert(name);
                         }
                     }
                 }
@@ -304,6 +367,7 @@ impl<'a> UnifiedVisitor {
             implements.push(CompactString::new(name));
         }
 
+        let name_span = it.id.as_ref().map_or(it.span, |id| id.span);
         let class_symbol = ClassSymbol {
             name: class_name,
             super_class,
@@ -311,6 +375,11 @@ impl<'a> UnifiedVisitor {
             fields: self.temp_fields.iter().cloned().collect(),
             methods: self.temp_methods.clone(),
             is_abstract: it.r#abstract,
+            field_symbols: self.temp_field_symbols.clone(),
+            used_members: self.temp_member_usages.clone(),
+            line: self.get_line_number(name_span),
+            range: self.get_range(name_span),
+            loc: self.lines_of_code(it.span),
         };
         self.classes.push(class_symbol);
     }
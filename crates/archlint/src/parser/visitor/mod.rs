@@ -1,8 +1,8 @@
 use crate::detectors::CodeRange;
-use crate::parser::line_index::LineIndex;
+use crate::parser::line_index::{CodeLines, LineIndex};
 use crate::parser::types::{
-    ClassSymbol, ExportedSymbol, FunctionComplexity, ImportedSymbol, MethodSymbol, ParserConfig,
-    SymbolName, SymbolSet,
+    ClassSymbol, ExportedSymbol, FieldSymbol, FunctionComplexity, ImportedSymbol, InjectableClass,
+    KeyedCall, MethodSymbol, ParserConfig, SymbolName, SymbolSet,
 };
 use compact_str::CompactString;
 use oxc_ast_visit::Visit;
@@ -11,6 +11,8 @@ use smallvec::SmallVec;
 
 pub mod exports;
 pub mod imports;
+pub mod injections;
+pub mod keyed_calls;
 pub mod locals;
 pub mod metrics;
 
@@ -36,6 +38,9 @@ pub struct UnifiedVisitor {
     pub classes: Vec<ClassSymbol>,
     pub local_definitions: Vec<SymbolName>,
     pub local_usages: SymbolSet,
+    pub usages_outside_exports: SymbolSet,
+    pub injectables: Vec<InjectableClass>,
+    pub keyed_calls: Vec<KeyedCall>,
     pub has_runtime_code: bool,
     pub functions: Vec<FunctionComplexity>,
     pub config: ParserConfig,
@@ -45,12 +50,16 @@ pub struct UnifiedVisitor {
 
     pub(crate) temp_fields: SymbolSet,
     pub(crate) temp_methods: SmallVec<[MethodSymbol; 8]>,
+    pub(crate) temp_field_symbols: Vec<FieldSymbol>,
+    pub(crate) temp_member_usages: SymbolSet,
     pub(crate) current_method: Option<MethodSymbol>,
     pub(crate) current_top_level_export: Option<usize>,
     pub env_vars: SymbolSet,
 
     /// Pre-computed line index for O(log n) line/column lookup
     pub(crate) line_index: LineIndex,
+    /// Code line counts used for lines-of-code metrics
+    pub(crate) code_lines: Option<CodeLines>,
 }
 
 impl UnifiedVisitor {
@@ -72,6 +81,9 @@ impl UnifiedVisitor {
             classes: Vec::new(),
             local_definitions: Vec::new(),
             local_usages: SymbolSet::default(),
+            usages_outside_exports: SymbolSet::default(),
+            injectables: Vec::new(),
+            keyed_calls: Vec::new(),
             has_runtime_code: false,
             functions: Vec::with_capacity(estimated_functions),
             config,
@@ -80,13 +92,34 @@ impl UnifiedVisitor {
             current_class: None,
             temp_fields: SymbolSet::default(),
             temp_methods: SmallVec::new(),
+            temp_field_symbols: Vec::new(),
+            temp_member_usages: SymbolSet::default(),
             current_method: None,
             current_top_level_export: None,
             env_vars: SymbolSet::default(),
             line_index: LineIndex::new(source_text),
+            code_lines: None,
         }
     }
 
+    /// Enable lines-of-code metrics for functions and classes.
+    #[must_use]
+    pub fn with_code_lines(mut self, code_lines: CodeLines) -> Self {
+        self.code_lines = Some(code_lines);
+        self
+    }
+
+    /// Lines of code covered by `span`, or 0 without code line information.
+    #[inline]
+    pub(crate) fn lines_of_code(&self, span: oxc_span::Span) -> usize {
+        self.code_lines.as_ref().map_or(0, |code_lines| {
+            code_lines.count(
+                self.line_index.line(span.start as usize),
+                self.line_index.line(span.end as usize),
+            )
+        })
+    }
+
     #[inline]
     pub(crate) fn get_range(&self, span: oxc_span::Span) -> CodeRange {
         let (start_line, start_column) = self.line_index.line_col(span.start as usize);
@@ -1,5 +1,5 @@
 use super::*;
-use crate::parser::types::ParserConfig;
+use crate::parser::types::{InjectableRole, InjectionKind, MethodAccessibility, ParserConfig};
 use oxc_allocator::Allocator;
 use oxc_parser::Parser;
 use oxc_span::SourceType;
@@ -70,6 +70,33 @@ fn test_class_with_constructor() {
         .any(|m| m.name == "constructor"));
 }
 
+#[test]
+fn test_class_field_symbols_and_member_usages() {
+    let visitor = parse_code(
+        "class A { #a = 1; protected b = 2; constructor(private c: number, d: number) {} \
+         #m() { const { b } = this; return this.#a + this['c']; } }",
+    );
+    let class = &visitor.classes[0];
+    let fields: Vec<_> = class
+        .field_symbols
+        .iter()
+        .map(|f| f.name.as_str())
+        .collect();
+    assert_eq!(fields, vec!["a", "b", "c"]);
+    assert_eq!(
+        class.field_symbols[0].accessibility,
+        Some(MethodAccessibility::Private)
+    );
+    assert_eq!(
+        class.methods[1].accessibility,
+        Some(MethodAccessibility::Private)
+    );
+    for member in ["a", "b", "c"] {
+        assert!(class.used_members.contains(member));
+    }
+    assert!(!class.used_members.contains("m"));
+}
+
 #[test]
 fn test_interface_extends() {
     let visitor = parse_code("interface A {} interface B extends A {}");
@@ -81,3 +108,86 @@ fn test_type_alias_union() {
     let visitor = parse_code("type T = string | number | MyType;");
     assert!(visitor.local_usages.contains("MyType"));
 }
+
+#[test]
+fn test_nest_module_metadata() {
+    let visitor = parse_code(
+        "@Global()\n@Module({\n  imports: [ConfigModule.forRoot(), forwardRef(() => UsersModule)],\n  \
+         providers: [OrdersService, { provide: 'CLOCK', useValue: clock }],\n  \
+         controllers: [OrdersController],\n  exports: [OrdersService],\n})\nexport class OrdersModule {}",
+    );
+    let module = &visitor.injectables[0];
+    assert_eq!(module.name, "OrdersModule");
+    assert_eq!(module.role, InjectableRole::Module);
+    assert!(module.is_global);
+
+    let entries: Vec<(InjectionKind, &str, usize)> = module
+        .injections
+        .iter()
+        .map(|i| (i.kind, i.target.as_str(), i.line))
+        .collect();
+    assert_eq!(
+        entries,
+        vec![
+            (InjectionKind::Import, "ConfigModule", 3),
+            (InjectionKind::Import, "UsersModule", 3),
+            (InjectionKind::Provide, "OrdersService", 4),
+            (InjectionKind::Provide, "CLOCK", 4),
+            (InjectionKind::Declare, "OrdersController", 5),
+            (InjectionKind::Export, "OrdersService", 6),
+        ]
+    );
+}
+
+#[test]
+fn test_constructor_injection() {
+    let visitor = parse_code(
+        "@Injectable({ providedIn: 'root' })\nclass OrdersService {\n  \
+         private clock = inject(Clock);\n  \
+         constructor(private repo: OrdersRepository, @Inject('CONFIG') config: object, id: string) {}\n}\n\
+         class Plain { constructor(private repo: OrdersRepository) {} }",
+    );
+    assert_eq!(visitor.injectables.len(), 1);
+    let service = &visitor.injectables[0];
+    assert_eq!(service.role, InjectableRole::Provider);
+    assert!(service.is_global);
+
+    let targets: Vec<&str> = service
+        .injections
+        .iter()
+        .map(|i| i.target.as_str())
+        .collect();
+    assert_eq!(targets, vec!["Clock", "OrdersRepository", "CONFIG"]);
+    assert!(service
+        .injections
+        .iter()
+        .all(|i| i.kind == InjectionKind::Inject));
+}
+
+#[test]
+fn test_keyed_calls_collected() {
+    let visitor = parse_code(
+        "this.bus.emit('order.created', payload);\n\
+         store.dispatch({ type: `cart/add`, payload });\n\
+         getBus().on(name, handler);",
+    );
+    let calls: Vec<(&str, usize, Option<&str>, &str)> = visitor
+        .keyed_calls
+        .iter()
+        .map(|c| {
+            (
+                c.callee.as_str(),
+                c.argument,
+                c.property.as_deref(),
+                c.key.as_str(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        calls,
+        vec![
+            ("this.bus.emit", 0, None, "order.created"),
+            ("store.dispatch", 0, Some("type"), "cart/add"),
+        ]
+    );
+}
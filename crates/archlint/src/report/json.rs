@@ -18,10 +18,32 @@ pub fn generate_json(
 
     let summary = build_summary_json(report, config);
 
-    json!({
+    let mut output = json!({
         "summary": summary,
         "smells": smells_json,
-    })
+    });
+
+    if let Some(gates) = &report.gates {
+        output["gates"] = json!(gates);
+    }
+
+    if !report.suppressed.is_empty() {
+        output["suppressed"] = report
+            .suppressed
+            .iter()
+            .map(|suppressed| {
+                let mut entry = build_smell_json(&suppressed.smell, &suppressed.explanation);
+                entry["suppression"] = json!({
+                    "file": suppressed.suppression.file.to_string_lossy(),
+                    "line": suppressed.suppression.line,
+                    "reason": suppressed.suppression.reason,
+                });
+                entry
+            })
+            .collect();
+    }
+
+    output
 }
 
 fn format_smell_type(smell_type: &SmellType) -> String {
@@ -162,7 +184,7 @@ fn build_summary_json(
 
     let grade = report.grade(config);
 
-    json!({
+    let mut summary = json!({
         "files_analyzed": report.files_analyzed(),
         "cyclic_dependencies": report.cyclic_dependencies(),
         "cycle_clusters": cycle_clusters_count,
@@ -178,12 +200,29 @@ fn build_summary_json(
         "shotgun_surgery": report.shotgun_surgery(),
         "hub_dependencies": report.hub_dependencies(),
         "total_smells": report.smells.len(),
+        "technical_debt": {
+            "total_minutes": report.debt.total_minutes,
+            "by_type": report.debt.by_type,
+            "by_folder": report.debt.by_folder,
+            "by_owner": report.debt.by_owner,
+        },
         "architecture_grade": {
             "score": format!("{:.1}", grade.score),
             "level": grade.level.to_string(),
             "density": format!("{:.2}", grade.density),
         }
-    })
+    });
+
+    if let Some(stats) = &report.import_resolution {
+        summary["import_resolution"] = json!({
+            "internal_imports": stats.internal_imports,
+            "resolved_imports": stats.resolved_imports,
+            "unresolved_imports": stats.unresolved_imports(),
+            "resolved_percent": format!("{:.1}", stats.resolved_percent()),
+        });
+    }
+
+    summary
 }
 
 pub fn write_report<P: AsRef<Path>>(
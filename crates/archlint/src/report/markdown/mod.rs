@@ -3,6 +3,7 @@ mod summary;
 
 use crate::config::SeverityConfig;
 use crate::graph::DependencyGraph;
+use crate::package_metrics::{assign_components, compute_metrics, ComponentGrouping};
 use crate::report::AnalysisReport;
 use crate::Result;
 use std::fs;
@@ -14,11 +15,15 @@ pub fn generate_markdown(
     graph: Option<&DependencyGraph>,
     include_diagram: bool,
     severity_config: &SeverityConfig,
+    project_root: Option<&Path>,
 ) -> String {
     let mut output = summary::generate(report, severity_config);
 
     if report.smells.is_empty() {
         output.push_str("✅ No architectural smells detected!\n");
+        if include_diagram {
+            push_main_sequence(&mut output, report, graph, project_root);
+        }
         return output;
     }
 
@@ -41,16 +46,27 @@ pub fn generate_markdown(
         "god_module",
         "dead_code",
         "dead_symbols",
+        "unused_class_members",
+        "test_only_code",
+        "unused_suppression",
         "cyclomatic_complexity",
         "cognitive_complexity",
         "large_file",
+        "large_class",
+        "long_function",
         "unstable_interface",
         "feature_envy",
         "shotgun_surgery",
         "hub_dependency",
         "barrel_file",
         "vendor_coupling",
+        "npm_dependencies",
+        "circular_module_imports",
+        "provider_outside_module",
+        "event_cycles",
+        "orphan_events",
         "side_effect_import",
+        "unresolved_import",
         "hub_module",
         "lcom",
         "module_cohesion",
@@ -66,6 +82,7 @@ pub fn generate_markdown(
         "scattered_config",
         "code_clone",
         "test_leakage",
+        "server_code_in_client",
         "layer_violation",
         "sdp_violation",
     ];
@@ -78,17 +95,54 @@ pub fn generate_markdown(
         }
     }
 
+    if include_diagram {
+        push_main_sequence(&mut output, report, graph, project_root);
+    }
+
     output
 }
 
+/// Main sequence chart of the folders at `metrics.folder_depth`, as in `archlint metrics`.
+fn push_main_sequence(
+    output: &mut String,
+    report: &AnalysisReport,
+    graph: Option<&DependencyGraph>,
+    project_root: Option<&Path>,
+) {
+    let (Some(graph), Some(project_root)) = (graph, project_root) else {
+        return;
+    };
+    let components = assign_components(
+        report.file_symbols.keys(),
+        project_root,
+        ComponentGrouping::Folder {
+            depth: report.config.metrics.folder_depth,
+        },
+    );
+    let metrics = compute_metrics(graph, &report.file_symbols, &components);
+    if metrics.is_empty() {
+        return;
+    }
+
+    output.push_str("\n## Main Sequence\n\n");
+    output.push_str(&super::mermaid::MermaidGenerator::main_sequence(&metrics));
+}
+
 pub fn write_report<P: AsRef<Path>>(
     report: &AnalysisReport,
     path: P,
     graph: Option<&DependencyGraph>,
     include_diagram: bool,
     severity_config: &SeverityConfig,
+    project_root: Option<&Path>,
 ) -> Result<()> {
-    let output = generate_markdown(report, graph, include_diagram, severity_config);
+    let output = generate_markdown(
+        report,
+        graph,
+        include_diagram,
+        severity_config,
+        project_root,
+    );
     fs::write(path, output)?;
     Ok(())
 }
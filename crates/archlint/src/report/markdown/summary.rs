@@ -1,5 +1,8 @@
 use crate::config::SeverityConfig;
+use crate::debt::format_minutes;
+use crate::gates::{GateReport, GateStatus};
 use crate::report::AnalysisReport;
+use std::collections::BTreeMap;
 
 pub fn generate(report: &AnalysisReport, severity_config: &SeverityConfig) -> String {
     let mut output = String::new();
@@ -20,6 +23,10 @@ pub fn generate(report: &AnalysisReport, severity_config: &SeverityConfig) -> St
         "| **Total Score** | **{} points** (density: {:.2}) |\n",
         total_score, grade.density
     ));
+    output.push_str(&format!(
+        "| **Technical Debt** | **{}** |\n",
+        format_minutes(report.debt.total_minutes)
+    ));
     output.push_str(&format!(
         "| Files analyzed | {} |\n",
         report.files_analyzed()
@@ -55,5 +62,49 @@ pub fn generate(report: &AnalysisReport, severity_config: &SeverityConfig) -> St
     ));
     output.push('\n');
 
+    if let Some(gates) = &report.gates {
+        push_gates(&mut output, gates);
+    }
+
+    if report.debt.total_minutes > 0.0 {
+        output.push_str("## Technical Debt\n\n");
+        push_debt_table(&mut output, "Smell type", &report.debt.by_type);
+        push_debt_table(&mut output, "Folder", &report.debt.by_folder);
+        if !report.debt.by_owner.is_empty() {
+            push_debt_table(&mut output, "Owner", &report.debt.by_owner);
+        }
+    }
+
     output
 }
+
+fn push_debt_table(output: &mut String, label: &str, minutes: &BTreeMap<String, f64>) {
+    let mut rows: Vec<_> = minutes.iter().collect();
+    rows.sort_by(|a, b| b.1.total_cmp(a.1).then_with(|| a.0.cmp(b.0)));
+
+    output.push_str(&format!("| {label} | Effort |\n"));
+    output.push_str("| :--- | ---: |\n");
+    for (name, value) in rows {
+        output.push_str(&format!("| {name} | {} |\n", format_minutes(*value)));
+    }
+    output.push('\n');
+}
+
+fn push_gates(output: &mut String, gates: &GateReport) {
+    let verdict = if gates.passed { "passed" } else { "failed" };
+    output.push_str(&format!("## Quality Gates: {verdict}\n\n"));
+    output.push_str("| Gate | Status | Result |\n");
+    output.push_str("| :--- | :--- | :--- |\n");
+    for gate in &gates.results {
+        let status = match gate.status {
+            GateStatus::Passed => "✅ passed",
+            GateStatus::Failed => "❌ failed",
+            GateStatus::Skipped => "⏭️ skipped",
+        };
+        output.push_str(&format!(
+            "| {} | {status} | {} |\n",
+            gate.name, gate.message
+        ));
+    }
+    output.push('\n');
+}
@@ -1,6 +1,8 @@
 use crate::detectors::{ArchSmell, SmellType};
 use crate::graph::DependencyGraph;
+use crate::package_metrics::ComponentMetrics;
 use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
 use std::path::{Path, PathBuf};
 
 pub struct MermaidGenerator;
@@ -31,6 +33,51 @@ impl MermaidGenerator {
         output
     }
 
+    /// Quadrant chart plotting components by instability and abstractness.
+    #[must_use]
+    pub fn main_sequence(metrics: &[ComponentMetrics]) -> String {
+        let mut output = String::from("```mermaid\nquadrantChart\n");
+        output.push_str("    title Main Sequence\n");
+        output.push_str("    x-axis Stable --> Unstable\n");
+        output.push_str("    y-axis Concrete --> Abstract\n");
+        output.push_str("    quadrant-1 Zone of Uselessness\n");
+        output.push_str("    quadrant-2 Stable abstractions\n");
+        output.push_str("    quadrant-3 Zone of Pain\n");
+        output.push_str("    quadrant-4 Unstable details\n");
+        for (index, m) in metrics.iter().enumerate() {
+            let _ = writeln!(
+                output,
+                "    {}: [{:.2}, {:.2}]",
+                Self::chart_label(&m.name, index),
+                m.instability,
+                m.abstractness
+            );
+        }
+        output.push_str("```\n");
+        output
+    }
+
+    /// Mermaid point labels only allow a limited character set; names with
+    /// none of it are labelled by their position in the chart.
+    fn chart_label(name: &str, index: usize) -> String {
+        let label: String = name
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || matches!(c, ' ' | '-' | '_' | '.') {
+                    c
+                } else {
+                    '-'
+                }
+            })
+            .collect();
+        let label = label.trim_matches('-');
+        if label.trim().is_empty() {
+            format!("component {}", index + 1)
+        } else {
+            label.to_string()
+        }
+    }
+
     fn collect_problem_data(
         smells: &[(ArchSmell, crate::detectors::Explanation)],
     ) -> (HashSet<PathBuf>, HashMap<PathBuf, Vec<&SmellType>>) {
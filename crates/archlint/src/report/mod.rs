@@ -6,10 +6,12 @@ pub mod mermaid;
 pub mod sarif;
 
 use crate::config::SeverityConfig;
+use crate::debt::{DebtModel, DebtSummary};
 use crate::detectors::{ArchSmell, CodeRange, LocationDetail, Severity, SmellType};
 use crate::engine::context::FileMetrics;
 use crate::explain::{ExplainEngine, Explanation};
 use crate::framework::presets::FrameworkPreset;
+use crate::gates::GateReport;
 use crate::graph::DependencyGraph;
 #[cfg(not(feature = "cli"))]
 use crate::no_cli_mocks::comfy_table::modifiers::UTF8_ROUND_CORNERS;
@@ -20,6 +22,7 @@ use crate::no_cli_mocks::comfy_table::{Attribute, Cell, Color, ContentArrangemen
 #[cfg(not(feature = "cli"))]
 use crate::no_cli_mocks::console::style;
 use crate::parser::{FileIgnoredLines, FileSymbols, FunctionComplexity};
+use crate::resolver::ResolutionStats;
 use crate::Result;
 #[cfg(feature = "cli")]
 use comfy_table::modifiers::UTF8_ROUND_CORNERS;
@@ -32,14 +35,23 @@ use console::style;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default, Display)]
+/// Qualitative architecture grade, ordered from best to worst.
+#[derive(
+    Debug, Clone, Copy, Serialize, Deserialize, Default, Display, PartialEq, Eq, PartialOrd, Ord,
+)]
 pub enum GradeLevel {
     #[default]
+    #[serde(alias = "excellent")]
     Excellent,
+    #[serde(alias = "good")]
     Good,
+    #[serde(alias = "fair")]
     Fair,
+    #[serde(alias = "moderate")]
     Moderate,
+    #[serde(alias = "poor")]
     Poor,
+    #[serde(alias = "critical")]
     Critical,
 }
 
@@ -115,6 +127,25 @@ pub(crate) fn format_location_parts(
     }
 }
 
+/// The inline comment that suppressed a smell.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Suppression {
+    /// File containing the comment.
+    pub file: PathBuf,
+    /// Line of the comment (1-based).
+    pub line: usize,
+    /// Justification given after `--`, if any.
+    pub reason: Option<String>,
+}
+
+/// A smell hidden by an inline suppression comment.
+#[derive(Debug, Clone)]
+pub struct SuppressedSmell {
+    pub smell: ArchSmell,
+    pub explanation: Explanation,
+    pub suppression: Suppression,
+}
+
 /// A comprehensive report containing all analysis results.
 pub struct AnalysisReport {
     /// Number of files analyzed.
@@ -145,6 +176,8 @@ pub struct AnalysisReport {
     code_clones: usize,
     /// List of detected smells with their human-readable explanations.
     pub smells: Vec<(ArchSmell, Explanation)>,
+    /// Smells hidden by inline comments, kept when `suppressions.include_suppressed` is set.
+    pub suppressed: Vec<SuppressedSmell>,
     /// The project's dependency graph.
     pub graph: Option<DependencyGraph>,
     /// Symbol information for each analyzed file.
@@ -165,6 +198,12 @@ pub struct AnalysisReport {
     pub min_score: Option<u32>,
     /// Config used during analysis
     pub config: crate::config::Config,
+    /// Estimated remediation effort of the reported smells.
+    pub debt: DebtSummary,
+    /// Quality gate results, if any gates are configured.
+    pub gates: Option<GateReport>,
+    /// Share of internal imports resolved to a file, when the graph was built.
+    pub import_resolution: Option<ResolutionStats>,
 }
 
 impl AnalysisReport {
@@ -245,6 +284,23 @@ impl AnalysisReport {
         self.files_analyzed = count;
     }
 
+    /// Recompute the technical debt summary from the reported smells.
+    pub fn compute_debt(&mut self, project_root: &Path) {
+        let model = DebtModel::new(&self.config.debt);
+        self.debt = DebtSummary::compute(self.smells.iter().map(|(s, _)| s), &model, project_root);
+    }
+
+    /// Check the configured quality gates against this report.
+    pub fn evaluate_gates(&mut self, project_root: &Path) {
+        if !self.config.gates.is_empty() {
+            self.gates = Some(crate::gates::evaluate_scan(
+                &self.config.gates,
+                self,
+                project_root,
+            ));
+        }
+    }
+
     /// Recompute per-smell counters from `self.smells`.
     /// Useful if callers mutate `smells` directly.
     pub fn recompute_counts(&mut self) {
@@ -314,18 +370,30 @@ impl AnalysisReport {
                 SmellType::CodeClone { .. } => self.code_clones += 1,
                 // These types don't have dedicated summary counters yet
                 SmellType::TestLeakage { .. }
+                | SmellType::ServerCodeInClient { .. }
+                | SmellType::TestOnlyCode { .. }
+                | SmellType::UnusedSuppression { .. }
                 | SmellType::LayerViolation { .. }
                 | SmellType::SdpViolation
                 | SmellType::BarrelFileAbuse
                 | SmellType::VendorCoupling { .. }
                 | SmellType::SideEffectImport
+                | SmellType::UnresolvedImport { .. }
                 | SmellType::HubModule
                 | SmellType::LowCohesion { .. }
                 | SmellType::ScatteredModule { .. }
                 | SmellType::HighCoupling { .. }
                 | SmellType::PackageCycle { .. }
+                | SmellType::CircularModuleImport { .. }
+                | SmellType::ProviderOutsideModule { .. }
+                | SmellType::OrphanEvent { .. }
+                | SmellType::EventCycle { .. }
+                | SmellType::NpmDependency { .. }
+                | SmellType::UnusedClassMember { .. }
                 | SmellType::DeepNesting { .. }
                 | SmellType::LongParameterList { .. }
+                | SmellType::LongFunction { .. }
+                | SmellType::LargeClass { .. }
                 | SmellType::PrimitiveObsession { .. }
                 | SmellType::OrphanType { .. }
                 | SmellType::CircularTypeDependency
@@ -406,13 +474,20 @@ impl AnalysisReport {
             crate::args::OutputFormat::Table => self.write_table(severity_config, scan_root),
             crate::args::OutputFormat::Markdown => {
                 if let Some(path) = path {
-                    self.write_markdown(path, self.graph.as_ref(), !no_diagram, severity_config)
+                    self.write_markdown(
+                        path,
+                        self.graph.as_ref(),
+                        !no_diagram,
+                        severity_config,
+                        scan_root,
+                    )
                 } else {
                     let output = markdown::generate_markdown(
                         self,
                         self.graph.as_ref(),
                         !no_diagram,
                         severity_config,
+                        scan_root,
                     );
                     println!("{output}");
                     Ok(())
@@ -544,6 +619,7 @@ impl AnalysisReport {
         cell
     }
 
+    #[allow(clippy::too_many_lines)]
     fn format_smell_type(smell_type: &SmellType) -> String {
         match smell_type {
             SmellType::CyclicDependency => "Cyclic Dependency".to_string(),
@@ -553,6 +629,11 @@ impl AnalysisReport {
             SmellType::DeadSymbol { name, .. } => {
                 format!("Dead Symbol\n({name})")
             }
+            SmellType::UnusedClassMember {
+                class_name, member, ..
+            } => {
+                format!("Unused Class Member\n({class_name}.{member})")
+            }
             SmellType::HighCyclomaticComplexity {
                 name, complexity, ..
             } => {
@@ -573,6 +654,16 @@ impl AnalysisReport {
             SmellType::OrphanType { name } => {
                 format!("Orphan Type\n({name})")
             }
+            SmellType::TestOnlyCode { symbol } => match symbol {
+                Some(symbol) => format!("Test-Only Code\n({symbol})"),
+                None => "Test-Only Module".to_string(),
+            },
+            SmellType::UnusedSuppression { rules, .. } => {
+                format!("Unused Suppression\n({})", rules.join(", "))
+            }
+            SmellType::ServerCodeInClient { package, .. } => {
+                format!("Server Code in Client\n({package})")
+            }
             SmellType::TestLeakage { test_file } => {
                 format!("Test Leakage\n({})", test_file.display())
             }
@@ -588,6 +679,9 @@ impl AnalysisReport {
                 format!("Vendor Coupling\n({package})")
             }
             SmellType::SideEffectImport => "Side-Effect Import".to_string(),
+            SmellType::UnresolvedImport { specifier, .. } => {
+                format!("Unresolved Import\n({specifier})")
+            }
             SmellType::HubModule => "Hub Module".to_string(),
             SmellType::LowCohesion { lcom, .. } => {
                 format!("Low Cohesion\n(LCOM: {lcom})")
@@ -601,19 +695,38 @@ impl AnalysisReport {
             SmellType::PackageCycle { packages } => {
                 format!("Package Cycle\n({} packages)", packages.len())
             }
+            SmellType::CircularModuleImport { modules } => {
+                format!("Circular Module Import\n({} modules)", modules.len())
+            }
+            SmellType::ProviderOutsideModule {
+                provider, consumer, ..
+            } => {
+                format!("Provider Outside Module\n({consumer} -> {provider})")
+            }
+            SmellType::OrphanEvent { key, issue } => {
+                format!("Orphan Event\n({key}: {issue})")
+            }
+            SmellType::EventCycle { keys } => {
+                format!("Event Cycle\n({})", keys.join(", "))
+            }
             SmellType::SharedMutableState { symbol } => {
                 format!("Shared Mutable State\n({symbol})")
             }
-            SmellType::DeepNesting {
-                name,
-                depth,
-                line: _,
-            } => {
+            SmellType::NpmDependency { package, issue } => {
+                format!("npm Dependency\n({package}: {issue})")
+            }
+            SmellType::DeepNesting { name, depth, .. } => {
                 format!("Deep Nesting\n({name}: depth {depth})")
             }
             SmellType::LongParameterList { count, name } => {
                 format!("Long Parameter List\n({name}: {count} params)")
             }
+            SmellType::LongFunction { name, loc } => {
+                format!("Long Function\n({name}: {loc} lines)")
+            }
+            SmellType::LargeClass { name, loc, .. } => {
+                format!("Large Class\n({name}: {loc} lines)")
+            }
             SmellType::PrimitiveObsession { primitives, name } => {
                 format!("Primitive Obsession\n({name}: {primitives} primitives)")
             }
@@ -696,8 +809,16 @@ impl AnalysisReport {
         graph: Option<&DependencyGraph>,
         include_diagram: bool,
         severity_config: &SeverityConfig,
+        project_root: Option<&Path>,
     ) -> Result<()> {
-        markdown::write_report(self, path, graph, include_diagram, severity_config)
+        markdown::write_report(
+            self,
+            path,
+            graph,
+            include_diagram,
+            severity_config,
+            project_root,
+        )
     }
 
     pub fn write_json<P: AsRef<Path>>(&self, path: P, config: &SeverityConfig) -> Result<()> {
@@ -709,6 +830,7 @@ impl AnalysisReport {
 #[derive(Default)]
 pub struct AnalysisReportBuilder {
     smells: Vec<ArchSmell>,
+    suppressed: Vec<(ArchSmell, Suppression)>,
     graph: Option<DependencyGraph>,
     file_symbols: HashMap<PathBuf, FileSymbols>,
     file_metrics: HashMap<PathBuf, FileMetrics>,
@@ -718,6 +840,7 @@ pub struct AnalysisReportBuilder {
     presets: Vec<FrameworkPreset>,
     config: Option<crate::config::Config>,
     files_analyzed: usize,
+    import_resolution: Option<ResolutionStats>,
 }
 
 impl AnalysisReportBuilder {
@@ -732,6 +855,12 @@ impl AnalysisReportBuilder {
         self
     }
 
+    #[must_use]
+    pub fn with_suppressed(mut self, suppressed: Vec<(ArchSmell, Suppression)>) -> Self {
+        self.suppressed = suppressed;
+        self
+    }
+
     #[must_use]
     pub fn with_graph(mut self, graph: Option<DependencyGraph>) -> Self {
         self.graph = graph;
@@ -789,6 +918,12 @@ impl AnalysisReportBuilder {
         self
     }
 
+    #[must_use]
+    pub const fn with_import_resolution(mut self, stats: ResolutionStats) -> Self {
+        self.import_resolution = Some(stats);
+        self
+    }
+
     #[must_use]
     pub fn build(self) -> AnalysisReport {
         let config = self.config.unwrap_or_default();
@@ -800,6 +935,15 @@ impl AnalysisReportBuilder {
                 (smell, explanation)
             })
             .collect();
+        let suppressed = self
+            .suppressed
+            .into_iter()
+            .map(|(smell, suppression)| SuppressedSmell {
+                explanation: ExplainEngine::explain(&smell, &config),
+                smell,
+                suppression,
+            })
+            .collect();
 
         let mut report = AnalysisReport {
             files_analyzed: self.files_analyzed,
@@ -816,6 +960,7 @@ impl AnalysisReportBuilder {
             hub_dependencies: 0,
             code_clones: 0,
             smells: smells_with_explanations,
+            suppressed,
             graph: self.graph,
             file_symbols: self.file_symbols,
             file_metrics: self.file_metrics,
@@ -826,6 +971,9 @@ impl AnalysisReportBuilder {
             min_severity: None,
             min_score: None,
             config,
+            debt: DebtSummary::default(),
+            gates: None,
+            import_resolution: self.import_resolution,
         };
 
         report.update_counts();
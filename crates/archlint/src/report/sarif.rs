@@ -1,5 +1,6 @@
 use crate::detectors::{ArchSmell, Severity, SmellKind};
 use crate::explain::Explanation;
+use crate::gates::GateReport;
 use crate::report::AnalysisReport;
 use crate::Result;
 use serde::{Deserialize, Serialize};
@@ -23,6 +24,32 @@ struct SarifLog {
 struct SarifRun {
     tool: SarifTool,
     results: Vec<SarifResult>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    invocations: Vec<SarifInvocation>,
+}
+
+/// A run of the tool; carries quality gate results
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifInvocation {
+    execution_successful: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    tool_execution_notifications: Vec<SarifNotification>,
+    properties: SarifInvocationProperties,
+}
+
+/// A message reported by the tool itself, e.g. a failed quality gate
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifNotification {
+    level: String,
+    message: SarifMessage,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifInvocationProperties {
+    quality_gates: GateReport,
 }
 
 /// Information about the tool that produced the SARIF log
@@ -59,6 +86,17 @@ struct SarifResult {
     level: String,
     message: SarifMessage,
     locations: Vec<SarifLocation>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    suppressions: Vec<SarifSuppression>,
+}
+
+/// A suppression applied to a result, e.g. an inline `archlint-disable` comment
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifSuppression {
+    kind: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    justification: Option<String>,
 }
 
 /// A message in SARIF, usually containing plain text
@@ -119,7 +157,20 @@ pub fn generate_sarif(
     let mut results = Vec::new();
     let mut seen_rules = HashSet::new();
 
-    for (smell, explanation) in &report.smells {
+    let suppressed = report.suppressed.iter().map(|s| {
+        let suppression = SarifSuppression {
+            kind: "inSource".to_string(),
+            justification: s.suppression.reason.clone(),
+        };
+        (&s.smell, &s.explanation, Some(suppression))
+    });
+    let findings = report
+        .smells
+        .iter()
+        .map(|(smell, explanation)| (smell, explanation, None))
+        .chain(suppressed);
+
+    for (smell, explanation, suppression) in findings {
         let category = smell.smell_type.category();
         let rule_id = category.to_id();
 
@@ -135,6 +186,7 @@ pub fn generate_sarif(
                 text: format_sarif_message(explanation),
             },
             locations: map_locations(smell, scan_root),
+            suppressions: suppression.into_iter().collect(),
         });
     }
 
@@ -151,10 +203,30 @@ pub fn generate_sarif(
                 },
             },
             results,
+            invocations: report.gates.iter().map(create_invocation).collect(),
         }],
     })?)
 }
 
+fn create_invocation(gates: &GateReport) -> SarifInvocation {
+    SarifInvocation {
+        // The analysis itself succeeded; gate failures are reported as notifications
+        execution_successful: true,
+        tool_execution_notifications: gates
+            .failed()
+            .map(|gate| SarifNotification {
+                level: "error".to_string(),
+                message: SarifMessage {
+                    text: format!("Quality gate failed: {} ({})", gate.name, gate.message),
+                },
+            })
+            .collect(),
+        properties: SarifInvocationProperties {
+            quality_gates: gates.clone(),
+        },
+    }
+}
+
 const fn map_severity(severity: &Severity) -> &'static str {
     match severity {
         Severity::Low => "note",
@@ -297,6 +369,39 @@ mod tests {
         assert!(sarif.runs[0].results[0].message.text.contains("Reason:"));
     }
 
+    #[test]
+    fn test_sarif_includes_suppressed_results() {
+        let smell = ArchSmell {
+            smell_type: SmellType::DeadCode,
+            severity: Severity::Low,
+            files: vec![PathBuf::from("legacy.ts")],
+            metrics: vec![],
+            locations: vec![],
+            cluster: None,
+        };
+        let suppression = crate::report::Suppression {
+            file: PathBuf::from("legacy.ts"),
+            line: 1,
+            reason: Some("loaded by the plugin host".to_string()),
+        };
+
+        let report = AnalysisReportBuilder::new()
+            .with_suppressed(vec![(smell, suppression)])
+            .build();
+
+        let config = crate::config::SeverityConfig::default();
+        let sarif_val = generate_sarif(&report, &config, None).unwrap();
+        let sarif: SarifLog = serde_json::from_value(sarif_val).unwrap();
+        let result = &sarif.runs[0].results[0];
+        assert_eq!(result.rule_id, "dead_code");
+        assert_eq!(result.suppressions.len(), 1);
+        assert_eq!(result.suppressions[0].kind, "inSource");
+        assert_eq!(
+            result.suppressions[0].justification.as_deref(),
+            Some("loaded by the plugin host")
+        );
+    }
+
     #[test]
     fn test_sarif_relative_paths_and_windows_separators() {
         let file_path = if cfg!(windows) {
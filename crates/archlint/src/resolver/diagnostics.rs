@@ -0,0 +1,87 @@
+use super::{PathResolver, UnresolvedReason};
+use crate::parser::FileSymbols;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+/// A local import that does not resolve to a file, leaving an edge out of the graph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnresolvedImport {
+    pub file: PathBuf,
+    pub line: usize,
+    pub specifier: String,
+    pub reason: UnresolvedReason,
+}
+
+/// How many of the imports pointing into the project resolved to a file.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResolutionStats {
+    pub internal_imports: usize,
+    pub resolved_imports: usize,
+}
+
+impl ResolutionStats {
+    #[must_use]
+    pub const fn unresolved_imports(&self) -> usize {
+        self.internal_imports - self.resolved_imports
+    }
+
+    /// Share of internal imports that resolved, 100 when there are none.
+    #[must_use]
+    pub fn resolved_percent(&self) -> f64 {
+        if self.internal_imports == 0 {
+            return 100.0;
+        }
+        self.resolved_imports as f64 * 100.0 / self.internal_imports as f64
+    }
+}
+
+/// Unresolved local imports of a project, with the resolution rate.
+#[derive(Debug, Clone, Default)]
+pub struct ImportDiagnostics {
+    /// Sorted by file and line.
+    pub unresolved: Vec<UnresolvedImport>,
+    pub stats: ResolutionStats,
+}
+
+impl ImportDiagnostics {
+    /// Inspect symbols whose imports went through [`PathResolver::resolve`]:
+    /// resolved sources were rewritten to absolute paths, the others are kept
+    /// when they should point into the project.
+    ///
+    /// Each import statement counts once, however many names it imports.
+    #[must_use]
+    pub fn collect(file_symbols: &HashMap<PathBuf, FileSymbols>, resolver: &PathResolver) -> Self {
+        let mut diagnostics = Self::default();
+
+        for (file, symbols) in file_symbols {
+            let mut seen = HashSet::new();
+            for import in &symbols.imports {
+                let source = import.source.as_str();
+                if !seen.insert((import.line, source)) {
+                    continue;
+                }
+
+                if Path::new(source).is_absolute() {
+                    diagnostics.stats.internal_imports += 1;
+                    diagnostics.stats.resolved_imports += 1;
+                } else if resolver.is_local_import(source, file) {
+                    diagnostics.stats.internal_imports += 1;
+                    diagnostics.unresolved.push(UnresolvedImport {
+                        file: file.clone(),
+                        line: import.line,
+                        specifier: source.to_string(),
+                        reason: resolver
+                            .unresolved_reason(source, file)
+                            .unwrap_or(UnresolvedReason::NotFound),
+                    });
+                }
+            }
+        }
+
+        diagnostics
+            .unresolved
+            .sort_by(|a, b| (&a.file, a.line, &a.specifier).cmp(&(&b.file, b.line, &b.specifier)));
+        diagnostics
+    }
+}
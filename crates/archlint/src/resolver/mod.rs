@@ -0,0 +1,480 @@
+use crate::args::SUPPORTED_EXTENSIONS;
+use crate::config::{Config, TsConfigConfig};
+use crate::tsconfig::project::normalize;
+use crate::tsconfig::{TsProject, TsProjects};
+use crate::{AnalysisError, Result};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+pub mod diagnostics;
+pub mod package;
+
+pub use diagnostics::{ImportDiagnostics, ResolutionStats, UnresolvedImport};
+use package::{PackageError, PackageJson, PackageResolution, PackageScopes};
+
+/// Why a local import could not be resolved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UnresolvedReason {
+    /// No file matches the import, with or without extensions.
+    NotFound,
+    /// The import matches an alias or a tsconfig `paths` pattern, but none of its targets exist.
+    AliasMismatch(String),
+    /// A file with the imported name exists, but only with an extension that is not resolved.
+    ExtensionNotFound(PathBuf),
+    /// `exports` or `imports` of a `package.json` rule the import out.
+    Package(PackageError),
+    /// `exports` or `imports` map the import to a file that does not exist.
+    MissingTarget(PathBuf),
+}
+
+impl fmt::Display for UnresolvedReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotFound => write!(f, "no matching file"),
+            Self::AliasMismatch(pattern) => {
+                write!(f, "matches alias '{pattern}' but none of its targets exist")
+            }
+            Self::ExtensionNotFound(path) => write!(
+                f,
+                "found {} but its extension is not resolved",
+                path.file_name()
+                    .unwrap_or(path.as_os_str())
+                    .to_string_lossy()
+            ),
+            Self::Package(error) => write!(f, "{error}"),
+            Self::MissingTarget(path) => write!(f, "target {} does not exist", path.display()),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct PathResolver {
+    root: PathBuf,
+    aliases: HashMap<String, String>,
+    /// tsconfig files below the root, `None` when tsconfig integration is off.
+    tsconfigs: Option<Arc<TsProjects>>,
+    packages: Arc<PackageScopes>,
+    /// Active conditions for `exports` and `imports`.
+    conditions: Vec<String>,
+    /// Files removed from the working tree that imports still resolve to.
+    deleted_files: HashSet<PathBuf>,
+}
+
+impl PathResolver {
+    pub fn new<P: AsRef<Path>>(root: P, config: &Config) -> Self {
+        let root = root.as_ref().to_path_buf();
+        let tsconfigs = match &config.tsconfig {
+            None | Some(TsConfigConfig::Boolean(false)) => None,
+            Some(TsConfigConfig::Boolean(true)) => Some(root.join("tsconfig.json")),
+            Some(TsConfigConfig::Path(path)) => Some(root.join(path)),
+        }
+        .map(|root_config| Arc::new(TsProjects::new(&root, Some(root_config))));
+
+        Self {
+            packages: Arc::new(PackageScopes::new(&root)),
+            root,
+            aliases: config.aliases.clone(),
+            tsconfigs,
+            conditions: config.resolve.conditions.clone(),
+            deleted_files: HashSet::new(),
+        }
+    }
+
+    /// Resolve imports of `files` as if they still existed, which gives the
+    /// edges the graph had before they were deleted.
+    #[must_use]
+    pub fn with_deleted_files(mut self, files: impl IntoIterator<Item = PathBuf>) -> Self {
+        self.deleted_files = files.into_iter().map(|f| normalize(&f)).collect();
+        self
+    }
+
+    /// Whether `path` is one of the files passed to [`Self::with_deleted_files`].
+    #[must_use]
+    pub fn is_deleted_file(&self, path: &Path) -> bool {
+        self.deleted_files.contains(path)
+    }
+
+    fn is_file(&self, path: &Path) -> bool {
+        path.is_file() || (!self.deleted_files.is_empty() && self.is_deleted_file(&normalize(path)))
+    }
+
+    /// The tsconfig governing `file`, if any.
+    #[must_use]
+    pub fn governing_tsconfig(&self, file: &Path) -> Option<PathBuf> {
+        self.tsconfigs
+            .as_ref()?
+            .governing(file)
+            .map(|project| project.config_path.clone())
+    }
+
+    /// Whether `import_path` should point into the project: relative and `#`
+    /// imports, imports matching an alias or a `paths` pattern, and imports of
+    /// project packages with `exports`.
+    #[must_use]
+    pub fn is_local_import(&self, import_path: &str, from_file: &Path) -> bool {
+        import_path.starts_with('.')
+            || (import_path.starts_with('#')
+                && !matches!(
+                    self.package_import(import_path, from_file),
+                    Ok(PackageResolution::Package(_))
+                ))
+            || self
+                .project_package(import_path, from_file)
+                .is_some_and(|(package, _)| package.exports.is_some())
+            || self
+                .aliases
+                .keys()
+                .any(|alias| import_path.starts_with(alias.trim_end_matches('*')))
+            || self
+                .governing_project(from_file)
+                .is_some_and(|project| project.path_targets(import_path).is_some())
+    }
+
+    /// Explain why a local import does not resolve, `None` if it does.
+    #[must_use]
+    pub fn unresolved_reason(
+        &self,
+        import_path: &str,
+        from_file: &Path,
+    ) -> Option<UnresolvedReason> {
+        let package_target = if import_path.starts_with('#') {
+            match self.package_import(import_path, from_file) {
+                Ok(PackageResolution::Package(_)) => return None,
+                Ok(PackageResolution::File(path)) => Some(Ok(path)),
+                Err(error) => Some(Err(error)),
+            }
+        } else if let Some((package, subpath)) = self.project_package(import_path, from_file) {
+            package
+                .resolve_export(&subpath, &self.conditions)
+                .transpose()
+        } else {
+            None
+        };
+
+        match package_target {
+            Some(Err(error)) => Some(UnresolvedReason::Package(error)),
+            Some(Ok(target)) => match self.try_resolve_with_extensions(&target) {
+                Ok(Some(_)) => None,
+                _ => Some(UnresolvedReason::MissingTarget(target)),
+            },
+            None => match self.resolve(import_path, from_file) {
+                Ok(Some(_)) => None,
+                _ => Some(self.not_found_reason(import_path, from_file)),
+            },
+        }
+    }
+
+    /// Tell a file with another extension and an alias pointing nowhere apart
+    /// from a missing file.
+    fn not_found_reason(&self, import_path: &str, from_file: &Path) -> UnresolvedReason {
+        let alias = self.matching_alias(import_path, from_file);
+        let candidates = if import_path.starts_with('.') {
+            from_file
+                .parent()
+                .map(|dir| vec![dir.join(import_path)])
+                .unwrap_or_default()
+        } else {
+            alias
+                .as_ref()
+                .map(|(_, targets)| targets.clone())
+                .unwrap_or_default()
+        };
+
+        if let Some(found) = candidates
+            .iter()
+            .find_map(|candidate| file_with_other_extension(candidate))
+        {
+            return UnresolvedReason::ExtensionNotFound(found);
+        }
+        alias.map_or(UnresolvedReason::NotFound, |(pattern, _)| {
+            UnresolvedReason::AliasMismatch(pattern)
+        })
+    }
+
+    /// The `paths` pattern or alias `import_path` matches, with its targets.
+    fn matching_alias(
+        &self,
+        import_path: &str,
+        from_file: &Path,
+    ) -> Option<(String, Vec<PathBuf>)> {
+        if let Some(project) = self.governing_project(from_file) {
+            if let Some((pattern, targets)) = project.path_match(import_path) {
+                return Some((pattern.to_string(), targets));
+            }
+        }
+
+        self.aliases
+            .iter()
+            .find(|(alias, _)| import_path.starts_with(alias.trim_end_matches('*')))
+            .map(|(alias, actual)| {
+                let relative_path = import_path.replacen(
+                    alias.trim_end_matches('*'),
+                    actual.trim_end_matches('*'),
+                    1,
+                );
+                (alias.clone(), vec![self.root.join(relative_path)])
+            })
+    }
+
+    pub fn resolve(&self, import_path: &str, from_file: &Path) -> Result<Option<PathBuf>> {
+        if import_path.starts_with('#') {
+            return match self.package_import(import_path, from_file) {
+                Ok(PackageResolution::File(target)) => self.try_resolve_with_extensions(&target),
+                // Mapped to another package, which stays external
+                Ok(PackageResolution::Package(_)) | Err(_) => Ok(None),
+            };
+        }
+
+        let project = self.governing_project(from_file);
+
+        if import_path.starts_with('.') {
+            // Relative import
+            let resolved = self.resolve_relative(import_path, from_file)?;
+            if resolved.is_none() {
+                if let Some(project) = &project {
+                    return self.resolve_root_dirs(project, import_path, from_file);
+                }
+            }
+            return Ok(resolved);
+        }
+
+        // A nested tsconfig takes precedence over the aliases, which already
+        // contain the `paths` of the root one
+        if let Some(project) = project.filter(|project| !self.is_root_project(project)) {
+            if let Some(resolved) = self.resolve_project_paths(&project, import_path)? {
+                return Ok(Some(resolved));
+            }
+        }
+
+        // Try alias resolution
+        if let Some(resolved) = self.resolve_alias(import_path)? {
+            return Ok(Some(resolved));
+        }
+
+        // Packages of the project, imported by name through their `exports`
+        if let Some((package, subpath)) = self.project_package(import_path, from_file) {
+            if let Ok(Some(target)) = package.resolve_export(&subpath, &self.conditions) {
+                return self.try_resolve_with_extensions(&target);
+            }
+        }
+
+        // Try absolute resolution from root or root/src (baseUrl fallback)
+        let root_candidate = self.root.join(import_path);
+        if let Some(resolved) = self.try_resolve_with_extensions(&root_candidate)? {
+            return Ok(Some(resolved));
+        }
+
+        let src_candidate = self.root.join("src").join(import_path);
+        if let Some(resolved) = self.try_resolve_with_extensions(&src_candidate)? {
+            return Ok(Some(resolved));
+        }
+
+        Ok(None)
+    }
+
+    /// Target of a `#specifier` in the `imports` of the package containing `from_file`.
+    fn package_import(
+        &self,
+        specifier: &str,
+        from_file: &Path,
+    ) -> std::result::Result<PackageResolution, PackageError> {
+        let scope = self
+            .packages
+            .scope_of(from_file)
+            .filter(|scope| scope.imports.is_some())
+            .ok_or(PackageError::NoPackageScope)?;
+        scope.resolve_import(specifier, &self.conditions)
+    }
+
+    /// The project package a bare specifier refers to, with the subpath
+    /// inside it: the package containing `from_file` itself or a workspace package.
+    fn project_package(
+        &self,
+        specifier: &str,
+        from_file: &Path,
+    ) -> Option<(Arc<PackageJson>, String)> {
+        let (name, subpath) = package::split_specifier(specifier)?;
+        let package = self
+            .packages
+            .scope_of(from_file)
+            .filter(|scope| scope.name.as_deref() == Some(name))
+            .or_else(|| self.packages.workspace_package(name))?;
+        Some((package, subpath))
+    }
+
+    fn governing_project(&self, file: &Path) -> Option<Arc<TsProject>> {
+        self.tsconfigs.as_ref()?.governing(file)
+    }
+
+    fn is_root_project(&self, project: &TsProject) -> bool {
+        self.tsconfigs
+            .as_ref()
+            .is_some_and(|tsconfigs| tsconfigs.root_config() == Some(project.config_path.as_path()))
+    }
+
+    fn resolve_project_paths(
+        &self,
+        project: &TsProject,
+        import_path: &str,
+    ) -> Result<Option<PathBuf>> {
+        if let Some(targets) = project.path_targets(import_path) {
+            for target in targets {
+                if let Some(resolved) = self.try_resolve_with_extensions(&target)? {
+                    return Ok(Some(resolved));
+                }
+            }
+        }
+
+        if let Some(base_url) = &project.base_url {
+            return self.try_resolve_with_extensions(&base_url.join(import_path));
+        }
+
+        Ok(None)
+    }
+
+    /// Resolve a relative import against the other `rootDirs`, which TypeScript
+    /// treats as one merged directory.
+    fn resolve_root_dirs(
+        &self,
+        project: &TsProject,
+        import_path: &str,
+        from_file: &Path,
+    ) -> Result<Option<PathBuf>> {
+        let Some(from_dir) = from_file.parent() else {
+            return Ok(None);
+        };
+        let Some(relative_dir) = project
+            .root_dirs
+            .iter()
+            .find_map(|root| from_dir.strip_prefix(root).ok())
+        else {
+            return Ok(None);
+        };
+
+        for root in &project.root_dirs {
+            let candidate = root.join(relative_dir).join(import_path);
+            if let Some(resolved) = self.try_resolve_with_extensions(&candidate)? {
+                return Ok(Some(resolved));
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn resolve_relative(&self, import_path: &str, from_file: &Path) -> Result<Option<PathBuf>> {
+        let from_dir = from_file.parent().ok_or_else(|| {
+            AnalysisError::PathResolution(format!("Invalid file path: {from_file:?}"))
+        })?;
+
+        let candidate = from_dir.join(import_path);
+        self.try_resolve_with_extensions(&candidate)
+    }
+
+    fn resolve_alias(&self, import_path: &str) -> Result<Option<PathBuf>> {
+        for (alias_prefix, actual_prefix) in &self.aliases {
+            if import_path.starts_with(alias_prefix.trim_end_matches('*')) {
+                let relative_path = import_path.replacen(
+                    alias_prefix.trim_end_matches('*'),
+                    actual_prefix.trim_end_matches('*'),
+                    1,
+                );
+                let candidate = self.root.join(&relative_path);
+                return self.try_resolve_with_extensions(&candidate);
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn try_resolve_with_extensions(&self, base: &Path) -> Result<Option<PathBuf>> {
+        // Try exact path first
+        if self.is_file(base) {
+            return Ok(Some(self.canonicalize_path(base.to_path_buf())));
+        }
+
+        if let Some(resolved) = self.resolve_esm_extension(base) {
+            return Ok(Some(resolved));
+        }
+
+        if let Some(resolved) = self.resolve_by_extensions(base) {
+            return Ok(Some(resolved));
+        }
+
+        if let Some(resolved) = self.resolve_index_file(base) {
+            return Ok(Some(resolved));
+        }
+
+        Ok(None)
+    }
+
+    fn resolve_esm_extension(&self, base: &Path) -> Option<PathBuf> {
+        let base_str = base.to_string_lossy();
+
+        // Special case for TS ESM: if importing .js but only .ts exists
+        if base_str.ends_with(".js") {
+            let ts_base = base.with_extension("ts");
+            if self.is_file(&ts_base) {
+                return Some(self.canonicalize_path(ts_base));
+            }
+        }
+        if base_str.ends_with(".jsx") {
+            let tsx_base = base.with_extension("tsx");
+            if self.is_file(&tsx_base) {
+                return Some(self.canonicalize_path(tsx_base));
+            }
+        }
+        None
+    }
+
+    fn resolve_by_extensions(&self, base: &Path) -> Option<PathBuf> {
+        let base_str = base.to_string_lossy();
+        // Try adding extensions (don't use with_extension as it replaces existing ones like .service)
+        for ext in SUPPORTED_EXTENSIONS {
+            let with_ext = PathBuf::from(format!("{base_str}.{ext}"));
+            if self.is_file(&with_ext) {
+                return Some(self.canonicalize_path(with_ext));
+            }
+        }
+        None
+    }
+
+    fn resolve_index_file(&self, base: &Path) -> Option<PathBuf> {
+        if base.is_dir() || !self.deleted_files.is_empty() {
+            for ext in SUPPORTED_EXTENSIONS {
+                let index = base.join(format!("index.{ext}"));
+                if self.is_file(&index) {
+                    return Some(self.canonicalize_path(index));
+                }
+            }
+        }
+        None
+    }
+
+    fn canonicalize_path(&self, path: PathBuf) -> PathBuf {
+        // Deleted files cannot be canonicalized
+        path.canonicalize().unwrap_or_else(|_| normalize(&path))
+    }
+}
+
+/// A file next to `base` with the same name plus an extension, or with its
+/// extension replaced.
+fn file_with_other_extension(base: &Path) -> Option<PathBuf> {
+    let name = base.file_name()?.to_string_lossy().to_string();
+    let stem = base.file_stem()?.to_string_lossy().to_string();
+    let entries = std::fs::read_dir(base.parent()?).ok()?;
+
+    entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.is_file())
+        .filter(|path| {
+            let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+            [&name, &stem].iter().any(|prefix| {
+                file_name
+                    .strip_prefix(prefix.as_str())
+                    .is_some_and(|rest| rest.starts_with('.'))
+            })
+        })
+        .min()
+}
@@ -0,0 +1,414 @@
+//! `package.json` `exports` and `imports` resolution following the Node.js algorithm.
+//!
+//! Supports subpath patterns with `*`, condition objects matched in key order
+//! against the active conditions, fallback arrays and `null` exclusions.
+
+use serde::de::{Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock, RwLock};
+
+/// Condition that always matches, whatever the active conditions are.
+const DEFAULT_CONDITION: &str = "default";
+
+/// A value of the `exports` or `imports` field. Condition objects keep the
+/// order of their keys, which decides between several matching conditions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PackageTarget {
+    Null,
+    Path(String),
+    Fallbacks(Vec<Self>),
+    Map(Vec<(String, Self)>),
+}
+
+impl<'de> Deserialize<'de> for PackageTarget {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct TargetVisitor;
+
+        impl<'de> Visitor<'de> for TargetVisitor {
+            type Value = PackageTarget;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a package exports or imports target")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E> {
+                Ok(PackageTarget::Path(value.to_string()))
+            }
+
+            fn visit_unit<E>(self) -> Result<Self::Value, E> {
+                Ok(PackageTarget::Null)
+            }
+
+            // Numbers and booleans are invalid targets and never match
+            fn visit_bool<E>(self, _: bool) -> Result<Self::Value, E> {
+                Ok(PackageTarget::Null)
+            }
+
+            fn visit_i64<E>(self, _: i64) -> Result<Self::Value, E> {
+                Ok(PackageTarget::Null)
+            }
+
+            fn visit_u64<E>(self, _: u64) -> Result<Self::Value, E> {
+                Ok(PackageTarget::Null)
+            }
+
+            fn visit_f64<E>(self, _: f64) -> Result<Self::Value, E> {
+                Ok(PackageTarget::Null)
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut targets = Vec::new();
+                while let Some(target) = seq.next_element()? {
+                    targets.push(target);
+                }
+                Ok(PackageTarget::Fallbacks(targets))
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut entries = Vec::new();
+                while let Some(entry) = map.next_entry()? {
+                    entries.push(entry);
+                }
+                Ok(PackageTarget::Map(entries))
+            }
+        }
+
+        deserializer.deserialize_any(TargetVisitor)
+    }
+}
+
+/// Where an `exports` or `imports` entry points to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PackageResolution {
+    /// A file inside the package.
+    File(PathBuf),
+    /// Another package, from an `imports` entry such as `"#dep": "lodash"`.
+    Package(String),
+}
+
+/// Why a package specifier could not be resolved.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum PackageError {
+    #[error("'{subpath}' is not exported by {package}")]
+    NotExported { package: String, subpath: String },
+    #[error("'{specifier}' is not defined in the imports of {package}")]
+    ImportNotDefined { package: String, specifier: String },
+    #[error("no condition of '{subpath}' matches the active conditions [{}]", conditions.join(", "))]
+    NoMatchingCondition {
+        subpath: String,
+        conditions: Vec<String>,
+    },
+    #[error("invalid target '{target}' for '{subpath}'")]
+    InvalidTarget { subpath: String, target: String },
+    #[error("no package.json with an imports field above the importing file")]
+    NoPackageScope,
+}
+
+/// The fields of a `package.json` that take part in module resolution.
+#[derive(Debug, Clone, Default)]
+pub struct PackageJson {
+    /// Directory containing the manifest.
+    pub dir: PathBuf,
+    pub name: Option<String>,
+    pub exports: Option<PackageTarget>,
+    pub imports: Option<PackageTarget>,
+}
+
+#[derive(Deserialize)]
+struct RawPackageJson {
+    name: Option<String>,
+    exports: Option<PackageTarget>,
+    imports: Option<PackageTarget>,
+}
+
+/// Outcome of matching a target against the active conditions.
+enum Step {
+    Found(PackageResolution),
+    /// A `null` target: the subpath is explicitly not exported.
+    Excluded,
+    /// No condition of an object applies.
+    Unmatched,
+}
+
+impl PackageJson {
+    #[must_use]
+    pub fn load(path: &Path) -> Option<Self> {
+        let content = fs::read_to_string(path).ok()?;
+        let raw: RawPackageJson = serde_json::from_str(&content)
+            .inspect_err(|e| log::debug!("Failed to parse {}: {e}", path.display()))
+            .ok()?;
+        Some(Self {
+            dir: path.parent()?.to_path_buf(),
+            name: raw.name,
+            exports: raw.exports,
+            imports: raw.imports,
+        })
+    }
+
+    fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| self.dir.join("package.json").display().to_string())
+    }
+
+    /// Resolve `subpath` (`.` or `./feature`) through the `exports` field.
+    /// `Ok(None)` means the package has no `exports` field.
+    pub fn resolve_export(
+        &self,
+        subpath: &str,
+        conditions: &[String],
+    ) -> Result<Option<PathBuf>, PackageError> {
+        let Some(exports) = &self.exports else {
+            return Ok(None);
+        };
+        let not_exported = || PackageError::NotExported {
+            package: self.display_name(),
+            subpath: subpath.to_string(),
+        };
+
+        let step = match exports {
+            PackageTarget::Map(entries) if entries.iter().any(|(key, _)| key.starts_with('.')) => {
+                self.resolve_key(subpath, entries, false, conditions)
+                    .ok_or_else(not_exported)??
+            }
+            // Sugar: a string, array or condition object exports the main entry
+            _ if subpath == "." => {
+                self.resolve_target(subpath, exports, None, false, conditions)?
+            }
+            _ => return Err(not_exported()),
+        };
+
+        match self.finish(subpath, step, conditions, not_exported)? {
+            PackageResolution::File(path) => Ok(Some(path)),
+            PackageResolution::Package(target) => Err(PackageError::InvalidTarget {
+                subpath: subpath.to_string(),
+                target,
+            }),
+        }
+    }
+
+    /// Resolve a `#specifier` through the `imports` field.
+    pub fn resolve_import(
+        &self,
+        specifier: &str,
+        conditions: &[String],
+    ) -> Result<PackageResolution, PackageError> {
+        let not_defined = || PackageError::ImportNotDefined {
+            package: self.display_name(),
+            specifier: specifier.to_string(),
+        };
+        let Some(PackageTarget::Map(entries)) = &self.imports else {
+            return Err(not_defined());
+        };
+
+        let step = self
+            .resolve_key(specifier, entries, true, conditions)
+            .ok_or_else(not_defined)??;
+        self.finish(specifier, step, conditions, not_defined)
+    }
+
+    fn finish(
+        &self,
+        subpath: &str,
+        step: Step,
+        conditions: &[String],
+        excluded: impl FnOnce() -> PackageError,
+    ) -> Result<PackageResolution, PackageError> {
+        match step {
+            Step::Found(resolution) => Ok(resolution),
+            Step::Excluded => Err(excluded()),
+            Step::Unmatched => Err(PackageError::NoMatchingCondition {
+                subpath: subpath.to_string(),
+                conditions: conditions.to_vec(),
+            }),
+        }
+    }
+
+    /// Find the entry for `key`: an exact key first, then the most specific
+    /// `*` pattern. `None` when no entry matches.
+    fn resolve_key(
+        &self,
+        key: &str,
+        entries: &[(String, PackageTarget)],
+        is_imports: bool,
+        conditions: &[String],
+    ) -> Option<Result<Step, PackageError>> {
+        if !key.contains('*') {
+            if let Some((_, target)) = entries.iter().find(|(k, _)| k == key) {
+                return Some(self.resolve_target(key, target, None, is_imports, conditions));
+            }
+        }
+
+        let mut patterns: Vec<&(String, PackageTarget)> = entries
+            .iter()
+            .filter(|(k, _)| k.matches('*').count() == 1)
+            .collect();
+        patterns.sort_by(|(a, _), (b, _)| pattern_key_compare(a, b));
+
+        patterns.into_iter().find_map(|(pattern, target)| {
+            let (base, trailer) = pattern.split_once('*')?;
+            let matched = key
+                .strip_prefix(base)
+                .filter(|rest| !rest.is_empty())
+                .and_then(|rest| rest.strip_suffix(trailer))
+                .filter(|_| key.len() >= pattern.len())?;
+            Some(self.resolve_target(key, target, Some(matched), is_imports, conditions))
+        })
+    }
+
+    fn resolve_target(
+        &self,
+        subpath: &str,
+        target: &PackageTarget,
+        pattern_match: Option<&str>,
+        is_imports: bool,
+        conditions: &[String],
+    ) -> Result<Step, PackageError> {
+        match target {
+            PackageTarget::Null => Ok(Step::Excluded),
+            PackageTarget::Path(path) => self
+                .resolve_path(path, pattern_match, is_imports)
+                .map(Step::Found)
+                .ok_or_else(|| PackageError::InvalidTarget {
+                    subpath: subpath.to_string(),
+                    target: path.clone(),
+                }),
+            PackageTarget::Map(entries) => {
+                for (condition, value) in entries {
+                    if condition != DEFAULT_CONDITION && !conditions.contains(condition) {
+                        continue;
+                    }
+                    match self.resolve_target(
+                        subpath,
+                        value,
+                        pattern_match,
+                        is_imports,
+                        conditions,
+                    )? {
+                        Step::Unmatched => {}
+                        step => return Ok(step),
+                    }
+                }
+                Ok(Step::Unmatched)
+            }
+            PackageTarget::Fallbacks(targets) => {
+                let mut last = Ok(Step::Unmatched);
+                for target in targets {
+                    last =
+                        self.resolve_target(subpath, target, pattern_match, is_imports, conditions);
+                    if matches!(last, Ok(Step::Found(_))) {
+                        break;
+                    }
+                }
+                last
+            }
+        }
+    }
+
+    /// Substitute the pattern match into a string target. Package-relative
+    /// targets must start with `./` and stay inside the package.
+    fn resolve_path(
+        &self,
+        target: &str,
+        pattern_match: Option<&str>,
+        is_imports: bool,
+    ) -> Option<PackageResolution> {
+        let target = pattern_match.map_or_else(|| target.to_string(), |m| target.replace('*', m));
+
+        let Some(relative) = target.strip_prefix("./") else {
+            let is_bare =
+                !target.starts_with('/') && !target.starts_with("../") && !target.contains("://");
+            return (is_imports && is_bare).then_some(PackageResolution::Package(target));
+        };
+        if relative
+            .split(['/', '\\'])
+            .any(|segment| matches!(segment, "." | ".." | "node_modules"))
+        {
+            return None;
+        }
+        Some(PackageResolution::File(self.dir.join(relative)))
+    }
+}
+
+/// Order of `*` keys from the most to the least specific, as in `PATTERN_KEY_COMPARE`.
+fn pattern_key_compare(a: &str, b: &str) -> std::cmp::Ordering {
+    let base = |key: &str| key.find('*').map_or(key.len(), |i| i + 1);
+    base(b).cmp(&base(a)).then_with(|| b.len().cmp(&a.len()))
+}
+
+/// Split `@scope/name/sub/path` into the package name and the `./sub/path` subpath.
+#[must_use]
+pub fn split_specifier(specifier: &str) -> Option<(&str, String)> {
+    let mut slashes = specifier.match_indices('/').map(|(i, _)| i);
+    let end = if specifier.starts_with('@') {
+        slashes.nth(1)
+    } else {
+        slashes.next()
+    };
+    let (name, rest) = end.map_or((specifier, ""), |end| specifier.split_at(end));
+    if name.is_empty() || name.starts_with('.') || name.contains('\\') {
+        return None;
+    }
+    Some((name, format!(".{rest}")))
+}
+
+/// `package.json` files of a project: the scope of each directory and the
+/// workspace packages by name, both loaded on first use.
+#[derive(Debug)]
+pub struct PackageScopes {
+    root: PathBuf,
+    scopes: RwLock<HashMap<PathBuf, Option<Arc<PackageJson>>>>,
+    workspace: OnceLock<HashMap<String, Arc<PackageJson>>>,
+}
+
+impl PackageScopes {
+    #[must_use]
+    pub fn new(root: &Path) -> Self {
+        Self {
+            root: root.to_path_buf(),
+            scopes: RwLock::new(HashMap::new()),
+            workspace: OnceLock::new(),
+        }
+    }
+
+    /// The closest `package.json` above `file`, within the project root.
+    pub fn scope_of(&self, file: &Path) -> Option<Arc<PackageJson>> {
+        let dir = file.parent()?;
+        if let Some(scope) = self.scopes.read().ok()?.get(dir) {
+            return scope.clone();
+        }
+
+        let scope = dir
+            .ancestors()
+            .take_while(|d| d.starts_with(&self.root))
+            .find_map(|d| {
+                let manifest = d.join("package.json");
+                manifest.is_file().then(|| PackageJson::load(&manifest))?
+            })
+            .map(Arc::new);
+        if let Ok(mut scopes) = self.scopes.write() {
+            scopes.insert(dir.to_path_buf(), scope.clone());
+        }
+        scope
+    }
+
+    /// A package of the project (outside `node_modules`) by its `name`.
+    pub fn workspace_package(&self, name: &str) -> Option<Arc<PackageJson>> {
+        self.workspace
+            .get_or_init(|| {
+                crate::package_json::PackageJsonParser::find_manifests(&self.root)
+                    .into_iter()
+                    .filter_map(|manifest| {
+                        let package = PackageJson::load(&manifest.path)?;
+                        Some((package.name.clone()?, Arc::new(package)))
+                    })
+                    .collect()
+            })
+            .get(name)
+            .cloned()
+    }
+}
@@ -1,4 +1,5 @@
 use crate::api::result::{ScanResult, SmellWithExplanation};
+use crate::debt::{DebtModel, DebtSummary, DEBT_METRIC};
 use crate::detectors::{ArchSmell, SmellType};
 use crate::snapshot::id::generate_smell_id;
 use crate::snapshot::types::{
@@ -11,6 +12,7 @@ use std::path::{Path, PathBuf};
 pub struct SnapshotGenerator {
     project_root: PathBuf,
     include_commit: bool,
+    debt_model: DebtModel,
 }
 
 impl SnapshotGenerator {
@@ -20,6 +22,7 @@ impl SnapshotGenerator {
         Self {
             project_root,
             include_commit: true,
+            debt_model: DebtModel::default(),
         }
     }
 
@@ -29,6 +32,13 @@ impl SnapshotGenerator {
         self
     }
 
+    /// Use the given remediation costs instead of the built-in ones.
+    #[must_use]
+    pub fn with_debt_model(mut self, model: DebtModel) -> Self {
+        self.debt_model = model;
+        self
+    }
+
     #[must_use]
     pub fn generate(&self, scan_result: &ScanResult) -> Snapshot {
         let commit = if self.include_commit {
@@ -42,6 +52,11 @@ impl SnapshotGenerator {
             .iter()
             .map(|s| self.convert_smell(&s.smell))
             .collect();
+        let debt = DebtSummary::compute(
+            scan_result.smells.iter().map(|s| &s.smell),
+            &self.debt_model,
+            &self.project_root,
+        );
 
         Snapshot {
             schema_version: SCHEMA_VERSION,
@@ -51,6 +66,7 @@ impl SnapshotGenerator {
             smells,
             summary: self.build_summary(scan_result),
             grade: format!("{:?}", scan_result.grade),
+            debt: Some(debt),
         }
     }
 
@@ -127,6 +143,7 @@ impl SnapshotGenerator {
             SmellType::CodeClone {
                 clone_hash,
                 token_count,
+                ..
             } => {
                 metrics.insert("cloneHash".into(), MetricValue::String(clone_hash.clone()));
                 metrics.insert(
@@ -143,6 +160,11 @@ impl SnapshotGenerator {
             _ => {}
         }
 
+        metrics.insert(
+            DEBT_METRIC.into(),
+            MetricValue::Float(self.debt_model.estimate(smell)),
+        );
+
         metrics
     }
 
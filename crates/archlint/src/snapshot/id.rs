@@ -4,6 +4,7 @@ use std::path::{Path, PathBuf};
 
 /// Generate stable, deterministic ID for a smell
 #[must_use]
+#[allow(clippy::too_many_lines)]
 pub fn generate_smell_id(smell: &ArchSmell, project_root: &Path) -> String {
     match &smell.smell_type {
         SmellType::CyclicDependency | SmellType::CyclicDependencyCluster => {
@@ -26,6 +27,19 @@ pub fn generate_smell_id(smell: &ArchSmell, project_root: &Path) -> String {
             id_for_symbol_smell("dead", &smell.files[0], name, line, project_root)
         }),
 
+        SmellType::UnusedClassMember {
+            class_name, member, ..
+        } => with_line_hash_fallback(smell, |line| {
+            let symbol = format!("{class_name}.{member}");
+            id_for_symbol_smell(
+                "unused_member",
+                &smell.files[0],
+                &symbol,
+                line,
+                project_root,
+            )
+        }),
+
         SmellType::HighCyclomaticComplexity { name, .. } => {
             with_line_hash_fallback(smell, |line| {
                 id_for_symbol_smell("ccycl", &smell.files[0], name, line, project_root)
@@ -50,12 +64,48 @@ pub fn generate_smell_id(smell: &ArchSmell, project_root: &Path) -> String {
             format!("vendor:{package}")
         }
 
+        SmellType::NpmDependency { package, issue } => {
+            // The manifest comes first; importers change as code is edited
+            let manifest = relative_path(&smell.files[0], project_root);
+            format!("npm:{issue:?}:{manifest}:{package}")
+        }
+
+        SmellType::ProviderOutsideModule {
+            provider, consumer, ..
+        } => {
+            let file = relative_path(&smell.files[0], project_root);
+            format!("provider_scope:{file}:{consumer}:{provider}")
+        }
+
+        SmellType::OrphanEvent { key, issue } => {
+            format!("orphan_event:{issue:?}:{key}")
+        }
+
+        SmellType::EventCycle { .. } => {
+            format!("event_{}", id_for_cycle(&smell.files, project_root))
+        }
+
+        SmellType::UnresolvedImport { specifier, .. } => {
+            let file = relative_path(&smell.files[0], project_root);
+            format!("unresolved:{file}:{specifier}")
+        }
+
         SmellType::SideEffectImport => with_line_hash_fallback(smell, |line| {
             let file = &smell.files[0];
             let relative = relative_path(file, project_root);
             format!("sideeffect:{relative}:{line}")
         }),
 
+        SmellType::TestOnlyCode { symbol } => {
+            let file = relative_path(&smell.files[0], project_root);
+            format!("test_only:{file}:{}", symbol.as_deref().unwrap_or("*"))
+        }
+
+        SmellType::UnusedSuppression { .. } => with_line_hash_fallback(smell, |line| {
+            let relative = relative_path(&smell.files[0], project_root);
+            format!("unused_suppression:{relative}:{line}")
+        }),
+
         SmellType::TestLeakage { test_file } => {
             let from = &smell.files[0];
             let from_rel = relative_path(from, project_root);
@@ -63,6 +113,12 @@ pub fn generate_smell_id(smell: &ArchSmell, project_root: &Path) -> String {
             format!("test_leak:{from_rel}:{to_rel}")
         }
 
+        SmellType::ServerCodeInClient { module, .. } => {
+            let client_rel = relative_path(&smell.files[0], project_root);
+            let module_rel = relative_path(module, project_root);
+            format!("server_in_client:{client_rel}:{module_rel}")
+        }
+
         SmellType::FeatureEnvy { most_envied_module } => {
             let from = &smell.files[0];
             let from_rel = relative_path(from, project_root);
@@ -82,6 +138,14 @@ pub fn generate_smell_id(smell: &ArchSmell, project_root: &Path) -> String {
             id_for_symbol_smell("params", &smell.files[0], name, line, project_root)
         }),
 
+        SmellType::LongFunction { name, .. } => with_line_hash_fallback(smell, |line| {
+            id_for_symbol_smell("long_fn", &smell.files[0], name, line, project_root)
+        }),
+
+        SmellType::LargeClass { name, .. } => with_line_hash_fallback(smell, |line| {
+            id_for_symbol_smell("large_class", &smell.files[0], name, line, project_root)
+        }),
+
         SmellType::PrimitiveObsession { name, .. } => with_line_hash_fallback(smell, |line| {
             id_for_symbol_smell("prim", &smell.files[0], name, line, project_root)
         }),
@@ -1,3 +1,4 @@
+use crate::debt::DebtSummary;
 use crate::detectors::{CodeRange, SmellType};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -29,6 +30,10 @@ pub struct Snapshot {
 
     /// Architecture grade (A/B/C/D/F)
     pub grade: String,
+
+    /// Estimated technical debt (absent in snapshots from older versions)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub debt: Option<DebtSummary>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -179,6 +184,7 @@ mod tests {
             smells: vec![],
             summary: SnapshotSummary::default(),
             grade: "A".into(),
+            debt: None,
         };
 
         let json = serde_json::to_string(&snapshot).unwrap();
@@ -227,6 +233,7 @@ mod tests {
             ],
             summary: SnapshotSummary::default(),
             grade: "A".into(),
+            debt: None,
         };
 
         assert!(matches!(
@@ -0,0 +1,446 @@
+//! Dependency graph between exported symbols.
+//!
+//! Every exported declaration becomes a node. An edge `a -> b` means the body
+//! of `a` refers to `b`, either through an import (following re-exports to the
+//! declaring file) or directly within the same file. References made outside
+//! of any exported declaration are kept per file, so the graph can tell which
+//! symbols are used by code that is not itself a symbol.
+//!
+//! Requires symbols parsed with `ParserConfig::collect_used_symbols`.
+
+use crate::parser::{FileSymbols, ImportedSymbol, SymbolKind, SymbolSet};
+use petgraph::algo::tarjan_scc;
+use petgraph::graph::{DiGraph, NodeIndex};
+use petgraph::visit::{Bfs, Walker};
+use petgraph::Direction;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+/// Re-export chains longer than this are not followed.
+const MAX_REEXPORT_DEPTH: usize = 16;
+
+pub type SymbolId = NodeIndex;
+
+/// An exported declaration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Symbol {
+    pub file: PathBuf,
+    pub name: String,
+    pub kind: SymbolKind,
+    pub line: usize,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SymbolGraph {
+    graph: DiGraph<Symbol, ()>,
+    index: HashMap<(PathBuf, String), SymbolId>,
+    /// Files that use a symbol outside of their exported declarations.
+    file_users: HashMap<SymbolId, BTreeSet<PathBuf>>,
+    /// Symbols reachable through a dynamic import, whose usage is unknown.
+    dynamic: HashSet<SymbolId>,
+}
+
+impl SymbolGraph {
+    /// Build the graph from the symbols of all project files, with import
+    /// sources already resolved to file paths.
+    #[must_use]
+    pub fn build(file_symbols: &HashMap<PathBuf, FileSymbols>) -> Self {
+        let mut graph = Self::default();
+
+        let mut files: Vec<&PathBuf> = file_symbols.keys().collect();
+        files.sort();
+        for &file in &files {
+            graph.add_symbols(file, &file_symbols[file]);
+        }
+
+        let resolver = Resolver { file_symbols };
+        for &file in &files {
+            graph.add_references(&resolver, file, &file_symbols[file]);
+        }
+
+        graph
+    }
+
+    fn add_symbols(&mut self, file: &Path, symbols: &FileSymbols) {
+        for export in &symbols.exports {
+            if export.is_reexport || export.name == "*" {
+                continue;
+            }
+            let key = (file.to_path_buf(), export.name.to_string());
+            if self.index.contains_key(&key) {
+                continue;
+            }
+            let id = self.graph.add_node(Symbol {
+                file: file.to_path_buf(),
+                name: export.name.to_string(),
+                kind: export.kind.clone(),
+                line: export.line,
+            });
+            self.index.insert(key, id);
+        }
+    }
+
+    fn add_references(&mut self, resolver: &Resolver, file: &Path, symbols: &FileSymbols) {
+        for export in symbols.exports.iter().filter(|e| !e.is_reexport) {
+            let key = (file.to_path_buf(), export.name.to_string());
+            let Some(&from) = self.index.get(&key) else {
+                continue;
+            };
+            let referenced = resolver.referenced(file, symbols, &export.used_symbols);
+            for to in self.ids(&referenced) {
+                if to != from {
+                    self.graph.update_edge(from, to, ());
+                }
+            }
+        }
+
+        let referenced = resolver.referenced(file, symbols, &symbols.usages_outside_exports);
+        for id in self.ids(&referenced) {
+            self.file_users
+                .entry(id)
+                .or_default()
+                .insert(file.to_path_buf());
+        }
+
+        for import in symbols.imports.iter().filter(|i| i.is_dynamic) {
+            let source = Path::new(import.source.as_str());
+            let exported: Vec<SymbolId> = self
+                .graph
+                .node_indices()
+                .filter(|&id| self.graph[id].file == source)
+                .collect();
+            self.dynamic.extend(exported);
+        }
+    }
+
+    fn ids(&self, referenced: &BTreeSet<(PathBuf, String)>) -> Vec<SymbolId> {
+        referenced
+            .iter()
+            .filter_map(|key| self.index.get(key).copied())
+            .collect()
+    }
+
+    #[must_use]
+    pub fn node_count(&self) -> usize {
+        self.graph.node_count()
+    }
+
+    #[must_use]
+    pub fn edge_count(&self) -> usize {
+        self.graph.edge_count()
+    }
+
+    pub fn symbols(&self) -> impl Iterator<Item = SymbolId> + '_ {
+        self.graph.node_indices()
+    }
+
+    #[must_use]
+    pub fn symbol(&self, id: SymbolId) -> &Symbol {
+        &self.graph[id]
+    }
+
+    /// The symbol `name` declared in `file`.
+    #[must_use]
+    pub fn find(&self, file: &Path, name: &str) -> Option<SymbolId> {
+        self.index
+            .get(&(file.to_path_buf(), name.to_string()))
+            .copied()
+    }
+
+    /// Symbols that `id` refers to.
+    pub fn dependencies(&self, id: SymbolId) -> impl Iterator<Item = SymbolId> + '_ {
+        self.graph.neighbors_directed(id, Direction::Outgoing)
+    }
+
+    /// Symbols that refer to `id`.
+    pub fn dependents(&self, id: SymbolId) -> impl Iterator<Item = SymbolId> + '_ {
+        self.graph.neighbors_directed(id, Direction::Incoming)
+    }
+
+    /// Files that use `id` outside of their exported declarations.
+    pub fn file_users(&self, id: SymbolId) -> impl Iterator<Item = &PathBuf> + '_ {
+        self.file_users.get(&id).into_iter().flatten()
+    }
+
+    /// Groups of symbols that depend on each other, largest first.
+    #[must_use]
+    pub fn cycles(&self) -> Vec<Vec<SymbolId>> {
+        let mut cycles: Vec<Vec<SymbolId>> = tarjan_scc(&self.graph)
+            .into_iter()
+            .filter(|scc| scc.len() > 1)
+            .map(|mut scc| {
+                scc.sort_by(|a, b| self.sort_key(*a).cmp(&self.sort_key(*b)));
+                scc
+            })
+            .collect();
+        cycles.sort_by(|a, b| {
+            b.len()
+                .cmp(&a.len())
+                .then_with(|| self.sort_key(a[0]).cmp(&self.sort_key(b[0])))
+        });
+        cycles
+    }
+
+    /// Symbols unreachable from any live usage. A symbol is live when its
+    /// file is an entry point, when a file uses it outside of exported
+    /// declarations, when it is reachable through a dynamic import, or when a
+    /// live symbol refers to it.
+    #[must_use]
+    pub fn dead_symbols(&self, entry_points: &HashSet<PathBuf>) -> Vec<SymbolId> {
+        let mut live = HashSet::new();
+        for root in self
+            .graph
+            .node_indices()
+            .filter(|&id| self.is_root(id, entry_points))
+        {
+            if !live.contains(&root) {
+                live.extend(Bfs::new(&self.graph, root).iter(&self.graph));
+            }
+        }
+
+        let mut dead: Vec<SymbolId> = self
+            .graph
+            .node_indices()
+            .filter(|id| !live.contains(id))
+            .collect();
+        dead.sort_by(|a, b| self.sort_key(*a).cmp(&self.sort_key(*b)));
+        dead
+    }
+
+    /// Symbols that become dead given the already `dead` ones: symbols that
+    /// are not used directly by any file and only referenced by dead symbols.
+    #[must_use]
+    pub fn propagate_dead(
+        &self,
+        dead: &HashSet<SymbolId>,
+        entry_points: &HashSet<PathBuf>,
+    ) -> Vec<SymbolId> {
+        let mut all_dead = dead.clone();
+        let mut found = Vec::new();
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for id in self.graph.node_indices() {
+                if all_dead.contains(&id) || self.is_root(id, entry_points) {
+                    continue;
+                }
+                let mut users = self.dependents(id).peekable();
+                if users.peek().is_some() && users.all(|user| all_dead.contains(&user)) {
+                    all_dead.insert(id);
+                    found.push(id);
+                    changed = true;
+                }
+            }
+        }
+
+        found.sort_by(|a, b| self.sort_key(*a).cmp(&self.sort_key(*b)));
+        found
+    }
+
+    fn is_root(&self, id: SymbolId, entry_points: &HashSet<PathBuf>) -> bool {
+        entry_points.contains(&self.graph[id].file)
+            || self.file_users.contains_key(&id)
+            || self.dynamic.contains(&id)
+    }
+
+    fn sort_key(&self, id: SymbolId) -> (&Path, usize, &str) {
+        let symbol = &self.graph[id];
+        (&symbol.file, symbol.line, &symbol.name)
+    }
+}
+
+/// Maps names used in a file to the symbols they refer to.
+pub(crate) struct Resolver<'a> {
+    pub(crate) file_symbols: &'a HashMap<PathBuf, FileSymbols>,
+}
+
+impl Resolver<'_> {
+    /// Symbols referenced by `names`, as used in `file`.
+    fn referenced(
+        &self,
+        file: &Path,
+        symbols: &FileSymbols,
+        names: &SymbolSet,
+    ) -> BTreeSet<(PathBuf, String)> {
+        let mut referenced = BTreeSet::new();
+        for name in names {
+            match Self::local_import(symbols, name) {
+                // Namespace import: members are recorded as used names too
+                Some(import) if import.name == "*" => {
+                    let source = Path::new(import.source.as_str());
+                    for member in names {
+                        referenced.extend(self.resolve(source, member, 0));
+                    }
+                }
+                Some(import) => {
+                    let source = Path::new(import.source.as_str());
+                    referenced.extend(self.resolve(source, &import.name, 0));
+                }
+                None => {
+                    if Self::declares(symbols, name) {
+                        referenced.insert((file.to_path_buf(), name.to_string()));
+                    }
+                }
+            }
+        }
+        referenced
+    }
+
+    /// The import that binds `local` in a file.
+    pub(crate) fn local_import<'s>(
+        symbols: &'s FileSymbols,
+        local: &str,
+    ) -> Option<&'s ImportedSymbol> {
+        symbols
+            .imports
+            .iter()
+            .filter(|i| !i.is_reexport && !i.is_dynamic)
+            .find(|i| i.alias.as_deref() == Some(local))
+    }
+
+    fn declares(symbols: &FileSymbols, name: &str) -> bool {
+        symbols
+            .exports
+            .iter()
+            .any(|e| !e.is_reexport && e.name == name)
+    }
+
+    /// Declaring file and name of `name` as exported by `file`.
+    pub(crate) fn resolve(
+        &self,
+        file: &Path,
+        name: &str,
+        depth: usize,
+    ) -> Option<(PathBuf, String)> {
+        if depth > MAX_REEXPORT_DEPTH {
+            return None;
+        }
+        let symbols = self.file_symbols.get(file)?;
+        if Self::declares(symbols, name) {
+            return Some((file.to_path_buf(), name.to_string()));
+        }
+
+        let reexports = || symbols.imports.iter().filter(|i| i.is_reexport);
+        if let Some(import) =
+            reexports().find(|i| i.name != "*" && i.alias.as_deref().unwrap_or(&i.name) == name)
+        {
+            return self.resolve(Path::new(import.source.as_str()), &import.name, depth + 1);
+        }
+        reexports()
+            .filter(|i| i.name == "*" && i.alias.is_none())
+            .find_map(|i| self.resolve(Path::new(i.source.as_str()), name, depth + 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::ImportParser;
+
+    /// Parse `/p/<name>.ts` files, resolving `./x` sources to `/p/x.ts`.
+    fn project(files: &[(&str, &str)]) -> HashMap<PathBuf, FileSymbols> {
+        let parser = ImportParser::new().unwrap();
+        let resolve = |source: &str| format!("/p/{}.ts", source.trim_start_matches("./"));
+        files
+            .iter()
+            .map(|(name, code)| {
+                let path = PathBuf::from(format!("/p/{name}.ts"));
+                let mut symbols = parser.parse_code(code, &path).unwrap().symbols;
+                for import in &mut symbols.imports {
+                    import.source = resolve(&import.source).into();
+                }
+                for export in &mut symbols.exports {
+                    if let Some(source) = &mut export.source {
+                        *source = resolve(source).into();
+                    }
+                }
+                (path, symbols)
+            })
+            .collect()
+    }
+
+    fn id(graph: &SymbolGraph, file: &str, name: &str) -> SymbolId {
+        graph
+            .find(Path::new(&format!("/p/{file}.ts")), name)
+            .unwrap()
+    }
+
+    fn names(graph: &SymbolGraph, ids: &[SymbolId]) -> Vec<String> {
+        ids.iter().map(|&i| graph.symbol(i).name.clone()).collect()
+    }
+
+    #[test]
+    fn test_file_cycle_without_symbol_cycle() {
+        let a = "import { b1 } from './b';\nexport function a1() { return b1(); }\nexport const a2 = 1;";
+        let b = "import { a2 } from './a';\nexport function b1() { return 1; }\nexport function b2() { return a2; }";
+        let graph = SymbolGraph::build(&project(&[("a", a), ("b", b)]));
+
+        assert_eq!(graph.node_count(), 4);
+        assert_eq!(graph.edge_count(), 2);
+        assert!(graph.cycles().is_empty());
+
+        let b = "import { a1 } from './a';\nexport function b1() { return a1(); }";
+        let graph = SymbolGraph::build(&project(&[("a", a), ("b", b)]));
+        let cycles = graph.cycles();
+        assert_eq!(cycles.len(), 1);
+        assert_eq!(names(&graph, &cycles[0]), vec!["a1", "b1"]);
+    }
+
+    #[test]
+    fn test_reexports_and_namespace_imports() {
+        let graph = SymbolGraph::build(&project(&[
+            ("lib", "export function helper() { return 1; }"),
+            (
+                "util",
+                "export function tool() { return 2; }\nexport function other() {}",
+            ),
+            (
+                "index",
+                "export { helper as h } from './lib';\nexport * from './util';",
+            ),
+            (
+                "app",
+                "import { h, tool } from './index';\nimport * as u from './util';\n\
+                 export function run() { return h() + tool() + u.other(); }\nrun();",
+            ),
+        ]));
+
+        let run = id(&graph, "app", "run");
+        let mut deps: Vec<SymbolId> = graph.dependencies(run).collect();
+        deps.sort_by_key(|&d| graph.symbol(d).name.clone());
+        assert_eq!(names(&graph, &deps), vec!["helper", "other", "tool"]);
+
+        let helper = id(&graph, "lib", "helper");
+        assert_eq!(graph.dependents(helper).collect::<Vec<_>>(), vec![run]);
+        assert_eq!(
+            graph.file_users(run).collect::<Vec<_>>(),
+            vec![&PathBuf::from("/p/app.ts")]
+        );
+    }
+
+    #[test]
+    fn test_dead_symbols_propagate() {
+        let graph = SymbolGraph::build(&project(&[
+            ("main", "import { used } from './lib';\nused();"),
+            (
+                "lib",
+                "export function used() { return 0; }\n\
+                 export function dead() { return helper(); }\n\
+                 export function helper() { return 1; }",
+            ),
+        ]));
+        let entry_points = HashSet::from([PathBuf::from("/p/main.ts")]);
+
+        let dead = graph.dead_symbols(&HashSet::new());
+        assert_eq!(names(&graph, &dead), vec!["dead", "helper"]);
+
+        let seeds = HashSet::from([id(&graph, "lib", "dead")]);
+        let found = graph.propagate_dead(&seeds, &entry_points);
+        assert_eq!(names(&graph, &found), vec!["helper"]);
+
+        // Everything in an entry point stays alive
+        let entry_points = HashSet::from([PathBuf::from("/p/lib.ts")]);
+        assert!(graph.dead_symbols(&entry_points).is_empty());
+        assert!(graph.propagate_dead(&seeds, &entry_points).is_empty());
+    }
+}
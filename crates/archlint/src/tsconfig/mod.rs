@@ -3,9 +3,11 @@ use std::collections::HashSet;
 use std::fs;
 use std::path::{Path, PathBuf};
 
+pub mod project;
 pub mod resolver;
 pub mod types;
 
+pub use project::{TsProject, TsProjects};
 pub use types::*;
 
 impl TsConfig {
@@ -21,15 +23,16 @@ impl TsConfig {
         let canonical_path = path
             .canonicalize()
             .map_err(|e| anyhow::anyhow!("Failed to canonicalize tsconfig path {path:?}: {e}"))?;
-        if !visited.insert(canonical_path) {
+        if !visited.insert(canonical_path.clone()) {
             return Err(anyhow::anyhow!("Circular extends detected: {path:?}").into());
         }
 
         let contents = fs::read_to_string(path)?;
         let mut config: Self = json5::from_str(&contents)?;
+        let base_dir = path.parent().unwrap_or_else(|| Path::new("."));
+        config.set_origin(canonical_path.parent().unwrap_or(base_dir));
 
         if let Some(extends) = &config.extends {
-            let base_dir = path.parent().unwrap_or_else(|| Path::new("."));
             let parent_path = Self::resolve_extends_path(base_dir, extends)?;
             let parent_config = Self::load_internal(&parent_path, visited)?;
             config = config.merge_with_parent(parent_config);
@@ -38,6 +41,21 @@ impl TsConfig {
         Ok(config)
     }
 
+    /// Remember the directory that the relative settings of this file refer to.
+    fn set_origin(&mut self, dir: &Path) {
+        if self.files.is_some() || self.include.is_some() {
+            self.include_dir = Some(dir.to_path_buf());
+        }
+        if let Some(opts) = &mut self.compiler_options {
+            if opts.base_url.is_some() || opts.paths.is_some() {
+                opts.paths_dir = Some(dir.to_path_buf());
+            }
+            if opts.root_dirs.is_some() {
+                opts.root_dirs_dir = Some(dir.to_path_buf());
+            }
+        }
+    }
+
     /// Attempts to find and load a tsconfig file in the project root.
     /// If `explicit_path` is provided, it tries to load that specific file.
     /// Otherwise, it looks for the standard `tsconfig.json`.
@@ -140,6 +158,13 @@ impl TsConfig {
                 .merge(parent_opts);
         }
 
+        // `files` and `include` are inherited together, relative to the parent
+        if self.files.is_none() && self.include.is_none() {
+            self.files = parent.files;
+            self.include = parent.include;
+            self.include_dir = parent.include_dir;
+        }
+
         let mut seen: HashSet<_> = self.exclude.iter().cloned().collect();
         for ex in parent.exclude {
             if seen.insert(ex.clone()) {
@@ -0,0 +1,309 @@
+use super::TsConfig;
+use crate::Result;
+use glob::{MatchOptions, Pattern};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+
+/// Directories TypeScript leaves out when a tsconfig has no `exclude`.
+const DEFAULT_EXCLUDES: &[&str] = &["node_modules", "bower_components", "jspm_packages"];
+
+const MATCH_OPTIONS: MatchOptions = MatchOptions {
+    case_sensitive: true,
+    require_literal_separator: true,
+    require_literal_leading_dot: false,
+};
+
+/// A tsconfig file with its settings resolved against the directories they are relative to.
+#[derive(Debug, Clone)]
+pub struct TsProject {
+    /// Canonical path of the tsconfig file.
+    pub config_path: PathBuf,
+    /// Absolute `baseUrl`.
+    pub base_url: Option<PathBuf>,
+    /// `paths` patterns with absolute targets, longest prefix first.
+    pub paths: Vec<(String, Vec<String>)>,
+    /// Absolute `rootDirs`.
+    pub root_dirs: Vec<PathBuf>,
+    /// tsconfig files listed in `references`.
+    pub references: Vec<PathBuf>,
+    files: Vec<PathBuf>,
+    include: Vec<Pattern>,
+    exclude: Vec<Pattern>,
+}
+
+impl TsProject {
+    pub fn load(config_path: &Path) -> Result<Self> {
+        let config = TsConfig::load(config_path)?;
+        let config_path = config_path
+            .canonicalize()
+            .unwrap_or_else(|_| config_path.to_path_buf());
+        let dir = config_path.parent().unwrap_or_else(|| Path::new("."));
+        Ok(Self::from_config(config, &config_path, dir))
+    }
+
+    fn from_config(config: TsConfig, config_path: &Path, dir: &Path) -> Self {
+        let opts = config.compiler_options.unwrap_or_default();
+        let paths_dir = opts.paths_dir.as_deref().unwrap_or(dir);
+        let base_url = opts.base_url.as_ref().map(|url| paths_dir.join(url));
+        let paths_base = base_url.clone().unwrap_or_else(|| paths_dir.to_path_buf());
+
+        let mut paths: Vec<(String, Vec<String>)> = opts
+            .paths
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(pattern, targets)| {
+                let targets = targets
+                    .iter()
+                    .map(|target| paths_base.join(target).to_string_lossy().to_string())
+                    .collect();
+                (pattern, targets)
+            })
+            .collect();
+        paths.sort_by(|a, b| {
+            prefix(&b.0)
+                .len()
+                .cmp(&prefix(&a.0).len())
+                .then(a.0.cmp(&b.0))
+        });
+
+        let root_dirs_dir = opts.root_dirs_dir.as_deref().unwrap_or(dir);
+        let root_dirs = opts
+            .root_dirs
+            .unwrap_or_default()
+            .iter()
+            .map(|root| normalize(&root_dirs_dir.join(root)))
+            .collect();
+
+        let references = config
+            .references
+            .iter()
+            .map(|reference| {
+                let path = dir.join(&reference.path);
+                if path.is_dir() {
+                    path.join("tsconfig.json")
+                } else {
+                    path
+                }
+            })
+            .collect();
+
+        // Without `files` and `include`, a project contains everything below it
+        let include_dir = config.include_dir.as_deref().unwrap_or(dir);
+        let include = match (&config.files, config.include) {
+            (None, None) => vec!["**/*".to_string()],
+            (_, include) => include.unwrap_or_default(),
+        };
+        let mut excludes = config.exclude;
+        if excludes.is_empty() {
+            excludes = DEFAULT_EXCLUDES.iter().map(ToString::to_string).collect();
+        }
+        if let Some(out_dir) = &opts.out_dir {
+            excludes.push(out_dir.clone());
+        }
+
+        Self {
+            config_path: config_path.to_path_buf(),
+            base_url,
+            paths,
+            root_dirs,
+            references,
+            files: config
+                .files
+                .unwrap_or_default()
+                .iter()
+                .map(|file| normalize(&include_dir.join(file)))
+                .collect(),
+            include: patterns(include_dir, &include, "**/*"),
+            exclude: patterns(dir, &excludes, "**"),
+        }
+    }
+
+    /// Whether `file` is part of the project through `files` or `include` minus `exclude`.
+    #[must_use]
+    pub fn includes(&self, file: &Path) -> bool {
+        if self.files.iter().any(|f| f == file) {
+            return true;
+        }
+        self.include
+            .iter()
+            .any(|pattern| pattern.matches_path_with(file, MATCH_OPTIONS))
+            && !self
+                .exclude
+                .iter()
+                .any(|pattern| pattern.matches_path_with(file, MATCH_OPTIONS))
+    }
+
+    /// Targets of the most specific `paths` pattern matching `import_path`,
+    /// with the wildcard substituted.
+    #[must_use]
+    pub fn path_targets(&self, import_path: &str) -> Option<Vec<PathBuf>> {
+        self.path_match(import_path).map(|(_, targets)| targets)
+    }
+
+    /// Like [`Self::path_targets`], along with the pattern that matched.
+    #[must_use]
+    pub fn path_match(&self, import_path: &str) -> Option<(&str, Vec<PathBuf>)> {
+        self.paths.iter().find_map(|(pattern, targets)| {
+            let captured = match pattern.split_once('*') {
+                Some((prefix, suffix)) => import_path
+                    .strip_prefix(prefix)
+                    .and_then(|rest| rest.strip_suffix(suffix))?,
+                None if pattern == import_path => "",
+                None => return None,
+            };
+            Some((
+                pattern.as_str(),
+                targets
+                    .iter()
+                    .map(|target| PathBuf::from(target.replacen('*', captured, 1)))
+                    .collect(),
+            ))
+        })
+    }
+}
+
+/// Lazily loaded tsconfig files of a project, used to find the one governing each source file.
+///
+/// The governing tsconfig of a file is found in the closest directory above it
+/// that has a `tsconfig.json`: either that file or, for solution-style configs,
+/// one of the projects it references, whichever includes the file first.
+#[derive(Debug, Default)]
+pub struct TsProjects {
+    root: PathBuf,
+    root_config: Option<PathBuf>,
+    projects: RwLock<HashMap<PathBuf, Option<Arc<TsProject>>>>,
+    governing: RwLock<HashMap<PathBuf, Option<Arc<TsProject>>>>,
+}
+
+impl TsProjects {
+    /// `root_config` is the tsconfig of the project root, `tsconfig.json` unless configured otherwise.
+    #[must_use]
+    pub fn new(root: &Path, root_config: Option<PathBuf>) -> Self {
+        Self {
+            root: root.to_path_buf(),
+            root_config: root_config.map(|path| path.canonicalize().unwrap_or(path)),
+            ..Self::default()
+        }
+    }
+
+    /// Canonical path of the root tsconfig, whose settings are applied globally.
+    #[must_use]
+    pub fn root_config(&self) -> Option<&Path> {
+        self.root_config.as_deref()
+    }
+
+    /// The tsconfig governing `file`, if any.
+    pub fn governing(&self, file: &Path) -> Option<Arc<TsProject>> {
+        if let Some(project) = self.governing.read().ok()?.get(file) {
+            return project.clone();
+        }
+
+        let project = self.find_governing(file);
+        if let Ok(mut governing) = self.governing.write() {
+            governing.insert(file.to_path_buf(), project.clone());
+        }
+        project
+    }
+
+    fn find_governing(&self, file: &Path) -> Option<Arc<TsProject>> {
+        for dir in file.ancestors().skip(1) {
+            if !dir.starts_with(&self.root) {
+                break;
+            }
+            let config = if dir == self.root {
+                self.root_config.clone()
+            } else {
+                Some(dir.join("tsconfig.json"))
+            };
+            let Some(config) = config.filter(|c| c.is_file()) else {
+                continue;
+            };
+
+            let mut visited = HashSet::new();
+            if let Some(project) = self.find_including(&config, file, &mut visited) {
+                return Some(project);
+            }
+        }
+        None
+    }
+
+    /// `config` if it includes `file`, otherwise the first of its references that does.
+    fn find_including(
+        &self,
+        config: &Path,
+        file: &Path,
+        visited: &mut HashSet<PathBuf>,
+    ) -> Option<Arc<TsProject>> {
+        let project = self.project(config)?;
+        if !visited.insert(project.config_path.clone()) {
+            return None;
+        }
+        if project.includes(file) {
+            return Some(project);
+        }
+        project
+            .references
+            .iter()
+            .find_map(|reference| self.find_including(reference, file, visited))
+    }
+
+    fn project(&self, config: &Path) -> Option<Arc<TsProject>> {
+        if let Some(project) = self.projects.read().ok()?.get(config) {
+            return project.clone();
+        }
+
+        let project = match TsProject::load(config) {
+            Ok(project) => Some(Arc::new(project)),
+            Err(e) => {
+                log::warn!("Failed to load {}: {e}", config.display());
+                None
+            }
+        };
+        if let Ok(mut projects) = self.projects.write() {
+            projects.insert(config.to_path_buf(), project.clone());
+        }
+        project
+    }
+}
+
+/// Part of a `paths` pattern before the wildcard.
+fn prefix(pattern: &str) -> &str {
+    pattern
+        .split_once('*')
+        .map_or(pattern, |(prefix, _)| prefix)
+}
+
+/// Glob patterns relative to `dir`. Entries naming a directory match everything
+/// below it through `dir_suffix`, as in tsconfig `include` and `exclude`.
+fn patterns(dir: &Path, entries: &[String], dir_suffix: &str) -> Vec<Pattern> {
+    entries
+        .iter()
+        .filter_map(|entry| {
+            let entry = entry.replace('\\', "/");
+            let last = entry.rsplit('/').next().unwrap_or(&entry);
+            let entry = if last.contains('*') || last.contains('.') && last != "." && last != ".." {
+                entry
+            } else {
+                format!("{}/{dir_suffix}", entry.trim_end_matches('/'))
+            };
+            let path = normalize(&dir.join(entry));
+            Pattern::new(&path.to_string_lossy()).ok()
+        })
+        .collect()
+}
+
+/// Remove `.` and `..` components without touching the filesystem.
+pub(crate) fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            std::path::Component::CurDir => {}
+            std::path::Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
@@ -1,3 +1,4 @@
+use crate::resolver::package::PackageJson;
 use serde_json::Value;
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -47,33 +48,15 @@ impl TsConfigResolver {
     /// Attempts to resolve a tsconfig path through the "exports" field in package.json.
     #[must_use]
     pub fn resolve_via_exports(pkg_dir: &Path, subpath: &str) -> Option<PathBuf> {
-        let pkg_json_path = pkg_dir.join("package.json");
-        let content = fs::read_to_string(pkg_json_path).ok()?;
-        let pkg_json: Value = serde_json::from_str(&content).ok()?;
-
-        let exports = pkg_json.get("exports")?.as_object()?;
-        let sub_with_dot = format!("./{subpath}");
-
-        let target = exports.get(&sub_with_dot)?;
-        Self::resolve_conditional_export(target).map(|s| pkg_dir.join(s))
-    }
-
-    fn resolve_conditional_export(value: &Value) -> Option<String> {
-        match value {
-            Value::String(s) => Some(s.clone()),
-            Value::Object(obj) => {
-                // Priority keys for TypeScript resolution
-                let priority_keys = ["types", "import", "require", "default"];
-                for key in priority_keys {
-                    if let Some(inner) = obj.get(key) {
-                        if let Some(resolved) = Self::resolve_conditional_export(inner) {
-                            return Some(resolved);
-                        }
-                    }
-                }
-                None
-            }
-            _ => None,
-        }
+        let package = PackageJson::load(&pkg_dir.join("package.json"))?;
+        let conditions: Vec<String> = ["types", "import", "require"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+
+        package
+            .resolve_export(&format!("./{subpath}"), &conditions)
+            .ok()
+            .flatten()
     }
 }
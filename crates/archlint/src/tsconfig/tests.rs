@@ -250,3 +250,35 @@ fn test_extends_from_node_modules_package_json() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_extends_keeps_origin_of_inherited_settings() -> Result<()> {
+    let dir = tempdir()?;
+    let root = dir.path().canonicalize()?;
+    fs::create_dir_all(root.join("apps/web"))?;
+
+    fs::write(
+        root.join("tsconfig.base.json"),
+        r#"{
+            "compilerOptions": { "baseUrl": ".", "rootDirs": ["src", "gen"] },
+            "include": ["src"]
+        }"#,
+    )?;
+    let tsconfig_path = root.join("apps/web/tsconfig.json");
+    fs::write(
+        &tsconfig_path,
+        r#"{
+            "extends": "../../tsconfig.base.json",
+            "references": [{ "path": "../api" }]
+        }"#,
+    )?;
+
+    let config = TsConfig::load(&tsconfig_path)?;
+    assert_eq!(config.include_dir.as_deref(), Some(root.as_path()));
+    assert_eq!(config.references[0].path, "../api");
+    let opts = config.compiler_options.unwrap();
+    assert_eq!(opts.paths_dir.as_deref(), Some(root.as_path()));
+    assert_eq!(opts.root_dirs_dir.as_deref(), Some(root.as_path()));
+
+    Ok(())
+}
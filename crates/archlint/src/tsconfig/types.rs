@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::PathBuf;
 
 /// Represents a TypeScript configuration file (tsconfig.json).
 #[derive(Debug, Clone, Deserialize, Serialize, Default)]
@@ -12,6 +13,25 @@ pub struct TsConfig {
     pub exclude: Vec<String>,
     /// Path to a parent configuration file to extend.
     pub extends: Option<String>,
+    /// Files that belong to the project.
+    pub files: Option<Vec<String>>,
+    /// Patterns of files that belong to the project.
+    pub include: Option<Vec<String>>,
+    /// Projects this project depends on (`tsc --build`).
+    #[serde(default)]
+    pub references: Vec<ProjectReference>,
+    /// Directory that `files` and `include` are relative to, which is the
+    /// directory of the tsconfig declaring them when inherited through `extends`.
+    #[serde(skip)]
+    pub include_dir: Option<PathBuf>,
+}
+
+/// An entry of the `references` section.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectReference {
+    /// Directory containing a `tsconfig.json`, or the path of a tsconfig file.
+    pub path: String,
 }
 
 /// Represents the `compilerOptions` section of a tsconfig file.
@@ -26,6 +46,14 @@ pub struct CompilerOptions {
     pub out_dir: Option<String>,
     /// Root directory of source files.
     pub root_dir: Option<String>,
+    /// Directories whose contents are merged into one virtual directory.
+    pub root_dirs: Option<Vec<String>>,
+    /// Directory of the tsconfig declaring `baseUrl`, or `paths` when there is no `baseUrl`.
+    #[serde(skip)]
+    pub paths_dir: Option<PathBuf>,
+    /// Directory of the tsconfig declaring `rootDirs`.
+    #[serde(skip)]
+    pub root_dirs_dir: Option<PathBuf>,
 }
 
 impl CompilerOptions {
@@ -37,8 +65,15 @@ impl CompilerOptions {
             base_url,
             out_dir,
             root_dir,
+            root_dirs,
+            paths_dir,
+            root_dirs_dir,
         } = other;
 
+        // `paths` of the child are relative to its own `baseUrl` or directory
+        if self.paths_dir.is_none() || (self.base_url.is_none() && base_url.is_some()) {
+            self.paths_dir = paths_dir;
+        }
         if let Some(other_paths) = paths {
             let paths = self.paths.get_or_insert_with(HashMap::new);
             for (k, v) in other_paths {
@@ -54,5 +89,9 @@ impl CompilerOptions {
         if self.root_dir.is_none() {
             self.root_dir = root_dir;
         }
+        if self.root_dirs.is_none() {
+            self.root_dirs = root_dirs;
+            self.root_dirs_dir = root_dirs_dir;
+        }
     }
 }
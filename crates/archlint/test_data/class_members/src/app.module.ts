@@ -0,0 +1,5 @@
+import { OnModuleInit } from '@nestjs/common';
+
+export class AppModule implements OnModuleInit {
+  onModuleInit(): void {}
+}
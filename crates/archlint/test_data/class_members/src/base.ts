@@ -0,0 +1,13 @@
+export abstract class BaseHandler {
+  protected abstract handle(): void;
+
+  protected log(message: string): void {
+    console.log(message);
+  }
+
+  protected unusedProtected(): void {}
+
+  run(): void {
+    this.handle();
+  }
+}
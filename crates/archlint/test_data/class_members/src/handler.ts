@@ -0,0 +1,7 @@
+import { BaseHandler } from './base';
+
+export class EmailHandler extends BaseHandler {
+  protected handle(): void {
+    this.log('sent');
+  }
+}
@@ -0,0 +1,8 @@
+import { UserService } from './service';
+import { EmailHandler } from './handler';
+import { AppModule } from './app.module';
+
+export function main(service: UserService, module: AppModule): string[] {
+  new EmailHandler().run();
+  return service.findAll();
+}
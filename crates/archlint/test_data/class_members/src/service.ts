@@ -0,0 +1,39 @@
+interface Repo {
+  all(): string[];
+}
+
+interface Logger {
+  info(message: string): void;
+}
+
+export class UserService {
+  private readonly cache = new Map<string, string>();
+  private unusedField = 0;
+  #secret = 'secret';
+  #unusedSecret = 'unused';
+
+  constructor(
+    private readonly repo: Repo,
+    private unusedDep: Logger,
+  ) {}
+
+  public findAll(): string[] {
+    return this.repo.all().concat(this.#secret);
+  }
+
+  public neverCalled(): number {
+    return this.cache.size;
+  }
+
+  get size(): number {
+    return this.helper();
+  }
+
+  private helper(): number {
+    return 1;
+  }
+
+  private unusedHelper(): number {
+    return 2;
+  }
+}
@@ -0,0 +1,9 @@
+import { Component } from 'react';
+
+export class Widget extends Component {
+  protected renderContent() {
+    return null;
+  }
+
+  componentDidMount() {}
+}
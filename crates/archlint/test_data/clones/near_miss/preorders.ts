@@ -0,0 +1,15 @@
+import { Inventory, Order, Receipt } from './types';
+
+export function processPreorder(order: Order, inventory: Inventory): Receipt {
+    const items = order.items.filter((item) => item.quantity > 0);
+    const subtotal = items.reduce((sum, item) => sum + item.price * item.quantity, 0);
+    const tax = subtotal * 0.2;
+    const discount = order.coupon ? 5 : 0;
+    const shipping = subtotal > 100 ? 0 : 10;
+    for (const item of items) {
+        inventory.reserve(item.sku, item.quantity);
+    }
+    const total = subtotal + tax + shipping - discount;
+    console.log('Order processed', order.id, total);
+    return { orderId: order.id, subtotal, tax, shipping, total };
+}
@@ -0,0 +1,12 @@
+import { formatMoney } from '../money';
+
+export function summarizeInvoice(invoice, rates) {
+    const lines = invoice.lines.filter((line) => line.amount > 0);
+    const net = lines.reduce((acc, line) => acc + line.amount, 0);
+    const vat = net * rates.vat;
+    if (invoice.customer.exempt) {
+        return { id: invoice.id, total: formatMoney(net) };
+    }
+    const gross = net + vat;
+    return { id: invoice.id, total: formatMoney(gross) };
+}
@@ -0,0 +1,12 @@
+import { formatMoney } from '../money';
+
+export function summarizeQuote(quote, tariffs) {
+    const items = quote.lines.filter((entry) => entry.amount > 0);
+    const base = items.reduce((total, entry) => total + entry.amount, 0);
+    if (quote.customer.exempt) {
+        return { id: quote.id, total: formatMoney(base) };
+    }
+    const surcharge = base * tariffs.vat;
+    const final = base + surcharge;
+    return { id: quote.id, total: formatMoney(final) };
+}
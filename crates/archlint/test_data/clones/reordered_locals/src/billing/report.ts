@@ -0,0 +1,7 @@
+export function buildReport(orders, options) {
+    const paid = orders.filter((order) => order.settled);
+    const open = orders.filter((order) => !order.settled);
+    const heading = options.title.toUpperCase();
+    const overdue = open.filter((order) => order.due < options.today);
+    return { heading: heading, paid: paid.length, open: open.length, overdue: overdue.length };
+}
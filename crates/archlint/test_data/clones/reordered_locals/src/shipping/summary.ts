@@ -0,0 +1,7 @@
+export function buildSummary(shipments, settings) {
+    const title = settings.title.toUpperCase();
+    const pending = shipments.filter((shipment) => !shipment.settled);
+    const late = pending.filter((shipment) => shipment.due < settings.today);
+    const done = shipments.filter((shipment) => shipment.settled);
+    return { heading: title, paid: done.length, open: pending.length, overdue: late.length };
+}
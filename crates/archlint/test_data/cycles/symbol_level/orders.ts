@@ -0,0 +1,7 @@
+import { formatPrice } from './pricing';
+
+export const ORDER_CURRENCY = 'EUR';
+
+export function describeOrder(total: number) {
+  return `Order total: ${formatPrice(total)}`;
+}
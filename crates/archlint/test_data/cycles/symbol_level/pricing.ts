@@ -0,0 +1,5 @@
+import { ORDER_CURRENCY } from './orders';
+
+export function formatPrice(amount: number) {
+  return `${amount.toFixed(2)} ${ORDER_CURRENCY}`;
+}
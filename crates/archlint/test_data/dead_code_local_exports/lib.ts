@@ -0,0 +1,11 @@
+export function format(value: number) {
+  return `#${value}`;
+}
+
+export function render(value: number) {
+  return format(value);
+}
+
+export function unused() {
+  return 0;
+}
@@ -0,0 +1,3 @@
+import { render } from './lib';
+
+render(1);
@@ -0,0 +1,11 @@
+export function used() {
+  return 1;
+}
+
+export function dead() {
+  return helper() + 1;
+}
+
+export function helper() {
+  return 2;
+}
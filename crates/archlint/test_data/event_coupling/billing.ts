@@ -0,0 +1,12 @@
+import { bus } from './bus';
+
+export interface Invoice {
+  id: string;
+}
+
+bus.on('order.created', ({ id }: { id: string }) => {
+  bus.emit('invoice.paid', { id });
+});
+
+bus.once(`refund.requested`, () => undefined);
+bus.on('error', () => undefined);
@@ -0,0 +1,10 @@
+import { bus } from './bus';
+import { Invoice } from './billing';
+
+export function placeOrder(id: string): Invoice | undefined {
+  bus.emit('order.created', { id });
+  bus.emit('order.archived', { id });
+  return undefined;
+}
+
+bus.on('invoice.paid', (invoice: Invoice) => invoice);
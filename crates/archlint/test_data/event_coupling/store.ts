@@ -0,0 +1,3 @@
+export function checkout(store: { dispatch(action: { type: string }): void }) {
+  store.dispatch({ type: 'cart/checkout' });
+}
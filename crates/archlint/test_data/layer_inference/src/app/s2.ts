@@ -0,0 +1,3 @@
+import { user } from '../domain/user';
+import { rule } from '../domain/rule';
+export const s2 = () => user() && rule;
@@ -0,0 +1 @@
+export const rule = 1;
@@ -0,0 +1,2 @@
+import { save } from '../infra/db';
+export const user = () => save;
@@ -0,0 +1,3 @@
+import { s1 } from '../app/s1';
+import { user } from '../domain/user';
+export const save1 = () => s1 && user;
@@ -0,0 +1,3 @@
+import { s2 } from '../app/s2';
+import { save1 } from '../infra/db1';
+export const v = s2 && save1;
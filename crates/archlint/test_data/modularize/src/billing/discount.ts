@@ -0,0 +1,11 @@
+import { Order, OrderItem } from '../orders/order';
+
+export type DiscountCode = string;
+
+export function applyDiscount(total: number, code?: DiscountCode): number {
+  return code ? total * 0.9 : total;
+}
+
+export function discountedItems(order: Order): OrderItem[] {
+  return order.items;
+}
@@ -0,0 +1,13 @@
+import { taxFor, TaxRate } from './tax';
+import { Payment, charge } from './payment';
+
+export interface Invoice {
+  orderId: string;
+  amount: number;
+  payment?: Payment;
+}
+
+export function createInvoice(orderId: string, amount: number, rate?: TaxRate): Invoice {
+  const total = amount + taxFor(amount, rate);
+  return { orderId, amount: total, payment: charge(total) };
+}
@@ -0,0 +1,10 @@
+import { taxFor, TaxRate } from './tax';
+
+export interface Payment {
+  amount: number;
+  tax: number;
+}
+
+export function charge(amount: number, rate?: TaxRate): Payment {
+  return { amount, tax: taxFor(amount, rate) };
+}
@@ -0,0 +1,5 @@
+export type TaxRate = number;
+
+export function taxFor(amount: number, rate: TaxRate = 0.2): number {
+  return amount * rate;
+}
@@ -0,0 +1,7 @@
+import { Order, OrderItem, orderTotal } from './order';
+import { applyDiscount, DiscountCode } from '../billing/discount';
+
+export function cartTotal(order: Order, code?: DiscountCode): number {
+  const items: OrderItem[] = order.items;
+  return applyDiscount(orderTotal({ ...order, items }), code);
+}
@@ -0,0 +1,9 @@
+import { Order, orderTotal } from './order';
+import { cartTotal } from './cart';
+import { applyDiscount, DiscountCode } from '../billing/discount';
+import { createInvoice } from '../billing/invoice';
+
+export function checkout(order: Order, code?: DiscountCode) {
+  const total = applyDiscount(cartTotal(order), code);
+  return createInvoice(order.id, total || orderTotal(order));
+}
@@ -0,0 +1,13 @@
+export interface Order {
+  id: string;
+  items: OrderItem[];
+}
+
+export interface OrderItem {
+  sku: string;
+  price: number;
+}
+
+export function orderTotal(order: Order): number {
+  return order.items.reduce((sum, item) => sum + item.price, 0);
+}
@@ -0,0 +1,9 @@
+import { Module } from '@nestjs/common';
+import { ConfigModule } from './config/config.module';
+import { OrdersModule } from './orders/orders.module';
+import { UsersModule } from './users/users.module';
+
+@Module({
+  imports: [ConfigModule, OrdersModule, UsersModule],
+})
+export class AppModule {}
@@ -0,0 +1,8 @@
+import { Injectable } from '@nestjs/common';
+
+@Injectable()
+export class BillingService {
+  invoice(user: string) {
+    return { user, total: 0 };
+  }
+}
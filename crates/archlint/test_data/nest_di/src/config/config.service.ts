@@ -0,0 +1,8 @@
+import { Injectable } from '@nestjs/common';
+
+@Injectable()
+export class ConfigService {
+  get(key: string): string | undefined {
+    return process.env[key];
+  }
+}
@@ -0,0 +1,16 @@
+import { Controller, Get } from '@nestjs/common';
+import { ConfigService } from '../config/config.service';
+import { OrdersService } from './orders.service';
+
+@Controller('orders')
+export class OrdersController {
+  constructor(
+    private readonly orders: OrdersService,
+    private readonly config: ConfigService,
+  ) {}
+
+  @Get()
+  list() {
+    return this.orders.list(this.config.get('ORDERS_LIMIT'));
+  }
+}
@@ -0,0 +1,15 @@
+import { Injectable } from '@nestjs/common';
+import { BillingService } from '../billing/billing.service';
+import { UsersService } from '../users/users.service';
+
+@Injectable()
+export class OrdersService {
+  constructor(
+    private readonly users: UsersService,
+    private readonly billing: BillingService,
+  ) {}
+
+  list(limit?: string) {
+    return this.users.active(limit).map((user) => this.billing.invoice(user));
+  }
+}
@@ -0,0 +1,11 @@
+import { Module, forwardRef } from '@nestjs/common';
+import { OrdersModule } from '../orders/orders.module';
+import { UsersRepository } from './users.repository';
+import { UsersService } from './users.service';
+
+@Module({
+  imports: [forwardRef(() => OrdersModule)],
+  providers: [UsersService, UsersRepository],
+  exports: [UsersService],
+})
+export class UsersModule {}
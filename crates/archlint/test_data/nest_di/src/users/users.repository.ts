@@ -0,0 +1,8 @@
+import { Injectable } from '@nestjs/common';
+
+@Injectable()
+export class UsersRepository {
+  findActive(limit: number): string[] {
+    return [].slice(0, limit);
+  }
+}
@@ -0,0 +1,11 @@
+import { Injectable } from '@nestjs/common';
+import { UsersRepository } from './users.repository';
+
+@Injectable()
+export class UsersService {
+  constructor(private readonly repository: UsersRepository) {}
+
+  active(limit?: string) {
+    return this.repository.findActive(Number(limit ?? 10));
+  }
+}
@@ -0,0 +1,7 @@
+'use server';
+
+import { db } from '../lib/db';
+
+export async function archiveOrder(id: string) {
+  await db.query('update orders set archived = true where id = $1', [id]);
+}
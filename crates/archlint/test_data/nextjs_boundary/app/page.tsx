@@ -0,0 +1,7 @@
+import { db } from '../lib/db';
+import { Dashboard } from '../components/Dashboard';
+
+export default async function Page() {
+  const orders = await db.query('select * from orders');
+  return <Dashboard orders={orders.rows} />;
+}
@@ -0,0 +1,7 @@
+'use client';
+
+import { formatPrice } from '../lib/format';
+
+export function Chart({ values }: { values: number[] }) {
+  return <ul>{values.map((v) => <li key={v}>{formatPrice(v)}</li>)}</ul>;
+}
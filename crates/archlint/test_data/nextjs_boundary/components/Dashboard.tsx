@@ -0,0 +1,18 @@
+'use client';
+
+import type { Session } from '../lib/session';
+import { archiveOrder } from '../app/actions';
+import { formatPrice } from '../lib/format';
+import { summarize } from '../lib/stats';
+import { Chart } from './Chart';
+
+export function Dashboard({ orders, session }: { orders: any[]; session?: Session }) {
+  const summary = summarize(orders);
+  return (
+    <div title={session?.user}>
+      <Chart values={orders.map((o) => o.total)} />
+      <span>{formatPrice(summary.total)}</span>
+      <button onClick={() => archiveOrder(orders[0].id)}>Archive</button>
+    </div>
+  );
+}
@@ -0,0 +1,7 @@
+'use client';
+
+import { readFile } from 'node:fs/promises';
+
+export function Upload({ path }: { path: string }) {
+  return <button onClick={() => readFile(path)}>Upload</button>;
+}
@@ -0,0 +1,10 @@
+import 'server-only';
+import { cookies } from 'next/headers';
+
+export interface Session {
+  user: string;
+}
+
+export async function getSession(): Promise<Session> {
+  return { user: (await cookies()).get('user')?.value ?? '' };
+}
@@ -0,0 +1,6 @@
+import React from 'react';
+import { debounce } from 'lodash';
+import { readFileSync } from 'fs';
+import { log } from './logger';
+
+export const App = () => React.createElement('div', null, debounce(log, 10), readFileSync);
@@ -0,0 +1,8 @@
+import chalk from 'chalk';
+import type { Simplify } from 'type-fest';
+
+export type LogLine = Simplify<{ message: string }>;
+
+export function log(line: LogLine): void {
+  console.log(chalk.green(line.message));
+}
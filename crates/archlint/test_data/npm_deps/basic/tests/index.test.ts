@@ -0,0 +1,5 @@
+import { setupServer } from 'msw/node';
+import { App } from '../src/index';
+
+export const server = setupServer();
+export const app = App;
@@ -0,0 +1,3 @@
+import { Button } from '@acme/ui/button';
+
+export const app = Button;
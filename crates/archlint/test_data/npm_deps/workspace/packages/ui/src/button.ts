@@ -0,0 +1,7 @@
+import React from 'react';
+import clsx from 'clsx';
+import { z } from 'zod';
+import { theme } from '@acme/ui/theme';
+
+export const schema = z.object({});
+export const Button = () => React.createElement('button', { className: clsx(theme) });
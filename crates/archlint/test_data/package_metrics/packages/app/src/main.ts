@@ -0,0 +1,4 @@
+import { createId } from '../../core/src/base';
+import { UserService } from './service';
+
+console.log(new UserService().name(), createId());
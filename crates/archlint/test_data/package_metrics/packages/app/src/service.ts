@@ -0,0 +1,10 @@
+import { BaseService } from '../../core/src/base';
+import { Db } from '../../infra/src/db';
+
+export class UserService extends BaseService {
+  private db = new Db();
+
+  name(): string {
+    return this.db.table;
+  }
+}
@@ -0,0 +1,7 @@
+export abstract class BaseService {
+  abstract name(): string;
+}
+
+export function createId(): string {
+  return Math.random().toString(36);
+}
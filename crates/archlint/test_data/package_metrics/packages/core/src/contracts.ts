@@ -0,0 +1,5 @@
+export interface Repository<T> {
+  find(id: string): T | undefined;
+}
+
+export type Id = string;
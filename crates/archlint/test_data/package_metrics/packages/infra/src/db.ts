@@ -0,0 +1,3 @@
+export class Db {
+  table = 'users';
+}
@@ -0,0 +1,47 @@
+export function buildReport(rows: number[]): string {
+  // Collect totals first
+
+  let total = 0;
+  let max = 0;
+  for (const row of rows) {
+    total += row;
+    /*
+     * Track the largest row
+     */
+    max = Math.max(max, row);
+  }
+
+  const avg = rows.length ? total / rows.length : 0;
+  return `${total}/${max}/${avg}`;
+}
+
+export const short = (x: number) => x * 2;
+
+export class ReportService {
+  private rows: number[] = [];
+  private title = 'Report';
+  private footer = '';
+
+  constructor(private readonly name: string) {}
+
+  add(row: number) {
+    this.rows.push(row);
+  }
+
+  clear() {
+    this.rows = [];
+  }
+
+  render() {
+    return buildReport(this.rows);
+  }
+
+  describe() {
+    return `${this.title} ${this.name} ${this.footer}`;
+  }
+}
+
+export class Point {
+  x = 0;
+  y = 0;
+}
@@ -0,0 +1,11 @@
+// archlint-disable-next-line long_function -- generated code, regenerated on build
+export function generated() {
+  return 1;
+}
+
+// archlint-disable-next-line cyclomatic_complexity
+export function plain() {
+  return 2;
+}
+
+export const legacy = 3; // archlint-disable-line layer_violation -- layers are not configured yet
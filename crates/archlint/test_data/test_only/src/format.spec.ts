@@ -0,0 +1,8 @@
+import { format, debugFormat } from './format';
+
+describe('format', () => {
+  it('wraps values', () => {
+    expect(format('a')).toBe('[a]');
+    expect(debugFormat('a')).toContain('a');
+  });
+});
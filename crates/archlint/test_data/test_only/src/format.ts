@@ -0,0 +1,7 @@
+export function format(value: string): string {
+  return `[${value}]`;
+}
+
+export function debugFormat(value: string): string {
+  return JSON.stringify({ value });
+}
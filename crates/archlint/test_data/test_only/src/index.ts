@@ -0,0 +1,5 @@
+import { format } from './format';
+
+export function run(): string {
+  return format('report');
+}
@@ -0,0 +1,3 @@
+export function pad(value: string): string {
+  return value.padStart(10);
+}
@@ -0,0 +1,7 @@
+import { legacyFormat } from './legacy';
+
+describe('legacyFormat', () => {
+  it('pads values', () => {
+    expect(legacyFormat('a')).toHaveLength(10);
+  });
+});
@@ -0,0 +1,5 @@
+import { pad } from './legacy-helper';
+
+export function legacyFormat(value: string): string {
+  return pad(value);
+}
@@ -0,0 +1,7 @@
+import { format } from './utils';
+import { missing } from './missing';
+import Widget from './widget';
+import { ghost } from '@/ghost';
+import { pick, omit } from 'lodash';
+
+export const run = () => format(missing, ghost, Widget, pick, omit);
@@ -0,0 +1 @@
+export const format = (...values: unknown[]) => values.join(' ');
@@ -0,0 +1,147 @@
+use archlint::commands::affected::{collect_affected, AffectedFormat, AffectedOptions};
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+
+fn write(root: &Path, file: &str, content: &str) {
+    let path = root.join(file);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, content).unwrap();
+}
+
+/// `index.ts` -> `a.ts` -> `b.ts`, with `a.test.ts` testing `a.ts` and an unrelated `c.ts`.
+fn project() -> TempDir {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path();
+    write(
+        root,
+        "package.json",
+        r#"{"name": "app", "main": "src/index.ts"}"#,
+    );
+    write(
+        root,
+        "src/index.ts",
+        "import { a } from './a';\nconsole.log(a);\n",
+    );
+    write(
+        root,
+        "src/a.ts",
+        "import { b } from './b';\nexport const a = b;\n",
+    );
+    write(root, "src/b.ts", "export const b = 1;\n");
+    write(root, "src/c.ts", "export const c = 2;\n");
+    write(
+        root,
+        "src/a.test.ts",
+        "import { a } from './a';\ntest('a', () => a);\n",
+    );
+    dir
+}
+
+fn commit_all(root: &Path) {
+    let repo = git2::Repository::init(root).unwrap();
+    let mut index = repo.index().unwrap();
+    index
+        .add_all(["*"], git2::IndexAddOption::DEFAULT, None)
+        .unwrap();
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let signature = git2::Signature::now("test", "test@example.com").unwrap();
+    repo.commit(Some("HEAD"), &signature, &signature, "init", &tree, &[])
+        .unwrap();
+}
+
+fn options(root: &Path) -> AffectedOptions {
+    AffectedOptions {
+        path: root.to_path_buf(),
+        config: None,
+        since: None,
+        files: Vec::new(),
+        tests_only: false,
+        format: AffectedFormat::Json,
+        output: None,
+    }
+}
+
+#[test]
+fn test_affected_by_explicit_files() {
+    let dir = project();
+    let report = collect_affected(&AffectedOptions {
+        files: vec![dir.path().join("src/b.ts")],
+        ..options(dir.path())
+    })
+    .unwrap();
+
+    assert_eq!(report.changed, vec!["src/b.ts"]);
+    assert_eq!(report.sources, vec!["src/a.ts", "src/b.ts", "src/index.ts"]);
+    assert_eq!(report.entry_points, vec!["src/index.ts"]);
+    assert_eq!(report.tests, vec!["src/a.test.ts"]);
+}
+
+#[test]
+fn test_unrelated_change_affects_no_tests() {
+    let dir = project();
+    let report = collect_affected(&AffectedOptions {
+        files: vec![dir.path().join("src/c.ts")],
+        ..options(dir.path())
+    })
+    .unwrap();
+
+    assert_eq!(report.sources, vec!["src/c.ts"]);
+    assert!(report.entry_points.is_empty());
+    assert!(report.tests.is_empty());
+}
+
+#[test]
+fn test_affected_since_git_ref() {
+    let dir = project();
+    commit_all(dir.path());
+    write(dir.path(), "src/b.ts", "export const b = 3;\n");
+    write(dir.path(), "README.md", "changed\n");
+
+    let report = collect_affected(&AffectedOptions {
+        since: Some("HEAD".to_string()),
+        ..options(dir.path())
+    })
+    .unwrap();
+
+    assert_eq!(report.changed, vec!["src/b.ts"]);
+    assert_eq!(report.tests, vec!["src/a.test.ts"]);
+}
+
+#[test]
+fn test_untracked_files_count_as_changed() {
+    let dir = project();
+    commit_all(dir.path());
+    write(
+        dir.path(),
+        "src/d.ts",
+        "import { c } from './c';\nexport const d = c;\n",
+    );
+
+    let report = collect_affected(&AffectedOptions {
+        since: Some("HEAD".to_string()),
+        ..options(dir.path())
+    })
+    .unwrap();
+
+    assert_eq!(report.changed, vec!["src/d.ts"]);
+    assert_eq!(report.sources, vec!["src/d.ts"]);
+}
+
+#[test]
+fn test_deleted_files_affect_their_importers() {
+    let dir = project();
+    commit_all(dir.path());
+    fs::remove_file(dir.path().join("src/b.ts")).unwrap();
+
+    let report = collect_affected(&AffectedOptions {
+        since: Some("HEAD".to_string()),
+        ..options(dir.path())
+    })
+    .unwrap();
+
+    assert_eq!(report.changed, vec!["src/b.ts"]);
+    assert_eq!(report.sources, vec!["src/a.ts", "src/index.ts"]);
+    assert_eq!(report.tests, vec!["src/a.test.ts"]);
+}
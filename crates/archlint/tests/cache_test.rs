@@ -0,0 +1,108 @@
+use archlint::cache::hash::content_hash;
+use archlint::cache::AnalysisCache;
+use archlint::config::Config;
+use archlint::parser::{ImportParser, ParserConfig};
+use std::path::Path;
+use tempfile::TempDir;
+
+const CODE: &str = "import { a } from './a';\nexport const b = a + 1;\n";
+
+fn cache_with_entry(root: &Path, file: &Path, config: &Config) {
+    let parsed = ImportParser::new().unwrap().parse_code(CODE, file).unwrap();
+    let mut cache = AnalysisCache::load(root, config, &ParserConfig::all()).unwrap();
+    cache.insert(file, &content_hash(CODE), parsed);
+    cache.save().unwrap();
+}
+
+#[test]
+fn test_parse_results_survive_config_changes() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("b.ts");
+    cache_with_entry(dir.path(), &file, &Config::default());
+
+    let mut config = Config::default();
+    config.entry_points.push("src/main.ts".to_string());
+    let cache = AnalysisCache::load(dir.path(), &config, &ParserConfig::all()).unwrap();
+
+    let cached = cache.get(&file, &content_hash(CODE)).unwrap();
+    assert_eq!(cached.symbols.imports[0].source.as_str(), "./a");
+}
+
+#[test]
+fn test_parse_results_keyed_by_parser_config_and_content() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("b.ts");
+    cache_with_entry(dir.path(), &file, &Config::default());
+
+    let cache =
+        AnalysisCache::load(dir.path(), &Config::default(), &ParserConfig::minimal()).unwrap();
+    assert!(cache.get(&file, &content_hash(CODE)).is_none());
+    drop(cache);
+
+    let cache = AnalysisCache::load(dir.path(), &Config::default(), &ParserConfig::all()).unwrap();
+    assert!(cache
+        .get(&file, &content_hash("export const b = 2;\n"))
+        .is_none());
+    // Same content under another path reuses the entry
+    assert!(cache
+        .get(&dir.path().join("c.ts"), &content_hash(CODE))
+        .is_some());
+    // The extension decides how the content is parsed
+    assert!(cache
+        .get(&dir.path().join("b.vue"), &content_hash(CODE))
+        .is_none());
+}
+
+#[test]
+fn test_exported_cache_imports_into_another_checkout() {
+    let source = TempDir::new().unwrap();
+    cache_with_entry(
+        source.path(),
+        &source.path().join("src/b.ts"),
+        &Config::default(),
+    );
+    let archive = source.path().join("archlint-cache.bin");
+    assert_eq!(AnalysisCache::export(source.path(), &archive).unwrap(), 1);
+
+    let target = TempDir::new().unwrap();
+    assert_eq!(AnalysisCache::import(target.path(), &archive).unwrap(), 1);
+    let cache =
+        AnalysisCache::load(target.path(), &Config::default(), &ParserConfig::all()).unwrap();
+    assert!(cache
+        .get(&target.path().join("src/b.ts"), &content_hash(CODE))
+        .is_some());
+}
+
+#[test]
+fn test_stats_report_entries_and_last_run_hits() {
+    let dir = TempDir::new().unwrap();
+    assert!(AnalysisCache::stats(dir.path()).unwrap().is_none());
+
+    let file = dir.path().join("b.ts");
+    cache_with_entry(dir.path(), &file, &Config::default());
+    let cache = AnalysisCache::load(dir.path(), &Config::default(), &ParserConfig::all()).unwrap();
+    assert!(cache.get(&file, &content_hash(CODE)).is_some());
+    assert!(cache.get(&file, &content_hash("export {};\n")).is_none());
+    cache.save().unwrap();
+
+    let stats = AnalysisCache::stats(dir.path()).unwrap().unwrap();
+    assert_eq!((stats.entries, stats.files), (1, 1));
+    assert!(stats.size > 0);
+    assert!(!stats.is_outdated);
+    let run = stats.last_run.unwrap();
+    assert_eq!((run.hits, run.misses), (1, 1));
+    assert_eq!(run.hit_ratio(), Some(0.5));
+}
+
+#[test]
+fn test_loaded_cache_does_not_lock_the_store() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("b.ts");
+    let cache = AnalysisCache::load(dir.path(), &Config::default(), &ParserConfig::all()).unwrap();
+
+    // Another process saves while this one is still analyzing
+    cache_with_entry(dir.path(), &file, &Config::default());
+    assert!(cache.get(&file, &content_hash(CODE)).is_some());
+    assert!(AnalysisCache::stats(dir.path()).unwrap().is_some());
+    cache.save().unwrap();
+}
@@ -0,0 +1,104 @@
+use assert_cmd::cargo_bin;
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+use std::path::Path;
+use tempfile::{tempdir, TempDir};
+
+const GATE_FAILED: i32 = 3;
+
+fn setup_project(gates: &str) -> TempDir {
+    let dir = tempdir().unwrap();
+    fs::create_dir(dir.path().join("src")).unwrap();
+    fs::write(dir.path().join("src/a.ts"), "export const a = 1;").unwrap();
+    fs::write(dir.path().join("src/b.ts"), "export const b = 2;").unwrap();
+    fs::write(dir.path().join(".archlint.yaml"), gates).unwrap();
+    dir
+}
+
+fn add_cycle(root: &Path) {
+    fs::write(
+        root.join("src/a.ts"),
+        "import { b } from './b'; export const a = b;",
+    )
+    .unwrap();
+    fs::write(
+        root.join("src/b.ts"),
+        "import { a } from './a'; export const b = a;",
+    )
+    .unwrap();
+}
+
+fn scan(root: &Path) -> assert_cmd::assert::Assert {
+    Command::new(cargo_bin!("archlint"))
+        .arg("scan")
+        .arg(root)
+        .arg("--no-cache")
+        .assert()
+}
+
+#[test]
+fn test_scan_passes_gates() {
+    let dir = setup_project("gates:\n  - max_smells: { rule: cycles, max: 0 }\n");
+
+    scan(dir.path())
+        .success()
+        .stdout(predicate::str::contains("Quality gates: passed"));
+}
+
+#[test]
+fn test_scan_gate_failure_exits_3() {
+    let dir = setup_project(
+        "gates:\n  - name: no cycles\n    max_smells: { rule: cycles, max: 0 }\n  - no_new_smells: {}\n",
+    );
+    add_cycle(dir.path());
+
+    scan(dir.path())
+        .code(GATE_FAILED)
+        .stdout(predicate::str::contains("no cycles"))
+        .stdout(predicate::str::contains("requires a baseline"));
+}
+
+#[test]
+fn test_scan_json_contains_gates() {
+    let dir = setup_project("gates:\n  - max_debt: 1m\n");
+    add_cycle(dir.path());
+
+    let output = Command::new(cargo_bin!("archlint"))
+        .arg("scan")
+        .arg(dir.path())
+        .arg("--no-cache")
+        .arg("--json")
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(GATE_FAILED));
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["gates"]["passed"], false);
+    assert_eq!(json["gates"]["results"][0]["status"], "failed");
+}
+
+#[test]
+fn test_diff_no_new_smells_gate() {
+    let dir = setup_project("gates:\n  - no_new_smells: { rule: cycles }\n");
+    let baseline = dir.path().join("baseline.json");
+    Command::new(cargo_bin!("archlint"))
+        .arg("snapshot")
+        .arg("-o")
+        .arg(&baseline)
+        .arg("-p")
+        .arg(dir.path())
+        .assert()
+        .success();
+
+    add_cycle(dir.path());
+
+    Command::new(cargo_bin!("archlint"))
+        .arg("diff")
+        .arg(&baseline)
+        .arg("-p")
+        .arg(dir.path())
+        .assert()
+        .code(GATE_FAILED)
+        .stdout(predicate::str::contains("1 new smell"));
+}
@@ -1,8 +1,13 @@
 mod common;
 
+use archlint::detectors::code_clone::fingerprint::fingerprint_file;
 use archlint::detectors::code_clone::CodeCloneDetector;
-use archlint::detectors::Detector;
+use archlint::detectors::{ArchSmell, Detector, SmellType};
+use archlint::parser::tokenizer::tokenize_and_normalize;
 use common::analyze_fixture_with_rule;
+use oxc_span::SourceType;
+use std::path::Path;
+use std::sync::Arc;
 
 #[test]
 fn test_code_clones_detected_exact() {
@@ -47,3 +52,166 @@ fn test_code_clones_in_exported_class() {
         "Expected to detect code clones in exported class, got {smells:?}"
     );
 }
+
+fn clone_smells(fixture: &str, options: &str) -> Vec<ArchSmell> {
+    let ctx = analyze_fixture_with_rule(fixture, "code_clone", Some(options));
+    CodeCloneDetector.detect(&ctx)
+}
+
+fn cloned_functions(smell: &ArchSmell) -> Vec<String> {
+    match &smell.smell_type {
+        SmellType::CodeClone { functions, .. } => functions.clone(),
+        _ => Vec::new(),
+    }
+}
+
+#[test]
+fn test_identical_functions_grouped() {
+    let smells = clone_smells("clones/exact", "min_tokens: 20\nmin_lines: 3");
+
+    assert_eq!(smells.len(), 1);
+    assert_eq!(
+        cloned_functions(&smells[0]),
+        vec!["calculateTotal", "calculateTotal"]
+    );
+    assert_eq!(smells[0].similarity(), Some(100));
+    assert_eq!(
+        smells[0].locations[0].description,
+        "Function 'calculateTotal' (lines 1-11) is 100% similar to: file2.ts:calculateTotal. Suggested extraction target: ."
+    );
+    assert_eq!(
+        CodeCloneDetector.explain(&smells[0]).problem,
+        "2 functions are identical"
+    );
+}
+
+#[test]
+fn test_exact_mode_stops_at_edits() {
+    let smells = clone_smells("clones/near_miss", "min_tokens: 20\nmin_lines: 3");
+
+    // Only the block after the inserted statement is an exact clone, and it
+    // covers too little of the functions to group them
+    assert_eq!(smells.len(), 1);
+    assert!(cloned_functions(&smells[0]).is_empty());
+    assert_eq!(smells[0].token_count(), Some(23));
+    assert_eq!(smells[0].similarity(), Some(100));
+}
+
+#[test]
+fn test_near_miss_clone_spans_edit_gaps() {
+    let smells = clone_smells(
+        "clones/near_miss",
+        "min_tokens: 20\nmin_lines: 3\nmax_gap: 10",
+    );
+
+    assert_eq!(smells.len(), 1);
+    let smell = &smells[0];
+    assert_eq!(
+        cloned_functions(smell),
+        vec!["processOrder", "processPreorder"]
+    );
+    assert_eq!(smell.token_count(), Some(100));
+    assert_eq!(smell.similarity(), Some(93));
+    assert_eq!(
+        smell.locations[1].description,
+        "Function 'processPreorder' (lines 3-15) is 93% similar to: orders.ts:processOrder. Suggested extraction target: ."
+    );
+
+    let explanation = CodeCloneDetector.explain(smell);
+    assert_eq!(explanation.problem, "2 functions are 93% similar");
+    assert!(explanation.recommendations[0].contains("`processOrder`, `processPreorder`"));
+}
+
+#[test]
+fn test_near_miss_respects_min_similarity() {
+    let smells = clone_smells(
+        "clones/near_miss",
+        "min_tokens: 20\nmin_lines: 3\nmax_gap: 10\nmin_similarity: 95",
+    );
+
+    // Gaps that would drop below the threshold are left out of the clone
+    assert!(!smells.is_empty());
+    assert!(smells.iter().all(|s| s.similarity().unwrap_or(0) >= 95));
+}
+
+#[test]
+fn test_ast_fingerprints_match_renamed_and_reordered_functions() {
+    let options = "min_tokens: 20\nmin_lines: 3";
+
+    // Renamed locals and a moved statement break every token window
+    assert!(clone_smells("clones/renamed", options).is_empty());
+
+    let smells = clone_smells(
+        "clones/renamed",
+        &format!("{options}\nast_fingerprints: true"),
+    );
+    assert_eq!(smells.len(), 1);
+    let smell = &smells[0];
+    assert_eq!(
+        cloned_functions(smell),
+        vec!["summarizeInvoice", "summarizeQuote"]
+    );
+    assert_eq!(smell.similarity(), Some(100));
+    assert_eq!(
+        smell.locations[0].description,
+        "Function 'summarizeInvoice' (lines 3-12) has the same structure as: src/shipping/quote.ts:summarizeQuote. Suggested extraction target: src/"
+    );
+
+    let explanation = CodeCloneDetector.explain(smell);
+    assert!(explanation.recommendations[0].contains("in `src/`"));
+}
+
+#[test]
+fn test_ast_fingerprints_number_locals_after_reordering() {
+    let options = "min_tokens: 20\nmin_lines: 3";
+    assert!(clone_smells("clones/reordered_locals", options).is_empty());
+
+    // The locals are declared in a different order in each copy
+    let smells = clone_smells(
+        "clones/reordered_locals",
+        &format!("{options}\nast_fingerprints: true"),
+    );
+    assert_eq!(smells.len(), 1);
+    assert_eq!(
+        cloned_functions(&smells[0]),
+        vec!["buildReport", "buildSummary"]
+    );
+}
+
+#[test]
+fn test_ast_fingerprints_skip_functions_already_reported() {
+    let smells = clone_smells(
+        "clones/exact",
+        "min_tokens: 20\nmin_lines: 3\nast_fingerprints: true",
+    );
+
+    assert_eq!(smells.len(), 1);
+    // The token clone already groups the functions
+    assert!(smells[0].locations[0]
+        .description
+        .contains("is 100% similar to: file2.ts:calculateTotal"));
+}
+
+fn fingerprint_hashes(source: &str) -> Vec<u64> {
+    let tokens = tokenize_and_normalize(Arc::from(source), SourceType::ts());
+    fingerprint_file(Path::new("src/math.ts"), source, &tokens, &[], 5, 1)
+        .into_iter()
+        .map(|f| f.hash)
+        .collect()
+}
+
+#[test]
+fn test_ast_fingerprints_number_locals_per_function() {
+    let renamed = fingerprint_hashes(
+        "function f(a, b) {\n  return a - b * 2;\n}\nfunction g(x, y) {\n  return x - y * 2;\n}\n",
+    );
+    assert_eq!(renamed.len(), 2);
+    assert_eq!(renamed[0], renamed[1]);
+
+    // Same names, but each one plays the other's role
+    let swapped = fingerprint_hashes(
+        "function f(a, b) {\n  return a - b * 2;\n}\nfunction g(a, b) {\n  return b - a * 2;\n}\n",
+    );
+    assert_eq!(swapped.len(), 2);
+    assert_ne!(swapped[0], swapped[1]);
+}
@@ -3,7 +3,7 @@ use archlint::engine::{context::FileMetrics, AnalysisContext};
 use archlint::graph::DependencyGraph;
 use archlint::package_json::PackageJsonParser;
 use archlint::parser::{FileIgnoredLines, ImportParser};
-use archlint::resolver::PathResolver;
+use archlint::resolver::{ImportDiagnostics, PathResolver};
 use archlint::scanner::FileScanner;
 use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
@@ -94,6 +94,7 @@ pub fn analyze_fixture_with_config(name: &str, config: Config) -> AnalysisContex
         function_complexity: Arc::new(function_complexity),
         file_metrics: Arc::new(file_metrics),
         ignored_lines: Arc::new(ignored_lines),
+        import_diagnostics: Arc::new(ImportDiagnostics::default()),
         churn_map: HashMap::new(),
         config,
         script_entry_points,
@@ -103,6 +104,21 @@ pub fn analyze_fixture_with_config(name: &str, config: Config) -> AnalysisContex
     }
 }
 
+/// Rewrite import sources to resolved file paths and collect the unresolved
+/// ones, as the analysis engine does.
+#[allow(dead_code)]
+pub fn resolve_import_sources(ctx: &mut AnalysisContext) {
+    let resolver = PathResolver::new(&ctx.project_path, &ctx.config);
+    for (path, symbols) in ctx.file_symbols_mut().iter_mut() {
+        for import in &mut symbols.imports {
+            if let Ok(Some(resolved)) = resolver.resolve(&import.source, path) {
+                import.source = resolved.to_string_lossy().into();
+            }
+        }
+    }
+    ctx.import_diagnostics = Arc::new(ImportDiagnostics::collect(&ctx.file_symbols, &resolver));
+}
+
 #[allow(dead_code)]
 pub fn create_config_with_rule(rule_name: &str, options_yaml: Option<&str>) -> Config {
     let mut config = Config::default();
@@ -0,0 +1,186 @@
+#![cfg(unix)]
+
+use archlint::cache::hash::config_hash;
+use archlint::config::Config;
+use archlint::daemon::protocol::{
+    DaemonStatus, FileParams, FilesParams, ScanParams, ScanReply, SmellEntry,
+};
+use archlint::daemon::{DaemonClient, DaemonServer};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+use tempfile::TempDir;
+
+fn setup_project() -> (TempDir, PathBuf) {
+    let dir = TempDir::new().unwrap();
+    let root = fs::canonicalize(dir.path()).unwrap();
+    fs::write(root.join("package.json"), r#"{"name":"daemon-test"}"#).unwrap();
+    fs::write(
+        root.join("a.ts"),
+        "import { b } from './b';\nexport const a = () => b();\n",
+    )
+    .unwrap();
+    fs::write(
+        root.join("b.ts"),
+        "import { a } from './a';\nexport const b = () => a;\n",
+    )
+    .unwrap();
+    (dir, root)
+}
+
+fn start_daemon(root: &Path) -> DaemonClient {
+    let server = DaemonServer::new(root).unwrap();
+    thread::spawn(move || server.run().unwrap());
+    for _ in 0..50 {
+        if let Some(client) = DaemonClient::connect(root) {
+            return client;
+        }
+        thread::sleep(Duration::from_millis(100));
+    }
+    panic!("daemon did not start");
+}
+
+#[test]
+fn test_daemon_answers_queries_until_shutdown() {
+    let (_dir, root) = setup_project();
+    let mut client = start_daemon(&root);
+
+    let status: DaemonStatus = client.call("status", &()).unwrap();
+    assert_eq!(status.project_root, root);
+    let config = Config::load_or_default(None, Some(&root)).unwrap();
+    assert_eq!(status.config_hash, config_hash(&config));
+    assert_eq!(status.files, 2);
+
+    let affected: Vec<PathBuf> = client
+        .call(
+            "affected",
+            &FilesParams {
+                files: vec![PathBuf::from("a.ts")],
+            },
+        )
+        .unwrap();
+    assert_eq!(affected, vec![root.join("a.ts"), root.join("b.ts")]);
+
+    let dependents: Vec<PathBuf> = client
+        .call(
+            "dependents",
+            &FileParams {
+                file: PathBuf::from("a.ts"),
+            },
+        )
+        .unwrap();
+    assert_eq!(dependents, vec![root.join("b.ts")]);
+
+    let smells: Vec<SmellEntry> = client
+        .call(
+            "smells",
+            &FileParams {
+                file: root.join("a.ts"),
+            },
+        )
+        .unwrap();
+    let cycle = smells
+        .iter()
+        .find(|s| s.id.starts_with("cycle"))
+        .expect("a.ts is in a cycle");
+    let explained: SmellEntry = client
+        .call("explain", &serde_json::json!({ "id": cycle.id }))
+        .unwrap();
+    assert_eq!(explained.smell.smell.files, cycle.smell.smell.files);
+
+    assert!(client.call::<_, serde_json::Value>("unknown", &()).is_err());
+
+    client
+        .call::<_, serde_json::Value>("shutdown", &())
+        .unwrap();
+    thread::sleep(Duration::from_millis(200));
+    assert!(DaemonClient::connect(&root).is_none());
+}
+
+#[test]
+fn test_daemon_only_scans_its_project_root() {
+    let (_dir, root) = setup_project();
+    let mut client = start_daemon(&root);
+
+    let scan = |path: PathBuf| ScanParams {
+        path: Some(path),
+        config_hash: None,
+    };
+    assert!(client
+        .call::<_, serde_json::Value>("scan", &scan(root.clone()))
+        .is_ok());
+    // Relative paths would be resolved against the daemon's working directory
+    assert!(client
+        .call::<_, serde_json::Value>("scan", &scan(PathBuf::from(".")))
+        .is_err());
+    assert!(client
+        .call::<_, serde_json::Value>("scan", &scan(root.join("src")))
+        .is_err());
+
+    client
+        .call::<_, serde_json::Value>("shutdown", &())
+        .unwrap();
+}
+
+#[test]
+fn test_daemon_answers_with_changes_the_watcher_has_not_reported() {
+    let (_dir, root) = setup_project();
+    let mut client = start_daemon(&root);
+
+    // Queried right away, before the watcher's debounce delay is over
+    fs::write(root.join("b.ts"), "export const b = () => 1;\n").unwrap();
+    let affected: Vec<PathBuf> = client
+        .call(
+            "affected",
+            &FilesParams {
+                files: vec![PathBuf::from("b.ts")],
+            },
+        )
+        .unwrap();
+    assert_eq!(affected, vec![root.join("a.ts"), root.join("b.ts")]);
+    let reply: ScanReply = client.call("scan", &ScanParams::default()).unwrap();
+    assert!(!reply
+        .result
+        .smells
+        .iter()
+        .any(|s| s.smell.files.contains(&root.join("b.ts"))));
+
+    fs::remove_file(root.join("a.ts")).unwrap();
+    let reply: ScanReply = client.call("scan", &ScanParams::default()).unwrap();
+    assert_eq!(reply.result.summary.files_analyzed, 1);
+
+    client
+        .call::<_, serde_json::Value>("shutdown", &())
+        .unwrap();
+}
+
+#[test]
+fn test_daemon_scan_reports_suppressed_smells_and_import_resolution() {
+    let (_dir, root) = setup_project();
+    fs::write(
+        root.join(".archlint.yaml"),
+        "suppressions:\n  include_suppressed: true\n",
+    )
+    .unwrap();
+    fs::write(
+        root.join("a.ts"),
+        "// archlint-disable-next-line * -- resolved at runtime\nimport { b } from './b';\nexport const a = () => b();\n",
+    )
+    .unwrap();
+    let mut client = start_daemon(&root);
+
+    let reply: ScanReply = client.call("scan", &ScanParams::default()).unwrap();
+    assert!(reply.result.smells.is_empty());
+    let suppressed = &reply.suppressed[0];
+    assert_eq!(suppressed.suppression.file, root.join("a.ts"));
+    assert_eq!(
+        suppressed.suppression.reason.as_deref(),
+        Some("resolved at runtime")
+    );
+    assert_eq!(reply.import_resolution.resolved_imports, 2);
+
+    client
+        .call::<_, serde_json::Value>("shutdown", &())
+        .unwrap();
+}
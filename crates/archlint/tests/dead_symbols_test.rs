@@ -0,0 +1,58 @@
+mod common;
+
+use archlint::detectors::dead_symbols::DeadSymbolsDetector;
+use archlint::detectors::{ArchSmell, Detector, SmellType};
+use archlint::engine::AnalysisContext;
+use archlint::resolver::PathResolver;
+use common::analyze_fixture_with_rule;
+
+/// Resolve import sources to file paths, as the analysis engine does.
+fn analyze(options: Option<&str>) -> AnalysisContext {
+    let mut ctx = analyze_fixture_with_rule("dead_code_local_exports", "dead_symbols", options);
+    let resolver = PathResolver::new(&ctx.project_path, &ctx.config);
+    for (path, symbols) in ctx.file_symbols_mut().iter_mut() {
+        for import in &mut symbols.imports {
+            if let Ok(Some(resolved)) = resolver.resolve(&import.source, path) {
+                import.source = resolved.to_string_lossy().into();
+            }
+        }
+    }
+    ctx
+}
+
+fn dead_names(smells: &[ArchSmell]) -> Vec<String> {
+    let mut names: Vec<String> = smells
+        .iter()
+        .filter_map(|s| match &s.smell_type {
+            SmellType::DeadSymbol { name, .. } => Some(name.clone()),
+            _ => None,
+        })
+        .collect();
+    names.sort();
+    names
+}
+
+#[test]
+fn test_local_exports_not_reported_by_default() {
+    let ctx = analyze(None);
+    assert_eq!(
+        dead_names(&DeadSymbolsDetector.detect(&ctx)),
+        vec!["unused"]
+    );
+}
+
+#[test]
+fn test_export_used_only_in_its_file() {
+    let ctx = analyze(Some("local_exports: true"));
+    let smells = DeadSymbolsDetector.detect(&ctx);
+    assert_eq!(dead_names(&smells), vec!["format", "unused"]);
+
+    let format = smells
+        .iter()
+        .find(|s| matches!(&s.smell_type, SmellType::DeadSymbol { name, .. } if name == "format"))
+        .unwrap();
+    assert!(
+        matches!(&format.smell_type, SmellType::DeadSymbol { kind, .. } if kind == "Function Export")
+    );
+    assert_eq!(format.locations[0].line, 1);
+}
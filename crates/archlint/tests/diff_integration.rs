@@ -149,6 +149,7 @@ fn make_snapshot(smells: Vec<SnapshotSmell>) -> Snapshot {
         smells,
         summary: SnapshotSummary::default(),
         grade: "B".to_string(),
+        debt: None,
     }
 }
 
@@ -0,0 +1,107 @@
+mod common;
+
+use archlint::config::{EventPatternConfig, EventRole};
+use archlint::detectors::event_cycles::EventCyclesDetector;
+use archlint::detectors::orphan_events::OrphanEventsDetector;
+use archlint::detectors::{Detector, DetectorRegistry, EventIssue, SmellType};
+use archlint::engine::AnalysisContext;
+use common::{analyze_fixture_with_config, analyze_fixture_with_rule, create_config_with_rule};
+
+fn analyze(rule: &str) -> AnalysisContext {
+    analyze_fixture_with_rule("event_coupling", rule, None)
+}
+
+fn orphans(ctx: &AnalysisContext) -> Vec<(String, EventIssue)> {
+    let mut orphans: Vec<(String, EventIssue)> = OrphanEventsDetector
+        .detect(ctx)
+        .into_iter()
+        .filter_map(|smell| match smell.smell_type {
+            SmellType::OrphanEvent { key, issue } => Some((key, issue)),
+            _ => None,
+        })
+        .collect();
+    orphans.sort();
+    orphans
+}
+
+#[test]
+fn test_orphan_events_reported_on_both_sides() {
+    let ctx = analyze("orphan_events");
+
+    assert_eq!(
+        orphans(&ctx),
+        vec![
+            ("order.archived".to_string(), EventIssue::NeverConsumed),
+            ("refund.requested".to_string(), EventIssue::NeverProduced),
+        ]
+    );
+}
+
+#[test]
+fn test_orphan_event_points_at_its_calls() {
+    let ctx = analyze("orphan_events");
+    let smells = OrphanEventsDetector.detect(&ctx);
+
+    let archived = smells
+        .iter()
+        .find(|s| matches!(&s.smell_type, SmellType::OrphanEvent { key, .. } if key == "order.archived"))
+        .unwrap();
+    assert!(archived.files[0].ends_with("orders.ts"));
+    assert_eq!(archived.locations[0].line, 6);
+    assert_eq!(
+        archived.locations[0].description,
+        "bus.emit emits 'order.archived'"
+    );
+}
+
+#[test]
+fn test_custom_event_patterns() {
+    let mut config = create_config_with_rule("orphan_events", None);
+    config.events.patterns.push(EventPatternConfig {
+        callee: "dispatch".to_string(),
+        argument: 0,
+        property: Some("type".to_string()),
+        role: EventRole::Producer,
+    });
+    config.events.ignore_keys.push("refund.*".to_string());
+    let ctx = analyze_fixture_with_config("event_coupling", config);
+
+    assert_eq!(
+        orphans(&ctx),
+        vec![
+            ("cart/checkout".to_string(), EventIssue::NeverConsumed),
+            ("order.archived".to_string(), EventIssue::NeverConsumed),
+        ]
+    );
+}
+
+#[test]
+fn test_event_cycle_through_import_and_events() {
+    let ctx = analyze("event_cycles");
+    let smells = EventCyclesDetector.detect(&ctx);
+
+    assert_eq!(smells.len(), 1);
+    let mut names: Vec<String> = smells[0]
+        .files
+        .iter()
+        .map(|f| f.file_name().unwrap().to_string_lossy().to_string())
+        .collect();
+    names.sort();
+    assert_eq!(names, vec!["billing.ts", "orders.ts"]);
+    assert!(matches!(
+        &smells[0].smell_type,
+        SmellType::EventCycle { keys } if keys == &["invoice.paid", "order.created"]
+    ));
+    assert!(smells[0]
+        .locations
+        .iter()
+        .any(|l| l.description == "Emits 'invoice.paid' consumed by orders.ts"));
+}
+
+#[test]
+fn test_event_detectors_disabled_by_default() {
+    let registry = DetectorRegistry::new();
+    for id in ["orphan_events", "event_cycles"] {
+        assert!(!registry.get_info(id).unwrap().default_enabled, "{id}");
+    }
+}
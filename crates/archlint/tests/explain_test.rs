@@ -0,0 +1,118 @@
+use archlint::commands::explain::{
+    collect_explanations, run_explain, ExplainFormat, ExplainOptions,
+};
+use archlint::AnalysisError;
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+
+fn write(root: &Path, file: &str, content: &str) {
+    let path = root.join(file);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, content).unwrap();
+}
+
+/// `a.ts` and `b.ts` import each other; `params.ts` has a function with seven parameters.
+fn project() -> TempDir {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path();
+    write(
+        root,
+        "src/a.ts",
+        "import { b } from './b';\nexport const a = () => b();\n",
+    );
+    write(
+        root,
+        "src/b.ts",
+        "import { a } from './a';\nexport const b = () => a();\n",
+    );
+    write(
+        root,
+        "src/params.ts",
+        "export function build(a: number, b: number, c: number, d: number, e: number, f: number, g: number) {\n  return a + b + c + d + e + f + g;\n}\n",
+    );
+    dir
+}
+
+fn explain(root: &Path, target: &str) -> Vec<archlint::commands::explain::SmellDetails> {
+    collect_explanations(&ExplainOptions {
+        path: root.to_path_buf(),
+        config: None,
+        target: target.to_string(),
+        format: ExplainFormat::Json,
+        output: None,
+    })
+    .unwrap()
+}
+
+#[test]
+fn test_explain_by_location_compares_thresholds() {
+    let dir = project();
+    let details = explain(dir.path(), "src/params.ts:1");
+    let long_params = details.iter().find(|d| d.rule == "long_params").unwrap();
+
+    assert_eq!(long_params.id, "params:src/params.ts:build:1");
+    assert_eq!(long_params.thresholds.len(), 1);
+    let check = &long_params.thresholds[0];
+    assert_eq!(check.option, "long_params.max_params");
+    assert_eq!(check.value, 7);
+    assert_eq!(check.threshold, 5);
+    assert!(check.exceeded);
+    assert_eq!(
+        long_params.suppression.comment,
+        "// archlint-disable-next-line long_params -- <reason>"
+    );
+}
+
+#[test]
+fn test_explain_by_id_matches_one_smell() {
+    let dir = project();
+    let details = explain(dir.path(), "params:src/params.ts:build:1");
+    assert_eq!(details.len(), 1);
+    assert_eq!(details[0].files, vec!["src/params.ts"]);
+}
+
+#[test]
+fn test_threshold_from_config_is_used() {
+    let dir = project();
+    write(
+        dir.path(),
+        ".archlint.yaml",
+        "rules:\n  long_params:\n    max_params: 6\n",
+    );
+    let details = explain(dir.path(), "params:src/params.ts:build:1");
+    assert_eq!(details[0].thresholds[0].threshold, 6);
+}
+
+#[test]
+fn test_cycle_has_import_chain() {
+    let dir = project();
+    let details = explain(dir.path(), "src/a.ts:1");
+    let cycle = details.iter().find(|d| d.id.starts_with("cycle:")).unwrap();
+
+    let chain: Vec<(&str, Option<usize>)> = cycle
+        .import_chain
+        .iter()
+        .map(|link| (link.file.as_str(), link.line))
+        .collect();
+    assert_eq!(chain.len(), 3);
+    assert_eq!(chain[0].0, chain[2].0);
+    assert!(chain[..2].iter().all(|(_, line)| *line == Some(1)));
+    assert_eq!(chain[2].1, None);
+}
+
+#[test]
+fn test_unknown_target_matches_nothing() {
+    let dir = project();
+    assert!(explain(dir.path(), "cycle:00000000").is_empty());
+    assert!(explain(dir.path(), "src/a.ts:40").is_empty());
+
+    let result = run_explain(&ExplainOptions {
+        path: dir.path().to_path_buf(),
+        config: None,
+        target: "cycle:00000000".to_string(),
+        format: ExplainFormat::Json,
+        output: None,
+    });
+    assert!(matches!(result, Err(AnalysisError::NotFound(_))));
+}
@@ -182,3 +182,81 @@ function complex() {}";
     assert!(rules.contains("cyclomatic_complexity"));
     assert_eq!(rules.len(), 1);
 }
+
+#[test]
+fn test_parse_ignore_comments_reason_after_double_dash() {
+    let parser = ImportParser::new().unwrap();
+    let code = r"// archlint-disable-next-line cyclomatic_complexity, large_file -- legacy parser, see #42
+function complex() {}
+
+/* archlint-disable-line * -- generated */ function generated() {}
+
+// archlint-disable-next-line cyclomatic_complexity --
+function noReason() {}";
+    let result = parser.parse_code(code, "test.ts").unwrap();
+
+    let line_comment = get_line_number(code, "legacy parser");
+    let rules = result
+        .ignored_lines
+        .get(&(line_comment + 1))
+        .expect("expected ignore rules for complex() line");
+    assert_eq!(rules.len(), 2);
+    let source = rules.source_for("large_file").unwrap();
+    assert_eq!(source.line, line_comment);
+    assert_eq!(source.reason.as_deref(), Some("legacy parser, see #42"));
+
+    let line_generated = get_line_number(code, "function generated()");
+    let source = result.ignored_lines[&line_generated]
+        .source_for("dead_code")
+        .expect("`*` should cover any rule");
+    assert_eq!(source.reason.as_deref(), Some("generated"));
+
+    let line_no_reason = get_line_number(code, "function noReason()");
+    let source = result.ignored_lines[&line_no_reason]
+        .source_for("cyclomatic_complexity")
+        .unwrap();
+    assert_eq!(source.reason, None);
+}
+
+#[test]
+fn test_block_suppression_points_to_disable_comment() {
+    let parser = ImportParser::new().unwrap();
+    let code = r"const before = 1;
+// archlint-disable cyclomatic_complexity -- migrating in Q3
+function a() {}
+function b() {}
+// archlint-enable cyclomatic_complexity";
+    let result = parser.parse_code(code, "test.ts").unwrap();
+
+    let line_disable = get_line_number(code, "archlint-disable ");
+    let line_b = get_line_number(code, "function b()");
+    let source = result.ignored_lines[&line_b]
+        .source_for("cyclomatic_complexity")
+        .unwrap();
+    assert_eq!(source.line, line_disable);
+    assert_eq!(source.reason.as_deref(), Some("migrating in Q3"));
+    assert!(result.ignored_lines[&line_b]
+        .source_for("large_file")
+        .is_none());
+}
+
+#[test]
+fn test_line_covered_by_several_comments_keeps_all_of_them() {
+    let parser = ImportParser::new().unwrap();
+    let code = r"// archlint-disable cyclomatic_complexity
+// archlint-disable-next-line cyclomatic_complexity -- parser table
+function a() {}
+// archlint-enable cyclomatic_complexity";
+    let result = parser.parse_code(code, "test.ts").unwrap();
+
+    let line_block = get_line_number(code, "archlint-disable ");
+    let line_next = get_line_number(code, "parser table");
+    let rules = &result.ignored_lines[&get_line_number(code, "function a()")];
+    let mut lines: Vec<usize> = rules.iter().map(|(_, source)| source.line).collect();
+    lines.sort_unstable();
+    assert_eq!(lines, vec![line_block, line_next]);
+
+    let source = rules.source_for("cyclomatic_complexity").unwrap();
+    assert_eq!(source.line, line_next);
+    assert_eq!(source.reason.as_deref(), Some("parser table"));
+}
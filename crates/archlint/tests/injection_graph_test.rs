@@ -0,0 +1,132 @@
+mod common;
+
+use archlint::detectors::circular_module_imports::CircularModuleImportsDetector;
+use archlint::detectors::provider_outside_module::ProviderOutsideModuleDetector;
+use archlint::detectors::{Detector, SmellType};
+use archlint::engine::AnalysisContext;
+use archlint::injection_graph::{InjectableId, InjectionGraph};
+use archlint::parser::{InjectableRole, InjectionKind};
+use common::{analyze_fixture, analyze_fixture_with_rule, resolve_import_sources};
+
+fn analyze(rule: &str) -> AnalysisContext {
+    let mut ctx = analyze_fixture_with_rule("nest_di", rule, None);
+    resolve_import_sources(&mut ctx);
+    ctx
+}
+
+fn names(graph: &InjectionGraph, ids: &[(InjectableId, usize)]) -> Vec<String> {
+    ids.iter()
+        .map(|&(id, _)| graph.get(id).name.clone())
+        .collect()
+}
+
+#[test]
+fn test_injection_graph_edges() {
+    let ctx = analyze("provider_outside_module");
+    let graph = InjectionGraph::build(ctx.file_symbols.as_ref());
+
+    let modules: Vec<String> = graph
+        .with_role(InjectableRole::Module)
+        .iter()
+        .map(|&id| graph.get(id).name.clone())
+        .collect();
+    assert_eq!(
+        modules,
+        vec![
+            "AppModule",
+            "BillingModule",
+            "ConfigModule",
+            "OrdersModule",
+            "UsersModule"
+        ]
+    );
+
+    let app = graph.with_role(InjectableRole::Module)[0];
+    assert_eq!(
+        names(&graph, &graph.targets(app, InjectionKind::Import)),
+        vec!["ConfigModule", "OrdersModule", "UsersModule"]
+    );
+
+    let orders_service = graph
+        .with_role(InjectableRole::Provider)
+        .into_iter()
+        .find(|&id| graph.get(id).name == "OrdersService")
+        .unwrap();
+    let injected = graph.targets(orders_service, InjectionKind::Inject);
+    assert_eq!(
+        names(&graph, &injected),
+        vec!["UsersService", "BillingService"]
+    );
+    // Injected classes resolve to their declaring files
+    let billing = graph.get(injected[1].0);
+    assert!(billing
+        .file
+        .as_ref()
+        .is_some_and(|f| f.ends_with("billing/billing.service.ts")));
+    assert_eq!(billing.role, Some(InjectableRole::Provider));
+}
+
+#[test]
+fn test_circular_module_imports_detected() {
+    let ctx = analyze("circular_module_imports");
+    let smells = CircularModuleImportsDetector.detect(&ctx);
+
+    assert_eq!(smells.len(), 1);
+    let SmellType::CircularModuleImport { modules } = &smells[0].smell_type else {
+        panic!("unexpected smell type");
+    };
+    assert_eq!(modules, &vec!["OrdersModule", "UsersModule"]);
+    assert_eq!(smells[0].locations.len(), 2);
+}
+
+#[test]
+fn test_provider_outside_module_detected() {
+    let ctx = analyze("provider_outside_module");
+    let smells = ProviderOutsideModuleDetector.detect(&ctx);
+
+    // UsersService is exported to OrdersModule and ConfigService is global
+    assert_eq!(smells.len(), 1);
+    let SmellType::ProviderOutsideModule {
+        provider,
+        consumer,
+        module,
+    } = &smells[0].smell_type
+    else {
+        panic!("unexpected smell type");
+    };
+    assert_eq!(provider, "BillingService");
+    assert_eq!(consumer, "OrdersService");
+    assert_eq!(module, "OrdersModule");
+    assert!(smells[0].files[0].ends_with("orders/orders.service.ts"));
+    assert!(smells[0].locations[1]
+        .file
+        .ends_with("billing/billing.module.ts"));
+}
+
+#[test]
+fn test_injection_detectors_disabled_by_default() {
+    let ctx = analyze_fixture("nest_di");
+    assert!(CircularModuleImportsDetector.detect(&ctx).is_empty());
+    assert!(ProviderOutsideModuleDetector.detect(&ctx).is_empty());
+}
+
+#[test]
+fn test_injection_report() {
+    let ctx = analyze("provider_outside_module");
+    let graph = InjectionGraph::build(ctx.file_symbols.as_ref());
+    let report = archlint::commands::injections::describe(&graph, &ctx.project_path);
+
+    let users = report
+        .providers
+        .iter()
+        .find(|p| p.name == "UsersService")
+        .expect("UsersService should be listed");
+    assert_eq!(users.modules, vec!["UsersModule"]);
+    assert_eq!(users.injects, vec!["UsersRepository"]);
+    assert_eq!(users.injected_by, vec!["OrdersService"]);
+    assert_eq!(users.file.as_deref(), Some("src/users/users.service.ts"));
+
+    assert_eq!(report.cycles, vec![vec!["OrdersModule", "UsersModule"]]);
+    assert_eq!(report.violations.len(), 1);
+    assert_eq!(report.violations[0].provider, "BillingService");
+}
@@ -0,0 +1,46 @@
+mod common;
+
+use archlint::detectors::large_class::LargeClassDetector;
+use archlint::detectors::{Detector, SmellType};
+use common::analyze_fixture_with_rule;
+
+fn large_classes(options: &str) -> Vec<(String, usize, usize, usize)> {
+    let ctx = analyze_fixture_with_rule("size", "large_class", Some(options));
+    let mut found: Vec<_> = LargeClassDetector
+        .detect(&ctx)
+        .into_iter()
+        .filter_map(|s| match s.smell_type {
+            SmellType::LargeClass {
+                name,
+                loc,
+                methods,
+                fields,
+            } => Some((name, loc, methods, fields)),
+            _ => None,
+        })
+        .collect();
+    found.sort();
+    found
+}
+
+#[test]
+fn test_large_class_by_lines() {
+    let found = large_classes("max_lines: 10");
+    assert_eq!(found, vec![("ReportService".to_string(), 18, 4, 4)]);
+}
+
+#[test]
+fn test_large_class_by_method_and_field_count() {
+    assert_eq!(large_classes("max_methods: 3").len(), 1);
+    // Parameter properties count as fields
+    assert_eq!(large_classes("max_fields: 3").len(), 1);
+    assert_eq!(large_classes("max_fields: 1").len(), 2);
+}
+
+#[test]
+fn test_large_class_location() {
+    let ctx = analyze_fixture_with_rule("size", "large_class", Some("max_lines: 10"));
+    let smells = LargeClassDetector.detect(&ctx);
+    assert_eq!(smells.len(), 1);
+    assert_eq!(smells[0].locations[0].line, 20);
+}
@@ -0,0 +1,92 @@
+mod common;
+
+use archlint::layer_inference::{infer_layers, LayerInference};
+use common::analyze_fixture;
+
+fn infer(depth: usize) -> LayerInference {
+    let ctx = analyze_fixture("layer_inference");
+    infer_layers(&ctx.graph, &ctx.project_path, depth)
+}
+
+#[test]
+fn test_layers_ordered_by_level() {
+    let inference = infer(2);
+    let layers: Vec<(&str, usize)> = inference
+        .layers
+        .iter()
+        .map(|l| (l.name.as_str(), l.level))
+        .collect();
+    assert_eq!(
+        layers,
+        vec![
+            ("src/domain", 0),
+            ("src/app", 1),
+            ("src/infra", 2),
+            ("src/ui", 3)
+        ]
+    );
+
+    let infra = &inference.layers[2];
+    assert_eq!(infra.path, "src/infra/**");
+    assert_eq!(infra.allowed_imports, vec!["src/domain", "src/app"]);
+}
+
+#[test]
+fn test_cycle_broken_at_lightest_dependency() {
+    // domain -> infra -> app -> domain; domain imports infra only once
+    let inference = infer(2);
+    assert_eq!(inference.violations.len(), 1);
+
+    let violation = &inference.violations[0];
+    assert_eq!(
+        (violation.from.as_str(), violation.to.as_str()),
+        ("src/domain", "src/infra")
+    );
+    assert_eq!(violation.imports.len(), 1);
+    assert!(violation.imports[0].0.ends_with("src/domain/user.ts"));
+}
+
+#[test]
+fn test_config_matches_layers() {
+    let inference = infer(2);
+    let config = inference.to_config();
+    assert_eq!(config.len(), inference.layers.len());
+    assert_eq!(config[1].name, "src/app");
+    assert_eq!(config[1].allowed_imports, vec!["src/domain"]);
+}
+
+#[test]
+fn test_single_folder_has_no_layers_to_order() {
+    let inference = infer(1);
+    assert_eq!(inference.layers.len(), 1);
+    assert!(inference.violations.is_empty());
+}
+
+#[test]
+fn test_long_dependency_chain() {
+    use archlint::graph::{DependencyGraph, EdgeData};
+    use std::path::Path;
+
+    // f0 -> f1 -> ... -> f1999; the small stack rules out recursing per folder
+    let inference = std::thread::Builder::new()
+        .stack_size(128 * 1024)
+        .spawn(|| {
+            let root = Path::new("/project");
+            let mut graph = DependencyGraph::new();
+            let nodes: Vec<_> = (0..2_000)
+                .map(|i| graph.add_file(root.join(format!("f{i}/index.ts"))))
+                .collect();
+            for pair in nodes.windows(2) {
+                graph.add_dependency(pair[0], pair[1], EdgeData::new(1));
+            }
+            infer_layers(&graph, root, 1)
+        })
+        .unwrap()
+        .join()
+        .unwrap();
+
+    assert_eq!(inference.layers.len(), 2_000);
+    assert!(inference.violations.is_empty());
+    let top = inference.layers.last().unwrap();
+    assert_eq!((top.name.as_str(), top.level), ("f0", 1_999));
+}
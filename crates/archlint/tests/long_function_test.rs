@@ -0,0 +1,32 @@
+mod common;
+
+use archlint::detectors::long_function::LongFunctionDetector;
+use archlint::detectors::{Detector, SmellType};
+use common::analyze_fixture_with_rule;
+
+fn long_functions(options: Option<&str>) -> Vec<(String, usize)> {
+    let ctx = analyze_fixture_with_rule("size", "long_function", options);
+    let mut found: Vec<(String, usize)> = LongFunctionDetector
+        .detect(&ctx)
+        .into_iter()
+        .filter_map(|s| match s.smell_type {
+            SmellType::LongFunction { name, loc } => Some((name, loc)),
+            _ => None,
+        })
+        .collect();
+    found.sort();
+    found
+}
+
+#[test]
+fn test_long_function_counts_code_lines_only() {
+    // 16 physical lines, of which comments and blank lines are not counted
+    let found = long_functions(Some("max_lines: 8"));
+    assert_eq!(found, vec![("buildReport".to_string(), 10)]);
+}
+
+#[test]
+fn test_long_function_threshold() {
+    assert!(long_functions(Some("max_lines: 10")).is_empty());
+    assert!(long_functions(None).is_empty());
+}
@@ -0,0 +1,80 @@
+mod common;
+
+use archlint::modularize::{modularize, Modularization, ModularizeSettings};
+use common::analyze_fixture;
+use std::collections::HashMap;
+
+fn run(settings: &ModularizeSettings) -> Modularization {
+    let ctx = analyze_fixture("modularize");
+    modularize(&ctx.graph, &HashMap::new(), &ctx.project_path, settings)
+}
+
+#[test]
+fn test_modules_follow_dependencies() {
+    let result = run(&ModularizeSettings::default());
+    assert_eq!(result.files, 7);
+
+    let modules: Vec<(&str, Vec<&str>)> = result
+        .modules
+        .iter()
+        .map(|m| {
+            (
+                m.name.as_str(),
+                m.files.iter().map(String::as_str).collect(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        modules,
+        vec![
+            (
+                "src/orders",
+                vec![
+                    "src/billing/discount.ts",
+                    "src/orders/cart.ts",
+                    "src/orders/checkout.ts",
+                    "src/orders/order.ts",
+                ]
+            ),
+            (
+                "src/billing",
+                vec![
+                    "src/billing/invoice.ts",
+                    "src/billing/payment.ts",
+                    "src/billing/tax.ts",
+                ]
+            ),
+        ]
+    );
+    assert!((result.modules[0].folder_share - 0.75).abs() < f64::EPSILON);
+}
+
+#[test]
+fn test_misplaced_file_reported() {
+    let result = run(&ModularizeSettings::default());
+    assert_eq!(result.misplaced.len(), 1);
+
+    let misplaced = &result.misplaced[0];
+    assert_eq!(misplaced.file, "src/billing/discount.ts");
+    assert_eq!(misplaced.folder, "src/billing");
+    assert_eq!(misplaced.suggested_folder, "src/orders");
+}
+
+#[test]
+fn test_modularity_higher_than_folders() {
+    let result = run(&ModularizeSettings::default());
+    assert!(result.modularity > result.folder_modularity);
+    assert!(result.modularity > 0.0 && result.modularity <= 1.0);
+}
+
+#[test]
+fn test_shallow_depth_puts_everything_in_one_folder() {
+    let result = run(&ModularizeSettings {
+        depth: 1,
+        ..ModularizeSettings::default()
+    });
+    assert!(result.misplaced.is_empty());
+    assert!(result.folder_modularity.abs() < 1e-9);
+    assert_eq!(result.modules[0].name, "src");
+    assert_eq!(result.modules[1].name, "src (2)");
+}
@@ -0,0 +1,139 @@
+mod common;
+
+use archlint::detectors::npm_dependencies::NpmDependenciesDetector;
+use archlint::detectors::{ArchSmell, DependencyIssue, Detector, SmellType};
+use common::{analyze_fixture_with_rule, resolve_import_sources};
+
+fn issues(smells: &[ArchSmell], expected: DependencyIssue) -> Vec<String> {
+    let mut packages: Vec<String> = smells
+        .iter()
+        .filter_map(|s| match &s.smell_type {
+            SmellType::NpmDependency { package, issue } if *issue == expected => {
+                Some(package.clone())
+            }
+            _ => None,
+        })
+        .collect();
+    packages.sort();
+    packages
+}
+
+#[test]
+fn test_missing_dependency_detected() {
+    let ctx = analyze_fixture_with_rule("npm_deps/basic", "npm_dependencies", None);
+    let smells = NpmDependenciesDetector.detect(&ctx);
+
+    assert_eq!(issues(&smells, DependencyIssue::Missing), vec!["lodash"]);
+
+    let lodash = smells
+        .iter()
+        .find(|s| matches!(&s.smell_type, SmellType::NpmDependency { package, .. } if package == "lodash"))
+        .unwrap();
+    assert!(lodash.files[0].ends_with("package.json"));
+    assert!(lodash.files[1].ends_with("src/index.ts"));
+    assert_eq!(lodash.locations[0].line, 2);
+}
+
+#[test]
+fn test_unused_dependency_detected() {
+    let ctx = analyze_fixture_with_rule("npm_deps/basic", "npm_dependencies", None);
+    let smells = NpmDependenciesDetector.detect(&ctx);
+
+    // jest/eslint/prettier/typescript are used from scripts, eslint-plugin-react
+    // from .eslintrc.json, and @types/* packages are never reported
+    assert_eq!(issues(&smells, DependencyIssue::Unused), vec!["left-pad"]);
+
+    let left_pad = smells
+        .iter()
+        .find(|s| matches!(&s.smell_type, SmellType::NpmDependency { package, .. } if package == "left-pad"))
+        .unwrap();
+    assert!(left_pad.files[0].ends_with("package.json"));
+    assert_eq!(left_pad.locations[0].line, 10);
+}
+
+#[test]
+fn test_dev_dependency_in_runtime_code() {
+    let ctx = analyze_fixture_with_rule("npm_deps/basic", "npm_dependencies", None);
+    let smells = NpmDependenciesDetector.detect(&ctx);
+
+    // msw is imported from a test file and type-fest only via `import type`
+    assert_eq!(
+        issues(&smells, DependencyIssue::DevOnlyInRuntime),
+        vec!["chalk"]
+    );
+}
+
+#[test]
+fn test_ignore_packages_option() {
+    let ctx = analyze_fixture_with_rule(
+        "npm_deps/basic",
+        "npm_dependencies",
+        Some("ignore_packages: [\"lodash\", \"left-pad\"]"),
+    );
+    let smells = NpmDependenciesDetector.detect(&ctx);
+
+    assert!(issues(&smells, DependencyIssue::Missing).is_empty());
+    assert!(issues(&smells, DependencyIssue::Unused).is_empty());
+}
+
+#[test]
+fn test_workspace_packages_use_root_dependencies() {
+    let ctx = analyze_fixture_with_rule("npm_deps/workspace", "npm_dependencies", None);
+    let smells = NpmDependenciesDetector.detect(&ctx);
+
+    // zod and typescript (via `tsc`) are hoisted from the root manifest,
+    // the self-reference to @acme/ui is not a dependency
+    assert_eq!(issues(&smells, DependencyIssue::Missing), vec!["clsx"]);
+    assert!(issues(&smells, DependencyIssue::Unused).is_empty());
+}
+
+#[test]
+fn test_workspace_package_resolved_through_exports_is_used() {
+    let mut ctx = analyze_fixture_with_rule("npm_deps/workspace", "npm_dependencies", None);
+    resolve_import_sources(&mut ctx);
+    let smells = NpmDependenciesDetector.detect(&ctx);
+
+    let main = ctx
+        .file_symbols
+        .iter()
+        .find(|(path, _)| path.ends_with("app/src/main.ts"))
+        .unwrap()
+        .1;
+    assert!(main.imports[0].source.ends_with("ui/src/button.ts"));
+    assert_eq!(issues(&smells, DependencyIssue::Missing), vec!["clsx"]);
+    assert!(issues(&smells, DependencyIssue::Unused).is_empty());
+}
+
+#[test]
+fn test_undeclared_workspace_package_is_missing() {
+    let mut ctx =
+        analyze_fixture_with_rule("npm_deps/workspace_undeclared", "npm_dependencies", None);
+    resolve_import_sources(&mut ctx);
+    let smells = NpmDependenciesDetector.detect(&ctx);
+
+    assert_eq!(
+        issues(&smells, DependencyIssue::Missing),
+        vec!["@acme/ui", "clsx"]
+    );
+    let ui = smells
+        .iter()
+        .find(|s| matches!(&s.smell_type, SmellType::NpmDependency { package, .. } if package == "@acme/ui"))
+        .unwrap();
+    assert!(ui.files[0].ends_with("app/package.json"));
+}
+
+#[test]
+fn test_root_dependencies_disallowed() {
+    let ctx = analyze_fixture_with_rule(
+        "npm_deps/workspace",
+        "npm_dependencies",
+        Some("allow_root_dependencies: false"),
+    );
+    let smells = NpmDependenciesDetector.detect(&ctx);
+
+    assert_eq!(
+        issues(&smells, DependencyIssue::Missing),
+        vec!["clsx", "zod"]
+    );
+    assert_eq!(issues(&smells, DependencyIssue::Unused), vec!["zod"]);
+}
@@ -0,0 +1,146 @@
+mod common;
+
+use archlint::config::{Config, SeverityConfig};
+use archlint::package_metrics::{
+    assign_components, compute_metrics, ComponentGrouping, ComponentMetrics,
+};
+use archlint::report::markdown::generate_markdown;
+use archlint::report::mermaid::MermaidGenerator;
+use archlint::report::AnalysisReportBuilder;
+use common::analyze_fixture;
+
+fn metrics_for(grouping: ComponentGrouping) -> Vec<ComponentMetrics> {
+    let ctx = analyze_fixture("package_metrics");
+    let components = assign_components(ctx.file_symbols.keys(), &ctx.project_path, grouping);
+    compute_metrics(&ctx.graph, &ctx.file_symbols, &components)
+}
+
+fn find<'a>(metrics: &'a [ComponentMetrics], name: &str) -> &'a ComponentMetrics {
+    metrics
+        .iter()
+        .find(|m| m.name == name)
+        .unwrap_or_else(|| panic!("Component {name} not found in {metrics:?}"))
+}
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() < 1e-9,
+        "expected {expected}, got {actual}"
+    );
+}
+
+#[test]
+fn test_folder_metrics() {
+    let metrics = metrics_for(ComponentGrouping::Folder { depth: 2 });
+    let names: Vec<&str> = metrics.iter().map(|m| m.name.as_str()).collect();
+    assert_eq!(
+        names,
+        vec!["packages/app", "packages/core", "packages/infra"]
+    );
+
+    let app = find(&metrics, "packages/app");
+    assert_eq!((app.files, app.ca, app.ce), (2, 0, 2));
+    assert_close(app.instability, 1.0);
+    assert_close(app.abstractness, 0.0);
+    assert_close(app.distance, 0.0);
+
+    // Interface, type alias and abstract class out of four exports
+    let core = find(&metrics, "packages/core");
+    assert_eq!((core.files, core.ca, core.ce), (2, 2, 0));
+    assert_close(core.instability, 0.0);
+    assert_close(core.abstractness, 0.75);
+    assert_close(core.distance, 0.25);
+
+    // Stable and concrete: zone of pain
+    let infra = find(&metrics, "packages/infra");
+    assert_eq!((infra.files, infra.ca, infra.ce), (1, 1, 0));
+    assert_close(infra.distance, 1.0);
+}
+
+#[test]
+fn test_folder_depth_changes_grouping() {
+    let metrics = metrics_for(ComponentGrouping::Folder { depth: 1 });
+    assert_eq!(metrics.len(), 1);
+    assert_eq!(metrics[0].name, "packages");
+    assert_eq!((metrics[0].ca, metrics[0].ce), (0, 0));
+}
+
+#[test]
+fn test_package_metrics_use_nearest_manifest() {
+    let metrics = metrics_for(ComponentGrouping::Package);
+    let names: Vec<&str> = metrics.iter().map(|m| m.name.as_str()).collect();
+    assert_eq!(names, vec!["@demo/app", "@demo/core", "demo"]);
+
+    let core = find(&metrics, "@demo/core");
+    assert_eq!((core.ca, core.ce), (2, 0));
+
+    // `packages/infra` has no manifest of its own
+    let root = find(&metrics, "demo");
+    assert_eq!((root.files, root.ca, root.ce), (1, 1, 0));
+}
+
+#[test]
+fn test_scan_markdown_report_has_main_sequence_chart() {
+    let ctx = analyze_fixture("package_metrics");
+    let report = AnalysisReportBuilder::new()
+        .with_graph(Some(ctx.graph.as_ref().clone()))
+        .with_symbols(ctx.file_symbols.as_ref().clone())
+        .build();
+    let severity = SeverityConfig::default();
+
+    let md = generate_markdown(
+        &report,
+        report.graph.as_ref(),
+        true,
+        &severity,
+        Some(&ctx.project_path),
+    );
+    assert!(md.contains("## Main Sequence"));
+    assert!(md.contains("    packages-core: [0.00, 0.75]"));
+
+    let md = generate_markdown(
+        &report,
+        report.graph.as_ref(),
+        false,
+        &severity,
+        Some(&ctx.project_path),
+    );
+    assert!(!md.contains("quadrantChart"));
+}
+
+#[test]
+fn test_main_sequence_chart_uses_configured_depth() {
+    let ctx = analyze_fixture("package_metrics");
+    let mut config = Config::default();
+    config.metrics.folder_depth = 1;
+    let report = AnalysisReportBuilder::new()
+        .with_graph(Some(ctx.graph.as_ref().clone()))
+        .with_symbols(ctx.file_symbols.as_ref().clone())
+        .with_config(config)
+        .build();
+
+    let md = generate_markdown(
+        &report,
+        report.graph.as_ref(),
+        true,
+        &SeverityConfig::default(),
+        Some(&ctx.project_path),
+    );
+    assert!(md.contains("    packages: ["));
+    assert!(!md.contains("packages-core"));
+}
+
+#[test]
+fn test_main_sequence_labels_punctuation_only_names() {
+    let metrics = vec![ComponentMetrics {
+        name: "@@/".to_string(),
+        files: 1,
+        ca: 0,
+        ce: 0,
+        instability: 0.0,
+        abstractness: 0.0,
+        distance: 1.0,
+    }];
+    let chart = MermaidGenerator::main_sequence(&metrics);
+    assert!(chart.contains("    component 1: [0.00, 0.00]"));
+}
@@ -1,6 +1,8 @@
 use archlint::config::Config;
-use archlint::resolver::PathResolver;
+use archlint::resolver::package::{PackageError, PackageJson};
+use archlint::resolver::{PathResolver, UnresolvedReason};
 use std::fs;
+use std::path::PathBuf;
 use tempfile::TempDir;
 
 #[test]
@@ -82,3 +84,318 @@ fn test_resolve_with_extensions() {
     assert!(result_jsx.is_some());
     assert_eq!(result_jsx.unwrap().extension().unwrap(), "jsx");
 }
+
+fn write(path: &std::path::Path, contents: &str) {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, contents).unwrap();
+}
+
+#[test]
+fn test_resolve_alias_per_package_tsconfig() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path().canonicalize().unwrap();
+
+    for package in ["a", "b"] {
+        let dir = root.join("packages").join(package);
+        write(
+            &dir.join("tsconfig.json"),
+            r#"{ "compilerOptions": { "baseUrl": ".", "paths": { "@/*": ["src/*"] } } }"#,
+        );
+        write(&dir.join("src/utils.ts"), "");
+        write(&dir.join("src/main.ts"), "");
+    }
+
+    let resolver = PathResolver::new(&root, &Config::default());
+    for package in ["a", "b"] {
+        let dir = root.join("packages").join(package);
+        let result = resolver
+            .resolve("@/utils", &dir.join("src/main.ts"))
+            .unwrap();
+        assert_eq!(result, Some(dir.join("src/utils.ts")));
+        assert_eq!(
+            resolver.governing_tsconfig(&dir.join("src/main.ts")),
+            Some(dir.join("tsconfig.json"))
+        );
+    }
+}
+
+#[test]
+fn test_resolve_through_project_references() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path().canonicalize().unwrap();
+
+    write(
+        &root.join("tsconfig.json"),
+        r#"{ "files": [], "references": [{ "path": "./tsconfig.node.json" }, { "path": "./tsconfig.app.json" }] }"#,
+    );
+    write(
+        &root.join("tsconfig.app.json"),
+        r#"{ "include": ["src"], "compilerOptions": { "paths": { "@/*": ["./src/*"] } } }"#,
+    );
+    write(
+        &root.join("tsconfig.node.json"),
+        r#"{ "include": ["vite.config.ts"] }"#,
+    );
+    write(&root.join("src/lib/api.ts"), "");
+    write(&root.join("src/main.ts"), "");
+    write(&root.join("vite.config.ts"), "");
+
+    let resolver = PathResolver::new(&root, &Config::default());
+    let result = resolver
+        .resolve("@/lib/api", &root.join("src/main.ts"))
+        .unwrap();
+    assert_eq!(result, Some(root.join("src/lib/api.ts")));
+    assert_eq!(
+        resolver.governing_tsconfig(&root.join("vite.config.ts")),
+        Some(root.join("tsconfig.node.json"))
+    );
+    assert_eq!(
+        resolver.governing_tsconfig(&root.join("scripts/seed.ts")),
+        None
+    );
+}
+
+#[test]
+fn test_resolve_paths_inherited_from_base_tsconfig() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path().canonicalize().unwrap();
+
+    write(
+        &root.join("tsconfig.base.json"),
+        r#"{ "compilerOptions": { "baseUrl": ".", "paths": { "@shared/*": ["libs/shared/*"] } } }"#,
+    );
+    write(
+        &root.join("apps/web/tsconfig.json"),
+        r#"{ "extends": "../../tsconfig.base.json", "include": ["src/**/*.ts"] }"#,
+    );
+    write(&root.join("libs/shared/date.ts"), "");
+    write(&root.join("apps/web/src/index.ts"), "");
+
+    let resolver = PathResolver::new(&root, &Config::default());
+    let result = resolver
+        .resolve("@shared/date", &root.join("apps/web/src/index.ts"))
+        .unwrap();
+    assert_eq!(result, Some(root.join("libs/shared/date.ts")));
+}
+
+#[test]
+fn test_resolve_relative_import_across_root_dirs() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path().canonicalize().unwrap();
+
+    write(
+        &root.join("tsconfig.json"),
+        r#"{ "compilerOptions": { "rootDirs": ["src", "generated"] } }"#,
+    );
+    write(&root.join("src/views/page.ts"), "");
+    write(&root.join("generated/views/page.types.ts"), "");
+
+    let resolver = PathResolver::new(&root, &Config::default());
+    let result = resolver
+        .resolve("./page.types", &root.join("src/views/page.ts"))
+        .unwrap();
+    assert_eq!(result, Some(root.join("generated/views/page.types.ts")));
+}
+
+#[test]
+fn test_nested_tsconfig_ignored_when_disabled() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path().canonicalize().unwrap();
+
+    write(
+        &root.join("app/tsconfig.json"),
+        r#"{ "compilerOptions": { "paths": { "~/*": ["./*"] } } }"#,
+    );
+    write(&root.join("app/util.ts"), "");
+
+    let config: Config = serde_yaml::from_str("tsconfig: false").unwrap();
+    let resolver = PathResolver::new(&root, &config);
+    assert_eq!(
+        resolver
+            .resolve("~/util", &root.join("app/main.ts"))
+            .unwrap(),
+        None
+    );
+    assert!(resolver.is_local_import("./missing", &root.join("app/main.ts")));
+    assert!(!resolver.is_local_import("~/util", &root.join("app/main.ts")));
+}
+
+#[test]
+fn test_resolve_subpath_imports_with_conditions() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path().canonicalize().unwrap();
+
+    write(
+        &root.join("package.json"),
+        r##"{
+            "name": "app",
+            "imports": {
+                "#db": { "node": "./src/db/node.ts", "default": "./src/db/browser.ts" },
+                "#utils/*": "./src/utils/*.ts",
+                "#dep": "lodash"
+            }
+        }"##,
+    );
+    write(&root.join("src/db/node.ts"), "");
+    write(&root.join("src/db/browser.ts"), "");
+    write(&root.join("src/utils/date.ts"), "");
+    let main = root.join("src/main.ts");
+
+    let resolver = PathResolver::new(&root, &Config::default());
+    assert_eq!(
+        resolver.resolve("#db", &main).unwrap(),
+        Some(root.join("src/db/node.ts"))
+    );
+    assert_eq!(
+        resolver.resolve("#utils/date", &main).unwrap(),
+        Some(root.join("src/utils/date.ts"))
+    );
+    assert_eq!(resolver.resolve("#dep", &main).unwrap(), None);
+    assert!(!resolver.is_local_import("#dep", &main));
+
+    let mut config = Config::default();
+    config.resolve.conditions = vec!["browser".to_string()];
+    let resolver = PathResolver::new(&root, &config);
+    assert_eq!(
+        resolver.resolve("#db", &main).unwrap(),
+        Some(root.join("src/db/browser.ts"))
+    );
+}
+
+#[test]
+fn test_resolve_workspace_package_exports() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path().canonicalize().unwrap();
+
+    write(
+        &root.join("packages/ui/package.json"),
+        r#"{
+            "name": "@acme/ui",
+            "exports": {
+                ".": { "types": "./src/index.ts", "default": "./dist/index.js" },
+                "./components/*": "./src/components/*.tsx",
+                "./components/internal/*": null
+            }
+        }"#,
+    );
+    write(&root.join("packages/ui/src/index.ts"), "");
+    write(&root.join("packages/ui/src/components/Button.tsx"), "");
+    write(
+        &root.join("packages/ui/src/components/internal/Portal.tsx"),
+        "",
+    );
+    write(
+        &root.join("apps/web/package.json"),
+        r#"{ "name": "web", "dependencies": { "@acme/ui": "workspace:*" } }"#,
+    );
+    let main = root.join("apps/web/src/main.ts");
+
+    let resolver = PathResolver::new(&root, &Config::default());
+    assert_eq!(
+        resolver.resolve("@acme/ui", &main).unwrap(),
+        Some(root.join("packages/ui/src/index.ts"))
+    );
+    assert_eq!(
+        resolver
+            .resolve("@acme/ui/components/Button", &main)
+            .unwrap(),
+        Some(root.join("packages/ui/src/components/Button.tsx"))
+    );
+    assert_eq!(
+        resolver
+            .resolve("@acme/ui/components/internal/Portal", &main)
+            .unwrap(),
+        None
+    );
+    assert_eq!(
+        resolver
+            .unresolved_reason("@acme/ui/components/internal/Portal", &main)
+            .unwrap()
+            .to_string(),
+        "'./components/internal/Portal' is not exported by @acme/ui"
+    );
+    assert_eq!(resolver.resolve("react", &main).unwrap(), None);
+    assert!(!resolver.is_local_import("react", &main));
+}
+
+#[test]
+fn test_unresolved_reasons_for_package_fields() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path().canonicalize().unwrap();
+
+    write(
+        &root.join("package.json"),
+        r##"{
+            "name": "lib",
+            "exports": { ".": { "source": "./src/index.ts", "default": "./dist/index.js" } },
+            "imports": { "#env": { "worker": "./src/env.ts" } }
+        }"##,
+    );
+    write(&root.join("src/index.ts"), "");
+    write(&root.join("src/env.ts"), "");
+    let file = root.join("src/cli.ts");
+
+    let resolver = PathResolver::new(&root, &Config::default());
+    assert_eq!(
+        resolver.unresolved_reason("lib", &file),
+        Some(UnresolvedReason::MissingTarget(root.join("dist/index.js")))
+    );
+    assert_eq!(
+        resolver
+            .unresolved_reason("#env", &file)
+            .unwrap()
+            .to_string(),
+        "no condition of '#env' matches the active conditions [types, import, require, node]"
+    );
+    assert!(matches!(
+        resolver.unresolved_reason("#missing", &file),
+        Some(UnresolvedReason::Package(
+            PackageError::ImportNotDefined { .. }
+        ))
+    ));
+    assert_eq!(
+        resolver.unresolved_reason("./nope", &file),
+        Some(UnresolvedReason::NotFound)
+    );
+
+    let mut config = Config::default();
+    config.resolve.conditions = vec!["source".to_string(), "worker".to_string()];
+    let resolver = PathResolver::new(&root, &config);
+    assert_eq!(
+        resolver.resolve("lib", &file).unwrap(),
+        Some(root.join("src/index.ts"))
+    );
+    assert_eq!(resolver.unresolved_reason("#env", &file), None);
+}
+
+#[test]
+fn test_exports_patterns_prefer_most_specific_key() {
+    let package: PackageJson = PackageJson {
+        dir: PathBuf::from("/pkg"),
+        name: Some("pkg".to_string()),
+        exports: serde_json::from_str(
+            r#"{
+                "./features/*": "./src/features/*/index.js",
+                "./features/internal/*": "./src/private/*.js",
+                "./features/*.css": ["./styles/*.css"]
+            }"#,
+        )
+        .unwrap(),
+        imports: None,
+    };
+
+    let resolve = |subpath: &str| package.resolve_export(subpath, &[]).unwrap().unwrap();
+    assert_eq!(
+        resolve("./features/cart"),
+        PathBuf::from("/pkg/src/features/cart/index.js")
+    );
+    assert_eq!(
+        resolve("./features/internal/flags"),
+        PathBuf::from("/pkg/src/private/flags.js")
+    );
+    assert_eq!(
+        resolve("./features/cart.css"),
+        PathBuf::from("/pkg/styles/cart.css")
+    );
+    assert!(package.resolve_export(".", &[]).is_err());
+}
@@ -0,0 +1,103 @@
+mod common;
+
+use archlint::detectors::server_code_in_client::ServerCodeInClientDetector;
+use archlint::detectors::{Detector, DetectorRegistry, SmellType};
+use archlint::engine::AnalysisContext;
+use archlint::parser::FileDirective;
+use common::{analyze_fixture_with_rule, resolve_import_sources};
+
+fn analyze(options: Option<&str>) -> AnalysisContext {
+    let mut ctx = analyze_fixture_with_rule("nextjs_boundary", "server_code_in_client", options);
+    resolve_import_sources(&mut ctx);
+    ctx
+}
+
+fn file_names(files: &[std::path::PathBuf]) -> Vec<String> {
+    files
+        .iter()
+        .map(|f| f.file_name().unwrap().to_string_lossy().to_string())
+        .collect()
+}
+
+#[test]
+fn test_file_directives_parsed() {
+    let ctx = analyze(None);
+    let directive = |name: &str| {
+        ctx.file_symbols
+            .iter()
+            .find(|(path, _)| path.ends_with(name))
+            .and_then(|(_, symbols)| symbols.directive)
+    };
+
+    assert_eq!(directive("Dashboard.tsx"), Some(FileDirective::UseClient));
+    assert_eq!(directive("actions.ts"), Some(FileDirective::UseServer));
+    assert_eq!(directive("page.tsx"), None);
+}
+
+#[test]
+fn test_transitive_server_module_reported_with_chain() {
+    let ctx = analyze(None);
+    let smells = ServerCodeInClientDetector.detect(&ctx);
+
+    let dashboard = smells
+        .iter()
+        .find(|s| s.files[0].ends_with("Dashboard.tsx"))
+        .expect("Dashboard should reach the database client");
+    assert_eq!(
+        file_names(&dashboard.files),
+        vec!["Dashboard.tsx", "stats.ts", "db.ts"]
+    );
+    assert!(matches!(
+        &dashboard.smell_type,
+        SmellType::ServerCodeInClient { package, .. } if package == "pg"
+    ));
+    let lines: Vec<usize> = dashboard.locations.iter().map(|l| l.line).collect();
+    assert_eq!(lines, vec![6, 1, 1]);
+}
+
+#[test]
+fn test_direct_server_import_reported() {
+    let ctx = analyze(None);
+    let smells = ServerCodeInClientDetector.detect(&ctx);
+
+    let upload = smells
+        .iter()
+        .find(|s| s.files[0].ends_with("Upload.tsx"))
+        .expect("Upload imports a Node.js module");
+    assert_eq!(upload.files.len(), 1);
+    assert!(matches!(
+        &upload.smell_type,
+        SmellType::ServerCodeInClient { package, .. } if package == "node:fs/promises"
+    ));
+}
+
+#[test]
+fn test_server_actions_and_type_imports_allowed() {
+    let ctx = analyze(None);
+    let smells = ServerCodeInClientDetector.detect(&ctx);
+
+    // actions.ts and session.ts are server-only but reached only through a
+    // "use server" boundary or a type-only import; Chart.tsx is clean
+    assert_eq!(smells.len(), 2);
+    assert!(smells.iter().all(|s| !s
+        .files
+        .iter()
+        .any(|f| f.ends_with("actions.ts") || f.ends_with("session.ts"))));
+}
+
+#[test]
+fn test_custom_server_only_packages() {
+    let ctx = analyze(Some("server_only_packages: ['pg']"));
+    let smells = ServerCodeInClientDetector.detect(&ctx);
+
+    assert_eq!(smells.len(), 1);
+    assert!(smells[0].files[0].ends_with("Dashboard.tsx"));
+}
+
+#[test]
+fn test_disabled_by_default() {
+    let info = DetectorRegistry::new()
+        .get_info("server_code_in_client")
+        .expect("detector should be registered");
+    assert!(!info.default_enabled);
+}
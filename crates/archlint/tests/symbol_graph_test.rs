@@ -0,0 +1,95 @@
+mod common;
+
+use archlint::detectors::cycles::CycleDetector;
+use archlint::detectors::dead_symbols::DeadSymbolsDetector;
+use archlint::detectors::{ArchSmell, Detector, SmellType};
+use archlint::engine::AnalysisContext;
+use archlint::parser::ParserConfig;
+use common::{analyze_fixture_with_rule, create_config_with_rule, resolve_import_sources};
+use std::collections::HashSet;
+
+fn analyze(fixture: &str, rule: &str, options: Option<&str>) -> AnalysisContext {
+    let mut ctx = analyze_fixture_with_rule(fixture, rule, options);
+    resolve_import_sources(&mut ctx);
+    ctx
+}
+
+fn dead_names(smells: &[ArchSmell]) -> Vec<String> {
+    let mut names: Vec<String> = smells
+        .iter()
+        .filter_map(|s| match &s.smell_type {
+            SmellType::DeadSymbol { name, .. } => Some(name.clone()),
+            _ => None,
+        })
+        .collect();
+    names.sort();
+    names
+}
+
+#[test]
+fn test_file_cycle_reported_by_default() {
+    let ctx = analyze("cycles/symbol_level", "cyclic_dependency", None);
+    assert_eq!(CycleDetector.detect(&ctx).len(), 1);
+}
+
+#[test]
+fn test_symbol_level_ignores_cycle_without_symbol_cycle() {
+    let ctx = analyze(
+        "cycles/symbol_level",
+        "cyclic_dependency",
+        Some("symbol_level: true"),
+    );
+    assert!(CycleDetector.detect(&ctx).is_empty());
+}
+
+#[test]
+fn test_symbol_level_keeps_symbol_cycle() {
+    let ctx = analyze(
+        "cycles/simple_cycle",
+        "cyclic_dependency",
+        Some("symbol_level: true"),
+    );
+    assert_eq!(CycleDetector.detect(&ctx).len(), 1);
+}
+
+#[test]
+fn test_dead_symbols_not_transitive_by_default() {
+    let ctx = analyze("dead_code_transitive", "dead_symbols", None);
+    assert_eq!(dead_names(&DeadSymbolsDetector.detect(&ctx)), vec!["dead"]);
+}
+
+#[test]
+fn test_transitive_dead_symbols() {
+    let ctx = analyze(
+        "dead_code_transitive",
+        "dead_symbols",
+        Some("transitive: true"),
+    );
+    let smells = DeadSymbolsDetector.detect(&ctx);
+    assert_eq!(dead_names(&smells), vec!["dead", "helper"]);
+
+    let helper = smells
+        .iter()
+        .find(|s| matches!(&s.smell_type, SmellType::DeadSymbol { name, .. } if name == "helper"))
+        .unwrap();
+    assert!(helper
+        .locations
+        .iter()
+        .any(|l| l.description.contains("only used by dead symbol 'dead'")));
+}
+
+#[test]
+fn test_symbol_usages_collected_only_for_symbol_level_options() {
+    let ids = |id: &str| HashSet::from([id.to_string()]);
+
+    for (id, option) in [
+        ("cyclic_dependency", "symbol_level"),
+        ("dead_symbols", "transitive"),
+    ] {
+        let config = create_config_with_rule(id, None);
+        assert!(!ParserConfig::from_active_detectors(&ids(id), &config).collect_used_symbols);
+
+        let config = create_config_with_rule(id, Some(&format!("{option}: true")));
+        assert!(ParserConfig::from_active_detectors(&ids(id), &config).collect_used_symbols);
+    }
+}
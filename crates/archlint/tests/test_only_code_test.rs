@@ -0,0 +1,101 @@
+mod common;
+
+use archlint::detectors::test_only_code::TestOnlyCodeDetector;
+use archlint::detectors::{ArchSmell, Detector, SmellType};
+use archlint::engine::AnalysisContext;
+use common::{analyze_fixture_with_rule, resolve_import_sources};
+
+fn analyze(options: Option<&str>) -> AnalysisContext {
+    let mut ctx = analyze_fixture_with_rule("test_only", "test_only_code", options);
+    resolve_import_sources(&mut ctx);
+    ctx
+}
+
+fn test_only_modules(smells: &[ArchSmell]) -> Vec<String> {
+    let mut files: Vec<String> = smells
+        .iter()
+        .filter(|s| matches!(s.smell_type, SmellType::TestOnlyCode { symbol: None }))
+        .map(|s| {
+            s.files[0]
+                .file_name()
+                .unwrap()
+                .to_string_lossy()
+                .to_string()
+        })
+        .collect();
+    files.sort();
+    files
+}
+
+fn test_only_symbols(smells: &[ArchSmell]) -> Vec<String> {
+    let mut symbols: Vec<String> = smells
+        .iter()
+        .filter_map(|s| match &s.smell_type {
+            SmellType::TestOnlyCode { symbol } => symbol.clone(),
+            _ => None,
+        })
+        .collect();
+    symbols.sort();
+    symbols
+}
+
+#[test]
+fn test_modules_reachable_only_from_tests() {
+    let ctx = analyze(None);
+    let smells = TestOnlyCodeDetector.detect(&ctx);
+
+    // legacy-helper.ts is reached transitively through legacy.ts; unused.ts is
+    // plain dead code and is left to the dead_code detector
+    assert_eq!(
+        test_only_modules(&smells),
+        vec!["legacy-helper.ts", "legacy.ts"]
+    );
+
+    let legacy = smells
+        .iter()
+        .find(|s| s.files[0].ends_with("legacy.ts"))
+        .unwrap();
+    assert!(legacy
+        .locations
+        .iter()
+        .any(|l| l.file.ends_with("legacy.spec.ts")
+            && l.description == "Used by test src/legacy.spec.ts"));
+}
+
+#[test]
+fn test_exports_used_only_by_tests() {
+    let ctx = analyze(None);
+    let smells = TestOnlyCodeDetector.detect(&ctx);
+
+    assert_eq!(test_only_symbols(&smells), vec!["debugFormat"]);
+
+    let smell = smells
+        .iter()
+        .find(|s| matches!(&s.smell_type, SmellType::TestOnlyCode { symbol: Some(_) }))
+        .unwrap();
+    assert!(smell.files[0].ends_with("format.ts"));
+    assert_eq!(smell.locations[0].line, 5);
+    assert_eq!(
+        smell.locations[1].description,
+        "Imported by test src/format.spec.ts"
+    );
+}
+
+#[test]
+fn test_check_symbols_disabled() {
+    let ctx = analyze(Some("check_symbols: false"));
+    let smells = TestOnlyCodeDetector.detect(&ctx);
+
+    assert!(test_only_symbols(&smells).is_empty());
+    assert_eq!(test_only_modules(&smells).len(), 2);
+}
+
+#[test]
+fn test_custom_test_patterns() {
+    // legacy.spec.ts is no longer a test file, so it keeps nothing alive
+    let ctx = analyze(Some("test_patterns: ['**/format.spec.ts']"));
+    let smells = TestOnlyCodeDetector.detect(&ctx);
+
+    assert!(test_only_modules(&smells).is_empty());
+    assert_eq!(test_only_symbols(&smells), vec!["debugFormat"]);
+}
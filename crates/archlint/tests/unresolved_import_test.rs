@@ -0,0 +1,90 @@
+mod common;
+
+use archlint::detectors::unresolved_import::UnresolvedImportDetector;
+use archlint::detectors::{Detector, DetectorRegistry, SmellType};
+use archlint::engine::AnalysisContext;
+use common::{analyze_fixture_with_config, create_config_with_rule, resolve_import_sources};
+
+fn analyze(options: Option<&str>) -> AnalysisContext {
+    let mut config = create_config_with_rule("unresolved_import", options);
+    config
+        .aliases
+        .insert("@/*".to_string(), "src/*".to_string());
+    let mut ctx = analyze_fixture_with_config("unresolved_imports", config);
+    resolve_import_sources(&mut ctx);
+    ctx
+}
+
+fn unresolved(ctx: &AnalysisContext) -> Vec<(String, String)> {
+    let mut unresolved: Vec<(String, String)> = UnresolvedImportDetector
+        .detect(ctx)
+        .into_iter()
+        .filter_map(|smell| match smell.smell_type {
+            SmellType::UnresolvedImport { specifier, reason } => Some((specifier, reason)),
+            _ => None,
+        })
+        .collect();
+    unresolved.sort();
+    unresolved
+}
+
+#[test]
+fn test_unresolved_imports_reported_with_reason() {
+    let ctx = analyze(None);
+
+    assert_eq!(
+        unresolved(&ctx),
+        vec![
+            ("./missing".to_string(), "no matching file".to_string()),
+            (
+                "./widget".to_string(),
+                "found widget.vue but its extension is not resolved".to_string()
+            ),
+            (
+                "@/ghost".to_string(),
+                "matches alias '@/*' but none of its targets exist".to_string()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_unresolved_import_points_at_its_line() {
+    let ctx = analyze(None);
+    let smells = UnresolvedImportDetector.detect(&ctx);
+
+    let missing = smells
+        .iter()
+        .find(|s| matches!(&s.smell_type, SmellType::UnresolvedImport { specifier, .. } if specifier == "./missing"))
+        .unwrap();
+    assert!(missing.files[0].ends_with("src/main.ts"));
+    assert_eq!(missing.locations[0].line, 2);
+}
+
+#[test]
+fn test_ignore_patterns() {
+    let ctx = analyze(Some("ignore_patterns: ['@/']"));
+
+    let specifiers: Vec<String> = unresolved(&ctx).into_iter().map(|(s, _)| s).collect();
+    assert_eq!(specifiers, vec!["./missing", "./widget"]);
+}
+
+#[test]
+fn test_resolution_stats_count_each_import_once() {
+    let ctx = analyze(None);
+    let stats = ctx.import_diagnostics.stats;
+
+    // `lodash` is external, its two names make a single import
+    assert_eq!(stats.internal_imports, 4);
+    assert_eq!(stats.resolved_imports, 1);
+    assert_eq!(stats.unresolved_imports(), 3);
+    assert!((stats.resolved_percent() - 25.0).abs() < f64::EPSILON);
+}
+
+#[test]
+fn test_unresolved_import_disabled_by_default() {
+    let info = DetectorRegistry::new()
+        .get_info("unresolved_import")
+        .unwrap();
+    assert!(!info.default_enabled);
+}
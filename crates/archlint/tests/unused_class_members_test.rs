@@ -0,0 +1,138 @@
+mod common;
+
+use archlint::config::{RuleConfig, RuleFullConfig};
+use archlint::detectors::unused_class_members::UnusedClassMembersDetector;
+use archlint::detectors::{ArchSmell, Detector, SmellType};
+use archlint::engine::AnalysisContext;
+use common::{analyze_fixture_with_config, create_config_with_rule, resolve_import_sources};
+
+fn analyze(options: Option<&str>, dead_symbols_options: Option<&str>) -> AnalysisContext {
+    let mut config = create_config_with_rule("unused_class_members", options);
+    if let Some(yaml) = dead_symbols_options {
+        config.rules.insert(
+            "dead_symbols".to_string(),
+            RuleConfig::Full(RuleFullConfig {
+                enabled: Some(true),
+                severity: None,
+                exclude: Vec::new(),
+                options: serde_yaml::from_str(yaml).unwrap(),
+            }),
+        );
+    }
+
+    let mut ctx = analyze_fixture_with_config("class_members", config);
+    resolve_import_sources(&mut ctx);
+    ctx
+}
+
+fn members(smells: &[ArchSmell]) -> Vec<(String, String)> {
+    let mut members: Vec<(String, String)> = smells
+        .iter()
+        .filter_map(|s| match &s.smell_type {
+            SmellType::UnusedClassMember {
+                class_name,
+                member,
+                kind,
+            } => Some((format!("{class_name}.{member}"), kind.clone())),
+            _ => None,
+        })
+        .collect();
+    members.sort();
+    members
+}
+
+fn has_member(smells: &[ArchSmell], name: &str) -> bool {
+    members(smells).iter().any(|(m, _)| m == name)
+}
+
+#[test]
+fn test_unused_private_members_detected() {
+    let ctx = analyze(None, None);
+    let smells = UnusedClassMembersDetector.detect(&ctx);
+    let found = members(&smells);
+
+    for (name, kind) in [
+        ("UserService.unusedDep", "Private Field"),
+        ("UserService.unusedField", "Private Field"),
+        ("UserService.unusedHelper", "Private Method"),
+        ("UserService.unusedSecret", "Private Field"),
+    ] {
+        assert!(
+            found.contains(&(name.to_string(), kind.to_string())),
+            "Expected {name} ({kind}) in {found:?}"
+        );
+    }
+
+    // Used via `this.x`, `this.#x` or from an accessor
+    for used in [
+        "UserService.cache",
+        "UserService.repo",
+        "UserService.secret",
+        "UserService.helper",
+        "UserService.size",
+    ] {
+        assert!(!has_member(&smells, used), "{used} should not be reported");
+    }
+
+    let helper = smells
+        .iter()
+        .find(|s| matches!(&s.smell_type, SmellType::UnusedClassMember { member, .. } if member == "unusedHelper"))
+        .unwrap();
+    assert!(helper.files[0].ends_with("service.ts"));
+    assert_eq!(helper.locations[0].line, 36);
+}
+
+#[test]
+fn test_protected_members_checked_across_hierarchy() {
+    let ctx = analyze(None, None);
+    let smells = UnusedClassMembersDetector.detect(&ctx);
+
+    assert!(has_member(&smells, "BaseHandler.unusedProtected"));
+    // `log` is called by the subclass, `handle` by the base class
+    assert!(!has_member(&smells, "BaseHandler.log"));
+    assert!(!has_member(&smells, "BaseHandler.handle"));
+    assert!(!has_member(&smells, "EmailHandler.handle"));
+}
+
+#[test]
+fn test_public_methods_checked_against_importers() {
+    let ctx = analyze(None, None);
+    let smells = UnusedClassMembersDetector.detect(&ctx);
+
+    assert!(has_member(&smells, "UserService.neverCalled"));
+    assert!(!has_member(&smells, "UserService.findAll"));
+    // Called by an importer of the subclass
+    assert!(!has_member(&smells, "BaseHandler.run"));
+    // Members of classes extending external bases may be framework hooks
+    assert!(!has_member(&smells, "Widget.renderContent"));
+    assert!(!has_member(&smells, "Widget.componentDidMount"));
+}
+
+#[test]
+fn test_contract_methods_from_dead_symbols_honored() {
+    let ctx = analyze(None, None);
+    let smells = UnusedClassMembersDetector.detect(&ctx);
+    assert!(has_member(&smells, "AppModule.onModuleInit"));
+
+    let ctx = analyze(
+        None,
+        Some("contract_methods:\n  OnModuleInit: [onModuleInit]"),
+    );
+    let smells = UnusedClassMembersDetector.detect(&ctx);
+    assert!(!has_member(&smells, "AppModule.onModuleInit"));
+}
+
+#[test]
+fn test_options_disable_checks() {
+    let ctx = analyze(
+        Some("check_fields: false\ncheck_protected: false\ncheck_public_methods: false\nignore_methods: [unusedHelper]"),
+        None,
+    );
+    let smells = UnusedClassMembersDetector.detect(&ctx);
+
+    assert!(
+        smells.is_empty(),
+        "Expected no smells, got {:?}",
+        members(&smells)
+    );
+}
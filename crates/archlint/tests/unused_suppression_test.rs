@@ -0,0 +1,174 @@
+mod common;
+
+use archlint::detectors::unused_suppression::UnusedSuppressionDetector;
+use archlint::detectors::{ArchSmell, SmellType};
+use archlint::engine::AnalysisContext;
+use common::{analyze_fixture_with_config, create_config_with_rule, fixture_path};
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+fn analyze() -> AnalysisContext {
+    let config = create_config_with_rule("unused_suppression", None);
+    analyze_fixture_with_config("suppressions", config)
+}
+
+fn service() -> PathBuf {
+    fixture_path("suppressions").join("service.ts")
+}
+
+fn active(ids: &[&str]) -> HashSet<String> {
+    ids.iter().map(ToString::to_string).collect()
+}
+
+fn reported(smells: &[ArchSmell]) -> Vec<(usize, Vec<String>, bool)> {
+    smells
+        .iter()
+        .filter_map(|s| match &s.smell_type {
+            SmellType::UnusedSuppression {
+                rules,
+                missing_reason,
+            } => Some((s.locations[0].line, rules.clone(), *missing_reason)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_reports_suppressions_that_matched_nothing() {
+    let ctx = analyze();
+    // The `long_function` comment on line 1 suppressed a smell
+    let used: HashSet<_> = std::iter::once((service(), 1)).collect();
+    let smells = UnusedSuppressionDetector::find_unused(
+        &ctx,
+        &used,
+        &active(&["long_function", "cyclomatic_complexity"]),
+        false,
+    );
+
+    assert_eq!(
+        reported(&smells),
+        vec![(6, vec!["cyclomatic_complexity".to_string()], false)]
+    );
+}
+
+#[test]
+fn test_skips_rules_that_did_not_run() {
+    let ctx = analyze();
+    let smells = UnusedSuppressionDetector::find_unused(
+        &ctx,
+        &HashSet::new(),
+        &active(&["long_function"]),
+        false,
+    );
+
+    // `layer_violation` and `cyclomatic_complexity` were not active
+    assert_eq!(
+        reported(&smells),
+        vec![(1, vec!["long_function".to_string()], false)]
+    );
+}
+
+#[test]
+fn test_require_reason_reports_comments_without_reason() {
+    let ctx = analyze();
+    let used: HashSet<_> = std::iter::once((service(), 1)).collect();
+    let smells = UnusedSuppressionDetector::find_unused(&ctx, &used, &HashSet::new(), true);
+
+    assert_eq!(
+        reported(&smells),
+        vec![(6, vec!["cyclomatic_complexity".to_string()], true)]
+    );
+}
+
+#[test]
+fn test_every_comment_suppressing_a_smell_is_used() {
+    use archlint::api::{Analyzer, ScanOptions};
+
+    let dir = tempfile::TempDir::new().unwrap();
+    let root = dir.path();
+    std::fs::write(
+        root.join(".archlint.yaml"),
+        "rules:\n  unused_suppression: medium\n  code_clone:\n    min_tokens: 10\n    min_lines: 3\n",
+    )
+    .unwrap();
+    // Both copies of the clone carry a comment for it
+    for file in ["a.ts", "b.ts"] {
+        std::fs::write(
+            root.join(file),
+            "// archlint-disable-next-line code_clone -- kept in sync on purpose\nexport function total(items: number[]) {\n  let sum = 0;\n  for (const item of items) {\n    sum += item * 2;\n  }\n  return sum;\n}\n",
+        )
+        .unwrap();
+    }
+
+    let mut analyzer = Analyzer::new(
+        root,
+        ScanOptions {
+            enable_cache: false,
+            enable_git: false,
+            ..ScanOptions::default()
+        },
+    )
+    .unwrap();
+    let smells: Vec<ArchSmell> = analyzer
+        .scan()
+        .unwrap()
+        .smells
+        .into_iter()
+        .map(|s| s.smell)
+        .collect();
+
+    assert!(!smells
+        .iter()
+        .any(|s| matches!(s.smell_type, SmellType::CodeClone { .. })));
+    assert!(reported(&smells).is_empty());
+}
+
+#[test]
+fn test_incremental_scans_apply_suppressions() {
+    use archlint::api::{Analyzer, ScanOptions};
+
+    let dir = tempfile::TempDir::new().unwrap();
+    let root = dir.path().canonicalize().unwrap();
+    std::fs::write(
+        root.join(".archlint.yaml"),
+        "rules:\n  unused_suppression: medium\n  code_clone:\n    min_tokens: 10\n    min_lines: 3\n",
+    )
+    .unwrap();
+    let code = "// archlint-disable-next-line code_clone -- kept in sync on purpose\nexport function total(items: number[]) {\n  let sum = 0;\n  for (const item of items) {\n    sum += item * 2;\n  }\n  return sum;\n}\n";
+    for file in ["a.ts", "b.ts"] {
+        std::fs::write(root.join(file), code).unwrap();
+    }
+
+    let mut analyzer = Analyzer::new(
+        &root,
+        ScanOptions {
+            enable_cache: false,
+            enable_git: false,
+            ..ScanOptions::default()
+        },
+    )
+    .unwrap();
+    analyzer.scan().unwrap();
+
+    // The new first comment targets another comment, so it hides nothing
+    std::fs::write(
+        root.join("a.ts"),
+        format!("// archlint-disable-next-line code_clone -- stale\n{code}"),
+    )
+    .unwrap();
+    let smells: Vec<ArchSmell> = analyzer
+        .scan_incremental(vec![root.join("a.ts")])
+        .unwrap()
+        .smells
+        .into_iter()
+        .map(|s| s.smell)
+        .collect();
+
+    assert!(!smells
+        .iter()
+        .any(|s| matches!(s.smell_type, SmellType::CodeClone { .. })));
+    assert_eq!(
+        reported(&smells),
+        vec![(1, vec!["code_clone".to_string()], false)]
+    );
+}
@@ -34,6 +34,9 @@ const enSidebar: DefaultTheme.Sidebar = {
         { text: 'Cycle Clusters', link: '/detectors/cycle_clusters' },
         { text: 'Type Cycles', link: '/detectors/circular_type_deps' },
         { text: 'Package Cycles', link: '/detectors/package_cycles' },
+        { text: 'Circular Module Imports', link: '/detectors/circular_module_imports' },
+        { text: 'Provider Outside Module', link: '/detectors/provider_outside_module' },
+        { text: 'Event Cycles', link: '/detectors/event_cycles' },
         { text: 'Layer Violation', link: '/detectors/layer_violation' },
         { text: 'SDP Violation', link: '/detectors/sdp_violation' },
       ]
@@ -53,6 +56,10 @@ const enSidebar: DefaultTheme.Sidebar = {
       items: [
         { text: 'Dead Code', link: '/detectors/dead_code' },
         { text: 'Dead Symbols', link: '/detectors/dead_symbols' },
+        { text: 'Unused Class Members', link: '/detectors/unused_class_members' },
+        { text: 'Test-Only Code', link: '/detectors/test_only_code' },
+        { text: 'Unused Suppressions', link: '/detectors/unused_suppression' },
+        { text: 'Orphan Events', link: '/detectors/orphan_events' },
         { text: 'Orphan Types', link: '/detectors/orphan_types' },
         { text: 'Barrel Abuse', link: '/detectors/barrel_file' },
         { text: 'Primitive Obsession', link: '/detectors/primitive_obsession' },
@@ -66,6 +73,8 @@ const enSidebar: DefaultTheme.Sidebar = {
         { text: 'Deep Nesting', link: '/detectors/deep_nesting' },
         { text: 'Long Parameters', link: '/detectors/long_params' },
         { text: 'Large File', link: '/detectors/large_file' },
+        { text: 'Long Function', link: '/detectors/long_function' },
+        { text: 'Large Class', link: '/detectors/large_class' },
       ]
     },
     {
@@ -79,8 +88,11 @@ const enSidebar: DefaultTheme.Sidebar = {
       text: 'Runtime & Safety',
       items: [
         { text: 'Test Leakage', link: '/detectors/test_leakage' },
+        { text: 'Server Code in Client', link: '/detectors/server_code_in_client' },
         { text: 'Vendor Coupling', link: '/detectors/vendor_coupling' },
+        { text: 'npm Dependencies', link: '/detectors/npm_dependencies' },
         { text: 'Side Effect Import', link: '/detectors/side_effect_import' },
+        { text: 'Unresolved Import', link: '/detectors/unresolved_import' },
         { text: 'Shared Mutable State', link: '/detectors/shared_mutable_state' },
       ]
     },
@@ -102,6 +114,14 @@ const enSidebar: DefaultTheme.Sidebar = {
         { text: 'diff', link: '/cli/diff' },
         { text: 'snapshot', link: '/cli/snapshot' },
         { text: 'watch', link: '/cli/watch' },
+        { text: 'metrics', link: '/cli/metrics' },
+        { text: 'calibrate', link: '/cli/calibrate' },
+        { text: 'modularize', link: '/cli/modularize' },
+        { text: 'injections', link: '/cli/injections' },
+        { text: 'affected', link: '/cli/affected' },
+        { text: 'explain', link: '/cli/explain' },
+        { text: 'cache', link: '/cli/cache' },
+        { text: 'daemon', link: '/cli/daemon' },
       ]
     }
   ],
@@ -164,6 +164,19 @@ export interface JsStateStats {
   graphNodes: number
   graphEdges: number
 }
+export interface JsSymbolRef {
+  file: string
+  name: string
+  kind: string
+  line: number
+}
+export interface JsSymbolUsages {
+  symbol: JsSymbolRef
+  /** Exported symbols referring to the symbol */
+  usedBy: Array<JsSymbolRef>
+  /** Files using the symbol outside of their exported declarations */
+  usedInFiles: Array<string>
+}
 export interface JsDiffOptions {
   baseline: string
   project: string
@@ -247,5 +260,6 @@ export declare class ArchlintAnalyzer {
   rescan(): Promise<JsScanResult>
   rescanSync(): JsScanResult
   getAffectedFiles(changedFiles: Array<string>): Array<string>
+  getSymbolUsages(file: string, name: string): JsSymbolUsages | null
   getStateStats(): JsStateStats
 }